@@ -0,0 +1,110 @@
+//! AASX packages, see
+//! <https://industrialdigitaltwin.io/aas-specifications/IDTA-01005/v3.1/index.html>
+//!
+//! An AASX package is a ZIP file in the Open Packaging Conventions (OPC). Its parts are linked by
+//! relationships: the package root points to the `aasx-origin`, the origin to the spec files with
+//! the environment in JSON or XML, and the spec files to their supplementary files, like the
+//! documents referenced by File elements. The thumbnail of the package is linked from the root.
+//!
+//! Parts are addressed by their part name, the absolute path inside the package, e.g.
+//! `/aasx/files/manual.pdf`, which is also what `File.value` and `Resource.path` contain.
+//! [AasxReader] reads packages and [AasxWriter] writes them.
+
+mod opc;
+mod reader;
+mod source;
+mod writer;
+
+pub use reader::AasxReader;
+pub use source::{Directory, FileSource};
+pub use writer::AasxWriter;
+
+use aas::part1::v3_1::core::AssetAdministrationShell;
+use aas::part1::v3_1::environment::Environment;
+use aas::part1::v3_1::id_short_path::ElementRef;
+use aas::part1::v3_1::primitives::Uri;
+use aas::part1::v3_1::submodel_elements::{DataElement, SubmodelElement};
+use aas::part1::v3_1::visitor::{Flow, Location, Visitor};
+use aas_convert::ConvertError;
+use thiserror::Error;
+
+/// Relationship from the package root to the origin part
+pub const ORIGIN_RELATIONSHIP: &str = "http://admin-shell.io/aasx/relationships/aasx-origin";
+
+/// Relationship from the origin to a spec file
+pub const SPEC_RELATIONSHIP: &str = "http://admin-shell.io/aasx/relationships/aas-spec";
+
+/// Relationship from a spec file to a file it references
+pub const SUPPLEMENTARY_RELATIONSHIP: &str = "http://admin-shell.io/aasx/relationships/aas-suppl";
+
+/// Relationship from the package root to its thumbnail
+pub const THUMBNAIL_RELATIONSHIP: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail";
+
+#[derive(Debug, Error)]
+pub enum AasxError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("zip error: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("invalid relationships or content types in '{part}': {source}")]
+    Opc {
+        part: String,
+        source: quick_xml::DeError,
+    },
+
+    #[error("xml serialisation error: {0}")]
+    Serialize(#[from] quick_xml::SeError),
+
+    #[error("the package has no aasx-origin relationship")]
+    MissingOrigin,
+
+    #[error("part '{0}' is missing in the package")]
+    MissingPart(String),
+
+    #[error("file '{path}' could not be read: {source}")]
+    FileSource {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("spec file '{part}' is invalid: {source}")]
+    Spec { part: String, source: ConvertError },
+}
+
+/// Paths of the files an environment references, the values of File elements and the default
+/// thumbnails of the shells. Files outside the package, e.g. with an `http` scheme, are included
+/// too, see [is_part_name].
+pub fn referenced_files(environment: &Environment) -> Vec<&Uri> {
+    struct Files<'a>(Vec<&'a Uri>);
+
+    impl<'a> Visitor<'a> for Files<'a> {
+        fn visit_shell(&mut self, shell: &'a AssetAdministrationShell) -> Flow {
+            if let Some(thumbnail) = &shell.asset_information.default_thumbnail {
+                self.0.push(&thumbnail.path);
+            }
+            Flow::Continue
+        }
+
+        fn visit_element(&mut self, element: ElementRef<'a>, _location: &Location) -> Flow {
+            let file = match element {
+                ElementRef::SubmodelElement(SubmodelElement::File(file)) => file,
+                ElementRef::Annotation(DataElement::File(file)) => file,
+                _ => return Flow::Continue,
+            };
+            self.0.extend(&file.value);
+            Flow::Continue
+        }
+    }
+
+    let mut files = Files(Vec::new());
+    environment.walk(&mut files);
+    files.0
+}
+
+/// Whether the path addresses a part of the package, i.e. it has no scheme and authority.
+pub fn is_part_name(path: &Uri) -> bool {
+    path.scheme().is_none() && path.authority().is_none()
+}
@@ -0,0 +1,254 @@
+//! Relationships and content types of the Open Packaging Conventions, ECMA-376 Part 2.
+
+use serde::{Deserialize, Serialize};
+
+pub(crate) const RELATIONSHIPS_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships";
+
+pub(crate) const CONTENT_TYPES_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/package/2006/content-types";
+
+pub(crate) const RELATIONSHIPS_CONTENT_TYPE: &str =
+    "application/vnd.openxmlformats-package.relationships+xml";
+
+/// Part name of the content types, it is no part itself
+pub(crate) const CONTENT_TYPES: &str = "/[Content_Types].xml";
+
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct Relationships {
+    #[serde(rename = "@xmlns", default)]
+    pub xmlns: String,
+
+    #[serde(rename = "Relationship", default)]
+    pub relationships: Vec<Relationship>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Relationship {
+    #[serde(rename = "@Type")]
+    pub ty: String,
+
+    #[serde(rename = "@Target")]
+    pub target: String,
+
+    #[serde(rename = "@Id")]
+    pub id: String,
+
+    /// `External` for targets outside the package
+    #[serde(
+        rename = "@TargetMode",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_mode: Option<String>,
+}
+
+impl Relationships {
+    /// Relationships to the internal targets, with the ids `R1`, `R2`, ...
+    pub fn new<'a>(relationships: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        Self {
+            xmlns: RELATIONSHIPS_NAMESPACE.to_string(),
+            relationships: relationships
+                .into_iter()
+                .enumerate()
+                .map(|(i, (ty, target))| Relationship {
+                    ty: ty.to_string(),
+                    target: target.to_string(),
+                    id: format!("R{}", i + 1),
+                    target_mode: None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Part names of the internal targets with the relationship type, relative to the source part
+    pub fn targets<'a>(
+        &'a self,
+        source: &'a str,
+        ty: &'a str,
+    ) -> impl Iterator<Item = String> + 'a {
+        self.relationships
+            .iter()
+            .filter(move |relationship| {
+                // packages of version 2 used the www subdomain
+                relationship.ty.replacen("://www.", "://", 1) == ty
+                    && relationship.target_mode.as_deref() != Some("External")
+            })
+            .map(move |relationship| resolve(source, &relationship.target))
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename = "Types")]
+pub(crate) struct ContentTypes {
+    #[serde(rename = "@xmlns", default)]
+    pub xmlns: String,
+
+    #[serde(rename = "$value", default)]
+    pub entries: Vec<ContentType>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) enum ContentType {
+    Default {
+        #[serde(rename = "@Extension")]
+        extension: String,
+
+        #[serde(rename = "@ContentType")]
+        content_type: String,
+    },
+    Override {
+        #[serde(rename = "@PartName")]
+        part_name: String,
+
+        #[serde(rename = "@ContentType")]
+        content_type: String,
+    },
+}
+
+impl ContentTypes {
+    /// The content type of a part: an override for the part name, or else the default for its
+    /// extension. Both are compared case-insensitively.
+    pub fn get(&self, part: &str) -> Option<&str> {
+        let name = part.rsplit('/').next().unwrap_or(part);
+        let extension = name.rsplit_once('.').map(|(_, extension)| extension);
+        let overridden = self.entries.iter().find_map(|entry| match entry {
+            ContentType::Override {
+                part_name,
+                content_type,
+            } if part_name.eq_ignore_ascii_case(part) => Some(content_type.as_str()),
+            _ => None,
+        });
+        overridden.or_else(|| {
+            self.entries.iter().find_map(|entry| match entry {
+                ContentType::Default {
+                    extension: default,
+                    content_type,
+                } if extension.is_some_and(|extension| default.eq_ignore_ascii_case(extension)) => {
+                    Some(content_type.as_str())
+                }
+                _ => None,
+            })
+        })
+    }
+}
+
+/// Part name of the relationships of a part, `/` for the package root, e.g.
+/// `/aasx/aasx-origin` has its relationships in `/aasx/_rels/aasx-origin.rels`.
+pub(crate) fn relationships_part(part: &str) -> String {
+    let (directory, name) = part.rsplit_once('/').unwrap_or(("", part));
+    format!("{directory}/_rels/{name}.rels")
+}
+
+/// Part name of a relationship target, which is either absolute or relative to the directory of
+/// the source part.
+pub(crate) fn resolve(source: &str, target: &str) -> String {
+    let mut segments: Vec<&str> = match target.starts_with('/') {
+        true => Vec::new(),
+        false => source.split('/').filter(|s| !s.is_empty()).collect(),
+    };
+    // the source part name itself, the package root has none
+    if !target.starts_with('/') && !source.ends_with('/') {
+        segments.pop();
+    }
+    for segment in target.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/// Decodes the percent-encoded characters of a part name, e.g. `/aasx/files/My%20Manual.pdf`
+/// is stored in the entry `aasx/files/My Manual.pdf`. Invalid escapes are kept as written.
+pub(crate) fn percent_decode(part: &str) -> String {
+    let bytes = part.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(decoded).unwrap_or_else(|_| part.to_string())
+}
+
+/// Name of the ZIP entry of a part, part names start with `/`, entry names don't.
+pub(crate) fn entry_name(part: &str) -> &str {
+    part.strip_prefix('/').unwrap_or(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_names() {
+        assert_eq!(relationships_part("/"), "/_rels/.rels");
+        assert_eq!(
+            relationships_part("/aasx/aasx-origin"),
+            "/aasx/_rels/aasx-origin.rels"
+        );
+        assert_eq!(resolve("/", "aasx/aasx-origin"), "/aasx/aasx-origin");
+        assert_eq!(
+            resolve("/aasx/aasx-origin", "/aasx/xml/content.xml"),
+            "/aasx/xml/content.xml"
+        );
+        assert_eq!(
+            resolve("/aasx/xml/content.xml", "../files/manual.pdf"),
+            "/aasx/files/manual.pdf"
+        );
+        assert_eq!(
+            resolve("/aasx/xml/content.xml", "./image.png"),
+            "/aasx/xml/image.png"
+        );
+    }
+
+    #[test]
+    fn percent_decoding() {
+        assert_eq!(
+            percent_decode("/aasx/files/My%20Manual.pdf"),
+            "/aasx/files/My Manual.pdf"
+        );
+        assert_eq!(
+            percent_decode("/aasx/files/%C3%9Cbersicht.pdf"),
+            "/aasx/files/Übersicht.pdf"
+        );
+        assert_eq!(
+            percent_decode("/aasx/files/100%.pdf"),
+            "/aasx/files/100%.pdf"
+        );
+        assert_eq!(percent_decode("/aasx/files/%zz%2"), "/aasx/files/%zz%2");
+        assert_eq!(percent_decode("/aasx/files/%FF.pdf"), "/aasx/files/%FF.pdf");
+    }
+
+    #[test]
+    fn content_types() {
+        let content_types: ContentTypes = quick_xml::de::from_str(
+            r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+                <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+                <Default Extension="XML" ContentType="text/xml"/>
+                <Override PartName="/aasx/aasx-origin" ContentType="text/plain"/>
+            </Types>"#,
+        )
+        .unwrap();
+        assert_eq!(content_types.get("/aasx/aasx-origin"), Some("text/plain"));
+        assert_eq!(content_types.get("/aasx/xml/content.xml"), Some("text/xml"));
+        assert_eq!(content_types.get("/aasx/files/manual.pdf"), None);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::opc::{
+    CONTENT_TYPES, ContentTypes, Relationships, entry_name, percent_decode, relationships_part,
+    resolve,
+};
+use crate::{
+    AasxError, ORIGIN_RELATIONSHIP, SPEC_RELATIONSHIP, SUPPLEMENTARY_RELATIONSHIP,
+    THUMBNAIL_RELATIONSHIP,
+};
+use aas::part1::v3_1::environment::Environment;
+use aas_convert::Format;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+/// Reads an AASX package. Opening a package only reads its relationships, the spec files and
+/// supplementary files are read on demand.
+///
+/// ```no_run
+/// use aas_aasx::AasxReader;
+///
+/// let mut package = AasxReader::open("example.aasx").unwrap();
+/// let environment = package.environment().unwrap();
+/// for file in aas_aasx::referenced_files(&environment) {
+///     let bytes = package.read(file.as_str()).unwrap();
+/// }
+/// ```
+pub struct AasxReader<R> {
+    archive: ZipArchive<R>,
+    content_types: ContentTypes,
+    specs: Vec<String>,
+    supplementary_files: Vec<String>,
+    thumbnail: Option<String>,
+}
+
+impl AasxReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, AasxError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> AasxReader<R> {
+    pub fn new(reader: R) -> Result<Self, AasxError> {
+        let mut archive = ZipArchive::new(reader)?;
+
+        let root: Relationships = read_xml(&mut archive, &relationships_part("/"))?;
+        let origin = root
+            .targets("/", ORIGIN_RELATIONSHIP)
+            .next()
+            .ok_or(AasxError::MissingOrigin)?;
+        let thumbnail = root.targets("/", THUMBNAIL_RELATIONSHIP).next();
+
+        let specs: Vec<String> =
+            read_xml::<_, Relationships>(&mut archive, &relationships_part(&origin))?
+                .targets(&origin, SPEC_RELATIONSHIP)
+                .collect();
+
+        let mut supplementary_files = Vec::new();
+        for spec in &specs {
+            let relationships: Relationships = read_xml(&mut archive, &relationships_part(spec))?;
+            for file in relationships.targets(spec, SUPPLEMENTARY_RELATIONSHIP) {
+                if !supplementary_files.contains(&file) {
+                    supplementary_files.push(file);
+                }
+            }
+        }
+
+        Ok(Self {
+            content_types: read_xml(&mut archive, CONTENT_TYPES)?,
+            archive,
+            specs,
+            supplementary_files,
+            thumbnail,
+        })
+    }
+
+    /// Part names of the spec files
+    pub fn specs(&self) -> &[String] {
+        &self.specs
+    }
+
+    /// Part names of the supplementary files of all spec files
+    pub fn supplementary_files(&self) -> &[String] {
+        &self.supplementary_files
+    }
+
+    /// Part name of the thumbnail of the package
+    pub fn thumbnail(&self) -> Option<&str> {
+        self.thumbnail.as_deref()
+    }
+
+    /// The content type of a part as declared in the package
+    pub fn content_type(&self, path: &str) -> Option<&str> {
+        self.content_types.get(&resolve("/", path))
+    }
+
+    /// Reads all spec files into one environment. The format of each file is taken from its
+    /// extension, or else detected from its content.
+    pub fn environment(&mut self) -> Result<Environment, AasxError> {
+        let mut environment = Environment {
+            asset_administration_shells: None,
+            submodels: None,
+            concept_descriptions: None,
+        };
+        for spec in self.specs.clone() {
+            let bytes = self.read(&spec)?;
+            let content = String::from_utf8_lossy(&bytes);
+            let format = match spec.rsplit_once('.').map(|(_, extension)| extension) {
+                Some(extension) if extension.eq_ignore_ascii_case("json") => Some(Format::Json),
+                Some(extension) if extension.eq_ignore_ascii_case("xml") => Some(Format::Xml),
+                _ => Format::detect(&content),
+            };
+            let read = format
+                .ok_or(aas_convert::ConvertError::UnknownFormat)
+                .and_then(|format| aas_convert::read::<Environment>(&content, format))
+                .map_err(|source| AasxError::Spec {
+                    part: spec.clone(),
+                    source,
+                })?;
+            merge(&mut environment, read);
+        }
+        Ok(environment)
+    }
+
+    /// Opens a part for reading. The path is a part name, or a path relative to the package
+    /// root, like the values of File elements and thumbnails. Percent-encoded characters are
+    /// decoded, packages that store the entries encoded are read as well.
+    pub fn reader(&mut self, path: &str) -> Result<impl Read + '_, AasxError> {
+        let part = resolve("/", path);
+        let index = [percent_decode(&part), part.clone()]
+            .iter()
+            .find_map(|name| self.archive.index_for_name(entry_name(name)));
+        match index {
+            Some(index) => Ok(self.archive.by_index(index)?),
+            None => Err(AasxError::MissingPart(part)),
+        }
+    }
+
+    /// Reads a part, see [AasxReader::reader].
+    pub fn read(&mut self, path: &str) -> Result<Vec<u8>, AasxError> {
+        let mut bytes = Vec::new();
+        self.reader(path)?.read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Reads an XML part, a missing part is read as default, e.g. no relationships.
+fn read_xml<R: Read + Seek, T: DeserializeOwned + Default>(
+    archive: &mut ZipArchive<R>,
+    part: &str,
+) -> Result<T, AasxError> {
+    let mut xml = String::new();
+    match archive.by_name(entry_name(part)) {
+        Ok(mut file) => file.read_to_string(&mut xml)?,
+        Err(ZipError::FileNotFound) => return Ok(T::default()),
+        Err(e) => return Err(e.into()),
+    };
+    quick_xml::de::from_str(xml.trim_start_matches('\u{feff}')).map_err(|source| AasxError::Opc {
+        part: part.to_string(),
+        source,
+    })
+}
+
+fn merge(environment: &mut Environment, other: Environment) {
+    fn extend<T>(list: &mut Option<Vec<T>>, other: Option<Vec<T>>) {
+        if let Some(other) = other {
+            list.get_or_insert_default().extend(other);
+        }
+    }
+
+    extend(
+        &mut environment.asset_administration_shells,
+        other.asset_administration_shells,
+    );
+    extend(&mut environment.submodels, other.submodels);
+    extend(
+        &mut environment.concept_descriptions,
+        other.concept_descriptions,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::referenced_files;
+    use aas_xml::Xml;
+    use std::io::{Cursor, Write};
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    /// A package as written by the AASX Package Explorer, with the spec relationships in the
+    /// namespace of version 2.
+    fn sample() -> Vec<u8> {
+        let xml = include_str!("../../aas/tests/mvp-dpp-1.0.0.xml");
+        let parts = [
+            (
+                "[Content_Types].xml",
+                r#"<?xml version="1.0" encoding="utf-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="xml" ContentType="text/xml" /><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml" /><Default Extension="png" ContentType="image/png" /><Override PartName="/aasx/aasx-origin" ContentType="text/plain" /></Types>"#.as_bytes(),
+            ),
+            (
+                "_rels/.rels",
+                r#"<?xml version="1.0" encoding="utf-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Type="http://www.admin-shell.io/aasx/relationships/aasx-origin" Target="/aasx/aasx-origin" Id="R1" /><Relationship Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail" Target="/aasx/files/turtle_dpp_thumbnail.jpg" Id="R2" /></Relationships>"#.as_bytes(),
+            ),
+            ("aasx/aasx-origin", b"Intentionally empty.".as_slice()),
+            (
+                "aasx/_rels/aasx-origin.rels",
+                r#"<?xml version="1.0" encoding="utf-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Type="http://www.admin-shell.io/aasx/relationships/aas-spec" Target="/aasx/xml/content.xml" Id="R3" /></Relationships>"#.as_bytes(),
+            ),
+            (
+                "aasx/xml/_rels/content.xml.rels",
+                r#"<?xml version="1.0" encoding="utf-8"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Type="http://www.admin-shell.io/aasx/relationships/aas-suppl" Target="../files/Logo_IOSB-INA.png" Id="R4" /><Relationship Type="http://www.admin-shell.io/aasx/relationships/aas-suppl" Target="https://example.com/manual.pdf" TargetMode="External" Id="R5" /></Relationships>"#.as_bytes(),
+            ),
+            ("aasx/xml/content.xml", xml.as_bytes()),
+            ("aasx/files/Logo_IOSB-INA.png", b"png"),
+            ("aasx/files/turtle_dpp_thumbnail.jpg", b"jpg"),
+            ("aasx/files/Safety Manual.pdf", b"pdf"),
+            ("aasx/files/100%25.pdf", b"encoded"),
+        ];
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in parts {
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn reads_package() {
+        let mut package = AasxReader::new(Cursor::new(sample())).unwrap();
+        assert_eq!(package.specs(), ["/aasx/xml/content.xml"]);
+        assert_eq!(
+            package.supplementary_files(),
+            ["/aasx/files/Logo_IOSB-INA.png"]
+        );
+        assert_eq!(
+            package.thumbnail(),
+            Some("/aasx/files/turtle_dpp_thumbnail.jpg")
+        );
+
+        let environment = package.environment().unwrap();
+        let expected =
+            Environment::from_xml(include_str!("../../aas/tests/mvp-dpp-1.0.0.xml")).unwrap();
+        assert_eq!(environment, expected);
+
+        let files = referenced_files(&environment);
+        assert!(
+            files
+                .iter()
+                .any(|file| file.as_str() == "/aasx/files/Logo_IOSB-INA.png")
+        );
+        assert_eq!(package.read(files[0].as_str()).unwrap(), b"jpg");
+        assert_eq!(
+            package.read("aasx/files/Logo_IOSB-INA.png").unwrap(),
+            b"png"
+        );
+        assert_eq!(
+            package.content_type("/aasx/files/Logo_IOSB-INA.png"),
+            Some("image/png")
+        );
+        assert_eq!(
+            package.read("/aasx/files/Safety%20Manual.pdf").unwrap(),
+            b"pdf"
+        );
+        assert_eq!(package.read("/aasx/files/100%25.pdf").unwrap(), b"encoded");
+        assert!(matches!(
+            package.read("/aasx/files/missing.pdf"),
+            Err(AasxError::MissingPart(part)) if part == "/aasx/files/missing.pdf"
+        ));
+    }
+
+    #[test]
+    fn requires_origin() {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("aasx/xml/content.xml", SimpleFileOptions::default())
+            .unwrap();
+        let package = zip.finish().unwrap();
+        assert!(matches!(
+            AasxReader::new(package),
+            Err(AasxError::MissingOrigin)
+        ));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{AasxError, AasxReader};
+use std::collections::HashMap;
+use std::io;
+use std::io::{Read, Seek};
+use std::path::{Component, Path, PathBuf};
+
+/// Provides the files an environment references, to embed them into a package.
+pub trait FileSource {
+    /// The content of the file at the path of a File element or thumbnail, `None` if the source
+    /// does not have it. The reference is then written unchanged.
+    fn file(&mut self, path: &str) -> io::Result<Option<Vec<u8>>>;
+}
+
+/// Files by their path as referenced in the environment
+impl FileSource for HashMap<String, Vec<u8>> {
+    fn file(&mut self, path: &str) -> io::Result<Option<Vec<u8>>> {
+        Ok(self.get(path).cloned())
+    }
+}
+
+/// Repackages the supplementary files of another package
+impl<R: Read + Seek> FileSource for AasxReader<R> {
+    fn file(&mut self, path: &str) -> io::Result<Option<Vec<u8>>> {
+        match self.read(path) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(AasxError::MissingPart(_)) => Ok(None),
+            Err(AasxError::Io(e)) => Err(e),
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+}
+
+/// Files in a directory, paths are relative to it. Paths leaving the directory and URLs are
+/// not read.
+pub struct Directory(pub PathBuf);
+
+impl FileSource for Directory {
+    fn file(&mut self, path: &str) -> io::Result<Option<Vec<u8>>> {
+        let relative = Path::new(path.trim_start_matches('/'));
+        if path.contains(':')
+            || !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        {
+            return Ok(None);
+        }
+        match std::fs::read(self.0.join(relative)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use crate::opc::{
+    CONTENT_TYPES, CONTENT_TYPES_NAMESPACE, ContentType, ContentTypes, RELATIONSHIPS_CONTENT_TYPE,
+    Relationships, entry_name, relationships_part,
+};
+use crate::source::FileSource;
+use crate::{
+    AasxError, ORIGIN_RELATIONSHIP, SPEC_RELATIONSHIP, SUPPLEMENTARY_RELATIONSHIP,
+    THUMBNAIL_RELATIONSHIP,
+};
+use aas::part1::v3_1::core::AssetAdministrationShell;
+use aas::part1::v3_1::environment::Environment;
+use aas::part1::v3_1::id_short_path::ElementMut;
+use aas::part1::v3_1::primitives::Uri;
+use aas::part1::v3_1::submodel_elements::{DataElement, SubmodelElement};
+use aas::part1::v3_1::visitor::{Flow, Location, VisitorMut};
+use aas_convert::Format;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{Seek, Write};
+use std::str::FromStr;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+const ORIGIN: &str = "/aasx/aasx-origin";
+
+/// Directory of the supplementary files
+const FILES: &str = "/aasx/files";
+
+/// Writes an environment as AASX package in the layout of the AASX Package Explorer.
+///
+/// The files referenced by File elements and default thumbnails are embedded from a
+/// [FileSource] into `/aasx/files`, and the references are rewritten to their part names.
+/// The default thumbnail of the first shell becomes the thumbnail of the package, it is only a
+/// supplementary file if a File element references it as well.
+///
+/// ```
+/// use aas::part1::v3_1::environment::Environment;
+/// use aas_aasx::{AasxReader, AasxWriter};
+/// use aas_convert::Format;
+/// use std::collections::HashMap;
+/// use std::io::Cursor;
+///
+/// let environment = Environment::builder().build().unwrap();
+/// let package = AasxWriter::new(Format::Json)
+///     .write(&environment, &mut HashMap::new(), Cursor::new(Vec::new()))
+///     .unwrap();
+/// let mut package = AasxReader::new(package).unwrap();
+/// assert_eq!(package.specs(), ["/aasx/json/content.json"]);
+/// assert_eq!(package.environment().unwrap(), environment);
+/// ```
+pub struct AasxWriter {
+    format: Format,
+}
+
+impl AasxWriter {
+    /// A writer of packages with the spec in the given format
+    pub fn new(format: Format) -> Self {
+        Self { format }
+    }
+
+    /// Writes the package and returns the writer after finishing the ZIP file.
+    pub fn write<W: Write + Seek>(
+        &self,
+        environment: &Environment,
+        files: &mut impl FileSource,
+        writer: W,
+    ) -> Result<W, AasxError> {
+        let mut environment = environment.clone();
+        let mut embed = Embed {
+            source: files,
+            parts: HashMap::new(),
+            files: Vec::new(),
+            thumbnail: None,
+            error: None,
+        };
+        environment.walk_mut(&mut embed);
+        if let Some(error) = embed.error {
+            return Err(error);
+        }
+
+        let spec = match self.format {
+            Format::Json => "/aasx/json/content.json",
+            Format::Xml => "/aasx/xml/content.xml",
+        };
+        let content =
+            aas_convert::write(&environment, self.format).map_err(|source| AasxError::Spec {
+                part: spec.to_string(),
+                source,
+            })?;
+
+        let mut content_types = vec![
+            ContentType::Default {
+                extension: "rels".to_string(),
+                content_type: RELATIONSHIPS_CONTENT_TYPE.to_string(),
+            },
+            ContentType::Default {
+                extension: "xml".to_string(),
+                content_type: "text/xml".to_string(),
+            },
+            ContentType::Default {
+                extension: "json".to_string(),
+                content_type: "application/json".to_string(),
+            },
+            ContentType::Override {
+                part_name: ORIGIN.to_string(),
+                content_type: "text/plain".to_string(),
+            },
+        ];
+        content_types.extend(embed.files.iter().map(|file| ContentType::Override {
+            part_name: file.part.clone(),
+            content_type: file.content_type.clone(),
+        }));
+
+        let mut root = vec![(ORIGIN_RELATIONSHIP, ORIGIN)];
+        root.extend(
+            embed
+                .thumbnail
+                .as_deref()
+                .map(|thumbnail| (THUMBNAIL_RELATIONSHIP, thumbnail)),
+        );
+
+        let mut zip = ZipWriter::new(writer);
+        let mut part = |name: &str, content: &[u8]| -> Result<(), AasxError> {
+            zip.start_file(entry_name(name), SimpleFileOptions::default())?;
+            zip.write_all(content)?;
+            Ok(())
+        };
+        part(
+            CONTENT_TYPES,
+            xml(&ContentTypes {
+                xmlns: CONTENT_TYPES_NAMESPACE.to_string(),
+                entries: content_types,
+            })?
+            .as_bytes(),
+        )?;
+        part(
+            &relationships_part("/"),
+            xml(&Relationships::new(root))?.as_bytes(),
+        )?;
+        part(ORIGIN, b"Intentionally empty.")?;
+        part(
+            &relationships_part(ORIGIN),
+            xml(&Relationships::new([(SPEC_RELATIONSHIP, spec)]))?.as_bytes(),
+        )?;
+        part(spec, content.as_bytes())?;
+        let supplementary: Vec<_> = embed
+            .files
+            .iter()
+            .filter(|file| file.supplementary || embed.thumbnail.as_ref() != Some(&file.part))
+            .map(|file| (SUPPLEMENTARY_RELATIONSHIP, file.part.as_str()))
+            .collect();
+        if !supplementary.is_empty() {
+            part(
+                &relationships_part(spec),
+                xml(&Relationships::new(supplementary))?.as_bytes(),
+            )?;
+        }
+        for file in &embed.files {
+            part(&file.part, &file.content)?;
+        }
+        Ok(zip.finish()?)
+    }
+}
+
+fn xml(value: &impl Serialize) -> Result<String, AasxError> {
+    let xml = quick_xml::se::to_string(value)?;
+    Ok(format!(r#"<?xml version="1.0" encoding="utf-8"?>{xml}"#))
+}
+
+struct EmbeddedFile {
+    part: String,
+    content: Vec<u8>,
+    content_type: String,
+
+    /// Whether a File element references it, thumbnails are not supplementary files
+    supplementary: bool,
+}
+
+/// Embeds the referenced files and rewrites the references
+struct Embed<'s, S> {
+    source: &'s mut S,
+
+    /// Part names by the original paths, `None` for files the source does not have
+    parts: HashMap<String, Option<String>>,
+
+    files: Vec<EmbeddedFile>,
+    thumbnail: Option<String>,
+    error: Option<AasxError>,
+}
+
+impl<S: FileSource> Embed<'_, S> {
+    /// Embeds the file once and rewrites the path to its part name
+    fn embed(&mut self, path: &mut Uri, content_type: Option<&str>, supplementary: bool) -> Flow {
+        let original = path.to_string();
+        if !self.parts.contains_key(&original) {
+            let part = match self.source.file(&original) {
+                Ok(content) => content.map(|content| {
+                    let part = self.part_name(&original);
+                    self.files.push(EmbeddedFile {
+                        part: part.clone(),
+                        content,
+                        content_type: content_type
+                            .unwrap_or("application/octet-stream")
+                            .to_string(),
+                        supplementary,
+                    });
+                    part
+                }),
+                Err(e) => {
+                    self.error = Some(AasxError::FileSource {
+                        path: original,
+                        source: e,
+                    });
+                    return Flow::Stop;
+                }
+            };
+            self.parts.insert(original.clone(), part);
+        }
+        if let Some(Some(part)) = self.parts.get(&original) {
+            if supplementary && let Some(file) = self.files.iter_mut().find(|f| &f.part == part) {
+                file.supplementary = true;
+            }
+            // part names are built from segments of a valid URI
+            *path = Uri::from_str(part).expect("part names are valid URIs");
+        }
+        Flow::Continue
+    }
+
+    /// `/aasx/files/` and the file name of the path, numbered if it is taken
+    fn part_name(&self, path: &str) -> String {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let name = path
+            .rsplit(['/', '\\'])
+            .next()
+            .filter(|name| !name.is_empty())
+            .unwrap_or("file");
+        let (stem, extension) = match name.rsplit_once('.') {
+            Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{extension}")),
+            _ => (name, String::new()),
+        };
+        (0..)
+            .map(|i| match i {
+                0 => format!("{FILES}/{stem}{extension}"),
+                i => format!("{FILES}/{stem}-{i}{extension}"),
+            })
+            .find(|part| {
+                !self
+                    .files
+                    .iter()
+                    .any(|file| file.part.eq_ignore_ascii_case(part))
+            })
+            .expect("there are infinitely many names")
+    }
+}
+
+impl<S: FileSource> VisitorMut for Embed<'_, S> {
+    fn visit_shell(&mut self, shell: &mut AssetAdministrationShell) -> Flow {
+        let Some(thumbnail) = &mut shell.asset_information.default_thumbnail else {
+            return Flow::Continue;
+        };
+        let flow = self.embed(
+            &mut thumbnail.path,
+            thumbnail.content_type.as_deref(),
+            false,
+        );
+        let part = thumbnail.path.as_str();
+        if self.thumbnail.is_none() && self.files.iter().any(|file| file.part == part) {
+            self.thumbnail = Some(part.to_string());
+        }
+        flow
+    }
+
+    fn visit_element(&mut self, element: ElementMut<'_>, _location: &Location) -> Flow {
+        let file = match element {
+            ElementMut::SubmodelElement(SubmodelElement::File(file)) => file,
+            ElementMut::Annotation(DataElement::File(file)) => file,
+            _ => return Flow::Continue,
+        };
+        match &mut file.value {
+            Some(value) => self.embed(value, file.content_type.as_deref(), true),
+            None => Flow::Continue,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AasxReader;
+    use crate::source::Directory;
+    use aas_xml::Xml;
+    use std::io::Cursor;
+
+    fn environment() -> Environment {
+        Environment::from_xml(include_str!("../../aas/tests/mvp-dpp-1.0.0.xml")).unwrap()
+    }
+
+    #[test]
+    fn writes_package() {
+        let environment = environment();
+        let mut files = HashMap::from([
+            (
+                "/aasx/files/turtle_dpp_thumbnail.jpg".to_string(),
+                b"jpg".to_vec(),
+            ),
+            ("/aasx/files/Logo_IOSB-INA.png".to_string(), b"png".to_vec()),
+        ]);
+        for format in [Format::Json, Format::Xml] {
+            let package = AasxWriter::new(format)
+                .write(&environment, &mut files, Cursor::new(Vec::new()))
+                .unwrap();
+
+            let mut package = AasxReader::new(package).unwrap();
+            assert_eq!(package.environment().unwrap(), environment);
+            assert_eq!(
+                package.thumbnail(),
+                Some("/aasx/files/turtle_dpp_thumbnail.jpg")
+            );
+            assert_eq!(
+                package.supplementary_files(),
+                ["/aasx/files/Logo_IOSB-INA.png"]
+            );
+            assert_eq!(
+                package.content_type("/aasx/files/Logo_IOSB-INA.png"),
+                Some("image/png")
+            );
+            assert_eq!(
+                package.content_type("/aasx/aasx-origin"),
+                Some("text/plain")
+            );
+            assert_eq!(
+                package.read("/aasx/files/Logo_IOSB-INA.png").unwrap(),
+                b"png"
+            );
+
+            // repackaging keeps the files
+            let repackaged = AasxWriter::new(Format::Xml)
+                .write(&environment, &mut package, Cursor::new(Vec::new()))
+                .unwrap();
+            let mut repackaged = AasxReader::new(repackaged).unwrap();
+            assert_eq!(
+                repackaged
+                    .read("/aasx/files/turtle_dpp_thumbnail.jpg")
+                    .unwrap(),
+                b"jpg"
+            );
+        }
+    }
+
+    #[test]
+    fn rewrites_paths() {
+        let xml = r#"<environment xmlns="https://admin-shell.io/aas/3/1"><submodels><submodel><id>urn:example:1</id><submodelElements>
+            <file><idShort>Manual</idShort><value>docs/manual.pdf</value><contentType>application/pdf</contentType></file>
+            <file><idShort>Other</idShort><value>other/manual.pdf</value><contentType>application/pdf</contentType></file>
+            <file><idShort>Again</idShort><value>docs/manual.pdf</value><contentType>application/pdf</contentType></file>
+            <file><idShort>Web</idShort><value>https://example.com/manual.pdf</value><contentType>application/pdf</contentType></file>
+        </submodelElements></submodel></submodels></environment>"#;
+        let environment = Environment::from_xml(xml).unwrap();
+
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(directory.path().join("docs")).unwrap();
+        std::fs::create_dir_all(directory.path().join("other")).unwrap();
+        std::fs::write(directory.path().join("docs/manual.pdf"), b"docs").unwrap();
+        std::fs::write(directory.path().join("other/manual.pdf"), b"other").unwrap();
+
+        let package = AasxWriter::new(Format::Xml)
+            .write(
+                &environment,
+                &mut Directory(directory.path().to_path_buf()),
+                Cursor::new(Vec::new()),
+            )
+            .unwrap();
+        let mut package = AasxReader::new(package).unwrap();
+        let written = package.environment().unwrap();
+        let values: Vec<&str> = crate::referenced_files(&written)
+            .into_iter()
+            .map(|value| value.as_str())
+            .collect();
+        assert_eq!(
+            values,
+            [
+                "/aasx/files/manual.pdf",
+                "/aasx/files/manual-1.pdf",
+                "/aasx/files/manual.pdf",
+                "https://example.com/manual.pdf"
+            ]
+        );
+        assert_eq!(package.read("/aasx/files/manual-1.pdf").unwrap(), b"other");
+        assert_eq!(package.thumbnail(), None);
+    }
+
+    #[test]
+    fn thumbnail_is_no_supplementary_file() {
+        let xml = r#"<environment xmlns="https://admin-shell.io/aas/3/1"><assetAdministrationShells>
+            <assetAdministrationShell><id>urn:example:aas:1</id><assetInformation><assetKind>Instance</assetKind><defaultThumbnail><path>thumbnail.png</path><contentType>image/png</contentType></defaultThumbnail></assetInformation></assetAdministrationShell>
+            <assetAdministrationShell><id>urn:example:aas:2</id><assetInformation><assetKind>Instance</assetKind><defaultThumbnail><path>other.png</path><contentType>image/png</contentType></defaultThumbnail></assetInformation></assetAdministrationShell>
+        </assetAdministrationShells><submodels><submodel><id>urn:example:1</id><submodelElements>
+            <file><idShort>Manual</idShort><value>manual.pdf</value><contentType>application/pdf</contentType></file>
+        </submodelElements></submodel></submodels></environment>"#;
+        let mut files = HashMap::from([
+            ("thumbnail.png".to_string(), b"thumbnail".to_vec()),
+            ("other.png".to_string(), b"other".to_vec()),
+            ("manual.pdf".to_string(), b"manual".to_vec()),
+        ]);
+
+        let package = AasxWriter::new(Format::Xml)
+            .write(
+                &Environment::from_xml(xml).unwrap(),
+                &mut files,
+                Cursor::new(Vec::new()),
+            )
+            .unwrap();
+        let package = AasxReader::new(package).unwrap();
+        assert_eq!(package.thumbnail(), Some("/aasx/files/thumbnail.png"));
+        assert_eq!(
+            package.supplementary_files(),
+            ["/aasx/files/other.png", "/aasx/files/manual.pdf"]
+        );
+
+        // a File element referencing the thumbnail makes it a supplementary file
+        let xml = xml.replace("<value>manual.pdf", "<value>thumbnail.png");
+        let package = AasxWriter::new(Format::Xml)
+            .write(
+                &Environment::from_xml(&xml).unwrap(),
+                &mut files,
+                Cursor::new(Vec::new()),
+            )
+            .unwrap();
+        let package = AasxReader::new(package).unwrap();
+        assert_eq!(package.thumbnail(), Some("/aasx/files/thumbnail.png"));
+        assert_eq!(
+            package.supplementary_files(),
+            ["/aasx/files/thumbnail.png", "/aasx/files/other.png"]
+        );
+    }
+}
@@ -2,6 +2,7 @@
 extern crate criterion;
 
 use aas::part1::v3_1::environment::Environment;
+use aas_json::Json;
 use criterion::Criterion;
 use criterion::criterion_group;
 
@@ -11,7 +12,7 @@ use pprof::criterion::{Output, PProfProfiler};
 // https://www.jibbow.com/posts/criterion-flamegraphs/
 
 fn parse_dev_aas_json(str: &str) -> Environment {
-    serde_json::from_str(str).unwrap()
+    Environment::from_json(str).unwrap()
 }
 
 fn bench(c: &mut Criterion) {
@@ -0,0 +1,144 @@
+//! Semantic equivalence of two values, independent of the format they were read from.
+//!
+//! Both values are compared in their JSON serialization, after differences that only stem
+//! from the serialization are removed:
+//! - empty lists are the same as absent ones,
+//! - language tags are compared case-insensitively,
+//! - the order of the elements of a SubmodelElementList with `orderRelevant = false` is ignored.
+
+use aas_json::{Json, JsonError};
+use serde_json::{Map, Value};
+
+/// Whether both values are the same apart from serialization-only differences.
+pub fn equivalent<T: Json<Error = JsonError>>(a: &T, b: &T) -> Result<bool, JsonError> {
+    Ok(difference(a, b)?.is_none())
+}
+
+/// JSON pointer to the first difference of both values, `None` if they are [equivalent].
+pub fn difference<T: Json<Error = JsonError>>(a: &T, b: &T) -> Result<Option<String>, JsonError> {
+    let a = canonical(serde_json::from_str(&a.to_json()?)?);
+    let b = canonical(serde_json::from_str(&b.to_json()?)?);
+    Ok(first_difference(&a, &b, ""))
+}
+
+fn canonical(value: Value) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(items.into_iter().map(canonical).collect()),
+        Value::Object(map) => {
+            let mut map: Map<String, Value> = map
+                .into_iter()
+                .map(|(key, value)| (key, canonical(value)))
+                .filter(|(_, value)| !matches!(value, Value::Array(items) if items.is_empty()))
+                .collect();
+
+            // lang strings
+            if map.contains_key("text")
+                && let Some(Value::String(language)) = map.get_mut("language")
+            {
+                *language = language.to_lowercase();
+            }
+
+            if map.get("modelType").and_then(Value::as_str) == Some("SubmodelElementList")
+                && map.get("orderRelevant") == Some(&Value::Bool(false))
+                && let Some(Value::Array(items)) = map.get_mut("value")
+            {
+                items.sort_by_cached_key(Value::to_string);
+            }
+
+            Value::Object(map)
+        }
+        value => value,
+    }
+}
+
+fn first_difference(a: &Value, b: &Value, path: &str) -> Option<String> {
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => a
+            .keys()
+            .chain(b.keys().filter(|key| !a.contains_key(*key)))
+            .find_map(|key| {
+                let path = format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+                match (a.get(key), b.get(key)) {
+                    (Some(a), Some(b)) => first_difference(a, b, &path),
+                    _ => Some(path),
+                }
+            }),
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => a
+            .iter()
+            .zip(b)
+            .enumerate()
+            .find_map(|(i, (a, b))| first_difference(a, b, &format!("{path}/{i}"))),
+        (a, b) => (a != b).then(|| path.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aas::part1::v3_1::LangString;
+    use aas::part1::v3_1::environment::Environment;
+    use aas_xml::Xml;
+
+    fn list(order_relevant: bool, values: [&str; 2]) -> Environment {
+        Environment::from_json(&format!(
+            r#"{{"submodels": [{{"modelType": "Submodel", "id": "urn:example:1", "submodelElements": [
+                {{"modelType": "SubmodelElementList", "idShort": "list", "orderRelevant": {order_relevant},
+                  "typeValueListElement": "Property", "valueTypeListElement": "xs:string", "value": [
+                    {{"modelType": "Property", "valueType": "xs:string", "value": "{}"}},
+                    {{"modelType": "Property", "valueType": "xs:string", "value": "{}"}}
+                ]}}
+            ]}}]}}"#,
+            values[0], values[1]
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn ignores_order_of_unordered_lists() {
+        assert!(equivalent(&list(false, ["a", "b"]), &list(false, ["b", "a"])).unwrap());
+        assert_eq!(
+            difference(&list(true, ["a", "b"]), &list(true, ["b", "a"])).unwrap(),
+            Some("/submodels/0/submodelElements/0/value/0/value".to_string())
+        );
+    }
+
+    #[test]
+    fn ignores_empty_lists_and_language_case() {
+        let json = Environment::from_json(
+            r#"{"submodels": [{"modelType": "Submodel", "id": "urn:example:1",
+                "description": [{"language": "de-DE", "text": "Beispiel"}]}]}"#,
+        )
+        .unwrap();
+        let mut xml = Environment::from_xml(
+            r#"<environment xmlns="https://admin-shell.io/aas/3/1">
+                <assetAdministrationShells></assetAdministrationShells>
+                <submodels><submodel>
+                    <description><langStringTextType><language>de-DE</language><text>Beispiel</text></langStringTextType></description>
+                    <id>urn:example:1</id>
+                </submodel></submodels>
+            </environment>"#,
+        )
+        .unwrap();
+        assert_eq!(xml.asset_administration_shells, Some(vec![]));
+        assert!(equivalent(&json, &xml).unwrap());
+
+        // tags built without normalization keep their case
+        fn description(environment: &mut Environment) -> &mut LangString {
+            &mut environment.submodels.as_mut().unwrap()[0]
+                .identifiable
+                .referable
+                .description
+                .as_mut()
+                .unwrap()[0]
+        }
+        description(&mut xml).language =
+            oxilangtag::LanguageTag::parse("DE-de".to_string()).unwrap();
+        assert!(equivalent(&json, &xml).unwrap());
+
+        description(&mut xml).text = "Example".into();
+        assert_eq!(
+            difference(&json, &xml).unwrap(),
+            Some("/submodels/0/description/0/text".to_string())
+        );
+    }
+}
@@ -0,0 +1,145 @@
+//! Conversion between the JSON and XML serializations of the [aas] types.
+//!
+//! Both formats are read into the same core types with the [Json] and [Xml] traits of
+//! `aas-json` and `aas-xml`, so converting is reading in one format and writing in the other.
+//! Because the formats differ in what they can express, e.g. an empty list and an absent one,
+//! the result is not byte for byte the same when converting back. [equivalent] compares two
+//! values without these differences.
+//!
+//! ```
+//! use aas_convert::{Format, convert};
+//!
+//! let json = r#"{"submodels":[{"modelType":"Submodel","id":"urn:example:1"}]}"#;
+//! let xml = convert(json, Format::Json, Format::Xml).unwrap();
+//! assert_eq!(
+//!     xml,
+//!     r#"<environment xmlns="https://admin-shell.io/aas/3/1"><submodels><submodel><id>urn:example:1</id></submodel></submodels></environment>"#
+//! );
+//! assert_eq!(convert(&xml, Format::Xml, Format::Json).unwrap(), json);
+//! ```
+
+mod equivalence;
+
+pub use equivalence::{difference, equivalent};
+
+use aas::part1::v3_1::environment::Environment;
+use aas_json::{Json, JsonError};
+use aas_xml::{XMLError, Xml};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// A serialization format of the metamodel
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Json,
+    Xml,
+}
+
+impl Format {
+    /// Guesses the format from the first character: XML starts with `<`, JSON with `{`.
+    pub fn detect(input: &str) -> Option<Self> {
+        match input
+            .trim_start_matches('\u{feff}')
+            .trim_start()
+            .chars()
+            .next()
+        {
+            Some('<') => Some(Self::Xml),
+            Some('{') => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Format::Json => write!(f, "JSON"),
+            Format::Xml => write!(f, "XML"),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ConvertError {
+    #[error(transparent)]
+    Json(#[from] JsonError),
+
+    #[error(transparent)]
+    Xml(#[from] XMLError),
+
+    #[error("input is neither JSON nor XML")]
+    UnknownFormat,
+}
+
+/// Reads a value of the given format
+pub fn read<T>(input: &str, format: Format) -> Result<T, ConvertError>
+where
+    T: Json<Error = JsonError> + Xml<Error = XMLError>,
+{
+    match format {
+        Format::Json => Ok(T::from_json(input)?),
+        Format::Xml => Ok(T::from_xml(input)?),
+    }
+}
+
+/// Writes a value in the given format
+pub fn write<T>(value: &T, format: Format) -> Result<String, ConvertError>
+where
+    T: Json<Error = JsonError> + Xml<Error = XMLError>,
+{
+    match format {
+        Format::Json => Ok(value.to_json()?),
+        Format::Xml => Ok(value.to_xml()?),
+    }
+}
+
+/// Reads an environment of either format, see [Format::detect].
+pub fn read_environment(input: &str) -> Result<(Environment, Format), ConvertError> {
+    let format = Format::detect(input).ok_or(ConvertError::UnknownFormat)?;
+    Ok((read(input, format)?, format))
+}
+
+/// Converts an environment from one format into the other
+pub fn convert(input: &str, from: Format, to: Format) -> Result<String, ConvertError> {
+    write(&read::<Environment>(input, from)?, to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect() {
+        assert_eq!(Format::detect("\u{feff}<environment/>"), Some(Format::Xml));
+        assert_eq!(Format::detect("  {}"), Some(Format::Json));
+        assert_eq!(Format::detect("submodels"), None);
+        assert!(matches!(
+            read_environment(""),
+            Err(ConvertError::UnknownFormat)
+        ));
+    }
+
+    #[test]
+    fn converts_back_and_forth() {
+        // the JSON example uses the placeholder 0000-00-00, which is no valid xs:date,
+        // the XML example 1970-01-01 instead
+        let json =
+            include_str!("../../aas/tests/mvp-dpp-1.0.0.json").replace("0000-00-00", "1970-01-01");
+        let (environment, format) = read_environment(&json).unwrap();
+        assert_eq!(format, Format::Json);
+
+        let xml = convert(&json, Format::Json, Format::Xml).unwrap();
+        let converted: Environment = read(&xml, Format::Xml).unwrap();
+        assert!(equivalent(&environment, &converted).unwrap());
+
+        let json = convert(&xml, Format::Xml, Format::Json).unwrap();
+        assert_eq!(read::<Environment>(&json, Format::Json).unwrap(), converted);
+
+        let xml = include_str!("../../aas/tests/mvp-dpp-1.0.0.xml");
+        let (example, format) = read_environment(xml).unwrap();
+        assert_eq!(format, Format::Xml);
+        assert_eq!(difference(&environment, &example).unwrap(), None);
+    }
+}
@@ -1,23 +1,32 @@
-//! This crate defines data types according to the
-//! [asset administration shell specifications](https://industrialdigitaltwin.org/en/content-hub/aasspecifications)
+//! JSON serialization of the metamodel types of the [aas] crate, see
+//! <https://industrialdigitaltwin.io/aas-specifications/IDTA-01001/v3.1.1/mappings/mappings.html#json>
 //!
-//! To support multiple specs and multiple versions of each, this crate it split in
-//! multiple modules for each part as well as version.
-//! Because each spec is versioned on their own, the modules are ordered `specs/version` instead of
-//! `version/specs`, i.e. `aas::part1::v3.1`.
+//! The types themselves are only defined in `aas`. This crate mirrors them with private proxy
+//! types in the shape of the JSON schema, which derive serde and convert from and into the core
+//! types. Reading checks the values the schema constrains, e.g. idShorts, language tags and
+//! values against their valueType.
+//!
+//! ```
+//! use aas::part1::v3_1::environment::Environment;
+//! use aas_json::Json;
+//!
+//! let environment = Environment::from_json(r#"{"submodels": [{"modelType": "Submodel", "id": "urn:example:1"}]}"#).unwrap();
+//! assert_eq!(environment.submodels.as_ref().unwrap()[0].identifiable.id.as_ref(), "urn:example:1");
+//! assert_eq!(environment.to_json().unwrap(), r#"{"submodels":[{"modelType":"Submodel","id":"urn:example:1"}]}"#);
+//! ```
 
 /// Part1: Metamodel
-pub mod part1;
-
-/// Part 2: Http Endpoints
-/// Can be used with feature = "part2"
-#[cfg(feature = "part2")]
-pub mod part2;
-
+mod part1;
 
+use aas::part1::v3_1::concept_description::ConceptDescription;
+use aas::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use aas::part1::v3_1::diff::Diff;
+use aas::part1::v3_1::environment::Environment;
+use aas::part1::v3_1::reference::Reference;
+use aas::part1::v3_1::submodel_elements::SubmodelElement;
+use part1::v3_1 as proxy;
 use serde::{Deserialize, Deserializer, Serialize};
-
-
+use thiserror::Error;
 
 /// Wrapper Struct to serialize/deserialize given struct to/from JSON
 pub struct JSON<T: Json>(T);
@@ -52,3 +61,212 @@ pub trait Json: Sized {
 
     fn from_json(json: &str) -> Result<Self, Self::Error>;
 }
+
+#[derive(Debug, Error)]
+pub enum JsonError {
+    #[error(transparent)]
+    Syntax(#[from] serde_json::Error),
+
+    #[error("invalid {attribute} '{value}': {reason}")]
+    InvalidAttribute {
+        attribute: &'static str,
+        value: String,
+        reason: String,
+    },
+}
+
+/// References are always written, the proxies of all other types may reject values outside the
+/// value space of their valueType.
+macro_rules! impl_json {
+    (@write $ty:ty => $proxy:ty, |$value:ident| $write:expr) => {
+        impl Json for $ty {
+            type Error = JsonError;
+
+            fn to_json(&self) -> Result<String, Self::Error> {
+                let $value = self;
+                Ok(serde_json::to_string(&$write)?)
+            }
+
+            fn from_json(json: &str) -> Result<Self, Self::Error> {
+                serde_json::from_str::<$proxy>(json)?.try_into()
+            }
+        }
+    };
+    ($($ty:ty => $proxy:ty),* $(,)?) => {$(
+        impl_json!(@write $ty => $proxy, |value| <$proxy>::try_from(value)?);
+    )*};
+}
+
+impl_json!(
+    Environment => proxy::environment::Environment,
+    AssetAdministrationShell => proxy::core::AssetAdministrationShell,
+    Submodel => proxy::core::Submodel,
+    ConceptDescription => proxy::core::ConceptDescription,
+    SubmodelElement => proxy::submodel_elements::SubmodelElement,
+    Diff => proxy::diff::Diff,
+);
+
+impl_json!(@write Reference => proxy::reference::Reference, |value| {
+    proxy::reference::Reference::from(value)
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aas::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use aas::part1::v3_1::submodel_elements::{Range, RangeInner, RangeValue};
+    use serde_json::Value;
+
+    #[test]
+    fn round_trip() {
+        // the example uses the placeholder 0000-00-00, which is no valid xs:date
+        let json =
+            include_str!("../../aas/tests/mvp-dpp-1.0.0.json").replace("0000-00-00", "2000-01-01");
+        let environment = Environment::from_json(&json).unwrap();
+        assert!(
+            environment
+                .submodels
+                .as_ref()
+                .is_some_and(|s| !s.is_empty())
+        );
+
+        let written = environment.to_json().unwrap();
+
+        // The written document only differs in what the core types do not keep: empty idShorts
+        // and the default orderRelevant are left out, dateTimes are written with an offset.
+        let mut expected: Value = serde_json::from_str(&json).unwrap();
+        normalize(&mut expected);
+        assert_eq!(serde_json::from_str::<Value>(&written).unwrap(), expected);
+        assert_eq!(Environment::from_json(&written).unwrap(), environment);
+    }
+
+    fn normalize(value: &mut Value) {
+        match value {
+            Value::Object(object) => {
+                object.retain(|key, value| match key.as_str() {
+                    "idShort" => value != "",
+                    "orderRelevant" => value != true,
+                    _ => true,
+                });
+                if let Some(Value::String(value)) = object.get_mut("value")
+                    && is_local_date_time(value)
+                {
+                    value.push_str(".0+00:00");
+                }
+                object.values_mut().for_each(normalize);
+            }
+            Value::Array(items) => items.iter_mut().for_each(normalize),
+            _ => {}
+        }
+    }
+
+    /// Whether the text is an xs:dateTime without timezone, e.g. `2024-09-30T15:00:00`
+    fn is_local_date_time(text: &str) -> bool {
+        text.len() == 19
+            && text.char_indices().all(|(i, c)| match i {
+                4 | 7 => c == '-',
+                10 => c == 'T',
+                13 | 16 => c == ':',
+                _ => c.is_ascii_digit(),
+            })
+    }
+
+    #[test]
+    fn diff_round_trip() {
+        let old = Environment::from_json(
+            r#"{"submodels": [{"modelType": "Submodel", "id": "urn:example:sm:1", "submodelElements": [
+                {"modelType": "Property", "idShort": "Speed", "valueType": "xs:int", "value": "1"},
+                {"modelType": "Property", "idShort": "Torque", "valueType": "xs:int", "value": "5"}
+            ]}]}"#,
+        )
+        .unwrap();
+        let new = Environment::from_json(
+            r#"{"submodels": [{"modelType": "Submodel", "id": "urn:example:sm:1", "idShort": "Motor", "submodelElements": [
+                {"modelType": "Property", "idShort": "Speed", "valueType": "xs:int", "value": "2"},
+                {"modelType": "MultiLanguageProperty", "idShort": "Name", "value": [{"language": "en", "text": "Motor"}]}
+            ]}], "conceptDescriptions": [{"modelType": "ConceptDescription", "id": "urn:example:cd:1"}]}"#,
+        )
+        .unwrap();
+        let diff = old.diff(&new);
+        assert_eq!(diff.changes.len(), 5);
+
+        let json = diff.to_json().unwrap();
+        let read = Diff::from_json(&json).unwrap();
+        assert_eq!(read, diff);
+
+        let mut applied = old.clone();
+        applied.apply(&read).unwrap();
+        assert_eq!(applied, new);
+
+        assert!(matches!(
+            Diff::from_json(r#"[{"op": "added", "target": {"shell": "urn:example:aas:1"}}]"#),
+            Err(JsonError::InvalidAttribute {
+                attribute: "item",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_attribute() {
+        let json = r#"{"modelType": "Submodel", "id": ""}"#;
+        assert!(matches!(
+            Submodel::from_json(json),
+            Err(JsonError::InvalidAttribute {
+                attribute: "id",
+                ..
+            })
+        ));
+
+        let json =
+            r#"{"modelType": "Property", "idShort": "p", "valueType": "xs:int", "value": "a"}"#;
+        assert!(matches!(
+            SubmodelElement::from_json(json),
+            Err(JsonError::InvalidAttribute {
+                attribute: "value",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn property_value_id() {
+        let json = r#"{"modelType":"Property","idShort":"colour","valueType":"xs:string","value":"red","valueId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/red"}]}}"#;
+        let property = SubmodelElement::from_json(json).unwrap();
+        let SubmodelElement::Property(inner) = &property else {
+            panic!("expected a property");
+        };
+        assert!(inner.value_id.is_some());
+        assert_eq!(property.to_json().unwrap(), json);
+    }
+
+    #[test]
+    fn out_of_value_space() {
+        let mut property = SubmodelElement::from_json(
+            r#"{"modelType": "Property", "idShort": "p", "valueType": "xs:negativeInteger", "value": "-5"}"#,
+        )
+        .unwrap();
+        if let SubmodelElement::Property(property) = &mut property {
+            property.value = DataXsd::NegativeInteger(Some(5.into()));
+        }
+        assert!(matches!(
+            property.to_json(),
+            Err(JsonError::InvalidAttribute {
+                attribute: "value",
+                ..
+            })
+        ));
+
+        let range = SubmodelElement::Range(Range::new(RangeValue::PositiveInteger(RangeInner {
+            min: Some(0.into()),
+            max: None,
+        })));
+        assert!(matches!(
+            range.to_json(),
+            Err(JsonError::InvalidAttribute {
+                attribute: "min/max",
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,982 @@
+use crate::JsonError;
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::{convert, identifier, invalid, parse, proxies, try_proxies};
+use aas::part1::v3_1::LangString as ModelLangString;
+use aas::part1::v3_1::attributes::administrative_information::{self as administration, Version};
+use aas::part1::v3_1::attributes::data_specification;
+use aas::part1::v3_1::attributes::extension::{self as extension, HasExtensions};
+use aas::part1::v3_1::attributes::identifiable;
+use aas::part1::v3_1::attributes::qualifiable::{self as qualifiable, QualifierInner};
+use aas::part1::v3_1::attributes::referable;
+use aas::part1::v3_1::attributes::semantics;
+use aas::part1::v3_1::level_type;
+use aas::part1::v3_1::primitives::data_type_def_xs::{DataTypeXSDef, DataXsd};
+use aas::part1::v3_1::value_list::{ValueList, ValueReferencePair};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct LangString {
+    language: String,
+    text: String,
+}
+
+impl From<&ModelLangString> for LangString {
+    fn from(value: &ModelLangString) -> Self {
+        Self {
+            language: value.language.to_string(),
+            text: value.text.clone(),
+        }
+    }
+}
+
+impl TryFrom<LangString> for ModelLangString {
+    type Error = JsonError;
+
+    fn try_from(value: LangString) -> Result<Self, Self::Error> {
+        parse("language", &value.language, |language| {
+            ModelLangString::try_new(language, value.text)
+        })
+    }
+}
+
+/// valueType and value of Properties, Qualifiers and Extensions. Values outside the value space
+/// of their type, e.g. a positive xs:negativeInteger, are not written.
+pub(crate) fn data_value(value: &DataXsd) -> Result<(String, Option<String>), JsonError> {
+    let data = value.to_lexical().map_err(|e| {
+        invalid(
+            "value",
+            Option::<String>::from(value.clone()).unwrap_or_default(),
+            e,
+        )
+    })?;
+    Ok((DataTypeXSDef::from(value).to_string(), data))
+}
+
+/// The strum prefix of [DataTypeXSDef] is only written, parsing expects the plain type name.
+pub(crate) fn value_type(
+    attribute: &'static str,
+    value_type: &str,
+) -> Result<DataTypeXSDef, JsonError> {
+    parse(attribute, value_type, |value_type| {
+        DataTypeXSDef::from_str(value_type.strip_prefix("xs:").unwrap_or(value_type))
+    })
+}
+
+pub(crate) fn data_xsd(value_type: &str, value: Option<String>) -> Result<DataXsd, JsonError> {
+    let data_type = self::value_type("valueType", value_type)?;
+    DataXsd::try_from((data_type, value.clone()))
+        .map_err(|e| invalid("value", value.unwrap_or_default(), e))
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Referable {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extensions: Option<Vec<Extension>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_short: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<Vec<LangString>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Vec<LangString>>,
+}
+
+impl TryFrom<&referable::Referable> for Referable {
+    type Error = JsonError;
+
+    #[allow(deprecated)]
+    fn try_from(value: &referable::Referable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            extensions: try_proxies(value.extensions.extension.iter().flatten())?,
+            category: value.category.clone(),
+            id_short: value.id_short.as_ref().map(ToString::to_string),
+            display_name: proxies(value.display_name.iter().flatten()),
+            description: proxies(value.description.iter().flatten()),
+        })
+    }
+}
+
+impl TryFrom<Referable> for referable::Referable {
+    type Error = JsonError;
+
+    #[allow(deprecated)]
+    fn try_from(value: Referable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            // an empty idShort is treated as absent
+            id_short: value
+                .id_short
+                .filter(|id_short| !id_short.is_empty())
+                .map(|id_short| identifier("idShort", &id_short))
+                .transpose()?,
+            display_name: convert(value.display_name)?,
+            description: convert(value.description)?,
+            category: value.category,
+            extensions: HasExtensions {
+                extension: convert(value.extensions)?,
+            },
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Identifiable {
+    #[serde(flatten)]
+    referable: Referable,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    administration: Option<AdministrativeInformation>,
+
+    id: String,
+}
+
+impl TryFrom<&identifiable::Identifiable> for Identifiable {
+    type Error = JsonError;
+
+    fn try_from(value: &identifiable::Identifiable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            referable: (&value.referable).try_into()?,
+            administration: value.administration.as_ref().map(Into::into),
+            id: value.id.to_string(),
+        })
+    }
+}
+
+impl TryFrom<Identifiable> for identifiable::Identifiable {
+    type Error = JsonError;
+
+    fn try_from(value: Identifiable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: identifier("id", &value.id)?,
+            administration: value.administration.map(TryInto::try_into).transpose()?,
+            referable: value.referable.try_into()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AdministrativeInformation {
+    #[serde(flatten)]
+    data_specification: HasDataSpecification,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    revision: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    creator: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_id: Option<String>,
+}
+
+impl From<&administration::AdministrativeInformation> for AdministrativeInformation {
+    fn from(value: &administration::AdministrativeInformation) -> Self {
+        Self {
+            data_specification: (&value.data_specification).into(),
+            version: value.version.version.clone(),
+            revision: value.version.revision.clone(),
+            creator: value.creator.as_ref().map(Into::into),
+            template_id: value.template_id.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+impl TryFrom<AdministrativeInformation> for administration::AdministrativeInformation {
+    type Error = JsonError;
+
+    fn try_from(value: AdministrativeInformation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            version: Version {
+                version: value.version,
+                revision: value.revision,
+            },
+            creator: value.creator.map(TryInto::try_into).transpose()?,
+            template_id: value
+                .template_id
+                .map(|id| identifier("templateId", &id))
+                .transpose()?,
+            data_specification: value.data_specification.try_into()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HasSemantics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    semantic_id: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supplemental_semantic_ids: Option<Vec<Reference>>,
+}
+
+impl From<&semantics::HasSemantics> for HasSemantics {
+    fn from(value: &semantics::HasSemantics) -> Self {
+        Self {
+            semantic_id: value.semantic_id.as_ref().map(Into::into),
+            supplemental_semantic_ids: proxies(value.supplemental_semantic_ids.iter().flatten()),
+        }
+    }
+}
+
+impl TryFrom<HasSemantics> for semantics::HasSemantics {
+    type Error = JsonError;
+
+    fn try_from(value: HasSemantics) -> Result<Self, Self::Error> {
+        Ok(Self {
+            semantic_id: value.semantic_id.map(TryInto::try_into).transpose()?,
+            supplemental_semantic_ids: convert(value.supplemental_semantic_ids)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Extension {
+    #[serde(flatten)]
+    semantics: HasSemantics,
+
+    name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refers_to: Option<Vec<Reference>>,
+}
+
+impl TryFrom<&extension::Extension> for Extension {
+    type Error = JsonError;
+
+    fn try_from(value: &extension::Extension) -> Result<Self, Self::Error> {
+        let (value_type, data) = data_value(&value.value)?;
+        Ok(Self {
+            semantics: HasSemantics {
+                semantic_id: value.semantic_id.as_ref().map(Into::into),
+                supplemental_semantic_ids: proxies(
+                    value.supplemental_semantic_ids.iter().flatten(),
+                ),
+            },
+            name: value.name.clone(),
+            value_type: Some(value_type),
+            value: data,
+            refers_to: proxies(value.refers_to.iter().flatten()),
+        })
+    }
+}
+
+impl TryFrom<Extension> for extension::Extension {
+    type Error = JsonError;
+
+    fn try_from(value: Extension) -> Result<Self, Self::Error> {
+        let semantics = semantics::HasSemantics::try_from(value.semantics)?;
+        Ok(Self {
+            name: value.name,
+            semantic_id: semantics.semantic_id,
+            supplemental_semantic_ids: semantics.supplemental_semantic_ids,
+            value: match value.value_type {
+                Some(value_type) => data_xsd(&value_type, value.value)?,
+                None => DataXsd::String(value.value),
+            },
+            refers_to: convert(value.refers_to)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct Qualifiable {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qualifiers: Option<Vec<Qualifier>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Qualifier {
+    #[serde(flatten)]
+    semantics: HasSemantics,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+
+    #[serde(rename = "type")]
+    ty: String,
+
+    value_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_id: Option<Reference>,
+}
+
+impl TryFrom<&qualifiable::Qualifiable> for Qualifiable {
+    type Error = JsonError;
+
+    fn try_from(value: &qualifiable::Qualifiable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            qualifiers: try_proxies(value.qualifiers.iter().flatten())?,
+        })
+    }
+}
+
+impl TryFrom<Qualifiable> for qualifiable::Qualifiable {
+    type Error = JsonError;
+
+    fn try_from(value: Qualifiable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            qualifiers: convert(value.qualifiers)?,
+        })
+    }
+}
+
+impl TryFrom<&qualifiable::Qualifier> for Qualifier {
+    type Error = JsonError;
+
+    fn try_from(value: &qualifiable::Qualifier) -> Result<Self, Self::Error> {
+        let (kind, inner) = match value {
+            qualifiable::Qualifier::ConceptQualifier(inner) => (Some("ConceptQualifier"), inner),
+            qualifiable::Qualifier::TemplateQualifier(inner) => (Some("TemplateQualifier"), inner),
+            qualifiable::Qualifier::ValueQualifier(inner) => (Some("ValueQualifier"), inner),
+            qualifiable::Qualifier::Unknown(inner) => (None, inner),
+        };
+        let (value_type, data) = data_value(&inner.value)?;
+        Ok(Self {
+            semantics: (&inner.semantics).into(),
+            kind: kind.map(Into::into),
+            ty: inner.ty.clone(),
+            value_type,
+            value: data,
+            value_id: inner.value_id.as_ref().map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<Qualifier> for qualifiable::Qualifier {
+    type Error = JsonError;
+
+    fn try_from(value: Qualifier) -> Result<Self, Self::Error> {
+        let inner = QualifierInner {
+            semantics: value.semantics.try_into()?,
+            ty: value.ty,
+            value: data_xsd(&value.value_type, value.value)?,
+            value_id: value.value_id.map(TryInto::try_into).transpose()?,
+        };
+        match value.kind.as_deref() {
+            Some("ConceptQualifier") => Ok(Self::ConceptQualifier(inner)),
+            Some("TemplateQualifier") => Ok(Self::TemplateQualifier(inner)),
+            Some("ValueQualifier") => Ok(Self::ValueQualifier(inner)),
+            None => Ok(Self::Unknown(inner)),
+            Some(kind) => Err(invalid(
+                "kind",
+                kind,
+                "expected ConceptQualifier, TemplateQualifier or ValueQualifier",
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HasDataSpecification {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embedded_data_specifications: Option<Vec<EmbeddedDataSpecification>>,
+}
+
+impl From<&data_specification::HasDataSpecification> for HasDataSpecification {
+    fn from(value: &data_specification::HasDataSpecification) -> Self {
+        Self {
+            embedded_data_specifications: proxies(
+                value.embedded_data_specifications.iter().flatten(),
+            ),
+        }
+    }
+}
+
+impl TryFrom<HasDataSpecification> for data_specification::HasDataSpecification {
+    type Error = JsonError;
+
+    fn try_from(value: HasDataSpecification) -> Result<Self, Self::Error> {
+        Ok(Self {
+            embedded_data_specifications: convert(value.embedded_data_specifications)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EmbeddedDataSpecification {
+    data_specification: Reference,
+    data_specification_content: DataSpecificationContent,
+}
+
+impl From<&data_specification::EmbeddedDataSpecification> for EmbeddedDataSpecification {
+    fn from(value: &data_specification::EmbeddedDataSpecification) -> Self {
+        Self {
+            data_specification: (&value.data_specification).into(),
+            data_specification_content: (&value.data_specification_content).into(),
+        }
+    }
+}
+
+impl TryFrom<EmbeddedDataSpecification> for data_specification::EmbeddedDataSpecification {
+    type Error = JsonError;
+
+    fn try_from(value: EmbeddedDataSpecification) -> Result<Self, Self::Error> {
+        Ok(Self {
+            data_specification: value.data_specification.try_into()?,
+            data_specification_content: value.data_specification_content.try_into()?,
+        })
+    }
+}
+
+/// Contents of other data specifications are kept as JSON.
+#[derive(Serialize)]
+#[serde(untagged)]
+pub(crate) enum DataSpecificationContent {
+    Known(Box<KnownDataSpecificationContent>),
+    Unknown(Map<String, Value>),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "modelType")]
+pub(crate) enum KnownDataSpecificationContent {
+    DataSpecificationIec61360(DataSpecificationIec61360),
+    DataSpecificationPhysicalUnit(DataSpecificationPhysicalUnit),
+}
+
+impl<'de> Deserialize<'de> for DataSpecificationContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let content = Map::deserialize(deserializer)?;
+        match content.get("modelType").and_then(Value::as_str) {
+            Some("DataSpecificationIec61360" | "DataSpecificationPhysicalUnit") => {
+                KnownDataSpecificationContent::deserialize(Value::Object(content))
+                    .map(|content| Self::Known(Box::new(content)))
+                    .map_err(D::Error::custom)
+            }
+            Some(_) => Ok(Self::Unknown(content)),
+            None => Err(D::Error::missing_field("modelType")),
+        }
+    }
+}
+
+impl From<&data_specification::DataSpecificationContent> for DataSpecificationContent {
+    fn from(value: &data_specification::DataSpecificationContent) -> Self {
+        match value {
+            data_specification::DataSpecificationContent::DataSpecificationIec61360(content) => {
+                Self::Known(Box::new(
+                    KnownDataSpecificationContent::DataSpecificationIec61360(content.into()),
+                ))
+            }
+            data_specification::DataSpecificationContent::DataSpecificationPhysicalUnit(
+                content,
+            ) => Self::Known(Box::new(
+                KnownDataSpecificationContent::DataSpecificationPhysicalUnit(content.into()),
+            )),
+            data_specification::DataSpecificationContent::Unknown(content) => {
+                let mut fields = Map::new();
+                fields.insert("modelType".into(), content.model_type.clone().into());
+                fields.extend(content.fields.clone());
+                Self::Unknown(fields)
+            }
+        }
+    }
+}
+
+impl TryFrom<DataSpecificationContent> for data_specification::DataSpecificationContent {
+    type Error = JsonError;
+
+    fn try_from(value: DataSpecificationContent) -> Result<Self, Self::Error> {
+        Ok(match value {
+            DataSpecificationContent::Known(content) => match *content {
+                KnownDataSpecificationContent::DataSpecificationIec61360(content) => {
+                    Self::DataSpecificationIec61360(content.try_into()?)
+                }
+                KnownDataSpecificationContent::DataSpecificationPhysicalUnit(content) => {
+                    Self::DataSpecificationPhysicalUnit(content.try_into()?)
+                }
+            },
+            DataSpecificationContent::Unknown(mut fields) => {
+                let model_type = match fields.remove("modelType") {
+                    Some(Value::String(model_type)) => model_type,
+                    other => {
+                        return Err(invalid(
+                            "modelType",
+                            other.unwrap_or_default(),
+                            "expected a string",
+                        ));
+                    }
+                };
+                Self::Unknown(data_specification::UnknownDataSpecificationContent {
+                    model_type,
+                    fields,
+                })
+            }
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataSpecificationIec61360 {
+    preferred_name: Vec<LangString>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_name: Option<Vec<LangString>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit_id: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_of_definition: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definition: Option<Vec<LangString>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_format: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_list: Option<ValueListProxy>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level_type: Option<LevelType>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ValueListProxy {
+    value_reference_pairs: Vec<ValueReferencePairProxy>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ValueReferencePairProxy {
+    value: String,
+    value_id: Reference,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct LevelType {
+    min: bool,
+    nom: bool,
+    typ: bool,
+    max: bool,
+}
+
+impl From<&data_specification::DataSpecificationIec61360> for DataSpecificationIec61360 {
+    fn from(value: &data_specification::DataSpecificationIec61360) -> Self {
+        let pairs: Vec<_> = value
+            .value_list
+            .iter()
+            .flatten()
+            .flat_map(|list| &list.value_reference_pairs)
+            .map(|pair| ValueReferencePairProxy {
+                value: pair.value.clone(),
+                value_id: (&pair.value_id).into(),
+            })
+            .collect();
+        Self {
+            preferred_name: value.preferred_name.iter().map(Into::into).collect(),
+            short_name: proxies(value.short_name.iter().flatten()),
+            unit: value.unit.clone(),
+            unit_id: value.unit_id.as_ref().map(Into::into),
+            source_of_definition: value.source_of_definition.clone(),
+            symbol: value.symbol.clone(),
+            data_type: value.data_type.as_ref().map(ToString::to_string),
+            definition: proxies(value.definition.iter().flatten()),
+            value_format: value.value_format.clone(),
+            value_list: value.value_list.as_ref().map(|_| ValueListProxy {
+                value_reference_pairs: pairs,
+            }),
+            value: value.value.clone(),
+            level_type: value.level_type.as_ref().map(|level| LevelType {
+                min: level.min,
+                nom: level.nom,
+                typ: level.typ,
+                max: level.max,
+            }),
+        }
+    }
+}
+
+impl TryFrom<DataSpecificationIec61360> for data_specification::DataSpecificationIec61360 {
+    type Error = JsonError;
+
+    fn try_from(value: DataSpecificationIec61360) -> Result<Self, Self::Error> {
+        let value_list = value
+            .value_list
+            .map(|list| {
+                let pairs = list
+                    .value_reference_pairs
+                    .into_iter()
+                    .map(|pair| {
+                        Ok(ValueReferencePair {
+                            value: pair.value,
+                            value_id: pair.value_id.try_into()?,
+                        })
+                    })
+                    .collect::<Result<_, JsonError>>()?;
+                Ok::<_, JsonError>(vec![ValueList {
+                    value_reference_pairs: pairs,
+                }])
+            })
+            .transpose()?;
+
+        Ok(Self {
+            preferred_name: value
+                .preferred_name
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            short_name: convert(value.short_name)?,
+            unit: value.unit,
+            unit_id: value.unit_id.map(TryInto::try_into).transpose()?,
+            source_of_definition: value.source_of_definition,
+            symbol: value.symbol,
+            data_type: value
+                .data_type
+                .map(|data_type| {
+                    parse(
+                        "dataType",
+                        &data_type,
+                        data_specification::DataTypeIec61360::from_str,
+                    )
+                })
+                .transpose()?,
+            definition: convert(value.definition)?,
+            value_format: value.value_format,
+            value_list,
+            value: value.value,
+            level_type: value.level_type.map(|level| level_type::LevelType {
+                min: level.min,
+                nom: level.nom,
+                typ: level.typ,
+                max: level.max,
+            }),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataSpecificationPhysicalUnit {
+    unit_name: String,
+    unit_symbol: String,
+    definition: Vec<LangString>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    si_notation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    si_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    din_notation: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ece_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ece_code: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nist_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_of_definition: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversion_factor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    registration_authority_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supplier: Option<String>,
+}
+
+impl From<&data_specification::DataSpecificationPhysicalUnit> for DataSpecificationPhysicalUnit {
+    fn from(value: &data_specification::DataSpecificationPhysicalUnit) -> Self {
+        Self {
+            unit_name: value.unit_name.clone(),
+            unit_symbol: value.unit_symbol.clone(),
+            definition: value.definition.iter().map(Into::into).collect(),
+            si_notation: value.si_notation.clone(),
+            si_name: value.si_name.clone(),
+            din_notation: value.din_notation.clone(),
+            ece_name: value.ece_name.clone(),
+            ece_code: value.ece_code.clone(),
+            nist_name: value.nist_name.clone(),
+            source_of_definition: value.source_of_definition.clone(),
+            conversion_factor: value.conversion_factor.clone(),
+            registration_authority_id: value.registration_authority_id.clone(),
+            supplier: value.supplier.clone(),
+        }
+    }
+}
+
+impl TryFrom<DataSpecificationPhysicalUnit> for data_specification::DataSpecificationPhysicalUnit {
+    type Error = JsonError;
+
+    fn try_from(value: DataSpecificationPhysicalUnit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unit_name: value.unit_name,
+            unit_symbol: value.unit_symbol,
+            definition: value
+                .definition
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            si_notation: value.si_notation,
+            si_name: value.si_name,
+            din_notation: value.din_notation,
+            ece_name: value.ece_name,
+            ece_code: value.ece_code,
+            nist_name: value.nist_name,
+            source_of_definition: value.source_of_definition,
+            conversion_factor: value.conversion_factor,
+            registration_authority_id: value.registration_authority_id,
+            supplier: value.supplier,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aas::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+
+    #[test]
+    fn data_values_round_trip() {
+        for (value_type, value) in [
+            ("xs:string", "text"),
+            ("xs:double", "1.2"),
+            ("xs:duration", "P1Y"),
+            ("xs:gYear", "-0044"),
+            ("xs:base64Binary", "iVBORw0KGgo="),
+        ] {
+            let data = data_xsd(value_type, Some(value.into())).unwrap();
+            assert_eq!(
+                data_value(&data).unwrap(),
+                (value_type.to_string(), Some(value.to_string()))
+            );
+        }
+
+        assert_eq!(
+            data_xsd("xs:double", Some("1.2".into())).unwrap(),
+            DataXsd::Double(Some(1.2))
+        );
+        assert_eq!(data_xsd("xs:int", None).unwrap(), DataXsd::Int(None));
+    }
+
+    #[test]
+    fn date_and_time_values() {
+        let data =
+            |value_type: &str, value: &str| data_xsd(value_type, Some(value.into())).unwrap();
+
+        let DataXsd::DateTime(Some(date_time)) = data("xs:dateTime", "2001-10-26T21:32:52Z") else {
+            panic!("expected a dateTime");
+        };
+        assert_eq!(date_time.date.to_string(), "2001-10-26");
+        assert_eq!(
+            (
+                date_time.time.hour,
+                date_time.time.minute,
+                date_time.time.second
+            ),
+            (21, 32, 52)
+        );
+        assert_eq!(date_time.time.tz_offset_hours, 0);
+
+        // without timezone the offset is zero as well
+        assert_eq!(
+            data("xs:dateTime", "2001-10-26T21:32:52"),
+            DataXsd::DateTime(Some(date_time))
+        );
+
+        let DataXsd::Time(Some(time)) = data("xs:time", "21:32:52Z") else {
+            panic!("expected a time");
+        };
+        assert_eq!(time, date_time.time);
+        assert_eq!(
+            data("xs:date", "2001-10-26Z"),
+            DataXsd::Date(Some(date_time.date))
+        );
+
+        let (_, written) = data_value(&DataXsd::DateTime(Some(date_time))).unwrap();
+        assert_eq!(
+            data_xsd("xs:dateTime", written).unwrap(),
+            DataXsd::DateTime(Some(date_time))
+        );
+    }
+
+    #[test]
+    fn invalid_data_values() {
+        for (value_type, value) in [("xs:int", "a"), ("xs:gYear", "44"), ("xs:duration", "P1W")] {
+            assert!(matches!(
+                data_xsd(value_type, Some(value.into())),
+                Err(JsonError::InvalidAttribute {
+                    attribute: "value",
+                    ..
+                })
+            ));
+        }
+        assert!(matches!(
+            data_xsd("xs:float32", None),
+            Err(JsonError::InvalidAttribute {
+                attribute: "valueType",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn lang_string() {
+        let json = r#"{"language":"EN","text":"Sample test text"}"#;
+        let proxy: LangString = serde_json::from_str(json).unwrap();
+        let lang_string = ModelLangString::try_from(proxy).unwrap();
+        assert_eq!(
+            lang_string,
+            ModelLangString::try_new("EN", "Sample test text".into()).unwrap()
+        );
+        // language tags are written in lower case
+        assert_eq!(
+            serde_json::to_string(&LangString::from(&lang_string)).unwrap(),
+            json.replace("EN", "en")
+        );
+
+        let proxy: LangString =
+            serde_json::from_str(r#"{"language":"not a tag","text":""}"#).unwrap();
+        assert!(ModelLangString::try_from(proxy).is_err());
+    }
+
+    #[test]
+    fn extension() {
+        let json = r#"{"name":"","valueType":"xs:int","value":"123"}"#;
+        let proxy: Extension = serde_json::from_str(json).unwrap();
+        let extension = extension::Extension::try_from(proxy).unwrap();
+        assert_eq!(extension.value, DataXsd::Int(Some(123)));
+        assert_eq!(
+            serde_json::to_string(&Extension::try_from(&extension).unwrap()).unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn extension_without_value_type() {
+        let proxy: Extension = serde_json::from_str(r#"{"name":""}"#).unwrap();
+        let extension = extension::Extension::try_from(proxy).unwrap();
+        assert_eq!(extension.value, DataXsd::String(None));
+        assert_eq!(
+            serde_json::to_string(&Extension::try_from(&extension).unwrap()).unwrap(),
+            r#"{"name":"","valueType":"xs:string"}"#
+        );
+    }
+
+    #[test]
+    fn qualifier_kinds() {
+        let qualifier = |json: &str| {
+            let proxy: Qualifier = serde_json::from_str(json).unwrap();
+            qualifiable::Qualifier::try_from(proxy)
+        };
+
+        let json = r#"{"semanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://admin-shell.io/SubmodelTemplates/Cardinality/1/0"}]},"kind":"TemplateQualifier","type":"SMT/Cardinality","valueType":"xs:string","value":"One"}"#;
+        let template = qualifier(json).unwrap();
+        let qualifiable::Qualifier::TemplateQualifier(inner) = &template else {
+            panic!("expected a template qualifier");
+        };
+        assert_eq!(inner.ty, "SMT/Cardinality");
+        assert_eq!(inner.value, DataXsd::String(Some("One".into())));
+        assert!(inner.semantics.semantic_id.is_some());
+        assert_eq!(
+            serde_json::to_string(&Qualifier::try_from(&template).unwrap()).unwrap(),
+            json
+        );
+
+        assert!(matches!(
+            qualifier(
+                r#"{"kind":"ConceptQualifier","type":"Test","valueType":"xs:boolean","value":"true"}"#
+            ),
+            Ok(qualifiable::Qualifier::ConceptQualifier(_))
+        ));
+        assert!(matches!(
+            qualifier(r#"{"type":"Test","valueType":"xs:boolean","value":"true"}"#),
+            Ok(qualifiable::Qualifier::Unknown(_))
+        ));
+        assert!(matches!(
+            qualifier(r#"{"kind":"Test","type":"Test","valueType":"xs:boolean","value":"true"}"#),
+            Err(JsonError::InvalidAttribute {
+                attribute: "kind",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn physical_unit_round_trip() {
+        let json = r#"{"modelType":"DataSpecificationPhysicalUnit","unitName":"metre","unitSymbol":"m","definition":[{"language":"en","text":"length"}],"siNotation":"m"}"#;
+        let proxy: DataSpecificationContent = serde_json::from_str(json).unwrap();
+        let content = data_specification::DataSpecificationContent::try_from(proxy).unwrap();
+
+        let data_specification::DataSpecificationContent::DataSpecificationPhysicalUnit(unit) =
+            &content
+        else {
+            panic!("expected a physical unit");
+        };
+        assert_eq!(unit.unit_symbol, "m");
+        assert_eq!(
+            serde_json::to_string(&DataSpecificationContent::from(&content)).unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn unknown_content_survives_round_trip() {
+        let json = r#"{"modelType":"DataSpecificationAcme","color":"red","sizes":[1,2]}"#;
+        let proxy: DataSpecificationContent = serde_json::from_str(json).unwrap();
+        let content = data_specification::DataSpecificationContent::try_from(proxy).unwrap();
+
+        let data_specification::DataSpecificationContent::Unknown(unknown) = &content else {
+            panic!("expected unknown content");
+        };
+        assert_eq!(unknown.model_type, "DataSpecificationAcme");
+        assert_eq!(
+            serde_json::to_value(DataSpecificationContent::from(&content)).unwrap(),
+            serde_json::from_str::<Value>(json).unwrap()
+        );
+
+        assert!(serde_json::from_str::<DataSpecificationContent>(r#"{"color":"red"}"#).is_err());
+    }
+}
@@ -0,0 +1,404 @@
+use crate::JsonError;
+use crate::part1::v3_1::attributes::{
+    HasDataSpecification, HasSemantics, Identifiable, Qualifiable,
+};
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::submodel_elements::SubmodelElement;
+use crate::part1::v3_1::{convert, identifier, invalid, parse, proxies, try_proxies};
+use aas::part1::v3_1::attributes::kind::ModellingKind;
+use aas::part1::v3_1::concept_description;
+use aas::part1::v3_1::core::{self as model, AssetInformationInner};
+use aas::part1::v3_1::primitives::{Label, Uri};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The modelType of identifiables is written but not required when reading, it is implied by
+/// the position in the environment.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetAdministrationShell {
+    #[serde(default)]
+    model_type: String,
+
+    #[serde(flatten)]
+    identifiable: Identifiable,
+
+    #[serde(flatten)]
+    data_specification: HasDataSpecification,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    derived_from: Option<Reference>,
+
+    asset_information: AssetInformation,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    submodels: Option<Vec<Reference>>,
+}
+
+impl TryFrom<&model::AssetAdministrationShell> for AssetAdministrationShell {
+    type Error = JsonError;
+
+    fn try_from(value: &model::AssetAdministrationShell) -> Result<Self, Self::Error> {
+        Ok(Self {
+            model_type: "AssetAdministrationShell".into(),
+            identifiable: (&value.identifiable).try_into()?,
+            data_specification: (&value.data_specification).into(),
+            derived_from: value.derived_from.as_ref().map(Into::into),
+            asset_information: (&value.asset_information).into(),
+            submodels: proxies(value.submodels.iter().flatten()),
+        })
+    }
+}
+
+impl TryFrom<AssetAdministrationShell> for model::AssetAdministrationShell {
+    type Error = JsonError;
+
+    fn try_from(value: AssetAdministrationShell) -> Result<Self, Self::Error> {
+        Ok(Self {
+            asset_information: value.asset_information.try_into()?,
+            identifiable: value.identifiable.try_into()?,
+            data_specification: value.data_specification.try_into()?,
+            derived_from: value.derived_from.map(TryInto::try_into).transpose()?,
+            submodels: convert(value.submodels)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetInformation {
+    asset_kind: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_asset_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    specific_asset_ids: Option<Vec<SpecificAssetId>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    asset_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_thumbnail: Option<Resource>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Resource {
+    path: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+}
+
+impl From<&model::AssetInformation> for AssetInformation {
+    fn from(value: &model::AssetInformation) -> Self {
+        Self {
+            asset_kind: value.to_string(),
+            global_asset_id: value.global_asset_id.as_ref().map(ToString::to_string),
+            specific_asset_ids: proxies(value.specific_asset_ids.iter().flatten()),
+            asset_type: value.asset_type.as_ref().map(ToString::to_string),
+            default_thumbnail: value.default_thumbnail.as_ref().map(|thumbnail| Resource {
+                path: thumbnail.path.to_string(),
+                content_type: thumbnail.content_type.clone(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<AssetInformation> for model::AssetInformation {
+    type Error = JsonError;
+
+    fn try_from(value: AssetInformation) -> Result<Self, Self::Error> {
+        let inner = AssetInformationInner {
+            global_asset_id: value
+                .global_asset_id
+                .map(|id| identifier("globalAssetId", &id))
+                .transpose()?,
+            specific_asset_ids: convert(value.specific_asset_ids)?,
+            asset_type: value
+                .asset_type
+                .map(|id| identifier("assetType", &id))
+                .transpose()?,
+            default_thumbnail: value
+                .default_thumbnail
+                .map(|thumbnail| {
+                    Ok::<_, JsonError>(model::Resource {
+                        path: parse("path", &thumbnail.path, Uri::from_str)?,
+                        content_type: thumbnail.content_type,
+                    })
+                })
+                .transpose()?,
+        };
+        match value.asset_kind.as_str() {
+            "Instance" => Ok(Self::Instance(inner)),
+            "NotApplicable" => Ok(Self::NotApplicable(inner)),
+            "Role" => Ok(Self::Role(inner)),
+            "Type" => Ok(Self::Type(inner)),
+            kind => Err(invalid(
+                "assetKind",
+                kind,
+                "expected Instance, NotApplicable, Role or Type",
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SpecificAssetId {
+    #[serde(flatten)]
+    semantics: HasSemantics,
+
+    name: String,
+    value: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_subject_id: Option<Reference>,
+}
+
+impl From<&model::SpecificAssetId> for SpecificAssetId {
+    fn from(value: &model::SpecificAssetId) -> Self {
+        Self {
+            semantics: (&value.has_semantics).into(),
+            name: value.name.to_string(),
+            value: value.value.to_string(),
+            external_subject_id: value.external_subject_id.as_ref().map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<SpecificAssetId> for model::SpecificAssetId {
+    type Error = JsonError;
+
+    fn try_from(value: SpecificAssetId) -> Result<Self, Self::Error> {
+        Ok(Self {
+            has_semantics: value.semantics.try_into()?,
+            name: parse("name", &value.name, Label::try_from)?,
+            value: identifier("value", &value.value)?,
+            external_subject_id: value
+                .external_subject_id
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Submodel {
+    #[serde(default)]
+    model_type: String,
+
+    #[serde(flatten)]
+    identifiable: Identifiable,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+
+    #[serde(flatten)]
+    semantics: HasSemantics,
+
+    #[serde(flatten)]
+    qualifiable: Qualifiable,
+
+    #[serde(flatten)]
+    data_specification: HasDataSpecification,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    submodel_elements: Option<Vec<SubmodelElement>>,
+}
+
+impl TryFrom<&model::Submodel> for Submodel {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Submodel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            model_type: "Submodel".into(),
+            identifiable: (&value.identifiable).try_into()?,
+            kind: value.kind.as_ref().map(ToString::to_string),
+            semantics: (&value.semantics).into(),
+            qualifiable: (&value.qualifier).try_into()?,
+            data_specification: (&value.data_specification).into(),
+            submodel_elements: try_proxies(value.submodel_elements.iter().flatten())?,
+        })
+    }
+}
+
+impl TryFrom<Submodel> for model::Submodel {
+    type Error = JsonError;
+
+    fn try_from(value: Submodel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            identifiable: value.identifiable.try_into()?,
+            kind: value
+                .kind
+                .map(|kind| parse("kind", &kind, ModellingKind::from_str))
+                .transpose()?,
+            semantics: value.semantics.try_into()?,
+            qualifier: value.qualifiable.try_into()?,
+            data_specification: value.data_specification.try_into()?,
+            submodel_elements: convert(value.submodel_elements)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConceptDescription {
+    #[serde(default)]
+    model_type: String,
+
+    #[serde(flatten)]
+    identifiable: Identifiable,
+
+    #[serde(flatten)]
+    data_specification: HasDataSpecification,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_case_of: Option<Vec<Reference>>,
+}
+
+impl TryFrom<&concept_description::ConceptDescription> for ConceptDescription {
+    type Error = JsonError;
+
+    fn try_from(value: &concept_description::ConceptDescription) -> Result<Self, Self::Error> {
+        Ok(Self {
+            model_type: "ConceptDescription".into(),
+            identifiable: (&value.identifiable).try_into()?,
+            data_specification: value
+                .data_specification
+                .as_ref()
+                .map(Into::into)
+                .unwrap_or_default(),
+            is_case_of: proxies(value.is_case_of.iter().flatten()),
+        })
+    }
+}
+
+impl TryFrom<ConceptDescription> for concept_description::ConceptDescription {
+    type Error = JsonError;
+
+    fn try_from(value: ConceptDescription) -> Result<Self, Self::Error> {
+        let data_specification =
+            aas::part1::v3_1::attributes::data_specification::HasDataSpecification::try_from(
+                value.data_specification,
+            )?;
+        Ok(Self {
+            identifiable: value.identifiable.try_into()?,
+            data_specification: data_specification
+                .embedded_data_specifications
+                .is_some()
+                .then_some(data_specification),
+            is_case_of: convert(value.is_case_of)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Json, JsonError};
+    use aas::part1::v3_1::attributes::data_specification::DataSpecificationContent;
+    use aas::part1::v3_1::concept_description::ConceptDescription;
+    use aas::part1::v3_1::core::{AssetAdministrationShell, AssetInformation, Submodel};
+    use aas::part1::v3_1::submodel_elements::{Blob, SubmodelElement};
+
+    #[test]
+    fn asset_administration_shell() {
+        let json = r#"{"modelType":"AssetAdministrationShell","idShort":"AAS_Tortoise_DigitalProductPassport","administration":{"version":"1","revision":"0"},"id":"https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560","assetInformation":{"assetKind":"NotApplicable","defaultThumbnail":{"path":"/aasx/files/turtle_dpp_thumbnail.jpg","contentType":"image/jpeg"}},"submodels":[{"type":"ModelReference","keys":[{"type":"Submodel","value":"https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0"}]},{"type":"ModelReference","keys":[{"type":"Submodel","value":"https://admin-shell.io/idta/SubmodelTemplate/CarbonFootprint/1/0"}]}]}"#;
+
+        let shell = AssetAdministrationShell::from_json(json).unwrap();
+        let AssetInformation::NotApplicable(asset_information) = &shell.asset_information else {
+            panic!("expected a NotApplicable asset kind");
+        };
+        let thumbnail = asset_information.default_thumbnail.as_ref().unwrap();
+        assert_eq!(thumbnail.content_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(shell.submodels.as_ref().map(Vec::len), Some(2));
+        assert_eq!(shell.to_json().unwrap(), json);
+    }
+
+    #[test]
+    fn model_type_is_optional() {
+        let shell = AssetAdministrationShell::from_json(
+            r#"{"id":"https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560","assetInformation":{"assetKind":"Instance"}}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            shell.asset_information,
+            AssetInformation::Instance(_)
+        ));
+        assert!(
+            shell
+                .to_json()
+                .unwrap()
+                .starts_with(r#"{"modelType":"AssetAdministrationShell""#)
+        );
+    }
+
+    #[test]
+    fn submodel() {
+        let json = r#"{"modelType":"Submodel","idShort":"Nameplate","description":[{"language":"en","text":"Contains the nameplate information attached to the product"}],"administration":{"version":"3","revision":"0","templateId":"https://admin-shell.io/IDTA 02006-3-0"},"id":"https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0","kind":"Instance","semanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://admin-shell.io/idta/nameplate/3/0/Nameplate"}]},"submodelElements":[{"modelType":"Blob","value":"iVBORw0KGgo=","contentType":"image/png"}]}"#;
+
+        let submodel = Submodel::from_json(json).unwrap();
+        let administration = submodel.identifiable.administration.as_ref().unwrap();
+        assert_eq!(
+            administration.template_id.as_ref().unwrap().as_ref(),
+            "https://admin-shell.io/IDTA 02006-3-0"
+        );
+        assert_eq!(
+            submodel.submodel_elements,
+            Some(vec![SubmodelElement::Blob(Blob::new(
+                Some(b"\x89PNG\r\n\x1a\n".to_vec()),
+                "image/png".into(),
+            ))])
+        );
+        assert_eq!(submodel.to_json().unwrap(), json);
+    }
+
+    #[test]
+    fn invalid_identifiers() {
+        let invalid_id = |id: &str| {
+            let json = format!(r#"{{"modelType":"Submodel","id":"{id}"}}"#);
+            match Submodel::from_json(&json) {
+                Err(JsonError::InvalidAttribute { attribute, .. }) => attribute,
+                other => panic!("expected an invalid id, got {other:?}"),
+            }
+        };
+
+        assert_eq!(invalid_id(&"a".repeat(2049)), "id");
+        assert_eq!(invalid_id(r"\u0000"), "id");
+        assert!(
+            Submodel::from_json(&format!(
+                r#"{{"modelType":"Submodel","id":"{}"}}"#,
+                "a".repeat(2048)
+            ))
+            .is_ok()
+        );
+        assert!(matches!(
+            Submodel::from_json(r#"{"modelType":"Submodel","id":"urn:x","kind":"Type"}"#),
+            Err(JsonError::InvalidAttribute {
+                attribute: "kind",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn concept_description_with_unknown_data_specification() {
+        let json = r#"{"modelType":"ConceptDescription","id":"https://example.com/cd/1","embeddedDataSpecifications":[{"dataSpecification":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/acme"}]},"dataSpecificationContent":{"color":"red","modelType":"DataSpecificationAcme"}}]}"#;
+
+        let concept_description = ConceptDescription::from_json(json).unwrap();
+        let specification = &concept_description
+            .data_specification
+            .as_ref()
+            .and_then(|d| d.embedded_data_specifications.as_ref())
+            .unwrap()[0];
+        let DataSpecificationContent::Unknown(content) = &specification.data_specification_content
+        else {
+            panic!("expected unknown content");
+        };
+        assert_eq!(content.model_type, "DataSpecificationAcme");
+        assert_eq!(concept_description.to_json().unwrap(), json);
+    }
+}
@@ -0,0 +1,212 @@
+//! A [Diff](model::Diff) with the complete new state of every change, so it can be stored and
+//! applied later. The report written by its `Serialize` impl only has the `$metadata` and
+//! ValueOnly representation, which is not enough to rebuild the items.
+
+use crate::JsonError;
+use crate::part1::v3_1::core::{AssetAdministrationShell, ConceptDescription, Submodel};
+use crate::part1::v3_1::submodel_elements::SubmodelElement;
+use crate::part1::v3_1::{identifier, invalid, parse};
+use aas::part1::v3_1::diff as model;
+use aas::part1::v3_1::id_short_path::IdShortPath;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct Diff(Vec<Change>);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Op {
+    Added,
+    Removed,
+    ValueChanged,
+    MetadataChanged,
+}
+
+/// The item is written in the JSON serialization of its type, which follows from the target.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Change {
+    op: Op,
+
+    target: Target,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    item: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Target {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shell: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    submodel: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    concept_description: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_short_path: Option<String>,
+}
+
+impl From<&model::Target> for Target {
+    fn from(value: &model::Target) -> Self {
+        let mut target = Target {
+            shell: None,
+            submodel: None,
+            concept_description: None,
+            id_short_path: None,
+        };
+        match value {
+            model::Target::Shell(id) => target.shell = Some(id.to_string()),
+            model::Target::Submodel(id) => target.submodel = Some(id.to_string()),
+            model::Target::ConceptDescription(id) => {
+                target.concept_description = Some(id.to_string())
+            }
+            model::Target::Element { submodel, path } => {
+                target.submodel = submodel.as_ref().map(ToString::to_string);
+                target.id_short_path = Some(path.to_string());
+            }
+        }
+        target
+    }
+}
+
+impl TryFrom<Target> for model::Target {
+    type Error = JsonError;
+
+    fn try_from(value: Target) -> Result<Self, Self::Error> {
+        let id = |id: &str| identifier("target", id);
+        Ok(
+            match (
+                value.shell,
+                value.submodel,
+                value.concept_description,
+                value.id_short_path,
+            ) {
+                (Some(shell), None, None, None) => model::Target::Shell(id(&shell)?),
+                (None, Some(submodel), None, None) => model::Target::Submodel(id(&submodel)?),
+                (None, None, Some(cd), None) => model::Target::ConceptDescription(id(&cd)?),
+                (None, submodel, None, Some(path)) => model::Target::Element {
+                    submodel: submodel.as_deref().map(id).transpose()?,
+                    path: parse("idShortPath", &path, str::parse::<IdShortPath>)?,
+                },
+                _ => {
+                    return Err(invalid(
+                        "target",
+                        "",
+                        "expected a shell, submodel, conceptDescription or idShortPath",
+                    ));
+                }
+            },
+        )
+    }
+}
+
+fn item(item: &model::Item) -> Result<Value, JsonError> {
+    Ok(match item {
+        model::Item::Shell(shell) => {
+            serde_json::to_value(AssetAdministrationShell::try_from(shell)?)
+        }
+        model::Item::Submodel(submodel) => serde_json::to_value(Submodel::try_from(submodel)?),
+        model::Item::ConceptDescription(cd) => {
+            serde_json::to_value(ConceptDescription::try_from(cd)?)
+        }
+        model::Item::Element(element) => serde_json::to_value(SubmodelElement::try_from(element)?),
+    }?)
+}
+
+fn read_item(target: &model::Target, item: Value) -> Result<model::Item, JsonError> {
+    Ok(match target {
+        model::Target::Shell(_) => model::Item::Shell(
+            serde_json::from_value::<AssetAdministrationShell>(item)?.try_into()?,
+        ),
+        model::Target::Submodel(_) => {
+            model::Item::Submodel(serde_json::from_value::<Submodel>(item)?.try_into()?)
+        }
+        model::Target::ConceptDescription(_) => model::Item::ConceptDescription(
+            serde_json::from_value::<ConceptDescription>(item)?.try_into()?,
+        ),
+        model::Target::Element { .. } => {
+            model::Item::Element(serde_json::from_value::<SubmodelElement>(item)?.try_into()?)
+        }
+    })
+}
+
+impl TryFrom<&model::Change> for Change {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Change) -> Result<Self, Self::Error> {
+        let (op, new) = match value {
+            model::Change::Added { item, .. } => (Op::Added, Some(item)),
+            model::Change::Removed { .. } => (Op::Removed, None),
+            model::Change::ValueChanged { item, .. } => (Op::ValueChanged, Some(item)),
+            model::Change::MetadataChanged { item, .. } => (Op::MetadataChanged, Some(item)),
+        };
+        Ok(Self {
+            op,
+            target: value.target().into(),
+            item: new.map(item).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<Change> for model::Change {
+    type Error = JsonError;
+
+    fn try_from(value: Change) -> Result<Self, Self::Error> {
+        let target = model::Target::try_from(value.target)?;
+        let item = value
+            .item
+            .map(|item| read_item(&target, item))
+            .transpose()?;
+        let item = || item.ok_or_else(|| invalid("item", "", "the change needs an item"));
+
+        Ok(match value.op {
+            Op::Added => model::Change::Added {
+                item: item()?,
+                target,
+            },
+            Op::Removed => model::Change::Removed { target },
+            Op::ValueChanged => model::Change::ValueChanged {
+                item: item()?,
+                target,
+            },
+            Op::MetadataChanged => model::Change::MetadataChanged {
+                item: item()?,
+                target,
+            },
+        })
+    }
+}
+
+impl TryFrom<&model::Diff> for Diff {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Diff) -> Result<Self, Self::Error> {
+        Ok(Self(
+            value
+                .changes
+                .iter()
+                .map(Change::try_from)
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl TryFrom<Diff> for model::Diff {
+    type Error = JsonError;
+
+    fn try_from(value: Diff) -> Result<Self, Self::Error> {
+        Ok(Self {
+            changes: value
+                .0
+                .into_iter()
+                .map(model::Change::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
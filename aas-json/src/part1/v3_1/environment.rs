@@ -1,17 +1,44 @@
-use crate::part1::v3_1::concept_description::ConceptDescription;
-use crate::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use crate::JsonError;
+use crate::part1::v3_1::core::{AssetAdministrationShell, ConceptDescription, Submodel};
+use crate::part1::v3_1::{convert, try_proxies};
+use aas::part1::v3_1::environment as model;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
-pub struct Environment {
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Environment {
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "assetAdministrationShells")]
-    pub asset_administration_shells: Option<Vec<AssetAdministrationShell>>,
+    asset_administration_shells: Option<Vec<AssetAdministrationShell>>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submodels: Option<Vec<Submodel>>,
+    submodels: Option<Vec<Submodel>>,
 
-    #[serde(rename = "conceptDescriptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub concept_descriptions: Option<Vec<ConceptDescription>>,
+    concept_descriptions: Option<Vec<ConceptDescription>>,
+}
+
+impl TryFrom<&model::Environment> for Environment {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Environment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            asset_administration_shells: try_proxies(
+                value.asset_administration_shells.iter().flatten(),
+            )?,
+            submodels: try_proxies(value.submodels.iter().flatten())?,
+            concept_descriptions: try_proxies(value.concept_descriptions.iter().flatten())?,
+        })
+    }
+}
+
+impl TryFrom<Environment> for model::Environment {
+    type Error = JsonError;
+
+    fn try_from(value: Environment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            asset_administration_shells: convert(value.asset_administration_shells)?,
+            submodels: convert(value.submodels)?,
+            concept_descriptions: convert(value.concept_descriptions)?,
+        })
+    }
 }
@@ -1,12 +0,0 @@
-use serde::{Deserialize, Serialize};
-#[cfg(feature = "openapi")]
-use utoipa::ToSchema;
-
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
-
-pub struct LevelType {
-    pub max: bool,
-    pub min: bool,
-    pub nom: bool,
-    pub typ: bool,
-}
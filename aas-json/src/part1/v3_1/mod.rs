@@ -1,11 +1,70 @@
-pub mod environment;
-pub use primitives::lang_string::*;
-pub mod attributes;
-pub mod concept_description;
-pub mod core;
-pub mod key;
-pub mod level_type;
-pub mod primitives;
-pub mod reference;
-pub mod submodel_elements;
-pub mod value_list;
+//! Proxy types in the shape of the JSON schema.
+//!
+//! Every proxy converts `TryFrom` into its core type for reading, where the
+//! [JsonError::InvalidAttribute] names the offending JSON attribute. For writing, proxies convert
+//! `From` a reference to their core type, or `TryFrom` it if they contain a value that has to lie
+//! in the value space of its valueType, e.g. properties and everything with extensions.
+
+pub(crate) mod attributes;
+pub(crate) mod core;
+pub(crate) mod diff;
+pub(crate) mod environment;
+pub(crate) mod reference;
+pub(crate) mod submodel_elements;
+
+use crate::JsonError;
+use aas::part1::v3_1::primitives::Identifier;
+use std::fmt::Display;
+
+pub(crate) fn invalid(
+    attribute: &'static str,
+    value: impl Display,
+    reason: impl Display,
+) -> JsonError {
+    JsonError::InvalidAttribute {
+        attribute,
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Parses a JSON string into a core type
+pub(crate) fn parse<'a, T, E: Display>(
+    attribute: &'static str,
+    value: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, E>,
+) -> Result<T, JsonError> {
+    parse(value).map_err(|e| invalid(attribute, value, e))
+}
+
+pub(crate) fn identifier(attribute: &'static str, value: &str) -> Result<Identifier, JsonError> {
+    parse(attribute, value, Identifier::try_from)
+}
+
+/// Proxies of a list. Empty lists are left out, the schema requires at least one item.
+pub(crate) fn proxies<'a, C: 'a, P: From<&'a C>>(
+    items: impl IntoIterator<Item = &'a C>,
+) -> Option<Vec<P>> {
+    let items: Vec<_> = items.into_iter().map(P::from).collect();
+    (!items.is_empty()).then_some(items)
+}
+
+/// Proxies of a list whose items may fail to be written
+pub(crate) fn try_proxies<'a, C: 'a, P: TryFrom<&'a C, Error = JsonError>>(
+    items: impl IntoIterator<Item = &'a C>,
+) -> Result<Option<Vec<P>>, JsonError> {
+    let items = items
+        .into_iter()
+        .map(P::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((!items.is_empty()).then_some(items))
+}
+
+/// Core types of an optional list of proxies
+pub(crate) fn convert<P, T: TryFrom<P, Error = JsonError>>(
+    items: Option<Vec<P>>,
+) -> Result<Option<Vec<T>>, JsonError> {
+    items
+        .map(|items| items.into_iter().map(T::try_from).collect())
+        .transpose()
+}
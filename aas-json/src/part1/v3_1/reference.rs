@@ -1,208 +1,130 @@
-use crate::part1::v3_1::key::Key;
-use serde::de::{Error, Visitor};
-use serde::{Deserialize, Deserializer, Serialize};
-use std::fmt;
-use std::ops::Deref;
-use strum::{Display, EnumString};
-#[cfg(feature = "openapi")]
-use utoipa::ToSchema;
+use crate::JsonError;
+use crate::part1::v3_1::{invalid, parse};
+use aas::part1::v3_1::key;
+use aas::part1::v3_1::reference::{self as model, ReferenceInner};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Reference {
+    #[serde(rename = "type")]
+    ty: String,
 
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, Default)]
-#[serde(rename = "Reference")]
-pub struct ReferenceInner {
-    /// E.g. semantic id of a standard submodel
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "referredSemanticId")]
-    #[cfg_attr(feature = "openapi", schema(no_recursion))]
-    pub referred_semantic_id: Option<Box<Reference>>,
+    referred_semantic_id: Option<Box<Reference>>,
 
-    pub keys: Vec<Key>,
+    keys: Vec<Key>,
 }
 
-/// A `Reference` is a mechanism to unambiguously identify one or multiple elements within or across
-/// Asset Administration Shells. It consists of an ordered list of `Keys`, where each `Key` defines
-/// a single step in the reference path, targeting specific sub-elements or external resources.
-///
-/// The `ReferenceType` attribute of a `Reference` determines the scope and semantics of the reference:
-/// - `GlobalReference` means the reference resolves to an element identifiable globally,
-/// often outside the local AAS context.
-/// - `LocalReference` restricts the reference scope to internal elements or fragments
-/// within the current parent element or AAS.
-///
-/// This distinction affects how references are interpreted, resolved, and validated in distributed environments,
-/// ensuring interoperability and correct addressing in digital twin ecosystems.
-///
-/// A `Reference` supports multi-level navigation through composite structures by chaining multiple keys,
-/// enabling precise targeting of nested submodels, submodel elements, or fragments.
-#[derive(EnumString, Clone, PartialEq, Debug, Deserialize, Serialize, Display)]
-#[serde(tag = "type")]
-pub enum Reference {
-    ExternalReference(ReferenceInner),
-    ModelReference(ReferenceInner),
-}
-
-impl ReferenceInner {
-    pub fn new(key: Key) -> Self {
-        Self {
-            referred_semantic_id: None,
-            keys: vec![key],
-        }
-    }
-
-    pub fn from_vec(keys: Vec<Key>) -> Self {
-        Self {
-            referred_semantic_id: None,
-            keys,
-        }
-    }
-}
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Key {
+    #[serde(rename = "type")]
+    ty: String,
 
-impl Deref for Reference {
-    type Target = ReferenceInner;
-
-    fn deref(&self) -> &Self::Target {
-        match self {
-            Reference::ExternalReference(i) | Reference::ModelReference(i) => i,
-        }
-    }
+    value: String,
 }
 
-pub fn deserialize_model_reference<'de, D>(deserializer: D) -> Result<Reference, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    // Define a visitor to handle deserialization from sequence of ModelReference
-    struct SubmodelsVisitor;
-
-    impl<'de> Visitor<'de> for SubmodelsVisitor {
-        type Value = Reference;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("an optional sequence of ModelReference")
-        }
-
-        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let reference: Reference = Reference::deserialize(deserializer)?;
-
-            if let Reference::ModelReference(model_ref_inner) = reference {
-                // Convert model_ref_inner into Submodel
-                // Assuming From<ReferenceInner> for Submodel is implemented
-                Ok(Reference::ModelReference(model_ref_inner))
-            } else {
-                Err(D::Error::custom("unexpected reference type"))
-            }
+impl From<&model::Reference> for Reference {
+    fn from(reference: &model::Reference) -> Self {
+        let ty = match reference {
+            model::Reference::ExternalReference(_) => "ExternalReference",
+            model::Reference::ModelReference(_) => "ModelReference",
+        };
+        Self {
+            ty: ty.into(),
+            referred_semantic_id: reference
+                .referred_semantic_id
+                .as_deref()
+                .map(|referred| Box::new(referred.into())),
+            keys: reference
+                .keys
+                .iter()
+                .map(|key| Key {
+                    ty: key.to_string(),
+                    value: key.value().clone(),
+                })
+                .collect(),
         }
     }
-
-    deserializer.deserialize_option(SubmodelsVisitor)
 }
 
-pub fn deserialize_external_reference<'de, D>(deserializer: D) -> Result<Reference, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    // Define a visitor to handle deserialization from sequence of ModelReference
-    struct SubmodelsVisitor;
-
-    impl<'de> Visitor<'de> for SubmodelsVisitor {
-        type Value = Reference;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("an optional sequence of ModelReference")
-        }
-
-        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let reference: Reference = Reference::deserialize(deserializer)?;
-
-            if let Reference::ExternalReference(model_ref_inner) = reference {
-                // Convert model_ref_inner into Submodel
-                // Assuming From<ReferenceInner> for Submodel is implemented
-                Ok(Reference::ExternalReference(model_ref_inner))
-            } else {
-                Err(D::Error::custom("unexpected reference type"))
-            }
+impl TryFrom<Reference> for model::Reference {
+    type Error = JsonError;
+
+    fn try_from(reference: Reference) -> Result<Self, Self::Error> {
+        let keys = reference
+            .keys
+            .into_iter()
+            .map(|key| {
+                let mut parsed = parse("type", &key.ty, key::Key::from_str)?;
+                *parsed.value_mut() = key.value;
+                Ok(parsed)
+            })
+            .collect::<Result<_, JsonError>>()?;
+        let inner = ReferenceInner {
+            referred_semantic_id: reference
+                .referred_semantic_id
+                .map(|referred| (*referred).try_into().map(Box::new))
+                .transpose()?,
+            keys,
+        };
+
+        match reference.ty.as_str() {
+            "ExternalReference" => Ok(model::Reference::ExternalReference(inner)),
+            "ModelReference" => Ok(model::Reference::ModelReference(inner)),
+            ty => Err(invalid(
+                "type",
+                ty,
+                "expected ExternalReference or ModelReference",
+            )),
         }
     }
-
-    deserializer.deserialize_option(SubmodelsVisitor)
 }
 
-pub fn deserialize_optional_model_reference<'de, D>(
-    deserializer: D,
-) -> Result<Option<Reference>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    // Define a visitor to handle deserialization from sequence of ModelReference
-    struct SubmodelsVisitor;
-
-    impl<'de> Visitor<'de> for SubmodelsVisitor {
-        type Value = Option<Reference>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("an optional sequence of ModelReference")
-        }
-
-        fn visit_none<E>(self) -> Result<Self::Value, E> {
-            Ok(None)
-        }
-
-        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let reference: Reference = Reference::deserialize(deserializer)?;
-
-            if let Reference::ModelReference(model_ref_inner) = reference {
-                // Convert model_ref_inner into Submodel
-                // Assuming From<ReferenceInner> for Submodel is implemented
-                Ok(Some(Reference::ModelReference(model_ref_inner)))
-            } else {
-                Err(D::Error::custom("unexpected reference type"))
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use crate::{Json, JsonError};
+    use aas::part1::v3_1::key::Key;
+    use aas::part1::v3_1::reference::{Reference, ReferenceInner};
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"type":"ExternalReference","referredSemanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/semantics"}]},"keys":[{"type":"Blob","value":"http://example/blob"},{"type":"Blob","value":"http://example/blob2"}]}"#;
+
+        let reference = Reference::from_json(json).unwrap();
+        let Reference::ExternalReference(inner) = &reference else {
+            panic!("expected an external reference");
+        };
+        assert_eq!(
+            inner.keys,
+            [
+                Key::Blob("http://example/blob".into()),
+                Key::Blob("http://example/blob2".into())
+            ]
+        );
+        assert_eq!(
+            inner.referred_semantic_id.as_deref(),
+            Some(&Reference::ExternalReference(ReferenceInner::new(
+                Key::GlobalReference("https://example.com/semantics".into())
+            )))
+        );
+        assert_eq!(reference.to_json().unwrap(), json);
     }
 
-    deserializer.deserialize_option(SubmodelsVisitor)
-}
-
-pub fn deserialize_optional_external_reference<'de, D>(
-    deserializer: D,
-) -> Result<Option<Reference>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    // Define a visitor to handle deserialization from sequence of ModelReference
-    struct SubmodelsVisitor;
-
-    impl<'de> Visitor<'de> for SubmodelsVisitor {
-        type Value = Option<Reference>;
-
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("an optional sequence of ModelReference")
-        }
-
-        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
-        where
-            D: Deserializer<'de>,
-        {
-            let reference: Reference = Reference::deserialize(deserializer)?;
-
-            if let Reference::ExternalReference(model_ref_inner) = reference {
-                // Convert model_ref_inner into Submodel
-                // Assuming From<ReferenceInner> for Submodel is implemented
-                Ok(Some(Reference::ExternalReference(model_ref_inner)))
-            } else {
-                Err(D::Error::custom("unexpected reference type"))
-            }
+    #[test]
+    fn invalid_types() {
+        for json in [
+            r#"{"type":"GlobalReference","keys":[{"type":"Submodel","value":"urn:x"}]}"#,
+            r#"{"type":"ModelReference","keys":[{"type":"Submodels","value":"urn:x"}]}"#,
+        ] {
+            assert!(matches!(
+                Reference::from_json(json),
+                Err(JsonError::InvalidAttribute {
+                    attribute: "type",
+                    ..
+                })
+            ));
         }
     }
-
-    deserializer.deserialize_option(SubmodelsVisitor)
 }
@@ -0,0 +1,1055 @@
+use crate::JsonError;
+use crate::part1::v3_1::attributes::{
+    HasDataSpecification, HasSemantics, LangString, Qualifiable, Referable, data_value, data_xsd,
+    value_type,
+};
+use crate::part1::v3_1::core::SpecificAssetId;
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::{convert, identifier, invalid, parse, proxies, try_proxies};
+use aas::part1::v3_1::attributes::data_specification;
+use aas::part1::v3_1::attributes::qualifiable;
+use aas::part1::v3_1::attributes::referable;
+use aas::part1::v3_1::attributes::semantics;
+use aas::part1::v3_1::primitives::{BlobType, MessageTopic, Uri};
+use aas::part1::v3_1::submodel_elements::{
+    self as model, AasSubmodelElements, DataElement, Direction, EntityInner, OperationVariable,
+    RangeValue, StateOfEvent, SubmodelElementFields,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::base64::Base64;
+use serde_with::serde_as;
+use std::str::FromStr;
+
+/// Attributes every submodel element inherits
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ElementFields {
+    #[serde(flatten)]
+    referable: Referable,
+
+    #[serde(flatten)]
+    semantics: HasSemantics,
+
+    #[serde(flatten)]
+    qualifiable: Qualifiable,
+
+    #[serde(flatten)]
+    data_specification: HasDataSpecification,
+}
+
+impl ElementFields {
+    fn new(
+        referable: &referable::Referable,
+        semantics: &semantics::HasSemantics,
+        qualifiable: &qualifiable::Qualifiable,
+        data_specification: &data_specification::HasDataSpecification,
+    ) -> Result<Self, JsonError> {
+        Ok(Self {
+            referable: referable.try_into()?,
+            semantics: semantics.into(),
+            qualifiable: qualifiable.try_into()?,
+            data_specification: data_specification.into(),
+        })
+    }
+}
+
+impl TryFrom<&SubmodelElementFields> for ElementFields {
+    type Error = JsonError;
+
+    fn try_from(value: &SubmodelElementFields) -> Result<Self, Self::Error> {
+        Self::new(
+            &value.referable,
+            &value.semantics,
+            &value.qualifiable,
+            &value.embedded_data_specifications,
+        )
+    }
+}
+
+impl TryFrom<ElementFields> for SubmodelElementFields {
+    type Error = JsonError;
+
+    fn try_from(value: ElementFields) -> Result<Self, Self::Error> {
+        Ok(Self {
+            referable: value.referable.try_into()?,
+            semantics: value.semantics.try_into()?,
+            qualifiable: value.qualifiable.try_into()?,
+            embedded_data_specifications: value.data_specification.try_into()?,
+        })
+    }
+}
+
+/// Proxies of the concrete submodel elements. A core [DataElement] is written as the element it
+/// wraps, the JSON schema has no modelType for the abstract class.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "modelType")]
+pub(crate) enum SubmodelElement {
+    RelationshipElement(RelationshipElement),
+    AnnotatedRelationshipElement(AnnotatedRelationshipElement),
+    BasicEventElement(BasicEventElement),
+    Blob(Blob),
+    Capability(Capability),
+    Entity(Entity),
+    File(File),
+    MultiLanguageProperty(MultiLanguageProperty),
+    Operation(Operation),
+    Property(Property),
+    Range(Range),
+    ReferenceElement(ReferenceElement),
+    SubmodelElementCollection(SubmodelElementCollection),
+    SubmodelElementList(SubmodelElementList),
+}
+
+impl TryFrom<&model::SubmodelElement> for SubmodelElement {
+    type Error = JsonError;
+
+    fn try_from(value: &model::SubmodelElement) -> Result<Self, Self::Error> {
+        Ok(match value {
+            model::SubmodelElement::RelationshipElement(e) => {
+                Self::RelationshipElement(e.try_into()?)
+            }
+            model::SubmodelElement::AnnotatedRelationshipElement(e) => {
+                Self::AnnotatedRelationshipElement(e.try_into()?)
+            }
+            model::SubmodelElement::BasicEventElement(e) => Self::BasicEventElement(e.try_into()?),
+            model::SubmodelElement::Blob(e) => Self::Blob(e.try_into()?),
+            model::SubmodelElement::Capability(e) => Self::Capability(e.try_into()?),
+            model::SubmodelElement::DataElement(e) => e.try_into()?,
+            model::SubmodelElement::Entity(e) => Self::Entity(e.try_into()?),
+            model::SubmodelElement::File(e) => Self::File(e.try_into()?),
+            model::SubmodelElement::MultiLanguageProperty(e) => {
+                Self::MultiLanguageProperty(e.try_into()?)
+            }
+            model::SubmodelElement::Operation(e) => Self::Operation(e.try_into()?),
+            model::SubmodelElement::Property(e) => Self::Property(e.try_into()?),
+            model::SubmodelElement::Range(e) => Self::Range(e.try_into()?),
+            model::SubmodelElement::ReferenceElement(e) => Self::ReferenceElement(e.try_into()?),
+            model::SubmodelElement::SubmodelElementCollection(e) => {
+                Self::SubmodelElementCollection(e.try_into()?)
+            }
+            model::SubmodelElement::SubmodelElementList(e) => {
+                Self::SubmodelElementList(e.try_into()?)
+            }
+        })
+    }
+}
+
+impl TryFrom<&DataElement> for SubmodelElement {
+    type Error = JsonError;
+
+    fn try_from(value: &DataElement) -> Result<Self, Self::Error> {
+        Ok(match value {
+            DataElement::Blob(e) => Self::Blob(e.try_into()?),
+            DataElement::File(e) => Self::File(e.try_into()?),
+            DataElement::MultiLanguageProperty(e) => Self::MultiLanguageProperty(e.try_into()?),
+            DataElement::Property(e) => Self::Property(e.try_into()?),
+            DataElement::Range(e) => Self::Range(e.try_into()?),
+            DataElement::ReferenceElement(e) => Self::ReferenceElement(e.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<SubmodelElement> for model::SubmodelElement {
+    type Error = JsonError;
+
+    fn try_from(value: SubmodelElement) -> Result<Self, Self::Error> {
+        Ok(match value {
+            SubmodelElement::RelationshipElement(e) => Self::RelationshipElement(e.try_into()?),
+            SubmodelElement::AnnotatedRelationshipElement(e) => {
+                Self::AnnotatedRelationshipElement(e.try_into()?)
+            }
+            SubmodelElement::BasicEventElement(e) => Self::BasicEventElement(e.try_into()?),
+            SubmodelElement::Blob(e) => Self::Blob(e.try_into()?),
+            SubmodelElement::Capability(e) => Self::Capability(e.try_into()?),
+            SubmodelElement::Entity(e) => Self::Entity(e.try_into()?),
+            SubmodelElement::File(e) => Self::File(e.try_into()?),
+            SubmodelElement::MultiLanguageProperty(e) => Self::MultiLanguageProperty(e.try_into()?),
+            SubmodelElement::Operation(e) => Self::Operation(e.try_into()?),
+            SubmodelElement::Property(e) => Self::Property(e.try_into()?),
+            SubmodelElement::Range(e) => Self::Range(e.try_into()?),
+            SubmodelElement::ReferenceElement(e) => Self::ReferenceElement(e.try_into()?),
+            SubmodelElement::SubmodelElementCollection(e) => {
+                Self::SubmodelElementCollection(e.try_into()?)
+            }
+            SubmodelElement::SubmodelElementList(e) => Self::SubmodelElementList(e.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<SubmodelElement> for DataElement {
+    type Error = JsonError;
+
+    fn try_from(value: SubmodelElement) -> Result<Self, Self::Error> {
+        DataElement::try_from(model::SubmodelElement::try_from(value)?)
+            .map_err(|element| invalid("modelType", element, "expected a data element"))
+    }
+}
+
+/// Implements the conversions of an element proxy with the common [ElementFields], the
+/// remaining fields are converted by the given closures.
+macro_rules! element {
+    ($proxy:ident => $model:ty, |$from:ident| { $($write:tt)* }, |$into:ident| { $($read:tt)* }) => {
+        impl TryFrom<&$model> for $proxy {
+            type Error = JsonError;
+
+            fn try_from($from: &$model) -> Result<Self, Self::Error> {
+                Ok(Self {
+                    fields: ElementFields::new(
+                        &$from.referable,
+                        &$from.semantics,
+                        &$from.qualifiable,
+                        &$from.embedded_data_specifications,
+                    )?,
+                    $($write)*
+                })
+            }
+        }
+
+        impl TryFrom<$proxy> for $model {
+            type Error = JsonError;
+
+            fn try_from($into: $proxy) -> Result<Self, Self::Error> {
+                let SubmodelElementFields {
+                    referable,
+                    semantics,
+                    qualifiable,
+                    embedded_data_specifications,
+                } = $into.fields.try_into()?;
+                Ok(Self {
+                    referable,
+                    semantics,
+                    qualifiable,
+                    embedded_data_specifications,
+                    $($read)*
+                })
+            }
+        }
+    };
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelationshipElement {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    second: Option<Reference>,
+}
+
+element!(RelationshipElement => model::RelationshipElement, |value| {
+    first: value.first.as_ref().map(Into::into),
+    second: value.second.as_ref().map(Into::into),
+}, |value| {
+    first: value.first.map(TryInto::try_into).transpose()?,
+    second: value.second.map(TryInto::try_into).transpose()?,
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AnnotatedRelationshipElement {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    second: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    annotations: Option<Vec<SubmodelElement>>,
+}
+
+element!(AnnotatedRelationshipElement => model::AnnotatedRelationshipElement, |value| {
+    first: value.first.as_ref().map(Into::into),
+    second: value.second.as_ref().map(Into::into),
+    annotations: try_proxies(value.annotations.iter().flatten())?,
+}, |value| {
+    first: value.first.map(TryInto::try_into).transpose()?,
+    second: value.second.map(TryInto::try_into).transpose()?,
+    annotations: convert(value.annotations)?,
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BasicEventElement {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    observed: Reference,
+    direction: String,
+    state: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_topic: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message_broker: Option<Reference>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_update: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_interval: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_interval: Option<String>,
+}
+
+impl TryFrom<&model::BasicEventElement> for BasicEventElement {
+    type Error = JsonError;
+
+    fn try_from(value: &model::BasicEventElement) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fields: value.submodel_element_fields().try_into()?,
+            observed: (&value.observed).into(),
+            direction: match value.direction {
+                Direction::Input => "input",
+                Direction::Output => "output",
+            }
+            .into(),
+            state: match value.state {
+                StateOfEvent::On => "on",
+                StateOfEvent::Off => "off",
+            }
+            .into(),
+            message_topic: value.message_topic.as_ref().map(ToString::to_string),
+            message_broker: value.message_broker.as_ref().map(Into::into),
+            last_update: value.last_update.as_ref().map(ToString::to_string),
+            min_interval: value.min_interval.clone(),
+            max_interval: value.max_interval.clone(),
+        })
+    }
+}
+
+impl TryFrom<BasicEventElement> for model::BasicEventElement {
+    type Error = JsonError;
+
+    fn try_from(value: BasicEventElement) -> Result<Self, Self::Error> {
+        let direction = match value.direction.as_str() {
+            "input" => Direction::Input,
+            "output" => Direction::Output,
+            direction => return Err(invalid("direction", direction, "expected input or output")),
+        };
+        let state = match value.state.as_str() {
+            "on" => StateOfEvent::On,
+            "off" => StateOfEvent::Off,
+            state => return Err(invalid("state", state, "expected on or off")),
+        };
+        let mut element = Self::new(
+            value.fields.try_into()?,
+            value.observed.try_into()?,
+            direction,
+            state,
+        );
+        element.message_topic = value
+            .message_topic
+            .map(|topic| parse("messageTopic", &topic, MessageTopic::try_from))
+            .transpose()?;
+        element.message_broker = value.message_broker.map(TryInto::try_into).transpose()?;
+        element.last_update = value
+            .last_update
+            .map(|update| parse("lastUpdate", &update, str::parse))
+            .transpose()?;
+        element.min_interval = value.min_interval;
+        element.max_interval = value.max_interval;
+        Ok(element)
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Blob {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde_as(as = "Option<Base64>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<BlobType>,
+
+    content_type: String,
+}
+
+element!(Blob => model::Blob, |value| {
+    value: value.value.clone(),
+    content_type: value.content_type.clone(),
+}, |value| {
+    value: value.value,
+    content_type: value.content_type,
+});
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Capability {
+    #[serde(flatten)]
+    fields: ElementFields,
+}
+
+element!(Capability => model::Capability, |value| {}, |value| {});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Entity {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    statements: Option<Vec<SubmodelElement>>,
+
+    entity_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    global_asset_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    specific_asset_ids: Option<Vec<SpecificAssetId>>,
+}
+
+impl TryFrom<&model::Entity> for Entity {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Entity) -> Result<Self, Self::Error> {
+        let (entity_type, inner) = match value {
+            model::Entity::CoManagedEntity(inner) => ("CoManagedEntity", inner),
+            model::Entity::SelfManagedEntity(inner) => ("SelfManagedEntity", inner),
+        };
+        Ok(Self {
+            fields: ElementFields::new(
+                &inner.referable,
+                &inner.semantics,
+                &inner.qualifiable,
+                &inner.embedded_data_specifications,
+            )?,
+            statements: try_proxies(inner.statements.iter().flatten())?,
+            entity_type: entity_type.into(),
+            global_asset_id: inner.global_asset_id.as_ref().map(ToString::to_string),
+            specific_asset_ids: proxies(inner.specific_asset_id.iter().flatten()),
+        })
+    }
+}
+
+impl TryFrom<Entity> for model::Entity {
+    type Error = JsonError;
+
+    fn try_from(value: Entity) -> Result<Self, Self::Error> {
+        let SubmodelElementFields {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+        } = value.fields.try_into()?;
+        let inner = EntityInner {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+            statements: convert(value.statements)?,
+            global_asset_id: value
+                .global_asset_id
+                .map(|id| identifier("globalAssetId", &id))
+                .transpose()?,
+            specific_asset_id: convert(value.specific_asset_ids)?,
+        };
+        match value.entity_type.as_str() {
+            "CoManagedEntity" => Ok(Self::CoManagedEntity(inner)),
+            "SelfManagedEntity" => Ok(Self::SelfManagedEntity(inner)),
+            entity_type => Err(invalid(
+                "entityType",
+                entity_type,
+                "expected CoManagedEntity or SelfManagedEntity",
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct File {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+}
+
+element!(File => model::File, |value| {
+    value: value.value.as_ref().map(ToString::to_string),
+    content_type: value.content_type.clone(),
+}, |value| {
+    value: value
+        .value
+        .map(|path| parse("value", &path, Uri::from_str))
+        .transpose()?,
+    content_type: value.content_type,
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MultiLanguageProperty {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Vec<LangString>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_id: Option<Reference>,
+}
+
+element!(MultiLanguageProperty => model::MultiLanguageProperty, |value| {
+    value: proxies(value.value.iter().flatten()),
+    value_id: value.value_id.as_ref().map(Into::into),
+}, |value| {
+    value: convert(value.value)?,
+    value_id: value.value_id.map(TryInto::try_into).transpose()?,
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Operation {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input_variables: Option<Vec<OperationVariableProxy>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_variables: Option<Vec<OperationVariableProxy>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inoutput_variables: Option<Vec<OperationVariableProxy>>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct OperationVariableProxy {
+    value: SubmodelElement,
+}
+
+impl TryFrom<&OperationVariable> for OperationVariableProxy {
+    type Error = JsonError;
+
+    fn try_from(value: &OperationVariable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: (&value.value).try_into()?,
+        })
+    }
+}
+
+impl TryFrom<OperationVariableProxy> for OperationVariable {
+    type Error = JsonError;
+
+    fn try_from(value: OperationVariableProxy) -> Result<Self, Self::Error> {
+        Ok(Self::new(value.value.try_into()?))
+    }
+}
+
+element!(Operation => model::Operation, |value| {
+    input_variables: try_proxies(value.input_variables.iter().flatten())?,
+    output_variables: try_proxies(value.output_variables.iter().flatten())?,
+    inoutput_variables: try_proxies(value.inoutput_variables.iter().flatten())?,
+}, |value| {
+    input_variables: convert(value.input_variables)?,
+    output_variables: convert(value.output_variables)?,
+    inoutput_variables: convert(value.inoutput_variables)?,
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Property {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    value_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_id: Option<Reference>,
+}
+
+impl TryFrom<&model::Property> for Property {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Property) -> Result<Self, Self::Error> {
+        let (value_type, data) = data_value(&value.value)?;
+        Ok(Self {
+            fields: ElementFields::new(
+                &value.referable,
+                &value.semantics,
+                &value.qualifiable,
+                &value.embedded_data_specifications,
+            )?,
+            value_type,
+            value: data,
+            value_id: value.value_id.as_ref().map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<Property> for model::Property {
+    type Error = JsonError;
+
+    fn try_from(value: Property) -> Result<Self, Self::Error> {
+        let SubmodelElementFields {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+        } = value.fields.try_into()?;
+        Ok(Self {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+            value: data_xsd(&value.value_type, value.value)?,
+            value_id: value.value_id.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Range {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    value_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<String>,
+}
+
+impl TryFrom<&model::Range> for Range {
+    type Error = JsonError;
+
+    fn try_from(value: &model::Range) -> Result<Self, Self::Error> {
+        let (min, max) = value.value.to_lexical().map_err(|e| {
+            let (min, max) = value.value.clone().into_bounds();
+            let bounds = format!(
+                "{}..{}",
+                Option::<String>::from(min).unwrap_or_default(),
+                Option::<String>::from(max).unwrap_or_default()
+            );
+            invalid("min/max", bounds, e)
+        })?;
+        Ok(Self {
+            fields: ElementFields::new(
+                &value.referable,
+                &value.semantics,
+                &value.qualifiable,
+                &value.embedded_data_specifications,
+            )?,
+            value_type: value.value.value_type().to_string(),
+            min,
+            max,
+        })
+    }
+}
+
+impl TryFrom<Range> for model::Range {
+    type Error = JsonError;
+
+    fn try_from(value: Range) -> Result<Self, Self::Error> {
+        let SubmodelElementFields {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+        } = value.fields.try_into()?;
+        let value_type = value_type("valueType", &value.value_type)?;
+        let bounds = format!(
+            "{}..{}",
+            value.min.as_deref().unwrap_or_default(),
+            value.max.as_deref().unwrap_or_default()
+        );
+        Ok(Self {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+            value: RangeValue::try_from((value_type, value.min, value.max))
+                .map_err(|e| invalid("min/max", bounds, e))?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ReferenceElement {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Reference>,
+}
+
+element!(ReferenceElement => model::ReferenceElement, |value| {
+    value: value.value.as_ref().map(Into::into),
+}, |value| {
+    value: value.value.map(TryInto::try_into).transpose()?,
+});
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SubmodelElementCollection {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Vec<SubmodelElement>>,
+}
+
+element!(SubmodelElementCollection => model::SubmodelElementCollection, |value| {
+    value: try_proxies(value.value.iter().flatten())?,
+}, |value| {
+    value: convert(value.value)?,
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubmodelElementList {
+    #[serde(flatten)]
+    fields: ElementFields,
+
+    /// Defaults to true if absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_relevant: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    semantic_id_list_element: Option<Reference>,
+
+    type_value_list_element: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_type_list_element: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Vec<SubmodelElement>>,
+}
+
+element!(SubmodelElementList => model::SubmodelElementList, |value| {
+    order_relevant: (!value.is_order_relevant).then_some(false),
+    semantic_id_list_element: value.semantic_id_list_element.as_ref().map(Into::into),
+    type_value_list_element: value.type_value_list_element.to_string(),
+    value_type_list_element: value.value_type_list_element.as_ref().map(ToString::to_string),
+    value: try_proxies(value.value.iter().flatten())?,
+}, |value| {
+    is_order_relevant: value.order_relevant.unwrap_or(true),
+    semantic_id_list_element: value.semantic_id_list_element.map(TryInto::try_into).transpose()?,
+    type_value_list_element: parse(
+        "typeValueListElement",
+        &value.type_value_list_element,
+        AasSubmodelElements::from_str,
+    )?,
+    value_type_list_element: value
+        .value_type_list_element
+        .map(|list_type| value_type("valueTypeListElement", &list_type))
+        .transpose()?,
+    value: convert(value.value)?,
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::{Json, JsonError};
+    use aas::part1::v3_1::key::Key;
+    use aas::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use aas::part1::v3_1::reference::{Reference, ReferenceInner};
+    use aas::part1::v3_1::submodel_elements::{
+        Blob, Entity, OperationVariable, RangeInner, RangeValue, SubmodelElement,
+    };
+
+    /// Reads the element and writes it again unchanged
+    fn round_trip(json: &str) -> SubmodelElement {
+        let element = SubmodelElement::from_json(json).unwrap();
+        assert_eq!(element.to_json().unwrap(), json);
+        element
+    }
+
+    fn invalid_attribute(json: &str) -> &'static str {
+        match SubmodelElement::from_json(json) {
+            Err(JsonError::InvalidAttribute { attribute, .. }) => attribute,
+            other => panic!("expected an invalid attribute, got {other:?}"),
+        }
+    }
+
+    fn variable(element: &SubmodelElement) -> (&str, &DataXsd) {
+        let SubmodelElement::Property(property) = element else {
+            panic!("expected a property");
+        };
+        (
+            property.referable.id_short.as_ref().unwrap().as_ref(),
+            &property.value,
+        )
+    }
+
+    #[test]
+    fn blob_value_is_base64_encoded() {
+        let element =
+            round_trip(r#"{"modelType":"Blob","value":"iVBORw0KGgo=","contentType":"image/png"}"#);
+
+        assert_eq!(
+            element,
+            SubmodelElement::Blob(Blob::new(
+                Some(b"\x89PNG\r\n\x1a\n".to_vec()),
+                "image/png".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn blob_rejects_invalid_base64() {
+        assert!(matches!(
+            SubmodelElement::from_json(
+                r#"{"modelType":"Blob","value":"not base64!","contentType":"image/png"}"#
+            ),
+            Err(JsonError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn blob_without_value() {
+        let blob = SubmodelElement::Blob(Blob::new(
+            Some(vec![1, 2, 3]),
+            "application/octet-stream".into(),
+        ));
+
+        assert_eq!(
+            blob.without_blob_values().to_json().unwrap(),
+            r#"{"modelType":"Blob","contentType":"application/octet-stream"}"#
+        );
+    }
+
+    #[test]
+    fn operation_variables() {
+        let SubmodelElement::Operation(operation) = SubmodelElement::from_json(
+            r#"{
+                "modelType": "Operation",
+                "idShort": "SetSpeed",
+                "inputVariables": [
+                    { "value": { "modelType": "Property", "idShort": "speed", "valueType": "xs:int" } },
+                    { "value": { "modelType": "Property", "idShort": "ramp", "valueType": "xs:double" } }
+                ],
+                "outputVariables": [
+                    { "value": { "modelType": "Property", "idShort": "accepted", "valueType": "xs:boolean" } }
+                ]
+            }"#,
+        )
+        .unwrap() else {
+            panic!("expected an operation");
+        };
+
+        let input: Vec<_> = operation
+            .input_variables
+            .iter()
+            .flatten()
+            .map(|v| variable(&v.value))
+            .collect();
+        assert_eq!(
+            input,
+            [
+                ("speed", &DataXsd::Int(None)),
+                ("ramp", &DataXsd::Double(None))
+            ]
+        );
+        let output = operation.output_variables.as_ref().unwrap();
+        assert_eq!(
+            variable(&output[0].value),
+            ("accepted", &DataXsd::Boolean(None))
+        );
+        assert_eq!(operation.inoutput_variables, None);
+    }
+
+    #[test]
+    fn operation_round_trip() {
+        let element = round_trip(
+            r#"{"modelType":"Operation","idShort":"Add","inputVariables":[{"value":{"modelType":"Property","idShort":"a","valueType":"xs:int","value":"1"}},{"value":{"modelType":"Property","idShort":"b","valueType":"xs:int","value":"2"}}],"inoutputVariables":[{"value":{"modelType":"Property","idShort":"c","valueType":"xs:int","value":"3"}}]}"#,
+        );
+
+        let SubmodelElement::Operation(operation) = &element else {
+            panic!("expected an operation");
+        };
+        assert_eq!(operation.output_variables, None);
+        let inoutput: &[OperationVariable] = operation.inoutput_variables.as_ref().unwrap();
+        assert_eq!(variable(&inoutput[0].value), ("c", &DataXsd::Int(Some(3))));
+    }
+
+    #[test]
+    fn range_with_id_short() {
+        let element = round_trip(
+            r#"{"modelType":"Range","idShort":"Temperature","valueType":"xs:int","min":"-5","max":"40"}"#,
+        );
+
+        let SubmodelElement::Range(range) = element else {
+            panic!("expected a range");
+        };
+        assert_eq!(range.referable.id_short.unwrap().as_ref(), "Temperature");
+        assert_eq!(
+            range.value,
+            RangeValue::Int(RangeInner {
+                min: Some(-5),
+                max: Some(40),
+            })
+        );
+    }
+
+    #[test]
+    fn range_value_types() {
+        for (value_type, bounds) in [
+            ("xs:string", r#","min":"a","max":"z""#),
+            ("xs:boolean", r#","min":"false","max":"true""#),
+            ("xs:double", r#","min":"-1.5","max":"INF""#),
+            ("xs:decimal", r#","min":"0.5""#),
+            ("xs:unsignedByte", r#","max":"255""#),
+            ("xs:duration", r#","min":"PT1M","max":"P1D""#),
+            ("xs:gYear", r#","min":"-0044","max":"2024Z""#),
+            ("xs:base64Binary", ""),
+        ] {
+            let element = round_trip(&format!(
+                r#"{{"modelType":"Range","valueType":"{value_type}"{bounds}}}"#
+            ));
+            let SubmodelElement::Range(range) = element else {
+                panic!("expected a range");
+            };
+            assert_eq!(range.value_type().to_string(), value_type);
+        }
+    }
+
+    #[test]
+    fn range_rejects_invalid_bounds() {
+        for bounds in [
+            r#""valueType":"xs:int","min":"a""#,
+            r#""valueType":"xs:unsignedByte","max":"256""#,
+            r#""valueType":"xs:duration","min":"P1W""#,
+        ] {
+            assert_eq!(
+                invalid_attribute(&format!(r#"{{"modelType":"Range",{bounds}}}"#)),
+                "min/max"
+            );
+        }
+        assert_eq!(
+            invalid_attribute(r#"{"modelType":"Range","valueType":"xs:float32"}"#),
+            "valueType"
+        );
+    }
+
+    #[test]
+    fn reference_element() {
+        let element = round_trip(
+            r#"{"modelType":"ReferenceElement","idShort":"ManufacturerRef","semanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/ids/cd/1"}]},"value":{"type":"ModelReference","keys":[{"type":"Submodel","value":"https://example.com/ids/sm/1"}]}}"#,
+        );
+
+        let SubmodelElement::ReferenceElement(reference) = element else {
+            panic!("expected a reference element");
+        };
+        assert_eq!(
+            reference.semantics.semantic_id,
+            Some(Reference::ExternalReference(ReferenceInner::new(
+                Key::GlobalReference("https://example.com/ids/cd/1".into())
+            )))
+        );
+        assert_eq!(
+            reference.value,
+            Some(Reference::ModelReference(ReferenceInner::new(
+                Key::Submodel("https://example.com/ids/sm/1".into())
+            )))
+        );
+    }
+
+    #[test]
+    fn relationship_element_keeps_first_and_second() {
+        let element = round_trip(
+            r#"{"modelType":"RelationshipElement","idShort":"relationship_test","first":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/1"}]},"second":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/2"}]}}"#,
+        );
+
+        let SubmodelElement::RelationshipElement(relationship) = element else {
+            panic!("expected a relationship element");
+        };
+        assert!(relationship.first.is_some() && relationship.second.is_some());
+    }
+
+    #[test]
+    fn entity() {
+        let element = round_trip(
+            r#"{"modelType":"Entity","idShort":"Motor","statements":[{"modelType":"Property","idShort":"speed","valueType":"xs:int","value":"3000"}],"entityType":"SelfManagedEntity","globalAssetId":"https://example.com/assets/motor"}"#,
+        );
+
+        let SubmodelElement::Entity(Entity::SelfManagedEntity(entity)) = element else {
+            panic!("expected a self-managed entity");
+        };
+        assert_eq!(entity.statements.map(|s| s.len()), Some(1));
+
+        assert_eq!(
+            invalid_attribute(r#"{"modelType":"Entity","entityType":"Managed"}"#),
+            "entityType"
+        );
+    }
+
+    #[test]
+    fn file() {
+        let element = round_trip(
+            r#"{"modelType":"File","idShort":"AssemblyInstructions_DigitalFile","semanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"0173-1#02-ABK126#003"}]},"qualifiers":[{"semanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://admin-shell.io/SubmodelTemplates/Cardinality/1/0"}]},"type":"Cardinality","valueType":"xs:string","value":"OneToMany"}],"value":"/aasx/files/safety_information.pdf","contentType":"application/pdf"}"#,
+        );
+
+        let SubmodelElement::File(file) = element else {
+            panic!("expected a file");
+        };
+        assert_eq!(
+            file.value.unwrap().as_str(),
+            "/aasx/files/safety_information.pdf"
+        );
+    }
+
+    #[test]
+    fn multi_language_property() {
+        let element = round_trip(
+            r#"{"modelType":"MultiLanguageProperty","idShort":"CityTown","displayName":[{"language":"en","text":"city"}],"qualifiers":[{"kind":"ConceptQualifier","type":"Multiplicity","valueType":"xs:string","value":"ZeroToOne"}],"value":[{"language":"de","text":"Das ist ein deutscher Bezeichner"},{"language":"en","text":"That's an English label"}]}"#,
+        );
+
+        let SubmodelElement::MultiLanguageProperty(property) = element else {
+            panic!("expected a multi language property");
+        };
+        assert_eq!(property.value.map(|v| v.len()), Some(2));
+    }
+
+    #[test]
+    fn submodel_element_list() {
+        let element = round_trip(
+            r#"{"modelType":"SubmodelElementList","idShort":"PcfCalculationMethods","orderRelevant":false,"semanticIdListElement":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"0173-1#02-ABG854#003"}]},"typeValueListElement":"Property","valueTypeListElement":"xs:string","value":[{"modelType":"Property","category":"PARAMETER","valueType":"xs:string","value":"in-house method - see documentation"}]}"#,
+        );
+
+        let SubmodelElement::SubmodelElementList(list) = element else {
+            panic!("expected a list");
+        };
+        assert!(!list.is_order_relevant);
+        assert_eq!(list.value.map(|v| v.len()), Some(1));
+
+        assert_eq!(
+            invalid_attribute(
+                r#"{"modelType":"SubmodelElementList","typeValueListElement":"Properties"}"#
+            ),
+            "typeValueListElement"
+        );
+    }
+
+    #[test]
+    fn basic_event_element() {
+        round_trip(
+            r#"{"modelType":"BasicEventElement","idShort":"Overheated","observed":{"type":"ModelReference","keys":[{"type":"Submodel","value":"https://example.com/ids/sm/1"}]},"direction":"output","state":"on"}"#,
+        );
+
+        assert_eq!(
+            invalid_attribute(
+                r#"{"modelType":"BasicEventElement","observed":{"type":"ModelReference","keys":[{"type":"Submodel","value":"https://example.com/ids/sm/1"}]},"direction":"sideways","state":"on"}"#
+            ),
+            "direction"
+        );
+    }
+}
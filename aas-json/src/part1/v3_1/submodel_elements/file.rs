@@ -1,81 +0,0 @@
-use crate::part1::MetamodelError;
-use crate::part1::ToJsonMetamodel;
-use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
-use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
-use crate::part1::v3_1::attributes::referable::Referable;
-use crate::part1::v3_1::attributes::semantics::HasSemantics;
-use crate::part1::v3_1::primitives::{ContentType, Uri};
-use serde::{Deserialize, Serialize};
-#[cfg(feature = "openapi")]
-use utoipa::ToSchema;
-
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, Default)]
-#[cfg_attr(all(feature = "openapi"), derive(ToSchema))]
-pub struct File {
-    // Inherited from DataElement
-    #[serde(flatten)]
-    pub referable: Referable,
-
-    #[serde(flatten)]
-    pub semantics: HasSemantics,
-
-    #[serde(flatten)]
-    pub qualifiable: Qualifiable,
-
-    #[serde(flatten)]
-    pub embedded_data_specifications: HasDataSpecification,
-    // ----- end inheritance
-    /// Path and name of the file (with file extension)
-    /// The path can be absolute or relative.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
-    pub value: Option<Uri>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "contentType")]
-    pub content_type: Option<ContentType>,
-}
-
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, Default)]
-
-pub struct FileMeta {
-    // Inherited from DataElement
-    #[serde(flatten)]
-    pub referable: Referable,
-
-    #[serde(flatten)]
-    pub semantics: HasSemantics,
-
-    #[serde(flatten)]
-    pub qualifiable: Qualifiable,
-
-    #[serde(flatten)]
-    pub embedded_data_specifications: HasDataSpecification,
-    // ----- end inheritance
-}
-
-impl From<File> for FileMeta {
-    fn from(file: File) -> Self {
-        Self {
-            referable: file.referable,
-            semantics: file.semantics,
-            qualifiable: file.qualifiable,
-            embedded_data_specifications: file.embedded_data_specifications,
-        }
-    }
-}
-
-impl From<&File> for FileMeta {
-    fn from(file: &File) -> Self {
-        file.clone().into()
-    }
-}
-
-impl ToJsonMetamodel for File {
-    type Error = MetamodelError;
-
-    fn to_json_metamodel(&self) -> Result<String, Self::Error> {
-        serde_json::to_string::<FileMeta>(&self.into())
-            .map_err(|e| MetamodelError::FailedSerialisation(e))
-    }
-}
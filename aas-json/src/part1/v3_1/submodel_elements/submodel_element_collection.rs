@@ -1,36 +0,0 @@
-use crate::part1::ToJsonMetamodel;
-use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
-use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
-use crate::part1::v3_1::attributes::referable::Referable;
-use crate::part1::v3_1::attributes::semantics::HasSemantics;
-use crate::part1::v3_1::submodel_elements::SubmodelElement;
-use serde::{Deserialize, Serialize};
-#[cfg(feature = "openapi")]
-use utoipa::ToSchema;
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
-pub struct SubmodelElementCollection {
-    #[serde(flatten)]
-    pub referable: Referable,
-
-    // HasSemantics
-    #[serde(flatten)]
-    pub semantics: HasSemantics,
-
-    // Qualifiable
-    #[serde(flatten)]
-    pub qualifiable: Qualifiable,
-
-    #[serde(flatten)]
-    pub embedded_data_specifications: HasDataSpecification,
-
-    value: Option<Vec<SubmodelElement>>,
-}
-
-impl ToJsonMetamodel for SubmodelElementCollection {
-    type Error = ();
-
-    fn to_json_metamodel(&self) -> Result<String, Self::Error> {
-        Ok(format!(r#"{{"modelType":"SubmodelElementCollection"}}"#))
-    }
-}
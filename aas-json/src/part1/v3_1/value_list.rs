@@ -1,19 +0,0 @@
-use crate::part1::v3_1::reference::Reference;
-use serde::{Deserialize, Serialize};
-#[cfg(feature = "openapi")]
-use utoipa::ToSchema;
-
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
-
-pub struct ValueList {
-    #[cfg_attr(feature = "json", serde(rename = "valueReferencePairs"))]
-    pub value_reference_pairs: Vec<ValueReferencePair>,
-}
-
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
-
-pub struct ValueReferencePair {
-    pub value: String,
-    #[serde(rename = "valueId")]
-    pub value_id: Reference,
-}
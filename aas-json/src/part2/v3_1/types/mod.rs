@@ -1,2 +0,0 @@
-mod thumbnail;
-pub use thumbnail::*;
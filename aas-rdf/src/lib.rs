@@ -0,0 +1,232 @@
+//! RDF serialization of the metamodel types of the [aas] crate in the Turtle syntax, following
+//! the ontology at <https://admin-shell.io/aas/3/1/>.
+//!
+//! The mapping is done on the JSON serialization of `aas-json`, which has the same attribute
+//! names as the ontology, so reading checks the same constraints as reading JSON. Objects are
+//! written as nested blank nodes, e.g. the keys of a reference. Values of properties, ranges,
+//! extensions and qualifiers are literals of the datatype of their `valueType`.
+//!
+//! Reading accepts any Turtle document except collections. The items of lists are sorted by their
+//! `aas:index`, items without one keep the order of the document. Language-tagged strings like
+//! `"text"@en` may stand for lang strings.
+//!
+//! ```
+//! use aas::part1::v3_1::environment::Environment;
+//! use aas_rdf::Rdf;
+//!
+//! let environment = Environment::from_turtle(
+//!     r#"
+//!     @prefix aas: <https://admin-shell.io/aas/3/1/> .
+//!     @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+//!
+//!     [] a aas:Environment ;
+//!         <https://admin-shell.io/aas/3/1/Environment/submodels> [
+//!             a aas:Submodel ;
+//!             <https://admin-shell.io/aas/3/1/Identifiable/id> "urn:example:1"^^xsd:string
+//!         ] .
+//!     "#,
+//! )
+//! .unwrap();
+//! assert_eq!(environment.submodels.as_ref().unwrap()[0].identifiable.id.as_ref(), "urn:example:1");
+//! assert_eq!(Environment::from_turtle(&environment.to_turtle().unwrap()).unwrap(), environment);
+//! ```
+
+mod mapping;
+mod turtle;
+
+use aas::part1::v3_1::concept_description::ConceptDescription;
+use aas::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use aas::part1::v3_1::environment::Environment;
+use aas::part1::v3_1::reference::Reference;
+use aas::part1::v3_1::submodel_elements::SubmodelElement;
+use aas_json::{Json, JsonError};
+use thiserror::Error;
+
+pub trait Rdf: Sized {
+    type Error: std::fmt::Display;
+
+    fn to_turtle(&self) -> Result<String, Self::Error>;
+
+    fn from_turtle(turtle: &str) -> Result<Self, Self::Error>;
+}
+
+#[derive(Debug, Error)]
+pub enum RdfError {
+    #[error(transparent)]
+    Json(#[from] JsonError),
+
+    #[error("invalid Turtle in line {line}: {message}")]
+    Syntax { line: usize, message: String },
+
+    #[error("{0}")]
+    Mapping(String),
+}
+
+/// The class of the root node, `None` for types that are written with their modelType
+macro_rules! impl_rdf {
+    ($($ty:ty => $class:expr),* $(,)?) => {$(
+        impl Rdf for $ty {
+            type Error = RdfError;
+
+            fn to_turtle(&self) -> Result<String, Self::Error> {
+                let json = serde_json::from_str(&self.to_json()?).map_err(JsonError::from)?;
+                mapping::write(&json, $class)
+            }
+
+            fn from_turtle(turtle: &str) -> Result<Self, Self::Error> {
+                let json = mapping::read(&turtle::parse(turtle)?, $class)?;
+                Ok(Self::from_json(&json.to_string())?)
+            }
+        }
+    )*};
+}
+
+impl_rdf!(
+    Environment => Some("Environment"),
+    AssetAdministrationShell => None,
+    Submodel => None,
+    ConceptDescription => None,
+    SubmodelElement => None,
+    Reference => Some("Reference"),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aas_xml::Xml;
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    #[test]
+    fn round_trip() {
+        let environment =
+            Environment::from_xml(include_str!("../../aas/tests/mvp-dpp-1.0.0.xml")).unwrap();
+        let turtle = environment.to_turtle().unwrap();
+        assert!(turtle.contains("rdf:type aas:Environment"));
+        assert!(turtle.contains("<https://admin-shell.io/aas/3/1/Referable/idShort>"));
+        assert!(turtle.contains("<https://admin-shell.io/aas/3/1/KeyTypes/Submodel>"));
+        assert_eq!(Environment::from_turtle(&turtle).unwrap(), environment);
+
+        for submodel in environment.submodels.iter().flatten() {
+            assert_eq!(
+                &Submodel::from_turtle(&submodel.to_turtle().unwrap()).unwrap(),
+                submodel
+            );
+        }
+    }
+
+    #[test]
+    fn shuffled_triples() {
+        let environment =
+            Environment::from_xml(include_str!("../../aas/tests/mvp-dpp-1.0.0.xml")).unwrap();
+        let mut triples = turtle::parse(&environment.to_turtle().unwrap()).unwrap();
+        triples.sort_by_cached_key(|triple| {
+            let mut hasher = DefaultHasher::new();
+            format!("{triple:?}").hash(&mut hasher);
+            hasher.finish()
+        });
+
+        let json = mapping::read(&triples, Some("Environment")).unwrap();
+        assert_eq!(
+            Environment::from_json(&json.to_string()).unwrap(),
+            environment
+        );
+    }
+
+    #[test]
+    fn sorts_by_index() {
+        let reference = Reference::from_turtle(
+            r#"
+            @prefix aas: <https://admin-shell.io/aas/3/1/> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+            [] a aas:Reference ;
+                <https://admin-shell.io/aas/3/1/Reference/type> <https://admin-shell.io/aas/3/1/ReferenceTypes/ModelReference> ;
+                <https://admin-shell.io/aas/3/1/Reference/keys> [
+                    aas:index "1"^^xsd:integer ;
+                    <https://admin-shell.io/aas/3/1/Key/type> <https://admin-shell.io/aas/3/1/KeyTypes/Property> ;
+                    <https://admin-shell.io/aas/3/1/Key/value> "speed"
+                ], [
+                    aas:index "0"^^xsd:integer ;
+                    <https://admin-shell.io/aas/3/1/Key/type> <https://admin-shell.io/aas/3/1/KeyTypes/Submodel> ;
+                    <https://admin-shell.io/aas/3/1/Key/value> "urn:example:submodel"
+                ] .
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            reference.to_json().unwrap(),
+            r#"{"type":"ModelReference","keys":[{"type":"Submodel","value":"urn:example:submodel"},{"type":"Property","value":"speed"}]}"#
+        );
+        assert!(
+            reference
+                .to_turtle()
+                .unwrap()
+                .contains(r#"<https://admin-shell.io/aas/3/1/index> "1"^^xsd:integer"#)
+        );
+    }
+
+    #[test]
+    fn typed_literals() {
+        let property = SubmodelElement::from_json(
+            r#"{"modelType":"Property","idShort":"count","valueType":"xs:int","value":"5"}"#,
+        )
+        .unwrap();
+        let turtle = property.to_turtle().unwrap();
+        assert!(turtle.contains(r#""5"^^xsd:int"#));
+        assert!(turtle.contains("<https://admin-shell.io/aas/3/1/DataTypeDefXsd/Int>"));
+        assert_eq!(SubmodelElement::from_turtle(&turtle).unwrap(), property);
+
+        // values are checked against their valueType
+        let invalid = turtle.replace(r#""5"^^xsd:int"#, r#""five"^^xsd:int"#);
+        assert!(matches!(
+            SubmodelElement::from_turtle(&invalid),
+            Err(RdfError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn reads_turtle() {
+        let submodel = Submodel::from_turtle(
+            r#"
+            @prefix aas: <https://admin-shell.io/aas/3/1/> .
+            @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+            @prefix dc: <http://purl.org/dc/terms/> .
+
+            <urn:example:submodel> a aas:Submodel ;
+                <https://admin-shell.io/aas/3/1/Identifiable/id> "urn:example:submodel" ;
+                <https://admin-shell.io/aas/3/1/Referable/description> "Beispiel"@de, "Example"@en ;
+                <https://admin-shell.io/aas/3/1/HasSemantics/semanticId> _:reference ;
+                dc:creator "ignored" .
+
+            _:reference a aas:Reference ;
+                <https://admin-shell.io/aas/3/1/Reference/type> <https://admin-shell.io/aas/3/1/ReferenceTypes/ExternalReference> ;
+                <https://admin-shell.io/aas/3/1/Reference/keys> _:first, _:second .
+            _:first <https://admin-shell.io/aas/3/1/Key/type> <https://admin-shell.io/aas/3/1/KeyTypes/GlobalReference> ;
+                <https://admin-shell.io/aas/3/1/Key/value> "https://example.com/1" .
+            _:second <https://admin-shell.io/aas/3/1/Key/type> <https://admin-shell.io/aas/3/1/KeyTypes/FragmentReference> ;
+                <https://admin-shell.io/aas/3/1/Key/value> "fragment" .
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            submodel.to_json().unwrap(),
+            r#"{"modelType":"Submodel","description":[{"language":"de","text":"Beispiel"},{"language":"en","text":"Example"}],"id":"urn:example:submodel","semanticId":{"type":"ExternalReference","keys":[{"type":"GlobalReference","value":"https://example.com/1"},{"type":"FragmentReference","value":"fragment"}]}}"#
+        );
+    }
+
+    #[test]
+    fn invalid_turtle() {
+        assert!(matches!(
+            Environment::from_turtle("[] a <https://admin-shell.io/aas/3/1/Environment"),
+            Err(RdfError::Syntax { line: 1, .. })
+        ));
+        assert!(matches!(
+            Reference::from_turtle(
+                r#"_:a <https://admin-shell.io/aas/3/1/Reference/keys> _:b . _:b <https://admin-shell.io/aas/3/1/Reference/keys> _:a ."#
+            ),
+            Err(RdfError::Mapping(_))
+        ));
+    }
+}
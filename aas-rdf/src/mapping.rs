@@ -0,0 +1,572 @@
+//! Mapping between the JSON serialization and the RDF ontology of the metamodel, see
+//! <https://industrialdigitaltwin.io/aas-specifications/IDTA-01001/v3.1.1/mappings/mappings.html#rdf>
+//!
+//! Every JSON object is a blank node with its class as `rdf:type`. Its attributes are
+//! predicates in the namespace of the class that defines them, e.g. `idShort` is
+//! `<https://admin-shell.io/aas/3/1/Referable/idShort>` for every referable. Enumeration values
+//! are IRIs and the other values typed literals. Lists are repeated objects of the predicate,
+//! each item has its position in the list as [INDEX], since the objects of a predicate have no
+//! order in RDF.
+
+use crate::RdfError;
+use crate::turtle::{Literal, RDF, RDF_TYPE, Term, Triple, XSD, literal};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+pub(crate) const AAS: &str = "https://admin-shell.io/aas/3/1/";
+
+/// The position of an item in its list, starting at 0
+pub(crate) const INDEX: &str = "https://admin-shell.io/aas/3/1/index";
+
+/// How the value of an attribute is mapped
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Kind {
+    /// A literal of the XSD datatype, booleans are JSON booleans
+    Literal(&'static str),
+
+    /// A literal of the datatype in the `valueType` of the same object
+    Value,
+
+    /// A value of the enumeration
+    Enum(&'static str, Style),
+
+    /// An object of the class, `None` if the class is given by its modelType
+    Node(Option<&'static str>),
+
+    /// A list of objects, see [Kind::Node]
+    Nodes(Option<&'static str>),
+
+    /// Attributes the metamodel does not define, e.g. of unknown data specification contents.
+    /// They are mapped by their JSON type.
+    Unknown,
+}
+
+/// How JSON writes the values of an enumeration. The ontology uses the UpperCamelCase names.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Style {
+    /// `ModelReference`
+    Pascal,
+
+    /// `input` is `Input`
+    Lower,
+
+    /// `xs:anyURI` is `AnyUri`
+    Xsd,
+
+    /// `STRING_TRANSLATABLE` is `StringTranslatable`
+    Screaming,
+}
+
+const REFERENCE: Option<&str> = Some("Reference");
+
+fn kind(class: &str, attribute: &str) -> Kind {
+    match (class, attribute) {
+        ("Environment", _) => Kind::Nodes(None),
+        ("Qualifier", "kind") => Kind::Enum("QualifierKind", Style::Pascal),
+        ("Qualifier", "type") => Kind::Literal("string"),
+        ("Reference", "type") => Kind::Enum("ReferenceTypes", Style::Pascal),
+        ("Key", "type") => Kind::Enum("KeyTypes", Style::Pascal),
+        ("Property" | "Extension" | "Qualifier", "value") | ("Range", "min" | "max") => Kind::Value,
+        ("LevelType", _) => Kind::Literal("boolean"),
+        ("Blob", "value") => Kind::Literal("base64Binary"),
+        ("MultiLanguageProperty", "value") => Kind::Nodes(Some("LangStringTextType")),
+        ("ReferenceElement", "value") => Kind::Node(REFERENCE),
+        ("SubmodelElementCollection" | "SubmodelElementList", "value") => Kind::Nodes(None),
+        ("OperationVariable", "value") => Kind::Node(None),
+        ("AssetAdministrationShell", "submodels") => Kind::Nodes(REFERENCE),
+        (_, "extensions") => Kind::Nodes(Some("Extension")),
+        (_, "displayName") => Kind::Nodes(Some("LangStringNameType")),
+        (_, "description") => Kind::Nodes(Some("LangStringTextType")),
+        (_, "administration") => Kind::Node(Some("AdministrativeInformation")),
+        (
+            _,
+            "semanticId"
+            | "creator"
+            | "derivedFrom"
+            | "valueId"
+            | "externalSubjectId"
+            | "first"
+            | "second"
+            | "observed"
+            | "messageBroker"
+            | "semanticIdListElement"
+            | "unitId"
+            | "dataSpecification"
+            | "referredSemanticId",
+        ) => Kind::Node(REFERENCE),
+        (_, "supplementalSemanticIds" | "refersTo" | "isCaseOf") => Kind::Nodes(REFERENCE),
+        (_, "qualifiers") => Kind::Nodes(Some("Qualifier")),
+        (_, "embeddedDataSpecifications") => Kind::Nodes(Some("EmbeddedDataSpecification")),
+        (_, "dataSpecificationContent") => Kind::Node(None),
+        (_, "kind") => Kind::Enum("ModellingKind", Style::Pascal),
+        (_, "valueType" | "valueTypeListElement") => Kind::Enum("DataTypeDefXsd", Style::Xsd),
+        (_, "keys") => Kind::Nodes(Some("Key")),
+        (_, "assetInformation") => Kind::Node(Some("AssetInformation")),
+        (_, "assetKind") => Kind::Enum("AssetKind", Style::Pascal),
+        (_, "specificAssetIds") => Kind::Nodes(Some("SpecificAssetId")),
+        (_, "defaultThumbnail") => Kind::Node(Some("Resource")),
+        (_, "submodelElements" | "statements" | "annotations") => Kind::Nodes(None),
+        (_, "inputVariables" | "outputVariables" | "inoutputVariables") => {
+            Kind::Nodes(Some("OperationVariable"))
+        }
+        (_, "entityType") => Kind::Enum("EntityType", Style::Pascal),
+        (_, "direction") => Kind::Enum("Direction", Style::Lower),
+        (_, "state") => Kind::Enum("StateOfEvent", Style::Lower),
+        (_, "lastUpdate") => Kind::Literal("dateTime"),
+        (_, "minInterval" | "maxInterval") => Kind::Literal("duration"),
+        (_, "orderRelevant") => Kind::Literal("boolean"),
+        (_, "typeValueListElement") => Kind::Enum("AasSubmodelElements", Style::Pascal),
+        (_, "preferredName") => Kind::Nodes(Some("LangStringPreferredNameTypeIec61360")),
+        (_, "shortName") => Kind::Nodes(Some("LangStringShortNameTypeIec61360")),
+        (_, "definition") => Kind::Nodes(Some("LangStringDefinitionTypeIec61360")),
+        (_, "dataType") => Kind::Enum("DataTypeIec61360", Style::Screaming),
+        (_, "valueList") => Kind::Node(Some("ValueList")),
+        (_, "valueReferencePairs") => Kind::Nodes(Some("ValueReferencePair")),
+        (_, "levelType") => Kind::Node(Some("LevelType")),
+        (
+            _,
+            "category" | "idShort" | "id" | "version" | "revision" | "templateId" | "name"
+            | "value" | "globalAssetId" | "assetType" | "path" | "contentType" | "messageTopic"
+            | "language" | "text" | "unit" | "sourceOfDefinition" | "symbol" | "valueFormat",
+        ) => Kind::Literal("string"),
+        _ => Kind::Unknown,
+    }
+}
+
+/// The class that defines an attribute, which is its namespace in the ontology
+fn domain<'a>(class: &'a str, attribute: &str) -> &'a str {
+    match (class, attribute) {
+        ("Qualifier", "kind") => class,
+        (_, "extensions") => "HasExtensions",
+        (_, "category" | "idShort" | "displayName" | "description") => "Referable",
+        (_, "administration" | "id") => "Identifiable",
+        (_, "semanticId" | "supplementalSemanticIds") => "HasSemantics",
+        (_, "qualifiers") => "Qualifiable",
+        (_, "embeddedDataSpecifications") => "HasDataSpecification",
+        (_, "kind") => "HasKind",
+        (_, "language" | "text") => "AbstractLangString",
+        ("AnnotatedRelationshipElement", "first" | "second") => "RelationshipElement",
+        _ => class,
+    }
+}
+
+fn enum_name(value: &str, style: Style) -> String {
+    match style {
+        Style::Pascal => value.to_string(),
+        Style::Lower => capitalize(value),
+        Style::Xsd => match value.strip_prefix("xs:").unwrap_or(value) {
+            "anyURI" => "AnyUri".to_string(),
+            value => capitalize(value),
+        },
+        Style::Screaming => value
+            .split('_')
+            .map(|word| capitalize(&word.to_lowercase()))
+            .collect(),
+    }
+}
+
+fn enum_value(name: &str, style: Style) -> String {
+    match style {
+        Style::Pascal => name.to_string(),
+        Style::Lower => decapitalize(name),
+        Style::Xsd => match name {
+            "AnyUri" => "xs:anyURI".to_string(),
+            name => format!("xs:{}", decapitalize(name)),
+        },
+        Style::Screaming => {
+            let mut value = String::new();
+            for (i, c) in name.char_indices() {
+                if i > 0 && c.is_uppercase() {
+                    value.push('_');
+                }
+                value.push(c.to_ascii_uppercase());
+            }
+            value
+        }
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn decapitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn mapping_error(message: impl Into<String>) -> RdfError {
+    RdfError::Mapping(message.into())
+}
+
+/// Writes the JSON serialization of a value as Turtle. The class of the root is given, or `None`
+/// if it is taken from its modelType.
+pub(crate) fn write(value: &Value, class: Option<&str>) -> Result<String, RdfError> {
+    let Value::Object(object) = value else {
+        return Err(mapping_error("expected an object"));
+    };
+    let mut turtle =
+        format!("@prefix aas: <{AAS}> .\n@prefix rdf: <{RDF}> .\n@prefix xsd: <{XSD}> .\n\n");
+    write_node(&mut turtle, object, class, None, 0)?;
+    turtle.push_str(" .\n");
+    Ok(turtle)
+}
+
+/// Writes an object as blank node, `index` is its position if it is an item of a list.
+fn write_node(
+    turtle: &mut String,
+    object: &Map<String, Value>,
+    class: Option<&str>,
+    index: Option<usize>,
+    depth: usize,
+) -> Result<(), RdfError> {
+    let class = object.get("modelType").and_then(Value::as_str).or(class);
+    let indent = "    ".repeat(depth + 1);
+
+    let mut statements = Vec::new();
+    if let Some(class) = class {
+        statements.push(format!("rdf:type aas:{class}"));
+    }
+    if let Some(index) = index {
+        statements.push(format!(
+            "<{INDEX}> {}",
+            literal(&index.to_string(), "xsd:integer")
+        ));
+    }
+    for (attribute, value) in object {
+        if attribute == "modelType" || value.is_null() {
+            continue;
+        }
+        let kind = class.map_or(Kind::Unknown, |class| kind(class, attribute));
+        let predicate = match class {
+            Some(class) => format!("<{AAS}{}/{attribute}>", domain(class, attribute)),
+            None => format!("<{AAS}{attribute}>"),
+        };
+        let values = match value {
+            Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+        let mut objects = Vec::new();
+        for (i, value) in values.into_iter().enumerate() {
+            let index = matches!(kind, Kind::Nodes(_)).then_some(i);
+            let mut term = String::new();
+            write_value(&mut term, object, attribute, kind, value, index, depth + 1)?;
+            objects.push(term);
+        }
+        if !objects.is_empty() {
+            statements.push(format!("{predicate} {}", objects.join(", ")));
+        }
+    }
+
+    turtle.push_str("[\n");
+    for (i, statement) in statements.iter().enumerate() {
+        let separator = if i + 1 < statements.len() { " ;" } else { "" };
+        let _ = writeln!(turtle, "{indent}{statement}{separator}");
+    }
+    turtle.push_str(&indent[4..]);
+    turtle.push(']');
+    Ok(())
+}
+
+fn write_value(
+    turtle: &mut String,
+    object: &Map<String, Value>,
+    attribute: &str,
+    kind: Kind,
+    value: &Value,
+    index: Option<usize>,
+    depth: usize,
+) -> Result<(), RdfError> {
+    let invalid = || mapping_error(format!("unexpected value of {attribute}: {value}"));
+    match (kind, value) {
+        (Kind::Node(class) | Kind::Nodes(class), Value::Object(node)) => {
+            write_node(turtle, node, class, index, depth)?
+        }
+        (Kind::Unknown, Value::Object(node)) => write_node(turtle, node, None, None, depth)?,
+        (Kind::Enum(enumeration, style), Value::String(value)) => {
+            let _ = write!(turtle, "<{AAS}{enumeration}/{}>", enum_name(value, style));
+        }
+        (Kind::Value, Value::String(value)) => {
+            let datatype = match object.get("valueType").and_then(Value::as_str) {
+                Some(value_type) => value_type.replacen("xs:", "xsd:", 1),
+                None => "xsd:string".to_string(),
+            };
+            turtle.push_str(&literal(value, &datatype));
+        }
+        (Kind::Literal(datatype), Value::String(value)) => {
+            turtle.push_str(&literal(value, &format!("xsd:{datatype}")))
+        }
+        (Kind::Unknown, Value::String(value)) => turtle.push_str(&literal(value, "xsd:string")),
+        (Kind::Literal(_) | Kind::Unknown, Value::Bool(value)) => {
+            turtle.push_str(&literal(&value.to_string(), "xsd:boolean"))
+        }
+        (Kind::Unknown, Value::Number(value)) => {
+            turtle.push_str(&literal(&value.to_string(), "xsd:decimal"))
+        }
+        _ => return Err(invalid()),
+    }
+    Ok(())
+}
+
+/// Reads the JSON serialization of a value from the triples of a Turtle document. The root is
+/// the only node of the class that is no object of another triple.
+pub(crate) fn read(triples: &[Triple], class: Option<&str>) -> Result<Value, RdfError> {
+    let mut graph: HashMap<&Term, Vec<(&str, &Term)>> = HashMap::new();
+    let mut subjects = Vec::new();
+    for triple in triples {
+        let properties = graph.entry(&triple.subject).or_insert_with(|| {
+            subjects.push(&triple.subject);
+            Vec::new()
+        });
+        properties.push((triple.predicate.as_str(), &triple.object));
+    }
+
+    let reader = Reader { graph };
+    let objects: HashSet<&Term> = triples.iter().map(|triple| &triple.object).collect();
+    let mut roots = subjects.into_iter().filter(|subject| {
+        !objects.contains(subject) && class.is_none_or(|class| reader.class(subject) == Some(class))
+    });
+    let root = roots.next().ok_or_else(|| mapping_error("no root node"))?;
+    if let Some(other) = roots.next() {
+        return Err(mapping_error(format!(
+            "several root nodes: {root:?} and {other:?}"
+        )));
+    }
+
+    reader
+        .read_node(root, class, class.is_none(), &mut Vec::new())
+        .map(Value::Object)
+}
+
+struct Reader<'a> {
+    graph: HashMap<&'a Term, Vec<(&'a str, &'a Term)>>,
+}
+
+impl<'a> Reader<'a> {
+    /// The position of a list item, see [INDEX]
+    fn index(&self, node: &Term) -> Result<Option<usize>, RdfError> {
+        let index =
+            self.graph
+                .get(node)
+                .into_iter()
+                .flatten()
+                .find_map(|(predicate, object)| match (*predicate, object) {
+                    (INDEX, Term::Literal(literal)) => Some(literal.value.as_str()),
+                    _ => None,
+                });
+        index
+            .map(|index| {
+                index
+                    .parse()
+                    .map_err(|_| mapping_error(format!("invalid index {index}")))
+            })
+            .transpose()
+    }
+
+    /// The class of a node in the ontology
+    fn class(&self, node: &Term) -> Option<&'a str> {
+        self.graph
+            .get(node)?
+            .iter()
+            .find_map(|(predicate, object)| match (*predicate, object) {
+                (RDF_TYPE, Term::Iri(class)) => class.strip_prefix(AAS),
+                _ => None,
+            })
+    }
+
+    /// Reads a node as object, `model_type` adds the modelType of polymorphic attributes.
+    fn read_node(
+        &self,
+        node: &'a Term,
+        class: Option<&str>,
+        model_type: bool,
+        visiting: &mut Vec<&'a Term>,
+    ) -> Result<Map<String, Value>, RdfError> {
+        if visiting.contains(&node) {
+            return Err(mapping_error("the graph contains a cycle"));
+        }
+        visiting.push(node);
+
+        let class = self.class(node).or(class);
+        let mut object = Map::new();
+        if model_type && let Some(class) = class {
+            object.insert("modelType".to_string(), class.into());
+        }
+
+        // the objects of each attribute, in the order of the document
+        let mut attributes: Vec<(&str, Vec<&Term>)> = Vec::new();
+        for (predicate, value) in self.graph.get(node).into_iter().flatten() {
+            let Some(name) = predicate.strip_prefix(AAS).filter(|_| *predicate != INDEX) else {
+                continue;
+            };
+            let attribute = name.rsplit('/').next().unwrap_or(name);
+            match attributes.iter_mut().find(|(name, _)| *name == attribute) {
+                Some((_, values)) => values.push(value),
+                None => attributes.push((attribute, vec![value])),
+            }
+        }
+
+        for (attribute, values) in attributes {
+            let kind = class.map_or(Kind::Unknown, |class| kind(class, attribute));
+            let value = match kind {
+                Kind::Nodes(class) => {
+                    // items without index, e.g. language-tagged strings, keep their order last
+                    let mut items = values
+                        .into_iter()
+                        .map(|value| Ok((self.index(value)?, value)))
+                        .collect::<Result<Vec<_>, RdfError>>()?;
+                    items.sort_by_key(|(index, _)| index.unwrap_or(usize::MAX));
+                    Value::Array(
+                        items
+                            .into_iter()
+                            .map(|(_, value)| {
+                                self.read_value(attribute, Kind::Node(class), value, visiting)
+                            })
+                            .collect::<Result<_, _>>()?,
+                    )
+                }
+                Kind::Unknown if values.len() > 1 => Value::Array(
+                    values
+                        .into_iter()
+                        .map(|value| self.read_value(attribute, kind, value, visiting))
+                        .collect::<Result<_, _>>()?,
+                ),
+                kind => match values[..] {
+                    [value] => self.read_value(attribute, kind, value, visiting)?,
+                    _ => return Err(mapping_error(format!("{attribute} has several values"))),
+                },
+            };
+            object.insert(attribute.to_string(), value);
+        }
+
+        visiting.pop();
+        Ok(object)
+    }
+
+    fn read_value(
+        &self,
+        attribute: &str,
+        kind: Kind,
+        value: &'a Term,
+        visiting: &mut Vec<&'a Term>,
+    ) -> Result<Value, RdfError> {
+        let invalid = || mapping_error(format!("unexpected value of {attribute}: {value:?}"));
+        Ok(match (kind, value) {
+            (Kind::Node(class), Term::Blank(_) | Term::Iri(_))
+                if self.graph.contains_key(value) =>
+            {
+                Value::Object(self.read_node(value, class, class.is_none(), visiting)?)
+            }
+            (Kind::Unknown, Term::Blank(_) | Term::Iri(_)) if self.graph.contains_key(value) => {
+                Value::Object(self.read_node(value, None, true, visiting)?)
+            }
+            // the short notation of language-tagged strings
+            (
+                Kind::Node(Some(class)),
+                Term::Literal(Literal {
+                    value,
+                    language: Some(language),
+                    ..
+                }),
+            ) if class.starts_with("LangString") => {
+                let mut object = Map::new();
+                object.insert("language".to_string(), language.as_str().into());
+                object.insert("text".to_string(), value.as_str().into());
+                Value::Object(object)
+            }
+            (Kind::Enum(enumeration, style), Term::Iri(iri)) => {
+                let name = iri
+                    .strip_prefix(AAS)
+                    .and_then(|iri| iri.strip_prefix(enumeration))
+                    .and_then(|iri| iri.strip_prefix('/'))
+                    .ok_or_else(invalid)?;
+                enum_value(name, style).into()
+            }
+            (Kind::Literal(_) | Kind::Value | Kind::Unknown, Term::Literal(literal)) => {
+                match literal.datatype.strip_prefix(XSD) {
+                    Some("boolean") => match literal.value.as_str() {
+                        "true" | "1" => true.into(),
+                        "false" | "0" => false.into(),
+                        _ => return Err(invalid()),
+                    },
+                    _ => literal.value.as_str().into(),
+                }
+            }
+            _ => return Err(invalid()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enumerations() {
+        let cases = [
+            ("ModelReference", Style::Pascal, "ModelReference"),
+            ("input", Style::Lower, "Input"),
+            ("xs:anyURI", Style::Xsd, "AnyUri"),
+            ("xs:nonNegativeInteger", Style::Xsd, "NonNegativeInteger"),
+            (
+                "STRING_TRANSLATABLE",
+                Style::Screaming,
+                "StringTranslatable",
+            ),
+            ("IRDI", Style::Screaming, "Irdi"),
+        ];
+        for (value, style, name) in cases {
+            assert_eq!(enum_name(value, style), name);
+            assert_eq!(enum_value(name, style), value);
+        }
+    }
+
+    #[test]
+    fn root_node() {
+        let triples = crate::turtle::parse(
+            r#"
+            @prefix aas: <https://admin-shell.io/aas/3/1/> .
+
+            _:a a aas:Reference ; <https://admin-shell.io/aas/3/1/Reference/type> <https://admin-shell.io/aas/3/1/ReferenceTypes/ExternalReference> .
+            _:b a aas:Reference ; <https://admin-shell.io/aas/3/1/Reference/type> <https://admin-shell.io/aas/3/1/ReferenceTypes/ModelReference> .
+            _:c a aas:Key .
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            read(&triples, Some("Reference")),
+            Err(RdfError::Mapping(message)) if message.starts_with("several root nodes")
+        ));
+        assert!(matches!(
+            read(&triples, None),
+            Err(RdfError::Mapping(message)) if message.starts_with("several root nodes")
+        ));
+        assert_eq!(
+            read(&triples[..2], Some("Reference")).unwrap(),
+            serde_json::json!({"type": "ExternalReference"})
+        );
+        assert!(matches!(
+            read(&triples, Some("Environment")),
+            Err(RdfError::Mapping(message)) if message == "no root node"
+        ));
+    }
+
+    #[test]
+    fn predicates() {
+        assert_eq!(domain("Property", "idShort"), "Referable");
+        assert_eq!(domain("Submodel", "kind"), "HasKind");
+        assert_eq!(domain("Qualifier", "kind"), "Qualifier");
+        assert_eq!(domain("Key", "value"), "Key");
+        assert_eq!(kind("Property", "value"), Kind::Value);
+        assert_eq!(kind("File", "value"), Kind::Literal("string"));
+    }
+}
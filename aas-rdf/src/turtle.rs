@@ -0,0 +1,570 @@
+//! Reading and writing of the Turtle syntax, see <https://www.w3.org/TR/turtle/>
+//!
+//! The reader supports the whole syntax except collections. The triples keep the order of the
+//! document, which the mapping relies on for ordered lists.
+
+use crate::RdfError;
+use std::collections::HashMap;
+
+pub(crate) const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+pub(crate) const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+pub(crate) const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Term {
+    Iri(String),
+    Blank(String),
+    Literal(Literal),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct Literal {
+    pub value: String,
+
+    /// Full IRI of the datatype, `rdf:langString` for literals with a language tag
+    pub datatype: String,
+
+    pub language: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Triple {
+    pub subject: Term,
+    pub predicate: String,
+    pub object: Term,
+}
+
+/// A string literal in Turtle syntax, with the datatype as prefixed name or IRI
+pub(crate) fn literal(value: &str, datatype: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    format!("{escaped}^^{datatype}")
+}
+
+pub(crate) fn parse(input: &str) -> Result<Vec<Triple>, RdfError> {
+    let mut parser = Parser {
+        input,
+        position: 0,
+        prefixes: HashMap::new(),
+        base: String::new(),
+        blank_nodes: 0,
+        triples: Vec::new(),
+    };
+    parser.document()?;
+    Ok(parser.triples)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    prefixes: HashMap<String, String>,
+    base: String,
+    blank_nodes: usize,
+    triples: Vec<Triple>,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> RdfError {
+        RdfError::Syntax {
+            line: self.input[..self.position].matches('\n').count() + 1,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.input[self.position..].starts_with(s)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), RdfError> {
+        self.skip_whitespace();
+        match self.next() {
+            Some(found) if found == c => Ok(()),
+            Some(found) => Err(self.error(format!("expected '{c}', found '{found}'"))),
+            None => Err(self.error(format!("expected '{c}', found end of input"))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' | '\t' | '\r' | '\n' | '\u{feff}' => {
+                    self.next();
+                }
+                '#' => while self.next().is_some_and(|c| c != '\n') {},
+                _ => break,
+            }
+        }
+    }
+
+    fn document(&mut self) -> Result<(), RdfError> {
+        loop {
+            self.skip_whitespace();
+            if self.peek().is_none() {
+                return Ok(());
+            }
+            if self.starts_with("@prefix") || self.starts_with("@base") {
+                self.next();
+                self.directive()?;
+                self.expect('.')?;
+            } else if self.keyword("PREFIX") || self.keyword("BASE") {
+                self.directive()?;
+            } else {
+                self.triples_statement()?;
+                self.expect('.')?;
+            }
+        }
+    }
+
+    /// Whether a SPARQL style directive follows, which is case-insensitive
+    fn keyword(&self, keyword: &str) -> bool {
+        self.input[self.position..]
+            .get(..keyword.len())
+            .is_some_and(|s| s.eq_ignore_ascii_case(keyword))
+            && self.input[self.position + keyword.len()..]
+                .starts_with(|c: char| c.is_whitespace() || c == '<')
+    }
+
+    fn directive(&mut self) -> Result<(), RdfError> {
+        let name = self.name();
+        self.skip_whitespace();
+        if name.eq_ignore_ascii_case("prefix") {
+            let prefix = self.name();
+            if self.next() != Some(':') {
+                return Err(self.error("expected ':' after the prefix"));
+            }
+            self.skip_whitespace();
+            let iri = self.iri_ref()?;
+            self.prefixes.insert(prefix, iri);
+            Ok(())
+        } else if name.eq_ignore_ascii_case("base") {
+            self.base = self.iri_ref()?;
+            Ok(())
+        } else {
+            Err(self.error(format!("unknown directive '{name}'")))
+        }
+    }
+
+    fn triples_statement(&mut self) -> Result<(), RdfError> {
+        if self.peek() == Some('[') {
+            let subject = self.blank_node_property_list()?;
+            self.skip_whitespace();
+            if self.peek() != Some('.') {
+                self.predicate_object_list(&subject)?;
+            }
+            Ok(())
+        } else {
+            let subject = match self.term()? {
+                Term::Literal(_) => return Err(self.error("a literal cannot be a subject")),
+                subject => subject,
+            };
+            self.predicate_object_list(&subject)
+        }
+    }
+
+    fn predicate_object_list(&mut self, subject: &Term) -> Result<(), RdfError> {
+        loop {
+            self.skip_whitespace();
+            let predicate = self.verb()?;
+            loop {
+                self.skip_whitespace();
+                let object = self.object()?;
+                self.triples.push(Triple {
+                    subject: subject.clone(),
+                    predicate: predicate.clone(),
+                    object,
+                });
+                self.skip_whitespace();
+                if self.peek() != Some(',') {
+                    break;
+                }
+                self.next();
+            }
+            // any number of ';', the last one may be followed by no predicate
+            let mut separated = false;
+            while self.peek() == Some(';') {
+                self.next();
+                self.skip_whitespace();
+                separated = true;
+            }
+            if !separated || matches!(self.peek(), Some('.' | ']') | None) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn verb(&mut self) -> Result<String, RdfError> {
+        if self.peek() == Some('a')
+            && self.input[self.position + 1..]
+                .starts_with(|c: char| c.is_whitespace() || c == '<' || c == '[' || c == '"')
+        {
+            self.next();
+            return Ok(RDF_TYPE.to_string());
+        }
+        match self.term()? {
+            Term::Iri(iri) => Ok(iri),
+            _ => Err(self.error("a predicate must be an IRI")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Term, RdfError> {
+        match self.peek() {
+            Some('[') => self.blank_node_property_list(),
+            _ => self.term(),
+        }
+    }
+
+    fn blank_node_property_list(&mut self) -> Result<Term, RdfError> {
+        self.expect('[')?;
+        let node = self.fresh_blank_node();
+        self.skip_whitespace();
+        if self.peek() != Some(']') {
+            self.predicate_object_list(&node)?;
+        }
+        self.expect(']')?;
+        Ok(node)
+    }
+
+    fn fresh_blank_node(&mut self) -> Term {
+        self.blank_nodes += 1;
+        // labels of the document cannot contain spaces
+        Term::Blank(format!("anonymous {}", self.blank_nodes))
+    }
+
+    /// IRIs, blank node labels and literals
+    fn term(&mut self) -> Result<Term, RdfError> {
+        match self.peek() {
+            Some('<') => Ok(Term::Iri(self.iri_ref()?)),
+            Some('"' | '\'') => self.string_literal(),
+            Some('(') => Err(self.error("collections are not supported")),
+            Some(c) if c.is_ascii_digit() || matches!(c, '+' | '-' | '.') => self.numeric_literal(),
+            Some('_') if self.starts_with("_:") => {
+                self.position += 2;
+                Ok(Term::Blank(self.local_name()))
+            }
+            Some(_) => {
+                let prefix = self.name();
+                if self.peek() != Some(':') {
+                    return match prefix.as_str() {
+                        "true" | "false" => Ok(Term::Literal(Literal {
+                            value: prefix,
+                            datatype: format!("{XSD}boolean"),
+                            language: None,
+                        })),
+                        _ => Err(self.error(format!("unexpected '{prefix}'"))),
+                    };
+                }
+                self.next();
+                let namespace = self
+                    .prefixes
+                    .get(&prefix)
+                    .ok_or_else(|| self.error(format!("undefined prefix '{prefix}'")))?
+                    .clone();
+                Ok(Term::Iri(namespace + &self.local_name()))
+            }
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    /// A prefix, keyword or directive name
+    fn name(&mut self) -> String {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        {
+            self.next();
+        }
+        self.input[start..self.position].to_string()
+    }
+
+    /// The local part of a prefixed name or blank node label, with escapes resolved.
+    /// A trailing dot ends the statement.
+    fn local_name(&mut self) -> String {
+        let mut name = String::new();
+        while let Some(c) = self.peek() {
+            match c {
+                '\\' => {
+                    self.next();
+                    name.extend(self.next());
+                }
+                c if c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '%') => {
+                    self.next();
+                    name.push(c);
+                }
+                '.' if self.input[self.position + 1..].starts_with(|c: char| {
+                    c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '%' | '.' | '\\')
+                }) =>
+                {
+                    self.next();
+                    name.push(c);
+                }
+                _ => break,
+            }
+        }
+        name
+    }
+
+    fn iri_ref(&mut self) -> Result<String, RdfError> {
+        self.expect('<')?;
+        let mut iri = String::new();
+        loop {
+            match self.next() {
+                Some('>') => break,
+                Some('\\') => iri.push(self.unicode_escape()?),
+                Some(c) if c.is_whitespace() => return Err(self.error("whitespace in IRI")),
+                Some(c) => iri.push(c),
+                None => return Err(self.error("unterminated IRI")),
+            }
+        }
+        // relative IRIs are resolved against the base by concatenation
+        match iri.contains(':') {
+            true => Ok(iri),
+            false => Ok(format!("{}{iri}", self.base)),
+        }
+    }
+
+    /// `\u` and `\U` escapes, after the backslash
+    fn unicode_escape(&mut self) -> Result<char, RdfError> {
+        let length = match self.next() {
+            Some('u') => 4,
+            Some('U') => 8,
+            _ => return Err(self.error("invalid escape sequence")),
+        };
+        let hex = self
+            .input
+            .get(self.position..self.position + length)
+            .ok_or_else(|| self.error("invalid escape sequence"))?;
+        let c = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error(format!("invalid code point '{hex}'")))?;
+        self.position += length;
+        Ok(c)
+    }
+
+    fn string_literal(&mut self) -> Result<Term, RdfError> {
+        let quote = self.next().expect("checked by the caller");
+        let long: String = [quote; 3].iter().collect();
+        let is_long = self.starts_with(&long[1..]);
+        if is_long {
+            self.position += 2;
+        }
+
+        let mut value = String::new();
+        loop {
+            if is_long && self.starts_with(&long) {
+                self.position += 3;
+                break;
+            }
+            match self.next() {
+                Some(c) if c == quote && !is_long => break,
+                Some('\\') => value.push(match self.peek() {
+                    Some('t') => '\t',
+                    Some('b') => '\u{8}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('f') => '\u{c}',
+                    Some(c @ ('"' | '\'' | '\\')) => c,
+                    Some('u' | 'U') => {
+                        value.push(self.unicode_escape()?);
+                        continue;
+                    }
+                    _ => return Err(self.error("invalid escape sequence")),
+                }),
+                Some('\n' | '\r') if !is_long => return Err(self.error("line break in string")),
+                Some(c) => {
+                    value.push(c);
+                    continue;
+                }
+                None => return Err(self.error("unterminated string")),
+            }
+            // the escaped character
+            self.next();
+        }
+
+        if self.peek() == Some('@') {
+            self.next();
+            let start = self.position;
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '-')
+            {
+                self.next();
+            }
+            return Ok(Term::Literal(Literal {
+                value,
+                datatype: RDF_LANG_STRING.to_string(),
+                language: Some(self.input[start..self.position].to_string()),
+            }));
+        }
+        let datatype = match self.starts_with("^^") {
+            true => {
+                self.position += 2;
+                match self.term()? {
+                    Term::Iri(iri) => iri,
+                    _ => return Err(self.error("a datatype must be an IRI")),
+                }
+            }
+            false => format!("{XSD}string"),
+        };
+        Ok(Term::Literal(Literal {
+            value,
+            datatype,
+            language: None,
+        }))
+    }
+
+    fn numeric_literal(&mut self) -> Result<Term, RdfError> {
+        let start = self.position;
+        if matches!(self.peek(), Some('+' | '-')) {
+            self.next();
+        }
+        let mut datatype = "integer";
+        while let Some(c) = self.peek() {
+            match c {
+                '0'..='9' => {}
+                // a dot is only part of the number if a digit follows
+                '.' if datatype == "integer"
+                    && self.input[self.position + 1..]
+                        .starts_with(|c: char| c.is_ascii_digit()) =>
+                {
+                    datatype = "decimal"
+                }
+                'e' | 'E' => {
+                    datatype = "double";
+                    self.next();
+                    if matches!(self.peek(), Some('+' | '-')) {
+                        self.next();
+                    }
+                    continue;
+                }
+                _ => break,
+            }
+            self.next();
+        }
+        let value = &self.input[start..self.position];
+        if !value.contains(|c: char| c.is_ascii_digit()) {
+            return Err(self.error(format!("invalid number '{value}'")));
+        }
+        Ok(Term::Literal(Literal {
+            value: value.to_string(),
+            datatype: format!("{XSD}{datatype}"),
+            language: None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iri(iri: &str) -> Term {
+        Term::Iri(iri.to_string())
+    }
+
+    fn string(value: &str) -> Term {
+        Term::Literal(Literal {
+            value: value.to_string(),
+            datatype: format!("{XSD}string"),
+            language: None,
+        })
+    }
+
+    #[test]
+    fn parses_turtle() {
+        let triples = parse(
+            r#"
+            @prefix ex: <http://example.com/> .
+            PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+            @base <http://example.com/base/> .
+
+            # a comment
+            ex:a a ex:Thing ;
+                ex:name "A \"quoted\" name", 'single' ;
+                ex:long """two
+lines""" ;
+                ex:label "Hallo"@de ;
+                ex:count 42, -1.5, 1e3 ;
+                ex:flag true ;
+                ex:typed "5"^^xsd:int ;
+                <relative> [ ex:nested _:b1 ] ;
+                ex:empty [] ;
+                .
+            _:b1 ex:escaped "ä\n" .
+            "#,
+        )
+        .unwrap();
+
+        let objects: Vec<&Term> = triples.iter().map(|triple| &triple.object).collect();
+        assert_eq!(objects[0], &iri("http://example.com/Thing"));
+        assert_eq!(triples[0].predicate, RDF_TYPE);
+        assert_eq!(objects[1], &string("A \"quoted\" name"));
+        assert_eq!(objects[2], &string("single"));
+        assert_eq!(objects[3], &string("two\nlines"));
+        assert!(matches!(
+            objects[4],
+            Term::Literal(Literal { language: Some(language), .. }) if language == "de"
+        ));
+        let numbers: Vec<&str> = objects[5..8]
+            .iter()
+            .map(|term| match term {
+                Term::Literal(literal) => literal.datatype.trim_start_matches(XSD),
+                _ => "",
+            })
+            .collect();
+        assert_eq!(numbers, ["integer", "decimal", "double"]);
+        assert!(matches!(
+            objects[9],
+            Term::Literal(Literal { datatype, .. }) if datatype == &format!("{XSD}int")
+        ));
+
+        // the nested triple comes first, its blank node is the object of <relative>
+        assert_eq!(triples[10].predicate, "http://example.com/nested");
+        assert_eq!(triples[10].object, Term::Blank("b1".to_string()));
+        assert_eq!(triples[11].predicate, "http://example.com/base/relative");
+        assert_eq!(triples[11].object, triples[10].subject);
+        assert_eq!(triples[13].object, string("ä\n"));
+        assert_eq!(triples.len(), 14);
+    }
+
+    #[test]
+    fn reports_line() {
+        assert!(matches!(
+            parse("@prefix ex: <http://example.com/> .\nex:a ex:b ex:c ;\nundefined:d ex:e ."),
+            Err(RdfError::Syntax { line: 3, .. })
+        ));
+        assert!(matches!(
+            parse("<a> <b> ( <c> ) ."),
+            Err(RdfError::Syntax { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn escapes_literals() {
+        let written = literal("a \"b\"\\\n", "xsd:string");
+        let triples = parse(&format!("@prefix xsd: <{XSD}> . <s> <p> {written} .")).unwrap();
+        assert_eq!(triples[0].object, string("a \"b\"\\\n"));
+    }
+}
@@ -1,37 +1,33 @@
-use serde::{Deserialize, Deserializer, Serialize};
-use thiserror::Error;
-//! This crate defines data types according to the
-//! [asset administration shell specifications](https://industrialdigitaltwin.org/en/content-hub/aasspecifications)
+//! XML serialization of the metamodel types of the [aas] crate, see
+//! <https://industrialdigitaltwin.io/aas-specifications/IDTA-01001/v3.1.1/mappings/mappings.html#xml>
+//!
+//! Like `aas-json`, this crate only mirrors the core types with private proxy types in the shape
+//! of the XML schema. Lists are wrapped in one more element and submodel elements are
+//! distinguished by their element name instead of a modelType.
+//! Data specification contents other than IEC 61360 and physical units are kept as the element
+//! they were read from, e.g. `<dataSpecificationAcme>`.
 //!
-//! To support multiple specs and multiple versions of each, this crate it split in
-//! multiple modules for each part as well as version.
-//! Because each spec is versioned on their own, the modules are ordered `specs/version` instead of
-//! `version/specs`, i.e. `aas::part1::v3.1`.
+//! ```
+//! use aas::part1::v3_1::environment::Environment;
+//! use aas_xml::Xml;
+//!
+//! let xml = r#"<environment xmlns="https://admin-shell.io/aas/3/1"><submodels><submodel><id>urn:example:1</id></submodel></submodels></environment>"#;
+//! let environment = Environment::from_xml(xml).unwrap();
+//! assert_eq!(environment.submodels.as_ref().unwrap()[0].identifiable.id.as_ref(), "urn:example:1");
+//! assert_eq!(environment.to_xml().unwrap(), xml);
+//! ```
 
 /// Part1: Metamodel
-pub mod part1;
-
-/// Part 2: Http Endpoints
-/// Can be used with feature = "part2"
-#[cfg(feature = "part2")]
-pub mod part2;
-
-/// Utility functions like validating text to specific formats and deserializers to specific needs,
-/// like text with defined constraints.
-pub mod utilities;
-
-// Aufbau:
-// if feature xml: pub use xml::Struct
-// if feature json: pub use json::Struct
-// if both: pub use xml::Struct as StructXML,
-//          pub use json::Struct as StructJSON
-//          pub Struct (without Des, Ser)
-// dabei wird das macro generate.. genutzt.
-// in den jeweiligen modulen (xml, json) wird dann impl Ser, Der
-// implementiert. Dabei kann innerhalb der implementierung das geeignete
-// struct (z.B. für flatten) benutzt werden.
-
-// das wird erst umgesetzt, nachdem die erste Version von XML steht.
+mod part1;
+
+use aas::part1::v3_1::concept_description::ConceptDescription;
+use aas::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use aas::part1::v3_1::environment::Environment;
+use aas::part1::v3_1::reference::Reference;
+use aas::part1::v3_1::submodel_elements::SubmodelElement;
+use part1::v3_1 as proxy;
+use serde::{Deserialize, Deserializer, Serialize};
+use thiserror::Error;
 
 /// Wrapper Struct to serialize/deserialize given struct to/from XML
 pub struct XML<T: Xml>(T);
@@ -64,14 +60,206 @@ pub trait Xml: Sized {
 
     fn to_xml(&self) -> Result<String, Self::Error>;
 
-    fn from_xml(json: &str) -> Result<Self, Self::Error>;
+    fn from_xml(xml: &str) -> Result<Self, Self::Error>;
 }
 
 #[derive(Debug, Error)]
 pub enum XMLError {
     #[error("xml serialisation error: {0:?}")]
     SerialiseError(#[from] quick_xml::se::SeError),
-    
+
     #[error("xml deserialisation error: {0:?}")]
-    DeserializeError(#[from] quick_xml::de::DeError)
+    DeserializeError(#[from] quick_xml::de::DeError),
+
+    #[error("invalid {attribute} '{value}': {reason}")]
+    InvalidAttribute {
+        attribute: &'static str,
+        value: String,
+        reason: String,
+    },
+}
+
+/// References are always written, the proxies of all other types may reject values outside the
+/// value space of their valueType.
+macro_rules! impl_xml {
+    (@write $ty:ty => $proxy:ty, |$value:ident| $write:expr) => {
+        impl Xml for $ty {
+            type Error = XMLError;
+
+            fn to_xml(&self) -> Result<String, Self::Error> {
+                let $value = self;
+                Ok(quick_xml::se::to_string(&$write)?)
+            }
+
+            fn from_xml(xml: &str) -> Result<Self, Self::Error> {
+                // files saved by some editors start with a byte order mark
+                let xml = xml.trim_start_matches('\u{feff}');
+                quick_xml::de::from_str::<$proxy>(xml)?.try_into()
+            }
+        }
+    };
+    ($($ty:ty => $proxy:ty),* $(,)?) => {$(
+        impl_xml!(@write $ty => $proxy, |value| <$proxy>::try_from(value)?);
+    )*};
+}
+
+impl_xml!(
+    Environment => proxy::environment::Environment,
+    AssetAdministrationShell => proxy::core::AssetAdministrationShell,
+    Submodel => proxy::core::Submodel,
+    ConceptDescription => proxy::core::ConceptDescription,
+    SubmodelElement => proxy::submodel_elements::SubmodelElement,
+);
+
+impl_xml!(@write Reference => proxy::reference::Reference, |value| {
+    proxy::reference::Reference::from(value)
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aas::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use aas::part1::v3_1::submodel_elements::{Range, RangeInner, RangeValue};
+    use quick_xml::events::Event;
+    use quick_xml::name::QName;
+
+    /// The elements of a document, one per line and indented by depth. Leaf elements are
+    /// written with their text on the same line. Namespace declarations, comments and the
+    /// formatting are left out.
+    fn canonical(xml: &str) -> Vec<String> {
+        let mut reader = quick_xml::Reader::from_str(xml.trim_start_matches('\u{feff}'));
+        reader.config_mut().trim_text(true);
+        let name = |name: QName| String::from_utf8_lossy(name.local_name().as_ref()).into_owned();
+
+        let mut lines = Vec::new();
+        let mut open: Vec<(usize, String)> = Vec::new();
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(start) => {
+                    let name = name(start.name());
+                    lines.push(format!("{}<{name}>", "  ".repeat(open.len())));
+                    open.push((lines.len(), name));
+                }
+                Event::Empty(start) => {
+                    lines.push(format!(
+                        "{}<{}/>",
+                        "  ".repeat(open.len()),
+                        name(start.name())
+                    ));
+                }
+                Event::Text(text) => {
+                    let text = text.decode().unwrap();
+                    match open.last() {
+                        // first content of the element
+                        Some((line, _)) if *line == lines.len() => {
+                            lines.last_mut().unwrap().push_str(&text)
+                        }
+                        _ => lines.push(format!("{}{text}", "  ".repeat(open.len()))),
+                    }
+                }
+                Event::End(_) => {
+                    let (line, name) = open.pop().unwrap();
+                    let indent = "  ".repeat(open.len());
+                    // without content, like `<text></text>`
+                    if line == lines.len() && lines[line - 1] == format!("{indent}<{name}>") {
+                        lines[line - 1] = format!("{indent}<{name}/>");
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        lines
+    }
+
+    /// Whether the text is an xs:dateTime without timezone, e.g. `2024-09-30T15:00:00`
+    fn is_local_date_time(text: &str) -> bool {
+        text.len() == 19
+            && text.char_indices().all(|(i, c)| match i {
+                4 | 7 => c == '-',
+                10 => c == 'T',
+                13 | 16 => c == ':',
+                _ => c.is_ascii_digit(),
+            })
+    }
+
+    #[test]
+    fn round_trip() {
+        let xml = include_str!("../../aas/tests/mvp-dpp-1.0.0.xml");
+        let environment = Environment::from_xml(xml).unwrap();
+        let written = environment.to_xml().unwrap();
+
+        // The written document only differs in what the core types do not keep: empty idShorts
+        // and the default orderRelevant are left out, dateTimes are written with an offset.
+        let expected: Vec<_> = canonical(xml)
+            .into_iter()
+            .filter(|line| !line.ends_with("<idShort/>") && !line.ends_with("<orderRelevant>true"))
+            .map(|line| match line.split_once("<value>") {
+                Some((_, value)) if is_local_date_time(value) => format!("{line}.0+00:00"),
+                _ => line,
+            })
+            .collect();
+        assert_eq!(canonical(&written), expected);
+        assert_eq!(Environment::from_xml(&written).unwrap(), environment);
+    }
+
+    #[test]
+    fn invalid_attribute() {
+        let xml = "<submodel><id></id></submodel>";
+        assert!(matches!(
+            Submodel::from_xml(xml),
+            Err(XMLError::InvalidAttribute {
+                attribute: "id",
+                ..
+            })
+        ));
+
+        let xml = "<property><idShort>p</idShort><valueType>xs:int</valueType><value>a</value></property>";
+        assert!(matches!(
+            SubmodelElement::from_xml(xml),
+            Err(XMLError::InvalidAttribute {
+                attribute: "value",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn property_value_id() {
+        let xml = r#"<property><idShort>colour</idShort><valueType>xs:string</valueType><value>red</value><valueId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://example.com/red</value></key></keys></valueId></property>"#;
+        let property = SubmodelElement::from_xml(xml).unwrap();
+        let SubmodelElement::Property(inner) = &property else {
+            panic!("expected a property");
+        };
+        assert!(inner.value_id.is_some());
+        assert_eq!(property.to_xml().unwrap(), xml);
+    }
+
+    #[test]
+    fn out_of_value_space() {
+        let xml = "<property><idShort>p</idShort><valueType>xs:negativeInteger</valueType><value>-5</value></property>";
+        let mut property = SubmodelElement::from_xml(xml).unwrap();
+        if let SubmodelElement::Property(property) = &mut property {
+            property.value = DataXsd::NegativeInteger(Some(5.into()));
+        }
+        assert!(matches!(
+            property.to_xml(),
+            Err(XMLError::InvalidAttribute {
+                attribute: "value",
+                ..
+            })
+        ));
+
+        let range = SubmodelElement::Range(Range::new(RangeValue::PositiveInteger(RangeInner {
+            min: Some(0.into()),
+            max: None,
+        })));
+        assert!(matches!(
+            range.to_xml(),
+            Err(XMLError::InvalidAttribute {
+                attribute: "min/max",
+                ..
+            })
+        ));
+    }
 }
@@ -0,0 +1,1047 @@
+use crate::XMLError;
+use crate::part1::v3_1::reference::{Reference, References};
+use crate::part1::v3_1::{convert, identifier, invalid, parse, proxies, proxy, wrapper};
+use aas::part1::v3_1::LangString as ModelLangString;
+use aas::part1::v3_1::attributes::administrative_information::{self as administration, Version};
+use aas::part1::v3_1::attributes::data_specification;
+use aas::part1::v3_1::attributes::extension;
+use aas::part1::v3_1::attributes::qualifiable::{self as qualifiable, QualifierInner};
+use aas::part1::v3_1::attributes::semantics;
+use aas::part1::v3_1::level_type;
+use aas::part1::v3_1::primitives::data_type_def_xs::{DataTypeXSDef, DataXsd};
+use aas::part1::v3_1::value_list::{ValueList, ValueReferencePair};
+use serde::de::{EnumAccess, VariantAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct LangString {
+    language: String,
+    text: String,
+}
+
+impl From<&ModelLangString> for LangString {
+    fn from(value: &ModelLangString) -> Self {
+        Self {
+            language: value.language.to_string(),
+            text: value.text.clone(),
+        }
+    }
+}
+
+impl TryFrom<LangString> for ModelLangString {
+    type Error = XMLError;
+
+    fn try_from(value: LangString) -> Result<Self, Self::Error> {
+        parse("language", &value.language, |language| {
+            ModelLangString::try_new(language, value.text)
+        })
+    }
+}
+
+/// The element names of lang strings depend on their length constraints.
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct LangStringPreferredNameTypes {
+    #[serde(rename = "langStringPreferredNameTypeIec61360", default)]
+    items: Vec<LangString>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct LangStringShortNameTypes {
+    #[serde(rename = "langStringShortNameTypeIec61360", default)]
+    items: Vec<LangString>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct LangStringDefinitionTypes {
+    #[serde(rename = "langStringDefinitionTypeIec61360", default)]
+    items: Vec<LangString>,
+}
+
+macro_rules! impl_wrapper {
+    ($($name:ident),*) => {$(
+        impl crate::part1::v3_1::Wrapper for $name {
+            type Item = LangString;
+
+            fn new(items: Vec<LangString>) -> Self {
+                Self { items }
+            }
+
+            fn into_items(self) -> Vec<LangString> {
+                self.items
+            }
+        }
+    )*};
+}
+
+impl_wrapper!(
+    LangStringPreferredNameTypes,
+    LangStringShortNameTypes,
+    LangStringDefinitionTypes
+);
+
+wrapper!(
+    LangStringNameTypes {
+        lang_string_name_type: LangString
+    },
+    LangStringTextTypes {
+        lang_string_text_type: LangString
+    },
+    Extensions {
+        extension: Extension
+    },
+    Qualifiers {
+        qualifier: Qualifier
+    },
+    EmbeddedDataSpecifications {
+        embedded_data_specification: EmbeddedDataSpecification
+    },
+    ValueReferencePairs {
+        value_reference_pair: ValueReferencePairProxy
+    },
+);
+
+/// valueType and value of Properties, Qualifiers and Extensions. Values outside the value space
+/// of their type, e.g. a positive xs:negativeInteger, are not written.
+pub(crate) fn data_value(value: &DataXsd) -> Result<(String, Option<String>), XMLError> {
+    let data = value.to_lexical().map_err(|e| {
+        invalid(
+            "value",
+            Option::<String>::from(value.clone()).unwrap_or_default(),
+            e,
+        )
+    })?;
+    Ok((DataTypeXSDef::from(value).to_string(), data))
+}
+
+/// The strum prefix of [DataTypeXSDef] is only written, parsing expects the plain type name.
+pub(crate) fn value_type(
+    attribute: &'static str,
+    value_type: &str,
+) -> Result<DataTypeXSDef, XMLError> {
+    parse(attribute, value_type, |value_type| {
+        DataTypeXSDef::from_str(value_type.strip_prefix("xs:").unwrap_or(value_type))
+    })
+}
+
+pub(crate) fn data_xsd(value_type: &str, value: Option<String>) -> Result<DataXsd, XMLError> {
+    let data_type = self::value_type("valueType", value_type)?;
+    DataXsd::try_from((data_type, value.clone()))
+        .map_err(|e| invalid("value", value.unwrap_or_default(), e))
+}
+
+proxy! {
+    struct AdministrativeInformation {
+        @data_specification
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        revision: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        creator: Option<Reference>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        template_id: Option<String>,
+    }
+}
+
+impl From<&administration::AdministrativeInformation> for AdministrativeInformation {
+    fn from(value: &administration::AdministrativeInformation) -> Self {
+        let mut proxy = Self {
+            version: value.version.version.clone(),
+            revision: value.version.revision.clone(),
+            creator: value.creator.as_ref().map(Into::into),
+            template_id: value.template_id.as_ref().map(ToString::to_string),
+            ..Default::default()
+        };
+        proxy.set_data_specification(&value.data_specification);
+        proxy
+    }
+}
+
+impl TryFrom<AdministrativeInformation> for administration::AdministrativeInformation {
+    type Error = XMLError;
+
+    fn try_from(mut value: AdministrativeInformation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            data_specification: value.take_data_specification()?,
+            version: Version {
+                version: value.version,
+                revision: value.revision,
+            },
+            creator: value.creator.map(TryInto::try_into).transpose()?,
+            template_id: value
+                .template_id
+                .map(|id| identifier("templateId", &id))
+                .transpose()?,
+        })
+    }
+}
+
+proxy! {
+    struct Extension {
+        @semantics
+
+        name: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value_type: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        refers_to: Option<References>,
+    }
+}
+
+impl TryFrom<&extension::Extension> for Extension {
+    type Error = XMLError;
+
+    fn try_from(value: &extension::Extension) -> Result<Self, Self::Error> {
+        let (value_type, data) = data_value(&value.value)?;
+        let mut proxy = Self {
+            name: value.name.clone(),
+            value_type: Some(value_type),
+            value: data,
+            refers_to: proxies(value.refers_to.iter().flatten()),
+            ..Default::default()
+        };
+        proxy.set_semantics(&semantics::HasSemantics {
+            semantic_id: value.semantic_id.clone(),
+            supplemental_semantic_ids: value.supplemental_semantic_ids.clone(),
+        });
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<Extension> for extension::Extension {
+    type Error = XMLError;
+
+    fn try_from(mut value: Extension) -> Result<Self, Self::Error> {
+        let semantics = value.take_semantics()?;
+        Ok(Self {
+            name: value.name,
+            semantic_id: semantics.semantic_id,
+            supplemental_semantic_ids: semantics.supplemental_semantic_ids,
+            value: match value.value_type {
+                Some(value_type) => data_xsd(&value_type, value.value)?,
+                None => DataXsd::String(value.value),
+            },
+            refers_to: convert(value.refers_to)?,
+        })
+    }
+}
+
+proxy! {
+    struct Qualifier {
+        @semantics
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+
+        #[serde(rename = "type")]
+        ty: String,
+
+        value_type: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value_id: Option<Reference>,
+    }
+}
+
+impl TryFrom<&qualifiable::Qualifier> for Qualifier {
+    type Error = XMLError;
+
+    fn try_from(value: &qualifiable::Qualifier) -> Result<Self, Self::Error> {
+        let (kind, inner) = match value {
+            qualifiable::Qualifier::ConceptQualifier(inner) => (Some("ConceptQualifier"), inner),
+            qualifiable::Qualifier::TemplateQualifier(inner) => (Some("TemplateQualifier"), inner),
+            qualifiable::Qualifier::ValueQualifier(inner) => (Some("ValueQualifier"), inner),
+            qualifiable::Qualifier::Unknown(inner) => (None, inner),
+        };
+        let (value_type, data) = data_value(&inner.value)?;
+        let mut proxy = Self {
+            kind: kind.map(Into::into),
+            ty: inner.ty.clone(),
+            value_type,
+            value: data,
+            value_id: inner.value_id.as_ref().map(Into::into),
+            ..Default::default()
+        };
+        proxy.set_semantics(&inner.semantics);
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<Qualifier> for qualifiable::Qualifier {
+    type Error = XMLError;
+
+    fn try_from(mut value: Qualifier) -> Result<Self, Self::Error> {
+        let inner = QualifierInner {
+            semantics: value.take_semantics()?,
+            ty: value.ty,
+            value: data_xsd(&value.value_type, value.value)?,
+            value_id: value.value_id.map(TryInto::try_into).transpose()?,
+        };
+        match value.kind.as_deref() {
+            Some("ConceptQualifier") => Ok(Self::ConceptQualifier(inner)),
+            Some("TemplateQualifier") => Ok(Self::TemplateQualifier(inner)),
+            Some("ValueQualifier") => Ok(Self::ValueQualifier(inner)),
+            None => Ok(Self::Unknown(inner)),
+            Some(kind) => Err(invalid(
+                "kind",
+                kind,
+                "expected ConceptQualifier, TemplateQualifier or ValueQualifier",
+            )),
+        }
+    }
+}
+
+pub(crate) fn embedded_data_specifications(
+    value: &data_specification::HasDataSpecification,
+) -> Option<EmbeddedDataSpecifications> {
+    proxies(value.embedded_data_specifications.iter().flatten())
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EmbeddedDataSpecification {
+    data_specification: Reference,
+    data_specification_content: DataSpecificationContentWrapper,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct DataSpecificationContentWrapper {
+    #[serde(rename = "$value")]
+    content: DataSpecificationContent,
+}
+
+/// quick-xml can not write the map of the content as `$value`, so the wrapper element holds the
+/// map itself.
+impl Serialize for DataSpecificationContentWrapper {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.content.serialize(serializer)
+    }
+}
+
+/// The content is a single element named after the data specification template,
+/// e.g. `<dataSpecificationIec61360>`. Unknown elements are kept as raw tree.
+pub(crate) enum DataSpecificationContent {
+    DataSpecificationIec61360(Box<DataSpecificationIec61360>),
+    DataSpecificationPhysicalUnit(Box<DataSpecificationPhysicalUnit>),
+    Unknown(data_specification::UnknownDataSpecificationContent),
+}
+
+const IEC61360_ELEMENT: &str = "dataSpecificationIec61360";
+const PHYSICAL_UNIT_ELEMENT: &str = "dataSpecificationPhysicalUnit";
+
+impl Serialize for DataSpecificationContent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // a single entry map becomes an element named after the key
+        let mut map = serializer.serialize_map(Some(1))?;
+        match self {
+            DataSpecificationContent::DataSpecificationIec61360(content) => {
+                map.serialize_entry(IEC61360_ELEMENT, content)?
+            }
+            DataSpecificationContent::DataSpecificationPhysicalUnit(content) => {
+                map.serialize_entry(PHYSICAL_UNIT_ELEMENT, content)?
+            }
+            DataSpecificationContent::Unknown(content) => {
+                map.serialize_entry(&content.model_type, &content.fields)?
+            }
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DataSpecificationContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ContentVisitor;
+
+        impl<'de> Visitor<'de> for ContentVisitor {
+            type Value = DataSpecificationContent;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a data specification content element")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (element, content): (String, _) = data.variant()?;
+
+                Ok(match element.as_str() {
+                    IEC61360_ELEMENT => DataSpecificationContent::DataSpecificationIec61360(
+                        content.newtype_variant()?,
+                    ),
+                    PHYSICAL_UNIT_ELEMENT => {
+                        DataSpecificationContent::DataSpecificationPhysicalUnit(
+                            content.newtype_variant()?,
+                        )
+                    }
+                    _ => DataSpecificationContent::Unknown(
+                        data_specification::UnknownDataSpecificationContent {
+                            model_type: element,
+                            fields: content.newtype_variant()?,
+                        },
+                    ),
+                })
+            }
+        }
+
+        deserializer.deserialize_enum(
+            "DataSpecificationContent",
+            &[IEC61360_ELEMENT, PHYSICAL_UNIT_ELEMENT],
+            ContentVisitor,
+        )
+    }
+}
+
+impl From<&data_specification::EmbeddedDataSpecification> for EmbeddedDataSpecification {
+    fn from(value: &data_specification::EmbeddedDataSpecification) -> Self {
+        let content = match &value.data_specification_content {
+            data_specification::DataSpecificationContent::DataSpecificationIec61360(content) => {
+                DataSpecificationContent::DataSpecificationIec61360(Box::new(content.into()))
+            }
+            data_specification::DataSpecificationContent::DataSpecificationPhysicalUnit(
+                content,
+            ) => DataSpecificationContent::DataSpecificationPhysicalUnit(Box::new(content.into())),
+            data_specification::DataSpecificationContent::Unknown(content) => {
+                DataSpecificationContent::Unknown(content.clone())
+            }
+        };
+        Self {
+            data_specification: (&value.data_specification).into(),
+            data_specification_content: DataSpecificationContentWrapper { content },
+        }
+    }
+}
+
+impl TryFrom<EmbeddedDataSpecification> for data_specification::EmbeddedDataSpecification {
+    type Error = XMLError;
+
+    fn try_from(value: EmbeddedDataSpecification) -> Result<Self, Self::Error> {
+        Ok(Self {
+            data_specification: value.data_specification.try_into()?,
+            data_specification_content: match value.data_specification_content.content {
+                DataSpecificationContent::DataSpecificationIec61360(content) => {
+                    data_specification::DataSpecificationContent::DataSpecificationIec61360(
+                        (*content).try_into()?,
+                    )
+                }
+                DataSpecificationContent::DataSpecificationPhysicalUnit(content) => {
+                    data_specification::DataSpecificationContent::DataSpecificationPhysicalUnit(
+                        (*content).try_into()?,
+                    )
+                }
+                DataSpecificationContent::Unknown(content) => {
+                    data_specification::DataSpecificationContent::Unknown(content)
+                }
+            },
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataSpecificationIec61360 {
+    preferred_name: LangStringPreferredNameTypes,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    short_name: Option<LangStringShortNameTypes>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unit: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unit_id: Option<Reference>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_of_definition: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    data_type: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    definition: Option<LangStringDefinitionTypes>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value_format: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value_list: Option<ValueListProxy>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    level_type: Option<LevelType>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ValueListProxy {
+    value_reference_pairs: ValueReferencePairs,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ValueReferencePairProxy {
+    value: String,
+    value_id: Reference,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct LevelType {
+    min: bool,
+    nom: bool,
+    typ: bool,
+    max: bool,
+}
+
+impl From<&ValueReferencePair> for ValueReferencePairProxy {
+    fn from(value: &ValueReferencePair) -> Self {
+        Self {
+            value: value.value.clone(),
+            value_id: (&value.value_id).into(),
+        }
+    }
+}
+
+impl TryFrom<ValueReferencePairProxy> for ValueReferencePair {
+    type Error = XMLError;
+
+    fn try_from(value: ValueReferencePairProxy) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: value.value,
+            value_id: value.value_id.try_into()?,
+        })
+    }
+}
+
+impl From<&data_specification::DataSpecificationIec61360> for DataSpecificationIec61360 {
+    fn from(value: &data_specification::DataSpecificationIec61360) -> Self {
+        Self {
+            preferred_name: proxies(&value.preferred_name).unwrap_or_default(),
+            short_name: proxies(value.short_name.iter().flatten()),
+            unit: value.unit.clone(),
+            unit_id: value.unit_id.as_ref().map(Into::into),
+            source_of_definition: value.source_of_definition.clone(),
+            symbol: value.symbol.clone(),
+            data_type: value.data_type.as_ref().map(ToString::to_string),
+            definition: proxies(value.definition.iter().flatten()),
+            value_format: value.value_format.clone(),
+            value_list: value.value_list.as_ref().map(|lists| ValueListProxy {
+                value_reference_pairs: proxies(
+                    lists.iter().flat_map(|list| &list.value_reference_pairs),
+                )
+                .unwrap_or_default(),
+            }),
+            value: value.value.clone(),
+            level_type: value.level_type.as_ref().map(|level| LevelType {
+                min: level.min,
+                nom: level.nom,
+                typ: level.typ,
+                max: level.max,
+            }),
+        }
+    }
+}
+
+impl TryFrom<DataSpecificationIec61360> for data_specification::DataSpecificationIec61360 {
+    type Error = XMLError;
+
+    fn try_from(value: DataSpecificationIec61360) -> Result<Self, Self::Error> {
+        Ok(Self {
+            preferred_name: convert(Some(value.preferred_name))?.unwrap_or_default(),
+            short_name: convert(value.short_name)?,
+            unit: value.unit,
+            unit_id: value.unit_id.map(TryInto::try_into).transpose()?,
+            source_of_definition: value.source_of_definition,
+            symbol: value.symbol,
+            data_type: value
+                .data_type
+                .map(|data_type| {
+                    parse(
+                        "dataType",
+                        &data_type,
+                        data_specification::DataTypeIec61360::from_str,
+                    )
+                })
+                .transpose()?,
+            definition: convert(value.definition)?,
+            value_format: value.value_format,
+            value_list: value
+                .value_list
+                .map(|list| {
+                    Ok::<_, XMLError>(vec![ValueList {
+                        value_reference_pairs: convert(Some(list.value_reference_pairs))?
+                            .unwrap_or_default(),
+                    }])
+                })
+                .transpose()?,
+            value: value.value,
+            level_type: value.level_type.map(|level| level_type::LevelType {
+                min: level.min,
+                nom: level.nom,
+                typ: level.typ,
+                max: level.max,
+            }),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DataSpecificationPhysicalUnit {
+    unit_name: String,
+    unit_symbol: String,
+    definition: LangStringDefinitionTypes,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    si_notation: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    si_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    din_notation: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ece_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ece_code: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nist_name: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_of_definition: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    conversion_factor: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    registration_authority_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    supplier: Option<String>,
+}
+
+impl From<&data_specification::DataSpecificationPhysicalUnit> for DataSpecificationPhysicalUnit {
+    fn from(value: &data_specification::DataSpecificationPhysicalUnit) -> Self {
+        Self {
+            unit_name: value.unit_name.clone(),
+            unit_symbol: value.unit_symbol.clone(),
+            definition: proxies(&value.definition).unwrap_or_default(),
+            si_notation: value.si_notation.clone(),
+            si_name: value.si_name.clone(),
+            din_notation: value.din_notation.clone(),
+            ece_name: value.ece_name.clone(),
+            ece_code: value.ece_code.clone(),
+            nist_name: value.nist_name.clone(),
+            source_of_definition: value.source_of_definition.clone(),
+            conversion_factor: value.conversion_factor.clone(),
+            registration_authority_id: value.registration_authority_id.clone(),
+            supplier: value.supplier.clone(),
+        }
+    }
+}
+
+impl TryFrom<DataSpecificationPhysicalUnit> for data_specification::DataSpecificationPhysicalUnit {
+    type Error = XMLError;
+
+    fn try_from(value: DataSpecificationPhysicalUnit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unit_name: value.unit_name,
+            unit_symbol: value.unit_symbol,
+            definition: convert(Some(value.definition))?.unwrap_or_default(),
+            si_notation: value.si_notation,
+            si_name: value.si_name,
+            din_notation: value.din_notation,
+            ece_name: value.ece_name,
+            ece_code: value.ece_code,
+            nist_name: value.nist_name,
+            source_of_definition: value.source_of_definition,
+            conversion_factor: value.conversion_factor,
+            registration_authority_id: value.registration_authority_id,
+            supplier: value.supplier,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aas::part1::v3_1::key::Key;
+    use aas::part1::v3_1::reference::{Reference as ModelReference, ReferenceInner};
+
+    fn read<P: for<'de> Deserialize<'de>>(xml: &str) -> P {
+        quick_xml::de::from_str(xml).unwrap()
+    }
+
+    fn write(root: &str, proxy: &impl Serialize) -> String {
+        quick_xml::se::to_string_with_root(root, proxy).unwrap()
+    }
+
+    #[test]
+    fn data_values_round_trip() {
+        for (value_type, value) in [
+            ("xs:string", "text"),
+            ("xs:double", "1.2"),
+            ("xs:duration", "P1Y"),
+            ("xs:gYear", "-0044"),
+            ("xs:hexBinary", "0FB7"),
+        ] {
+            let data = data_xsd(value_type, Some(value.into())).unwrap();
+            assert_eq!(
+                data_value(&data).unwrap(),
+                (value_type.to_string(), Some(value.to_string()))
+            );
+        }
+
+        assert_eq!(
+            data_xsd("xs:double", Some("1.2".into())).unwrap(),
+            DataXsd::Double(Some(1.2))
+        );
+        assert!(matches!(
+            data_xsd("xs:int", Some("a".into())),
+            Err(XMLError::InvalidAttribute {
+                attribute: "value",
+                ..
+            })
+        ));
+        assert!(matches!(
+            data_xsd("xs:float32", None),
+            Err(XMLError::InvalidAttribute {
+                attribute: "valueType",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn lang_string() {
+        let xml = "<langStringTextType><language>en</language><text>Sample text</text></langStringTextType>";
+        let lang_string = ModelLangString::try_from(read::<LangString>(xml)).unwrap();
+        assert_eq!(
+            lang_string,
+            ModelLangString::try_new("en", "Sample text".into()).unwrap()
+        );
+        assert_eq!(
+            write("langStringTextType", &LangString::from(&lang_string)),
+            xml
+        );
+
+        let invalid = read::<LangString>(
+            "<langStringTextType><language>not a tag</language><text/></langStringTextType>",
+        );
+        assert!(ModelLangString::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn extension() {
+        let xml = "<extension><name>count</name><valueType>xs:int</valueType><value>123</value></extension>";
+        let extension = extension::Extension::try_from(read::<Extension>(xml)).unwrap();
+        assert_eq!(extension.value, DataXsd::Int(Some(123)));
+        assert_eq!(
+            write("extension", &Extension::try_from(&extension).unwrap()),
+            xml
+        );
+
+        let extension =
+            extension::Extension::try_from(read::<Extension>("<extension><name/></extension>"))
+                .unwrap();
+        assert_eq!(extension.value, DataXsd::String(None));
+    }
+
+    #[test]
+    fn qualifier_kinds() {
+        let qualifier = |xml: &str| qualifiable::Qualifier::try_from(read::<Qualifier>(xml));
+
+        let xml = "<qualifier><semanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://admin-shell.io/SubmodelTemplates/Cardinality/1/0</value></key></keys></semanticId><kind>TemplateQualifier</kind><type>SMT/Cardinality</type><valueType>xs:string</valueType><value>One</value></qualifier>";
+        let template = qualifier(xml).unwrap();
+        let qualifiable::Qualifier::TemplateQualifier(inner) = &template else {
+            panic!("expected a template qualifier");
+        };
+        assert_eq!(inner.ty, "SMT/Cardinality");
+        assert_eq!(inner.value, DataXsd::String(Some("One".into())));
+        assert!(inner.semantics.semantic_id.is_some());
+        assert_eq!(
+            write("qualifier", &Qualifier::try_from(&template).unwrap()),
+            xml
+        );
+
+        assert!(matches!(
+            qualifier(
+                "<qualifier><kind>ConceptQualifier</kind><type>Test</type><valueType>xs:boolean</valueType><value>true</value></qualifier>"
+            ),
+            Ok(qualifiable::Qualifier::ConceptQualifier(_))
+        ));
+        assert!(matches!(
+            qualifier(
+                "<qualifier><type>Test</type><valueType>xs:boolean</valueType><value>true</value></qualifier>"
+            ),
+            Ok(qualifiable::Qualifier::Unknown(_))
+        ));
+        assert!(matches!(
+            qualifier(
+                "<qualifier><kind>Test</kind><type>Test</type><valueType>xs:boolean</valueType><value>true</value></qualifier>"
+            ),
+            Err(XMLError::InvalidAttribute {
+                attribute: "kind",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn template_qualifier() {
+        let xml = r#"
+            <qualifier>
+              <semanticId>
+                <type>ExternalReference</type>
+                <keys>
+                  <key>
+                    <type>GlobalReference</type>
+                    <value>https://admin-shell.io/SubmodelTemplates/Cardinality/1/0</value>
+                  </key>
+                </keys>
+              </semanticId>
+              <kind>TemplateQualifier</kind>
+              <type>SMT/Cardinality</type>
+              <valueType>xs:string</valueType>
+              <value>One</value>
+            </qualifier>"#;
+
+        assert_eq!(
+            qualifiable::Qualifier::try_from(read::<Qualifier>(xml)).unwrap(),
+            cardinality_one()
+        );
+    }
+
+    #[test]
+    fn qualifiers() {
+        let xml = r#"
+            <qualifiers>
+                <qualifier>
+                  <semanticId>
+                    <type>ExternalReference</type>
+                    <keys>
+                      <key>
+                        <type>GlobalReference</type>
+                        <value>https://admin-shell.io/SubmodelTemplates/Cardinality/1/0</value>
+                      </key>
+                    </keys>
+                  </semanticId>
+                  <kind>TemplateQualifier</kind>
+                  <type>SMT/Cardinality</type>
+                  <valueType>xs:string</valueType>
+                  <value>One</value>
+                </qualifier>
+                <qualifier>
+                  <kind>ConceptQualifier</kind>
+                  <type>Test</type>
+                  <valueType>xs:boolean</valueType>
+                  <value>true</value>
+                </qualifier>
+            </qualifiers>"#;
+
+        let qualifiers = convert::<_, qualifiable::Qualifier>(Some(read::<Qualifiers>(xml)));
+
+        assert_eq!(
+            qualifiers.unwrap(),
+            Some(vec![
+                cardinality_one(),
+                qualifiable::Qualifier::ConceptQualifier(QualifierInner {
+                    semantics: Default::default(),
+                    ty: "Test".to_string(),
+                    value: DataXsd::Boolean(Some(true)),
+                    value_id: None,
+                })
+            ])
+        );
+    }
+
+    #[test]
+    fn unknown_qualifier() {
+        let xml = "<qualifier><type>Test</type><valueType>xs:boolean</valueType><value>true</value></qualifier>";
+
+        let qualifier = qualifiable::Qualifier::try_from(read::<Qualifier>(xml)).unwrap();
+
+        assert_eq!(
+            qualifier,
+            qualifiable::Qualifier::Unknown(QualifierInner {
+                semantics: Default::default(),
+                ty: "Test".to_string(),
+                value: DataXsd::Boolean(Some(true)),
+                value_id: None,
+            })
+        );
+        assert_eq!(
+            write("qualifier", &Qualifier::try_from(&qualifier).unwrap()),
+            xml
+        );
+    }
+
+    fn cardinality_one() -> qualifiable::Qualifier {
+        qualifiable::Qualifier::TemplateQualifier(QualifierInner {
+            semantics: semantics::HasSemantics {
+                semantic_id: Some(ModelReference::ExternalReference(ReferenceInner {
+                    referred_semantic_id: None,
+                    keys: vec![Key::GlobalReference(
+                        "https://admin-shell.io/SubmodelTemplates/Cardinality/1/0".into(),
+                    )],
+                })),
+                supplemental_semantic_ids: None,
+            },
+            ty: "SMT/Cardinality".to_string(),
+            value: DataXsd::String(Some("One".into())),
+            value_id: None,
+        })
+    }
+
+    #[test]
+    fn extension_without_value() {
+        let extension = extension::Extension::try_from(read::<Extension>(
+            "<extension><name/><valueType>xs:string</valueType></extension>",
+        ))
+        .unwrap();
+
+        assert_eq!(
+            extension,
+            extension::Extension {
+                name: "".to_string(),
+                semantic_id: None,
+                supplemental_semantic_ids: None,
+                value: DataXsd::String(None),
+                refers_to: None,
+            }
+        );
+    }
+
+    #[test]
+    fn lang_string_without_text() {
+        let xml = "<langStringTextType><language>en</language><text></text></langStringTextType>";
+
+        assert_eq!(
+            ModelLangString::try_from(read::<LangString>(xml)).unwrap(),
+            ModelLangString::try_new("en", "".into()).unwrap()
+        );
+    }
+
+    #[test]
+    fn double() {
+        assert_eq!(
+            data_xsd("xs:double", Some("1.2".into())).unwrap(),
+            DataXsd::Double(Some(1.2))
+        );
+    }
+
+    #[test]
+    fn duration() {
+        let DataXsd::Duration(Some(duration)) =
+            data_xsd("xs:duration", Some("P1Y".into())).unwrap()
+        else {
+            panic!("expected a duration");
+        };
+
+        assert_eq!(duration.years(), Some(1));
+        assert_eq!(duration.months(), None);
+        assert_eq!(duration.seconds(), None);
+    }
+
+    #[test]
+    fn date_time() {
+        let expected = DataXsd::DateTime(Some(iso8601::DateTime {
+            date: iso8601::Date::YMD {
+                year: 2001,
+                month: 10,
+                day: 26,
+            },
+            time: iso8601::Time {
+                hour: 21,
+                minute: 32,
+                second: 52,
+                millisecond: 0,
+                tz_offset_hours: 0,
+                tz_offset_minutes: 0,
+            },
+        }));
+
+        assert_eq!(
+            data_xsd("xs:dateTime", Some("2001-10-26T21:32:52".into())).unwrap(),
+            expected
+        );
+        assert_eq!(
+            data_xsd("xs:dateTime", Some("2001-10-26T21:32:52Z".into())).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn time_with_zone() {
+        assert_eq!(
+            data_xsd("xs:time", Some("21:32:52Z".into())).unwrap(),
+            DataXsd::Time(Some(iso8601::Time {
+                hour: 21,
+                minute: 32,
+                second: 52,
+                millisecond: 0,
+                tz_offset_hours: 0,
+                tz_offset_minutes: 0,
+            }))
+        );
+    }
+
+    #[test]
+    fn date_with_zone() {
+        assert_eq!(
+            data_xsd("xs:date", Some("2001-10-26Z".into())).unwrap(),
+            DataXsd::Date(Some(iso8601::Date::YMD {
+                year: 2001,
+                month: 10,
+                day: 26,
+            }))
+        );
+    }
+
+    #[test]
+    fn unknown_content_survives_round_trip() {
+        let xml = r#"<dataSpecificationContent><dataSpecificationAcme><color>red</color></dataSpecificationAcme></dataSpecificationContent>"#;
+
+        let wrapper: DataSpecificationContentWrapper = quick_xml::de::from_str(xml).unwrap();
+
+        let DataSpecificationContent::Unknown(content) = &wrapper.content else {
+            panic!("expected unknown content");
+        };
+        assert_eq!(content.model_type, "dataSpecificationAcme");
+        assert_eq!(
+            quick_xml::se::to_string_with_root("dataSpecificationContent", &wrapper).unwrap(),
+            xml
+        );
+    }
+}
@@ -1,8 +0,0 @@
-pub mod administrative_information;
-pub mod data_specification;
-pub mod extension;
-pub mod identifiable;
-pub mod kind;
-pub mod qualifiable;
-pub mod referable;
-pub mod semantics;
@@ -0,0 +1,615 @@
+use crate::XMLError;
+use crate::part1::v3_1::reference::{Reference, References};
+use crate::part1::v3_1::submodel_elements::SubmodelElements;
+use crate::part1::v3_1::{
+    convert, identifier, invalid, parse, proxies, proxy, try_proxies, wrapper,
+};
+use aas::part1::v3_1::attributes::kind::ModellingKind;
+use aas::part1::v3_1::concept_description;
+use aas::part1::v3_1::core::{self as model, AssetInformationInner};
+use aas::part1::v3_1::primitives::{Label, Uri};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+proxy! {
+    #[serde(rename = "assetAdministrationShell")]
+    struct AssetAdministrationShell {
+        @identifiable
+        @data_specification
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        derived_from: Option<Reference>,
+
+        asset_information: AssetInformation,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        submodels: Option<References>,
+    }
+}
+
+impl TryFrom<&model::AssetAdministrationShell> for AssetAdministrationShell {
+    type Error = XMLError;
+
+    fn try_from(value: &model::AssetAdministrationShell) -> Result<Self, Self::Error> {
+        let mut proxy = Self {
+            derived_from: value.derived_from.as_ref().map(Into::into),
+            asset_information: (&value.asset_information).into(),
+            submodels: proxies(value.submodels.iter().flatten()),
+            ..Default::default()
+        };
+        proxy.set_identifiable(&value.identifiable)?;
+        proxy.set_data_specification(&value.data_specification);
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<AssetAdministrationShell> for model::AssetAdministrationShell {
+    type Error = XMLError;
+
+    fn try_from(mut value: AssetAdministrationShell) -> Result<Self, Self::Error> {
+        Ok(Self {
+            identifiable: value.take_identifiable()?,
+            data_specification: value.take_data_specification()?,
+            asset_information: value.asset_information.try_into()?,
+            derived_from: value.derived_from.map(TryInto::try_into).transpose()?,
+            submodels: convert(value.submodels)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetInformation {
+    asset_kind: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    global_asset_id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    specific_asset_ids: Option<SpecificAssetIds>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asset_type: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default_thumbnail: Option<Resource>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Resource {
+    path: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+}
+
+impl From<&model::AssetInformation> for AssetInformation {
+    fn from(value: &model::AssetInformation) -> Self {
+        Self {
+            asset_kind: value.to_string(),
+            global_asset_id: value.global_asset_id.as_ref().map(ToString::to_string),
+            specific_asset_ids: proxies(value.specific_asset_ids.iter().flatten()),
+            asset_type: value.asset_type.as_ref().map(ToString::to_string),
+            default_thumbnail: value.default_thumbnail.as_ref().map(|thumbnail| Resource {
+                path: thumbnail.path.to_string(),
+                content_type: thumbnail.content_type.clone(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<AssetInformation> for model::AssetInformation {
+    type Error = XMLError;
+
+    fn try_from(value: AssetInformation) -> Result<Self, Self::Error> {
+        let inner = AssetInformationInner {
+            global_asset_id: value
+                .global_asset_id
+                .map(|id| identifier("globalAssetId", &id))
+                .transpose()?,
+            specific_asset_ids: convert(value.specific_asset_ids)?,
+            asset_type: value
+                .asset_type
+                .map(|id| identifier("assetType", &id))
+                .transpose()?,
+            default_thumbnail: value
+                .default_thumbnail
+                .map(|thumbnail| {
+                    Ok::<_, XMLError>(model::Resource {
+                        path: parse("path", &thumbnail.path, Uri::from_str)?,
+                        content_type: thumbnail.content_type,
+                    })
+                })
+                .transpose()?,
+        };
+        match value.asset_kind.as_str() {
+            "Instance" => Ok(Self::Instance(inner)),
+            "NotApplicable" => Ok(Self::NotApplicable(inner)),
+            "Role" => Ok(Self::Role(inner)),
+            "Type" => Ok(Self::Type(inner)),
+            kind => Err(invalid(
+                "assetKind",
+                kind,
+                "expected Instance, NotApplicable, Role or Type",
+            )),
+        }
+    }
+}
+
+proxy! {
+    struct SpecificAssetId {
+        @semantics
+
+        name: String,
+        value: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        external_subject_id: Option<Reference>,
+    }
+}
+
+wrapper!(SpecificAssetIds {
+    specific_asset_id: SpecificAssetId
+});
+
+impl From<&model::SpecificAssetId> for SpecificAssetId {
+    fn from(value: &model::SpecificAssetId) -> Self {
+        let mut proxy = Self {
+            name: value.name.to_string(),
+            value: value.value.to_string(),
+            external_subject_id: value.external_subject_id.as_ref().map(Into::into),
+            ..Default::default()
+        };
+        proxy.set_semantics(&value.has_semantics);
+        proxy
+    }
+}
+
+impl TryFrom<SpecificAssetId> for model::SpecificAssetId {
+    type Error = XMLError;
+
+    fn try_from(mut value: SpecificAssetId) -> Result<Self, Self::Error> {
+        Ok(Self {
+            has_semantics: value.take_semantics()?,
+            name: parse("name", &value.name, Label::try_from)?,
+            value: identifier("value", &value.value)?,
+            external_subject_id: value
+                .external_subject_id
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+proxy! {
+    #[serde(rename = "submodel")]
+    struct Submodel {
+        @identifiable
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        kind: Option<String>,
+
+        @semantics
+        @qualifiable
+        @data_specification
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        submodel_elements: Option<SubmodelElements>,
+    }
+}
+
+impl TryFrom<&model::Submodel> for Submodel {
+    type Error = XMLError;
+
+    fn try_from(value: &model::Submodel) -> Result<Self, Self::Error> {
+        let mut proxy = Self {
+            kind: value.kind.as_ref().map(ToString::to_string),
+            submodel_elements: try_proxies(value.submodel_elements.iter().flatten())?,
+            ..Default::default()
+        };
+        proxy.set_identifiable(&value.identifiable)?;
+        proxy.set_semantics(&value.semantics);
+        proxy.set_qualifiable(&value.qualifier)?;
+        proxy.set_data_specification(&value.data_specification);
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<Submodel> for model::Submodel {
+    type Error = XMLError;
+
+    fn try_from(mut value: Submodel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            identifiable: value.take_identifiable()?,
+            kind: value
+                .kind
+                .take()
+                .map(|kind| parse("kind", &kind, ModellingKind::from_str))
+                .transpose()?,
+            semantics: value.take_semantics()?,
+            qualifier: value.take_qualifiable()?,
+            data_specification: value.take_data_specification()?,
+            submodel_elements: convert(value.submodel_elements)?,
+        })
+    }
+}
+
+proxy! {
+    #[serde(rename = "conceptDescription")]
+    struct ConceptDescription {
+        @identifiable
+        @data_specification
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        is_case_of: Option<References>,
+    }
+}
+
+impl TryFrom<&concept_description::ConceptDescription> for ConceptDescription {
+    type Error = XMLError;
+
+    fn try_from(value: &concept_description::ConceptDescription) -> Result<Self, Self::Error> {
+        let mut proxy = Self {
+            is_case_of: proxies(value.is_case_of.iter().flatten()),
+            ..Default::default()
+        };
+        proxy.set_identifiable(&value.identifiable)?;
+        if let Some(data_specification) = &value.data_specification {
+            proxy.set_data_specification(data_specification);
+        }
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<ConceptDescription> for concept_description::ConceptDescription {
+    type Error = XMLError;
+
+    fn try_from(mut value: ConceptDescription) -> Result<Self, Self::Error> {
+        let data_specification = value.take_data_specification()?;
+        Ok(Self {
+            identifiable: value.take_identifiable()?,
+            data_specification: data_specification
+                .embedded_data_specifications
+                .is_some()
+                .then_some(data_specification),
+            is_case_of: convert(value.is_case_of)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{XMLError, Xml};
+    use aas::part1::v3_1::LangString;
+    use aas::part1::v3_1::attributes::administrative_information::{
+        AdministrativeInformation, Version,
+    };
+    use aas::part1::v3_1::attributes::data_specification::DataSpecificationContent;
+    use aas::part1::v3_1::attributes::identifiable::Identifiable;
+    use aas::part1::v3_1::attributes::kind::ModellingKind;
+    use aas::part1::v3_1::attributes::referable::Referable;
+    use aas::part1::v3_1::attributes::semantics::HasSemantics;
+    use aas::part1::v3_1::concept_description::ConceptDescription;
+    use aas::part1::v3_1::core::{
+        AssetAdministrationShell, AssetInformation, AssetInformationInner, Resource, Submodel,
+    };
+    use aas::part1::v3_1::key::Key;
+    use aas::part1::v3_1::primitives::{Identifier, Uri};
+    use aas::part1::v3_1::reference::{Reference, ReferenceInner};
+    use aas::part1::v3_1::submodel_elements::{Blob, SubmodelElement};
+
+    fn tortoise_shell() -> AssetAdministrationShell {
+        let submodel = |id: &str| {
+            Reference::ModelReference(ReferenceInner {
+                referred_semantic_id: None,
+                keys: vec![Key::Submodel(id.into())],
+            })
+        };
+
+        AssetAdministrationShell {
+            asset_information: AssetInformation::NotApplicable(AssetInformationInner {
+                global_asset_id: None,
+                specific_asset_ids: None,
+                asset_type: None,
+                default_thumbnail: Some(Resource {
+                    path: Uri::new(b"/aasx/files/turtle_dpp_thumbnail.jpg".to_vec()).unwrap(),
+                    content_type: Some("image/jpeg".into()),
+                }),
+            }),
+            identifiable: Identifiable {
+                id: Identifier::try_from("https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560")
+                    .unwrap(),
+                administration: Some(AdministrativeInformation {
+                    version: Version {
+                        version: Some("1.0".into()),
+                        revision: Some("0".into()),
+                    },
+                    creator: None,
+                    template_id: None,
+                    data_specification: Default::default(),
+                }),
+                referable: Referable {
+                    id_short: Some(
+                        Identifier::try_from("AAS_Tortoise_DigitalProductPassport").unwrap(),
+                    ),
+                    ..Default::default()
+                },
+            },
+            data_specification: Default::default(),
+            derived_from: None,
+            submodels: Some(vec![
+                submodel("https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0"),
+                submodel("https://admin-shell.io/idta/SubmodelTemplate/CarbonFootprint/1/0"),
+                submodel("https://admin-shell.io/idta/SubmodelTemplate/HandoverDocumentation/1/0"),
+            ]),
+        }
+    }
+
+    fn nameplate() -> Submodel {
+        Submodel {
+            identifiable: Identifiable {
+                id: Identifier::try_from(
+                    "https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0",
+                )
+                .unwrap(),
+                administration: Some(AdministrativeInformation {
+                    version: Version {
+                        version: Some("3".into()),
+                        revision: Some("0".into()),
+                    },
+                    creator: None,
+                    template_id: Some(
+                        Identifier::try_from("https://admin-shell.io/IDTA 02006-3-0").unwrap(),
+                    ),
+                    data_specification: Default::default(),
+                }),
+                referable: Referable {
+                    id_short: Some(Identifier::try_from("Nameplate").unwrap()),
+                    description: Some(vec![
+                        LangString::try_new(
+                            "en",
+                            "Contains the nameplate information attached to the product".into(),
+                        )
+                        .unwrap(),
+                    ]),
+                    ..Default::default()
+                },
+            },
+            kind: Some(ModellingKind::Instance),
+            semantics: HasSemantics {
+                semantic_id: Some(Reference::ExternalReference(ReferenceInner {
+                    referred_semantic_id: None,
+                    keys: vec![Key::GlobalReference(
+                        "https://admin-shell.io/idta/nameplate/3/0/Nameplate".into(),
+                    )],
+                })),
+                supplemental_semantic_ids: None,
+            },
+            qualifier: Default::default(),
+            data_specification: Default::default(),
+            submodel_elements: None,
+        }
+    }
+
+    #[test]
+    fn asset_administration_shell() {
+        let xml = "<assetAdministrationShell><idShort>AAS_Tortoise_DigitalProductPassport</idShort><administration><version>1</version><revision>0</revision></administration><id>https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560</id><assetInformation><assetKind>NotApplicable</assetKind><defaultThumbnail><path>/aasx/files/turtle_dpp_thumbnail.jpg</path><contentType>image/jpeg</contentType></defaultThumbnail></assetInformation><submodels><reference><type>ModelReference</type><keys><key><type>Submodel</type><value>https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0</value></key></keys></reference><reference><type>ModelReference</type><keys><key><type>Submodel</type><value>https://admin-shell.io/idta/SubmodelTemplate/CarbonFootprint/1/0</value></key></keys></reference></submodels></assetAdministrationShell>";
+
+        let shell = AssetAdministrationShell::from_xml(xml).unwrap();
+        let AssetInformation::NotApplicable(asset_information) = &shell.asset_information else {
+            panic!("expected a NotApplicable asset kind");
+        };
+        let thumbnail = asset_information.default_thumbnail.as_ref().unwrap();
+        assert_eq!(thumbnail.content_type.as_deref(), Some("image/jpeg"));
+        assert_eq!(shell.submodels.as_ref().map(Vec::len), Some(2));
+        assert_eq!(shell.to_xml().unwrap(), xml);
+    }
+
+    #[test]
+    fn asset_administration_shell_complex() {
+        let xml = r#"
+            <assetAdministrationShell>
+              <idShort>AAS_Tortoise_DigitalProductPassport</idShort>
+              <id>https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560</id>
+              <assetInformation>
+                <assetKind>NotApplicable</assetKind>
+                <defaultThumbnail>
+                    <path>/aasx/files/turtle_dpp_thumbnail.jpg</path>
+                    <contentType>image/jpeg</contentType>
+                </defaultThumbnail>
+              </assetInformation>
+              <administration>
+                  <version>1.0</version>
+                  <revision>0</revision>
+              </administration>
+              <submodels>
+                <reference>
+                  <type>ModelReference</type>
+                  <keys>
+                    <key>
+                      <type>Submodel</type>
+                      <value>https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0</value>
+                    </key>
+                  </keys>
+                </reference>
+                <reference>
+                  <type>ModelReference</type>
+                  <keys>
+                    <key>
+                      <type>Submodel</type>
+                      <value>https://admin-shell.io/idta/SubmodelTemplate/CarbonFootprint/1/0</value>
+                    </key>
+                  </keys>
+                </reference>
+                <reference>
+                  <type>ModelReference</type>
+                  <keys>
+                    <key>
+                      <type>Submodel</type>
+                      <value>https://admin-shell.io/idta/SubmodelTemplate/HandoverDocumentation/1/0</value>
+                    </key>
+                  </keys>
+                </reference>
+              </submodels>
+            </assetAdministrationShell>
+            "#;
+
+        assert_eq!(
+            AssetAdministrationShell::from_xml(xml).unwrap(),
+            tortoise_shell()
+        );
+    }
+
+    #[test]
+    fn serialize_asset_administration_shell() {
+        let expected = "<assetAdministrationShell><idShort>AAS_Tortoise_DigitalProductPassport</idShort><administration><version>1.0</version><revision>0</revision></administration><id>https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560</id><assetInformation><assetKind>NotApplicable</assetKind><defaultThumbnail><path>/aasx/files/turtle_dpp_thumbnail.jpg</path><contentType>image/jpeg</contentType></defaultThumbnail></assetInformation><submodels><reference><type>ModelReference</type><keys><key><type>Submodel</type><value>https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0</value></key></keys></reference><reference><type>ModelReference</type><keys><key><type>Submodel</type><value>https://admin-shell.io/idta/SubmodelTemplate/CarbonFootprint/1/0</value></key></keys></reference><reference><type>ModelReference</type><keys><key><type>Submodel</type><value>https://admin-shell.io/idta/SubmodelTemplate/HandoverDocumentation/1/0</value></key></keys></reference></submodels></assetAdministrationShell>";
+
+        assert_eq!(tortoise_shell().to_xml().unwrap(), expected);
+    }
+
+    #[test]
+    fn asset_information_without_thumbnail() {
+        let shell = AssetAdministrationShell::from_xml(
+            "<assetAdministrationShell><id>urn:example:aas</id><assetInformation><assetKind>Type</assetKind><globalAssetId>urn:example:asset</globalAssetId></assetInformation></assetAdministrationShell>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            shell.asset_information,
+            AssetInformation::Type(AssetInformationInner {
+                global_asset_id: Some(Identifier::try_from("urn:example:asset").unwrap()),
+                specific_asset_ids: None,
+                asset_type: None,
+                default_thumbnail: None,
+            })
+        );
+    }
+
+    #[test]
+    fn elements_in_any_order() {
+        let shell = AssetAdministrationShell::from_xml(
+            r#"
+            <assetAdministrationShell>
+                <assetInformation><assetKind>Instance</assetKind></assetInformation>
+                <id>https://smartfactory-owl.de/ids/aas/2001_1172_9042_4560</id>
+            </assetAdministrationShell>
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            shell.asset_information,
+            AssetInformation::Instance(_)
+        ));
+    }
+
+    #[test]
+    fn submodel() {
+        let xml = "<submodel><idShort>Nameplate</idShort><description><langStringTextType><language>en</language><text>Contains the nameplate information attached to the product</text></langStringTextType></description><administration><version>3</version><revision>0</revision><templateId>https://admin-shell.io/IDTA 02006-3-0</templateId></administration><id>https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0</id><kind>Instance</kind><semanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://admin-shell.io/idta/nameplate/3/0/Nameplate</value></key></keys></semanticId><submodelElements><blob><value>iVBORw0KGgo=</value><contentType>image/png</contentType></blob></submodelElements></submodel>";
+
+        let submodel = Submodel::from_xml(xml).unwrap();
+        let administration = submodel.identifiable.administration.as_ref().unwrap();
+        assert_eq!(
+            administration.template_id.as_ref().unwrap().as_ref(),
+            "https://admin-shell.io/IDTA 02006-3-0"
+        );
+        assert_eq!(
+            submodel.submodel_elements,
+            Some(vec![SubmodelElement::Blob(Blob::new(
+                Some(b"\x89PNG\r\n\x1a\n".to_vec()),
+                "image/png".into(),
+            ))])
+        );
+        assert_eq!(submodel.to_xml().unwrap(), xml);
+    }
+
+    #[test]
+    fn deserialize_nameplate() {
+        let xml = r#"
+             <submodel>
+                  <idShort>Nameplate</idShort>
+                  <description>
+                    <langStringTextType>
+                      <language>en</language>
+                      <text>Contains the nameplate information attached to the product</text>
+                    </langStringTextType>
+                  </description>
+                  <administration>
+                    <version>3</version>
+                    <revision>0</revision>
+                    <templateId>https://admin-shell.io/IDTA 02006-3-0</templateId>
+                  </administration>
+                  <id>https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0</id>
+                  <kind>Instance</kind>
+                  <semanticId>
+                    <type>ExternalReference</type>
+                    <keys>
+                      <key>
+                        <type>GlobalReference</type>
+                        <value>https://admin-shell.io/idta/nameplate/3/0/Nameplate</value>
+                      </key>
+                    </keys>
+                  </semanticId>
+                </submodel>
+            "#;
+
+        assert_eq!(Submodel::from_xml(xml).unwrap(), nameplate());
+    }
+
+    #[test]
+    fn serialize_nameplate() {
+        let expected = "<submodel><idShort>Nameplate</idShort><description><langStringTextType><language>en</language><text>Contains the nameplate information attached to the product</text></langStringTextType></description><administration><version>3</version><revision>0</revision><templateId>https://admin-shell.io/IDTA 02006-3-0</templateId></administration><id>https://admin-shell.io/idta/SubmodelTemplate/DigitalNameplate/3/0</id><kind>Instance</kind><semanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://admin-shell.io/idta/nameplate/3/0/Nameplate</value></key></keys></semanticId></submodel>";
+
+        assert_eq!(nameplate().to_xml().unwrap(), expected);
+    }
+
+    #[test]
+    fn invalid_identifiers() {
+        let invalid_id =
+            |id: &str| match Submodel::from_xml(&format!("<submodel><id>{id}</id></submodel>")) {
+                Err(XMLError::InvalidAttribute { attribute, .. }) => attribute,
+                other => panic!("expected an invalid id, got {other:?}"),
+            };
+
+        assert_eq!(invalid_id(&"a".repeat(2049)), "id");
+        assert_eq!(invalid_id("\u{1}"), "id");
+        assert!(
+            Submodel::from_xml(&format!(
+                "<submodel><id>{}</id></submodel>",
+                "a".repeat(2048)
+            ))
+            .is_ok()
+        );
+        assert!(matches!(
+            Submodel::from_xml("<submodel><id>urn:x</id><kind>Type</kind></submodel>"),
+            Err(XMLError::InvalidAttribute {
+                attribute: "kind",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn concept_description_data_specifications() {
+        let xml = "<conceptDescription><id>https://example.com/cd/1</id><embeddedDataSpecifications><embeddedDataSpecification><dataSpecification><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://admin-shell.io/DataSpecificationTemplates/DataSpecificationPhysicalUnit/3/0</value></key></keys></dataSpecification><dataSpecificationContent><dataSpecificationPhysicalUnit><unitName>metre</unitName><unitSymbol>m</unitSymbol><definition><langStringDefinitionTypeIec61360><language>en</language><text>length</text></langStringDefinitionTypeIec61360></definition><siNotation>m</siNotation></dataSpecificationPhysicalUnit></dataSpecificationContent></embeddedDataSpecification><embeddedDataSpecification><dataSpecification><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://example.com/acme</value></key></keys></dataSpecification><dataSpecificationContent><dataSpecificationAcme><color>red</color></dataSpecificationAcme></dataSpecificationContent></embeddedDataSpecification></embeddedDataSpecifications></conceptDescription>";
+
+        let concept_description = ConceptDescription::from_xml(xml).unwrap();
+        let contents: Vec<_> = concept_description
+            .data_specification
+            .iter()
+            .flat_map(|d| d.embedded_data_specifications.iter().flatten())
+            .map(|s| &s.data_specification_content)
+            .collect();
+        let [
+            DataSpecificationContent::DataSpecificationPhysicalUnit(unit),
+            DataSpecificationContent::Unknown(unknown),
+        ] = contents.as_slice()
+        else {
+            panic!("expected a physical unit and unknown content");
+        };
+        assert_eq!(unit.unit_symbol, "m");
+        assert_eq!(unknown.model_type, "dataSpecificationAcme");
+        assert_eq!(concept_description.to_xml().unwrap(), xml);
+    }
+}
@@ -1,5 +0,0 @@
-mod asset_administration_shell;
-mod submodel;
-
-pub use asset_administration_shell::*;
-pub use submodel::*;
@@ -1,115 +1,138 @@
-use crate::part1::v3_1::concept_description::ConceptDescription;
-use crate::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use crate::XMLError;
+use crate::part1::v3_1::core::{AssetAdministrationShell, ConceptDescription, Submodel};
+use crate::part1::v3_1::{convert, try_proxies, wrapper};
+use aas::part1::v3_1::environment as model;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
-#[cfg_attr(
-    feature = "xml",
-    serde(from = "xml::EnvironmentXMLProxy", into = "xml::EnvironmentXMLProxy")
-)]
-pub struct Environment {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "assetAdministrationShells")]
-    pub asset_administration_shells: Option<Vec<AssetAdministrationShell>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub submodels: Option<Vec<Submodel>>,
-
-    #[serde(rename = "conceptDescriptions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub concept_descriptions: Option<Vec<ConceptDescription>>,
-}
+/// Namespace of the XML schema of version 3.1
+const NAMESPACE: &str = "https://admin-shell.io/aas/3/1";
 
-#[cfg(feature = "xml")]
-mod xml {
-    use crate::part1::v3_1::concept_description::ConceptDescription;
-    use crate::part1::v3_1::core::{AssetAdministrationShell, Submodel};
-    use crate::part1::v3_1::environment::Environment;
-    use serde::{Deserialize, Serialize};
-
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct AssetAdministrationShellWrapper {
-        #[serde(rename = "assetAdministrationShell")]
-        values: Option<Vec<AssetAdministrationShell>>,
-    }
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct SubmodelWrapper {
-        #[serde(rename = "submodel")]
-        values: Option<Vec<Submodel>>,
-    }
-
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct ConceptDescriptionWrapper {
-        #[serde(rename = "conceptDescription")]
-        values: Option<Vec<ConceptDescription>>,
-    }
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "environment", rename_all = "camelCase")]
+pub(crate) struct Environment {
+    #[serde(rename = "@xmlns", default)]
+    xmlns: String,
 
-    #[derive(Debug, Serialize, Deserialize)]
-    pub struct EnvironmentXMLProxy {
-        #[serde(skip_serializing_if = "Option::is_none")]
-        #[serde(rename = "assetAdministrationShells")]
-        pub asset_administration_shells: Option<AssetAdministrationShellWrapper>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asset_administration_shells: Option<AssetAdministrationShells>,
 
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub submodels: Option<SubmodelWrapper>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    submodels: Option<Submodels>,
 
-        #[serde(rename = "conceptDescriptions")]
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub concept_descriptions: Option<ConceptDescriptionWrapper>,
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    concept_descriptions: Option<ConceptDescriptions>,
+}
 
-    impl From<Environment> for EnvironmentXMLProxy {
-        fn from(value: Environment) -> Self {
-            Self {
-                asset_administration_shells: Some(AssetAdministrationShellWrapper {
-                    values: value.asset_administration_shells,
-                }),
-                submodels: Some(SubmodelWrapper {
-                    values: value.submodels,
-                }),
-                concept_descriptions: Some(ConceptDescriptionWrapper {
-                    values: value.concept_descriptions,
-                }),
-            }
-        }
+wrapper!(
+    AssetAdministrationShells {
+        asset_administration_shell: AssetAdministrationShell
+    },
+    Submodels { submodel: Submodel },
+    ConceptDescriptions {
+        concept_description: ConceptDescription
+    },
+);
+
+impl TryFrom<&model::Environment> for Environment {
+    type Error = XMLError;
+
+    fn try_from(value: &model::Environment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            xmlns: NAMESPACE.into(),
+            asset_administration_shells: try_proxies(
+                value.asset_administration_shells.iter().flatten(),
+            )?,
+            submodels: try_proxies(value.submodels.iter().flatten())?,
+            concept_descriptions: try_proxies(value.concept_descriptions.iter().flatten())?,
+        })
     }
-    impl From<EnvironmentXMLProxy> for Environment {
-        fn from(value: EnvironmentXMLProxy) -> Self {
-            Self {
-                asset_administration_shells: value
-                    .asset_administration_shells
-                    .and_then(|v| v.values),
-                submodels: value.submodels.and_then(|v| v.values),
-                concept_descriptions: value.concept_descriptions.and_then(|v| v.values),
-            }
-        }
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use crate::part1::v3_1::environment::Environment;
+}
 
-        #[test]
-        fn deserialize_xml_mvp_dpp() {
-            let xml = include_str!("../../../tests/mvp-dpp-1.0.0.xml");
+impl TryFrom<Environment> for model::Environment {
+    type Error = XMLError;
 
-            let env: Environment = quick_xml::de::from_str(xml).expect("Deserialize works");
-            println!("{:#?}", env);
-        }
+    fn try_from(value: Environment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            asset_administration_shells: convert(value.asset_administration_shells)?,
+            submodels: convert(value.submodels)?,
+            concept_descriptions: convert(value.concept_descriptions)?,
+        })
     }
 }
 
-#[cfg(not(feature = "xml"))]
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::Xml;
+    use aas::part1::v3_1::environment::Environment;
 
     #[test]
-    fn deserialize() {
-        let json = include_str!("../../../tests/env.json");
+    fn mvp_dpp() {
+        let xml = include_str!("../../../../aas/tests/mvp-dpp-1.0.0.xml");
+
+        let environment = Environment::from_xml(xml).expect("Deserialize works");
+
+        let shells = environment.asset_administration_shells.as_ref().unwrap();
+        assert_eq!(shells.len(), 1);
+        assert_eq!(
+            shells[0]
+                .identifiable
+                .referable
+                .id_short
+                .as_ref()
+                .unwrap()
+                .as_ref(),
+            "AAS_Tortoise_DigitalProductPassport"
+        );
+        assert_eq!(shells[0].submodels.as_ref().map(Vec::len), Some(3));
+
+        let submodels: Vec<_> = environment
+            .submodels
+            .iter()
+            .flatten()
+            .map(|submodel| {
+                submodel
+                    .identifiable
+                    .referable
+                    .id_short
+                    .as_ref()
+                    .unwrap()
+                    .as_ref()
+            })
+            .collect();
+        assert_eq!(
+            submodels,
+            ["Nameplate", "CarbonFootprint", "HandoverDocumentation"]
+        );
+        assert_eq!(
+            environment.concept_descriptions.map(|cds| cds.len()),
+            Some(51)
+        );
+    }
 
-        let env: Environment = serde_json::from_str(json).expect("Deserialize works");
+    #[test]
+    fn written_with_namespace() {
+        let environment = Environment::from_xml(
+            "<environment><conceptDescriptions><conceptDescription><id>urn:example:cd</id></conceptDescription></conceptDescriptions></environment>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            environment.to_xml().unwrap(),
+            r#"<environment xmlns="https://admin-shell.io/aas/3/1"><conceptDescriptions><conceptDescription><id>urn:example:cd</id></conceptDescription></conceptDescriptions></environment>"#
+        );
+    }
 
-        println!("{:#?}", env);
+    #[test]
+    fn byte_order_mark() {
+        let xml = "\u{feff}<environment xmlns=\"https://admin-shell.io/aas/3/1\"/>";
+
+        assert_eq!(
+            Environment::from_xml(xml).unwrap(),
+            Environment {
+                asset_administration_shells: None,
+                submodels: None,
+                concept_descriptions: None,
+            }
+        );
     }
 }
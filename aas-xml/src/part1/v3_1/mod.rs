@@ -1,11 +1,340 @@
-pub mod environment;
-pub use primitives::lang_string::*;
-pub mod attributes;
-pub mod concept_description;
-pub mod core;
-pub mod key;
-pub mod level_type;
-pub mod primitives;
-pub mod reference;
-pub mod submodel_elements;
-pub mod value_list;
+//! Proxy types in the shape of the XML schema.
+//!
+//! Every proxy converts `TryFrom` into its core type for reading, where the
+//! [XMLError::InvalidAttribute] names the offending XML element. For writing, proxies convert
+//! `From` a reference to their core type, or `TryFrom` it if they contain a value that has to lie
+//! in the value space of its valueType, e.g. properties and everything with extensions.
+//! quick-xml does not support `#[serde(flatten)]` well, so the inherited attributes are spelled
+//! out by the [proxy] macro in the order of the schema.
+
+pub(crate) mod attributes;
+pub(crate) mod core;
+pub(crate) mod environment;
+pub(crate) mod reference;
+pub(crate) mod submodel_elements;
+
+use crate::XMLError;
+use aas::part1::v3_1::primitives::Identifier;
+use std::fmt::Display;
+
+pub(crate) fn invalid(
+    attribute: &'static str,
+    value: impl Display,
+    reason: impl Display,
+) -> XMLError {
+    XMLError::InvalidAttribute {
+        attribute,
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Parses an XML text into a core type
+pub(crate) fn parse<'a, T, E: Display>(
+    attribute: &'static str,
+    value: &'a str,
+    parse: impl FnOnce(&'a str) -> Result<T, E>,
+) -> Result<T, XMLError> {
+    parse(value).map_err(|e| invalid(attribute, value, e))
+}
+
+pub(crate) fn identifier(attribute: &'static str, value: &str) -> Result<Identifier, XMLError> {
+    parse(attribute, value, Identifier::try_from)
+}
+
+/// A list of the schema, which wraps its items in one more element, e.g.
+/// `<keys><key>...</key></keys>`.
+pub(crate) trait Wrapper: Sized {
+    type Item;
+
+    fn new(items: Vec<Self::Item>) -> Self;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Defines a [Wrapper] with the given item element
+macro_rules! wrapper {
+    ($($name:ident { $item:ident: $ty:ty }),* $(,)?) => {$(
+        #[derive(serde::Serialize, serde::Deserialize, Default)]
+        #[serde(rename_all = "camelCase")]
+        pub(crate) struct $name {
+            #[serde(default)]
+            $item: Vec<$ty>,
+        }
+
+        impl $crate::part1::v3_1::Wrapper for $name {
+            type Item = $ty;
+
+            fn new(items: Vec<$ty>) -> Self {
+                Self { $item: items }
+            }
+
+            fn into_items(self) -> Vec<$ty> {
+                self.$item
+            }
+        }
+    )*};
+}
+pub(crate) use wrapper;
+
+/// Proxies of a list. Empty lists are left out, the schema requires at least one item.
+pub(crate) fn proxies<'a, C: 'a, W: Wrapper>(items: impl IntoIterator<Item = &'a C>) -> Option<W>
+where
+    W::Item: From<&'a C>,
+{
+    let items: Vec<_> = items.into_iter().map(W::Item::from).collect();
+    (!items.is_empty()).then_some(W::new(items))
+}
+
+/// Proxies of a list whose items may fail to be written
+pub(crate) fn try_proxies<'a, C: 'a, W: Wrapper>(
+    items: impl IntoIterator<Item = &'a C>,
+) -> Result<Option<W>, XMLError>
+where
+    W::Item: TryFrom<&'a C, Error = XMLError>,
+{
+    let items = items
+        .into_iter()
+        .map(W::Item::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((!items.is_empty()).then_some(W::new(items)))
+}
+
+/// Core types of an optional list of proxies
+pub(crate) fn convert<W: Wrapper, T: TryFrom<W::Item, Error = XMLError>>(
+    items: Option<W>,
+) -> Result<Option<Vec<T>>, XMLError> {
+    items
+        .map(|items| items.into_items().into_iter().map(T::try_from).collect())
+        .transpose()
+}
+
+/// Defines a proxy struct, where `@referable`, `@identifiable`, `@semantics`, `@qualifiable`,
+/// `@data_specification` and `@element` expand to the inherited fields. Each of them also gets a
+/// `set_*` method to fill the fields from the core type and a `take_*` method to convert them
+/// back. Setting the fields that contain extensions or qualifiers can fail.
+macro_rules! proxy {
+    ($(#[$meta:meta])* struct $name:ident { $($body:tt)* }) => {
+        $crate::part1::v3_1::proxy!(@munch [$(#[$meta])*] $name [] $($body)*);
+    };
+
+    (@munch [$($meta:tt)*] $name:ident [$($done:tt)*]) => {
+        #[derive(serde::Serialize, serde::Deserialize, Default)]
+        #[serde(rename_all = "camelCase")]
+        $($meta)*
+        pub(crate) struct $name {
+            $($done)*
+        }
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] @referable $($rest:tt)*) => {
+        #[allow(deprecated)]
+        impl $name {
+            fn set_referable(
+                &mut self,
+                value: &aas::part1::v3_1::attributes::referable::Referable,
+            ) -> Result<(), $crate::XMLError> {
+                self.extensions =
+                    $crate::part1::v3_1::try_proxies(value.extensions.extension.iter().flatten())?;
+                self.category = value.category.clone();
+                self.id_short = value.id_short.as_ref().map(ToString::to_string);
+                self.display_name = $crate::part1::v3_1::proxies(value.display_name.iter().flatten());
+                self.description = $crate::part1::v3_1::proxies(value.description.iter().flatten());
+                Ok(())
+            }
+
+            fn take_referable(
+                &mut self,
+            ) -> Result<aas::part1::v3_1::attributes::referable::Referable, $crate::XMLError> {
+                Ok(aas::part1::v3_1::attributes::referable::Referable {
+                    // an empty idShort is treated as absent
+                    id_short: self
+                        .id_short
+                        .take()
+                        .filter(|id_short| !id_short.is_empty())
+                        .map(|id_short| $crate::part1::v3_1::identifier("idShort", &id_short))
+                        .transpose()?,
+                    display_name: $crate::part1::v3_1::convert(self.display_name.take())?,
+                    description: $crate::part1::v3_1::convert(self.description.take())?,
+                    category: self.category.take(),
+                    extensions: aas::part1::v3_1::attributes::extension::HasExtensions {
+                        extension: $crate::part1::v3_1::convert(self.extensions.take())?,
+                    },
+                })
+            }
+        }
+
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)*
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            extensions: Option<$crate::part1::v3_1::attributes::Extensions>,
+
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            category: Option<String>,
+
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            id_short: Option<String>,
+
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            display_name: Option<$crate::part1::v3_1::attributes::LangStringNameTypes>,
+
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            description: Option<$crate::part1::v3_1::attributes::LangStringTextTypes>,
+        ] $($rest)*);
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] @identifiable $($rest:tt)*) => {
+        impl $name {
+            fn set_identifiable(
+                &mut self,
+                value: &aas::part1::v3_1::attributes::identifiable::Identifiable,
+            ) -> Result<(), $crate::XMLError> {
+                self.set_referable(&value.referable)?;
+                self.administration = value.administration.as_ref().map(Into::into);
+                self.id = value.id.to_string();
+                Ok(())
+            }
+
+            fn take_identifiable(
+                &mut self,
+            ) -> Result<aas::part1::v3_1::attributes::identifiable::Identifiable, $crate::XMLError>
+            {
+                Ok(aas::part1::v3_1::attributes::identifiable::Identifiable {
+                    id: $crate::part1::v3_1::identifier("id", &self.id)?,
+                    administration: self.administration.take().map(TryInto::try_into).transpose()?,
+                    referable: self.take_referable()?,
+                })
+            }
+        }
+
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)*] @referable
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            administration: Option<$crate::part1::v3_1::attributes::AdministrativeInformation>,
+
+            id: String,
+
+            $($rest)*);
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] @semantics $($rest:tt)*) => {
+        impl $name {
+            fn set_semantics(&mut self, value: &aas::part1::v3_1::attributes::semantics::HasSemantics) {
+                self.semantic_id = value.semantic_id.as_ref().map(Into::into);
+                self.supplemental_semantic_ids =
+                    $crate::part1::v3_1::proxies(value.supplemental_semantic_ids.iter().flatten());
+            }
+
+            fn take_semantics(
+                &mut self,
+            ) -> Result<aas::part1::v3_1::attributes::semantics::HasSemantics, $crate::XMLError> {
+                Ok(aas::part1::v3_1::attributes::semantics::HasSemantics {
+                    semantic_id: self.semantic_id.take().map(TryInto::try_into).transpose()?,
+                    supplemental_semantic_ids: $crate::part1::v3_1::convert(
+                        self.supplemental_semantic_ids.take(),
+                    )?,
+                })
+            }
+        }
+
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)*
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            semantic_id: Option<$crate::part1::v3_1::reference::Reference>,
+
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            supplemental_semantic_ids: Option<$crate::part1::v3_1::reference::References>,
+        ] $($rest)*);
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] @qualifiable $($rest:tt)*) => {
+        impl $name {
+            fn set_qualifiable(
+                &mut self,
+                value: &aas::part1::v3_1::attributes::qualifiable::Qualifiable,
+            ) -> Result<(), $crate::XMLError> {
+                self.qualifiers = $crate::part1::v3_1::try_proxies(value.qualifiers.iter().flatten())?;
+                Ok(())
+            }
+
+            fn take_qualifiable(
+                &mut self,
+            ) -> Result<aas::part1::v3_1::attributes::qualifiable::Qualifiable, $crate::XMLError> {
+                Ok(aas::part1::v3_1::attributes::qualifiable::Qualifiable {
+                    qualifiers: $crate::part1::v3_1::convert(self.qualifiers.take())?,
+                })
+            }
+        }
+
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)*
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            qualifiers: Option<$crate::part1::v3_1::attributes::Qualifiers>,
+        ] $($rest)*);
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] @data_specification $($rest:tt)*) => {
+        impl $name {
+            fn set_data_specification(
+                &mut self,
+                value: &aas::part1::v3_1::attributes::data_specification::HasDataSpecification,
+            ) {
+                self.embedded_data_specifications =
+                    $crate::part1::v3_1::attributes::embedded_data_specifications(value);
+            }
+
+            fn take_data_specification(
+                &mut self,
+            ) -> Result<
+                aas::part1::v3_1::attributes::data_specification::HasDataSpecification,
+                $crate::XMLError,
+            > {
+                Ok(aas::part1::v3_1::attributes::data_specification::HasDataSpecification {
+                    embedded_data_specifications: $crate::part1::v3_1::convert(
+                        self.embedded_data_specifications.take(),
+                    )?,
+                })
+            }
+        }
+
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)*
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            embedded_data_specifications:
+                Option<$crate::part1::v3_1::attributes::EmbeddedDataSpecifications>,
+        ] $($rest)*);
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] @element $($rest:tt)*) => {
+        impl $name {
+            fn set_element(
+                &mut self,
+                referable: &aas::part1::v3_1::attributes::referable::Referable,
+                semantics: &aas::part1::v3_1::attributes::semantics::HasSemantics,
+                qualifiable: &aas::part1::v3_1::attributes::qualifiable::Qualifiable,
+                data_specification: &aas::part1::v3_1::attributes::data_specification::HasDataSpecification,
+            ) -> Result<(), $crate::XMLError> {
+                self.set_referable(referable)?;
+                self.set_semantics(semantics);
+                self.set_qualifiable(qualifiable)?;
+                self.set_data_specification(data_specification);
+                Ok(())
+            }
+
+            fn take_element(
+                &mut self,
+            ) -> Result<aas::part1::v3_1::submodel_elements::SubmodelElementFields, $crate::XMLError>
+            {
+                Ok(aas::part1::v3_1::submodel_elements::SubmodelElementFields {
+                    referable: self.take_referable()?,
+                    semantics: self.take_semantics()?,
+                    qualifiable: self.take_qualifiable()?,
+                    embedded_data_specifications: self.take_data_specification()?,
+                })
+            }
+        }
+
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)*]
+            @referable @semantics @qualifiable @data_specification $($rest)*);
+    };
+
+    (@munch $meta:tt $name:ident [$($done:tt)*] $(#[$field_meta:meta])* $field:ident: $ty:ty, $($rest:tt)*) => {
+        $crate::part1::v3_1::proxy!(@munch $meta $name [$($done)* $(#[$field_meta])* $field: $ty,] $($rest)*);
+    };
+}
+pub(crate) use proxy;
@@ -1,119 +1,143 @@
-use crate::Xml;
-use crate::part1::v3_1::key::Key;
-use aas::part1::v3_1::reference::{Reference, ReferenceInner};
+use crate::XMLError;
+use crate::part1::v3_1::{invalid, parse, wrapper};
+use aas::part1::v3_1::key;
+use aas::part1::v3_1::reference::{self as model, ReferenceInner};
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumString};
+use std::str::FromStr;
 
-#[derive(EnumString, Clone, PartialEq, Debug, Deserialize, Serialize, Display)]
-pub enum ReferenceType {
-    ExternalReference,
-    ModelReference,
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename = "reference", rename_all = "camelCase")]
+pub(crate) struct Reference {
+    #[serde(rename = "type")]
+    ty: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    referred_semantic_id: Option<Box<Reference>>,
+
+    keys: Keys,
 }
 
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
-#[serde(rename = "Reference")]
-pub struct ReferenceXML {
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Key {
     #[serde(rename = "type")]
-    ty: ReferenceType,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "referredSemanticId")]
-    pub referred_semantic_id: Option<Box<ReferenceXML>>,
-    /// needed for <keys><key>...</key></keys>
-    pub keys: KeysXML,
-}
+    ty: String,
 
-impl From<ReferenceXML> for Reference {
-    fn from(value: ReferenceXML) -> Self {
-        match value.ty {
-            ReferenceType::ExternalReference => Reference::ExternalReference(ReferenceInner {
-                referred_semantic_id: value
-                    .referred_semantic_id
-                    .map(|v| *v)
-                    .map(|v| Box::new(Reference::from(v))),
-                keys: value.keys.key,
-            }),
-            ReferenceType::ModelReference => Reference::ModelReference(ReferenceInner {
-                referred_semantic_id: value
-                    .referred_semantic_id
-                    .map(|v| *v)
-                    .map(|v| Box::new(Reference::from(v))),
-                keys: value.keys.key,
-            }),
-        }
-    }
+    value: String,
 }
 
-impl From<Reference> for ReferenceXML {
-    fn from(value: Reference) -> Self {
-        match value {
-            Reference::ExternalReference(inner) => ReferenceXML {
-                ty: ReferenceType::ExternalReference,
-                keys: KeysXML { key: inner.keys },
-                referred_semantic_id: inner
-                    .referred_semantic_id
-                    .map(|v| *v)
-                    .map(|v| Box::new(ReferenceXML::from(v))),
-            },
-            Reference::ModelReference(inner) => ReferenceXML {
-                ty: ReferenceType::ModelReference,
-                keys: KeysXML { key: inner.keys },
-                referred_semantic_id: inner
-                    .referred_semantic_id
-                    .map(|v| *v)
-                    .map(|v| Box::new(ReferenceXML::from(v))),
+wrapper!(
+    Keys { key: Key },
+    References {
+        reference: Reference
+    },
+);
+
+impl From<&model::Reference> for Reference {
+    fn from(reference: &model::Reference) -> Self {
+        let ty = match reference {
+            model::Reference::ExternalReference(_) => "ExternalReference",
+            model::Reference::ModelReference(_) => "ModelReference",
+        };
+        Self {
+            ty: ty.into(),
+            referred_semantic_id: reference
+                .referred_semantic_id
+                .as_deref()
+                .map(|referred| Box::new(referred.into())),
+            keys: Keys {
+                key: reference
+                    .keys
+                    .iter()
+                    .map(|key| Key {
+                        ty: key.to_string(),
+                        value: key.value().clone(),
+                    })
+                    .collect(),
             },
         }
     }
 }
 
-impl Xml for Reference {
-    type Error = crate::XMLError;
-
-    fn to_xml(&self) -> Result<String, Self::Error> {
-        quick_xml::se::to_string(&ReferenceXML::from(self.clone()))
-            .map_err(crate::XMLError::SerialiseError)
-    }
+impl TryFrom<Reference> for model::Reference {
+    type Error = XMLError;
+
+    fn try_from(reference: Reference) -> Result<Self, Self::Error> {
+        let keys = reference
+            .keys
+            .key
+            .into_iter()
+            .map(|key| {
+                let mut parsed = parse("type", &key.ty, key::Key::from_str)?;
+                *parsed.value_mut() = key.value;
+                Ok(parsed)
+            })
+            .collect::<Result<_, XMLError>>()?;
+        let inner = ReferenceInner {
+            referred_semantic_id: reference
+                .referred_semantic_id
+                .map(|referred| (*referred).try_into().map(Box::new))
+                .transpose()?,
+            keys,
+        };
 
-    fn from_xml(json: &str) -> Result<Self, Self::Error> {
-        quick_xml::de::from_str::<ReferenceXML>(json)
-            .map_err(crate::XMLError::DeserializeError)
-            .map(|v| v.into())
-            .map(Into::into)
+        match reference.ty.as_str() {
+            "ExternalReference" => Ok(model::Reference::ExternalReference(inner)),
+            "ModelReference" => Ok(model::Reference::ModelReference(inner)),
+            ty => Err(invalid(
+                "type",
+                ty,
+                "expected ExternalReference or ModelReference",
+            )),
+        }
     }
 }
 
-/// needed for <key>...</key>
-#[derive(Clone, PartialEq, Debug, Deserialize, Serialize, Default)]
-#[serde(rename = "key")]
-pub struct KeysXML {
-    pub key: Vec<Key>,
-}
-
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::part1::v3_1::key::Key;
+    use crate::{XMLError, Xml};
+    use aas::part1::v3_1::key::Key;
     use aas::part1::v3_1::reference::{Reference, ReferenceInner};
 
     #[test]
-    fn test_xml_serialize() {
-        let reference = ReferenceXML {
-            ty: ReferenceType::ExternalReference,
-            referred_semantic_id: None,
-            keys: KeysXML {
-                key: vec![Key::Blob("http://example/blob".into())],
-            },
-        };
+    fn round_trip() {
+        let xml = "<reference><type>ExternalReference</type><referredSemanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://example.com/semantics</value></key></keys></referredSemanticId><keys><key><type>Blob</type><value>http://example/blob</value></key><key><type>Blob</type><value>http://example/blob2</value></key></keys></reference>";
 
-        let xml = quick_xml::se::to_string(&reference).unwrap();
+        let reference = Reference::from_xml(xml).unwrap();
+        let Reference::ExternalReference(inner) = &reference else {
+            panic!("expected an external reference");
+        };
+        assert_eq!(
+            inner.keys,
+            [
+                Key::Blob("http://example/blob".into()),
+                Key::Blob("http://example/blob2".into())
+            ]
+        );
+        assert_eq!(
+            inner.referred_semantic_id.as_deref(),
+            Some(&Reference::ExternalReference(ReferenceInner::new(
+                Key::GlobalReference("https://example.com/semantics".into())
+            )))
+        );
+        assert_eq!(reference.to_xml().unwrap(), xml);
+    }
 
-        println!("{}", xml);
+    #[test]
+    fn serialize() {
+        let reference = Reference::ExternalReference(ReferenceInner::new(Key::Blob(
+            "http://example/blob".into(),
+        )));
+
+        assert_eq!(
+            reference.to_xml().unwrap(),
+            "<reference><type>ExternalReference</type><keys><key><type>Blob</type><value>http://example/blob</value></key></keys></reference>"
+        );
     }
 
     #[test]
-    fn deserialize_blob_reference_xml() {
+    fn deserialize_blob_keys() {
         let xml = r#"
-                <Reference>
+                <reference>
                     <type>ExternalReference</type>
                     <keys>
                         <key>
@@ -125,18 +149,48 @@ mod tests {
                             <value>http://example/blob2</value>
                         </key>
                     </keys>
-                </Reference>"#;
+                </reference>"#;
+
+        assert_eq!(
+            Reference::from_xml(xml).unwrap(),
+            Reference::ExternalReference(ReferenceInner {
+                referred_semantic_id: None,
+                keys: vec![
+                    Key::Blob("http://example/blob".into()),
+                    Key::Blob("http://example/blob2".into()),
+                ],
+            })
+        );
+    }
 
-        let expected = Reference::ExternalReference(ReferenceInner {
-            referred_semantic_id: None,
-            keys: vec![
-                Key::Blob("http://example/blob".into()),
-                Key::Blob("http://example/blob2".into()),
-            ],
-        });
+    #[test]
+    fn submodel_key() {
+        let xml = "<reference><type>ModelReference</type><keys><key><type>Submodel</type><value>https://example.com/idta/Submodel/Test</value></key></keys></reference>";
 
-        let actual = quick_xml::de::from_str(xml).unwrap();
+        let Reference::ModelReference(inner) = Reference::from_xml(xml).unwrap() else {
+            panic!("expected a model reference");
+        };
+        assert_eq!(
+            inner.keys,
+            [Key::Submodel(
+                "https://example.com/idta/Submodel/Test".to_string()
+            )]
+        );
+    }
 
-        assert_eq!(expected, actual);
+    #[test]
+    fn invalid_types() {
+        for xml in [
+            "<reference><type>GlobalReference</type><keys><key><type>Submodel</type><value>urn:x</value></key></keys></reference>",
+            "<reference><type>ModelReference</type><keys><key><type>Submodels</type><value>urn:x</value></key></keys></reference>",
+        ] {
+            assert!(matches!(
+                Reference::from_xml(xml),
+                Err(XMLError::InvalidAttribute {
+                    attribute: "type",
+                    ..
+                })
+            ));
+        }
     }
 }
@@ -0,0 +1,1504 @@
+use crate::XMLError;
+use crate::part1::v3_1::attributes::{LangStringTextTypes, data_value, data_xsd, value_type};
+use crate::part1::v3_1::core::SpecificAssetIds;
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::{
+    Wrapper, convert, identifier, invalid, parse, proxies, proxy, try_proxies, wrapper,
+};
+use aas::part1::v3_1::primitives::{BlobType, MessageTopic, Uri};
+use aas::part1::v3_1::submodel_elements::{
+    self as model, AasSubmodelElements, DataElement, Direction, EntityInner, OperationVariable,
+    RangeValue, StateOfEvent, SubmodelElementFields,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::As;
+use serde_with::base64::Base64;
+use std::str::FromStr;
+
+/// Proxies of the concrete submodel elements, named by their element. A core [DataElement] is
+/// written as the element it wraps.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum SubmodelElement {
+    RelationshipElement(RelationshipElement),
+    AnnotatedRelationshipElement(AnnotatedRelationshipElement),
+    BasicEventElement(BasicEventElement),
+    Blob(Blob),
+    Capability(Capability),
+    Entity(Entity),
+    File(File),
+    MultiLanguageProperty(MultiLanguageProperty),
+    Operation(Operation),
+    Property(Property),
+    Range(Range),
+    ReferenceElement(ReferenceElement),
+    SubmodelElementCollection(SubmodelElementCollection),
+    SubmodelElementList(SubmodelElementList),
+}
+
+/// A list of submodel elements, where the element name of each item is its type
+#[derive(Serialize, Deserialize, Default)]
+pub(crate) struct SubmodelElements {
+    #[serde(rename = "$value", default)]
+    items: Vec<SubmodelElement>,
+}
+
+impl Wrapper for SubmodelElements {
+    type Item = SubmodelElement;
+
+    fn new(items: Vec<SubmodelElement>) -> Self {
+        Self { items }
+    }
+
+    fn into_items(self) -> Vec<SubmodelElement> {
+        self.items
+    }
+}
+
+impl TryFrom<&model::SubmodelElement> for SubmodelElement {
+    type Error = XMLError;
+
+    fn try_from(value: &model::SubmodelElement) -> Result<Self, Self::Error> {
+        Ok(match value {
+            model::SubmodelElement::RelationshipElement(e) => {
+                Self::RelationshipElement(e.try_into()?)
+            }
+            model::SubmodelElement::AnnotatedRelationshipElement(e) => {
+                Self::AnnotatedRelationshipElement(e.try_into()?)
+            }
+            model::SubmodelElement::BasicEventElement(e) => Self::BasicEventElement(e.try_into()?),
+            model::SubmodelElement::Blob(e) => Self::Blob(e.try_into()?),
+            model::SubmodelElement::Capability(e) => Self::Capability(e.try_into()?),
+            model::SubmodelElement::DataElement(e) => e.try_into()?,
+            model::SubmodelElement::Entity(e) => Self::Entity(e.try_into()?),
+            model::SubmodelElement::File(e) => Self::File(e.try_into()?),
+            model::SubmodelElement::MultiLanguageProperty(e) => {
+                Self::MultiLanguageProperty(e.try_into()?)
+            }
+            model::SubmodelElement::Operation(e) => Self::Operation(e.try_into()?),
+            model::SubmodelElement::Property(e) => Self::Property(e.try_into()?),
+            model::SubmodelElement::Range(e) => Self::Range(e.try_into()?),
+            model::SubmodelElement::ReferenceElement(e) => Self::ReferenceElement(e.try_into()?),
+            model::SubmodelElement::SubmodelElementCollection(e) => {
+                Self::SubmodelElementCollection(e.try_into()?)
+            }
+            model::SubmodelElement::SubmodelElementList(e) => {
+                Self::SubmodelElementList(e.try_into()?)
+            }
+        })
+    }
+}
+
+impl TryFrom<&DataElement> for SubmodelElement {
+    type Error = XMLError;
+
+    fn try_from(value: &DataElement) -> Result<Self, Self::Error> {
+        Ok(match value {
+            DataElement::Blob(e) => Self::Blob(e.try_into()?),
+            DataElement::File(e) => Self::File(e.try_into()?),
+            DataElement::MultiLanguageProperty(e) => Self::MultiLanguageProperty(e.try_into()?),
+            DataElement::Property(e) => Self::Property(e.try_into()?),
+            DataElement::Range(e) => Self::Range(e.try_into()?),
+            DataElement::ReferenceElement(e) => Self::ReferenceElement(e.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<SubmodelElement> for model::SubmodelElement {
+    type Error = XMLError;
+
+    fn try_from(value: SubmodelElement) -> Result<Self, Self::Error> {
+        Ok(match value {
+            SubmodelElement::RelationshipElement(e) => Self::RelationshipElement(e.try_into()?),
+            SubmodelElement::AnnotatedRelationshipElement(e) => {
+                Self::AnnotatedRelationshipElement(e.try_into()?)
+            }
+            SubmodelElement::BasicEventElement(e) => Self::BasicEventElement(e.try_into()?),
+            SubmodelElement::Blob(e) => Self::Blob(e.try_into()?),
+            SubmodelElement::Capability(e) => Self::Capability(e.try_into()?),
+            SubmodelElement::Entity(e) => Self::Entity(e.try_into()?),
+            SubmodelElement::File(e) => Self::File(e.try_into()?),
+            SubmodelElement::MultiLanguageProperty(e) => Self::MultiLanguageProperty(e.try_into()?),
+            SubmodelElement::Operation(e) => Self::Operation(e.try_into()?),
+            SubmodelElement::Property(e) => Self::Property(e.try_into()?),
+            SubmodelElement::Range(e) => Self::Range(e.try_into()?),
+            SubmodelElement::ReferenceElement(e) => Self::ReferenceElement(e.try_into()?),
+            SubmodelElement::SubmodelElementCollection(e) => {
+                Self::SubmodelElementCollection(e.try_into()?)
+            }
+            SubmodelElement::SubmodelElementList(e) => Self::SubmodelElementList(e.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<SubmodelElement> for DataElement {
+    type Error = XMLError;
+
+    fn try_from(value: SubmodelElement) -> Result<Self, Self::Error> {
+        DataElement::try_from(model::SubmodelElement::try_from(value)?)
+            .map_err(|element| invalid("annotations", element, "expected a data element"))
+    }
+}
+
+/// Implements the conversions of an element proxy defined with `@element`, the remaining fields
+/// are converted by the given closures.
+macro_rules! element {
+    ($proxy:ident => $model:ty, |$from:ident| { $($write:tt)* }, |$into:ident| { $($read:tt)* }) => {
+        impl TryFrom<&$model> for $proxy {
+            type Error = XMLError;
+
+            fn try_from($from: &$model) -> Result<Self, Self::Error> {
+                let mut proxy = Self {
+                    $($write)*
+                    ..Default::default()
+                };
+                proxy.set_element(
+                    &$from.referable,
+                    &$from.semantics,
+                    &$from.qualifiable,
+                    &$from.embedded_data_specifications,
+                )?;
+                Ok(proxy)
+            }
+        }
+
+        impl TryFrom<$proxy> for $model {
+            type Error = XMLError;
+
+            fn try_from(mut $into: $proxy) -> Result<Self, Self::Error> {
+                let SubmodelElementFields {
+                    referable,
+                    semantics,
+                    qualifiable,
+                    embedded_data_specifications,
+                } = $into.take_element()?;
+                Ok(Self {
+                    referable,
+                    semantics,
+                    qualifiable,
+                    embedded_data_specifications,
+                    $($read)*
+                })
+            }
+        }
+    };
+}
+
+proxy! {
+    struct RelationshipElement {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        first: Option<Reference>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        second: Option<Reference>,
+    }
+}
+
+element!(RelationshipElement => model::RelationshipElement, |value| {
+    first: value.first.as_ref().map(Into::into),
+    second: value.second.as_ref().map(Into::into),
+}, |value| {
+    first: value.first.map(TryInto::try_into).transpose()?,
+    second: value.second.map(TryInto::try_into).transpose()?,
+});
+
+proxy! {
+    struct AnnotatedRelationshipElement {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        first: Option<Reference>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        second: Option<Reference>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        annotations: Option<SubmodelElements>,
+    }
+}
+
+element!(AnnotatedRelationshipElement => model::AnnotatedRelationshipElement, |value| {
+    first: value.first.as_ref().map(Into::into),
+    second: value.second.as_ref().map(Into::into),
+    annotations: try_proxies(value.annotations.iter().flatten())?,
+}, |value| {
+    first: value.first.map(TryInto::try_into).transpose()?,
+    second: value.second.map(TryInto::try_into).transpose()?,
+    annotations: convert(value.annotations)?,
+});
+
+proxy! {
+    struct BasicEventElement {
+        @element
+
+        observed: Reference,
+        direction: String,
+        state: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message_topic: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message_broker: Option<Reference>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        last_update: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_interval: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_interval: Option<String>,
+    }
+}
+
+impl TryFrom<&model::BasicEventElement> for BasicEventElement {
+    type Error = XMLError;
+
+    fn try_from(value: &model::BasicEventElement) -> Result<Self, Self::Error> {
+        let fields = value.submodel_element_fields();
+        let mut proxy = Self {
+            observed: (&value.observed).into(),
+            direction: match value.direction {
+                Direction::Input => "input",
+                Direction::Output => "output",
+            }
+            .into(),
+            state: match value.state {
+                StateOfEvent::On => "on",
+                StateOfEvent::Off => "off",
+            }
+            .into(),
+            message_topic: value.message_topic.as_ref().map(ToString::to_string),
+            message_broker: value.message_broker.as_ref().map(Into::into),
+            last_update: value.last_update.as_ref().map(ToString::to_string),
+            min_interval: value.min_interval.clone(),
+            max_interval: value.max_interval.clone(),
+            ..Default::default()
+        };
+        proxy.set_element(
+            &fields.referable,
+            &fields.semantics,
+            &fields.qualifiable,
+            &fields.embedded_data_specifications,
+        )?;
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<BasicEventElement> for model::BasicEventElement {
+    type Error = XMLError;
+
+    fn try_from(mut value: BasicEventElement) -> Result<Self, Self::Error> {
+        let direction = match value.direction.as_str() {
+            "input" => Direction::Input,
+            "output" => Direction::Output,
+            direction => return Err(invalid("direction", direction, "expected input or output")),
+        };
+        let state = match value.state.as_str() {
+            "on" => StateOfEvent::On,
+            "off" => StateOfEvent::Off,
+            state => return Err(invalid("state", state, "expected on or off")),
+        };
+        let mut element = Self::new(
+            value.take_element()?,
+            value.observed.try_into()?,
+            direction,
+            state,
+        );
+        element.message_topic = value
+            .message_topic
+            .map(|topic| parse("messageTopic", &topic, MessageTopic::try_from))
+            .transpose()?;
+        element.message_broker = value.message_broker.map(TryInto::try_into).transpose()?;
+        element.last_update = value
+            .last_update
+            .map(|update| parse("lastUpdate", &update, str::parse))
+            .transpose()?;
+        element.min_interval = value.min_interval;
+        element.max_interval = value.max_interval;
+        Ok(element)
+    }
+}
+
+proxy! {
+    struct Blob {
+        @element
+
+        #[serde(with = "As::<Option<Base64>>")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<BlobType>,
+
+        content_type: String,
+    }
+}
+
+element!(Blob => model::Blob, |value| {
+    value: value.value.clone(),
+    content_type: value.content_type.clone(),
+}, |value| {
+    value: value.value,
+    content_type: value.content_type,
+});
+
+proxy! {
+    struct Capability {
+        @element
+    }
+}
+
+element!(Capability => model::Capability, |value| {}, |value| {});
+
+proxy! {
+    struct Entity {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        statements: Option<SubmodelElements>,
+
+        entity_type: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        global_asset_id: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        specific_asset_ids: Option<SpecificAssetIds>,
+    }
+}
+
+impl TryFrom<&model::Entity> for Entity {
+    type Error = XMLError;
+
+    fn try_from(value: &model::Entity) -> Result<Self, Self::Error> {
+        let (entity_type, inner) = match value {
+            model::Entity::CoManagedEntity(inner) => ("CoManagedEntity", inner),
+            model::Entity::SelfManagedEntity(inner) => ("SelfManagedEntity", inner),
+        };
+        let mut proxy = Self {
+            statements: try_proxies(inner.statements.iter().flatten())?,
+            entity_type: entity_type.into(),
+            global_asset_id: inner.global_asset_id.as_ref().map(ToString::to_string),
+            specific_asset_ids: proxies(inner.specific_asset_id.iter().flatten()),
+            ..Default::default()
+        };
+        proxy.set_element(
+            &inner.referable,
+            &inner.semantics,
+            &inner.qualifiable,
+            &inner.embedded_data_specifications,
+        )?;
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<Entity> for model::Entity {
+    type Error = XMLError;
+
+    fn try_from(mut value: Entity) -> Result<Self, Self::Error> {
+        let SubmodelElementFields {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+        } = value.take_element()?;
+        let inner = EntityInner {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+            statements: convert(value.statements)?,
+            global_asset_id: value
+                .global_asset_id
+                .map(|id| identifier("globalAssetId", &id))
+                .transpose()?,
+            specific_asset_id: convert(value.specific_asset_ids)?,
+        };
+        match value.entity_type.as_str() {
+            "CoManagedEntity" => Ok(Self::CoManagedEntity(inner)),
+            "SelfManagedEntity" => Ok(Self::SelfManagedEntity(inner)),
+            entity_type => Err(invalid(
+                "entityType",
+                entity_type,
+                "expected CoManagedEntity or SelfManagedEntity",
+            )),
+        }
+    }
+}
+
+proxy! {
+    struct File {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        content_type: Option<String>,
+    }
+}
+
+element!(File => model::File, |value| {
+    value: value.value.as_ref().map(ToString::to_string),
+    content_type: value.content_type.clone(),
+}, |value| {
+    value: value
+        .value
+        .map(|path| parse("value", &path, Uri::from_str))
+        .transpose()?,
+    content_type: value.content_type,
+});
+
+proxy! {
+    struct MultiLanguageProperty {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<LangStringTextTypes>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value_id: Option<Reference>,
+    }
+}
+
+element!(MultiLanguageProperty => model::MultiLanguageProperty, |value| {
+    value: proxies(value.value.iter().flatten()),
+    value_id: value.value_id.as_ref().map(Into::into),
+}, |value| {
+    value: convert(value.value)?,
+    value_id: value.value_id.map(TryInto::try_into).transpose()?,
+});
+
+proxy! {
+    struct Operation {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        input_variables: Option<OperationVariables>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        output_variables: Option<OperationVariables>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        inoutput_variables: Option<OperationVariables>,
+    }
+}
+
+/// The value of an operation variable holds exactly one element
+#[derive(Serialize, Deserialize)]
+pub(crate) struct OperationVariableProxy {
+    value: SubmodelElements,
+}
+
+wrapper!(OperationVariables {
+    operation_variable: OperationVariableProxy
+});
+
+impl TryFrom<&OperationVariable> for OperationVariableProxy {
+    type Error = XMLError;
+
+    fn try_from(value: &OperationVariable) -> Result<Self, Self::Error> {
+        Ok(Self {
+            value: SubmodelElements::new(vec![(&value.value).try_into()?]),
+        })
+    }
+}
+
+impl TryFrom<OperationVariableProxy> for OperationVariable {
+    type Error = XMLError;
+
+    fn try_from(value: OperationVariableProxy) -> Result<Self, Self::Error> {
+        let mut items = value.value.into_items();
+        match (items.pop(), items.is_empty()) {
+            (Some(element), true) => Ok(Self::new(element.try_into()?)),
+            _ => Err(invalid(
+                "operationVariable",
+                "value",
+                "expected exactly one submodel element",
+            )),
+        }
+    }
+}
+
+element!(Operation => model::Operation, |value| {
+    input_variables: try_proxies(value.input_variables.iter().flatten())?,
+    output_variables: try_proxies(value.output_variables.iter().flatten())?,
+    inoutput_variables: try_proxies(value.inoutput_variables.iter().flatten())?,
+}, |value| {
+    input_variables: convert(value.input_variables)?,
+    output_variables: convert(value.output_variables)?,
+    inoutput_variables: convert(value.inoutput_variables)?,
+});
+
+proxy! {
+    struct Property {
+        @element
+
+        value_type: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value_id: Option<Reference>,
+    }
+}
+
+impl TryFrom<&model::Property> for Property {
+    type Error = XMLError;
+
+    fn try_from(value: &model::Property) -> Result<Self, Self::Error> {
+        let (value_type, data) = data_value(&value.value)?;
+        let mut proxy = Self {
+            value_type,
+            value: data,
+            value_id: value.value_id.as_ref().map(Into::into),
+            ..Default::default()
+        };
+        proxy.set_element(
+            &value.referable,
+            &value.semantics,
+            &value.qualifiable,
+            &value.embedded_data_specifications,
+        )?;
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<Property> for model::Property {
+    type Error = XMLError;
+
+    fn try_from(mut value: Property) -> Result<Self, Self::Error> {
+        let SubmodelElementFields {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+        } = value.take_element()?;
+        Ok(Self {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+            value: data_xsd(&value.value_type, value.value)?,
+            value_id: value.value_id.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+proxy! {
+    struct Range {
+        @element
+
+        value_type: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<String>,
+    }
+}
+
+impl TryFrom<&model::Range> for Range {
+    type Error = XMLError;
+
+    fn try_from(value: &model::Range) -> Result<Self, Self::Error> {
+        let (min, max) = value.value.to_lexical().map_err(|e| {
+            let (min, max) = value.value.clone().into_bounds();
+            let bounds = format!(
+                "{}..{}",
+                Option::<String>::from(min).unwrap_or_default(),
+                Option::<String>::from(max).unwrap_or_default()
+            );
+            invalid("min/max", bounds, e)
+        })?;
+        let mut proxy = Self {
+            value_type: value.value.value_type().to_string(),
+            min,
+            max,
+            ..Default::default()
+        };
+        proxy.set_element(
+            &value.referable,
+            &value.semantics,
+            &value.qualifiable,
+            &value.embedded_data_specifications,
+        )?;
+        Ok(proxy)
+    }
+}
+
+impl TryFrom<Range> for model::Range {
+    type Error = XMLError;
+
+    fn try_from(mut value: Range) -> Result<Self, Self::Error> {
+        let SubmodelElementFields {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+        } = value.take_element()?;
+        let value_type = value_type("valueType", &value.value_type)?;
+        let bounds = format!(
+            "{}..{}",
+            value.min.as_deref().unwrap_or_default(),
+            value.max.as_deref().unwrap_or_default()
+        );
+        Ok(Self {
+            referable,
+            semantics,
+            qualifiable,
+            embedded_data_specifications,
+            value: RangeValue::try_from((value_type, value.min, value.max))
+                .map_err(|e| invalid("min/max", bounds, e))?,
+        })
+    }
+}
+
+proxy! {
+    struct ReferenceElement {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<Reference>,
+    }
+}
+
+element!(ReferenceElement => model::ReferenceElement, |value| {
+    value: value.value.as_ref().map(Into::into),
+}, |value| {
+    value: value.value.map(TryInto::try_into).transpose()?,
+});
+
+proxy! {
+    struct SubmodelElementCollection {
+        @element
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<SubmodelElements>,
+    }
+}
+
+element!(SubmodelElementCollection => model::SubmodelElementCollection, |value| {
+    value: try_proxies(value.value.iter().flatten())?,
+}, |value| {
+    value: convert(value.value)?,
+});
+
+proxy! {
+    struct SubmodelElementList {
+        @element
+
+        /// Defaults to true if absent
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        order_relevant: Option<bool>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        semantic_id_list_element: Option<Reference>,
+
+        type_value_list_element: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value_type_list_element: Option<String>,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<SubmodelElements>,
+    }
+}
+
+element!(SubmodelElementList => model::SubmodelElementList, |value| {
+    order_relevant: (!value.is_order_relevant).then_some(false),
+    semantic_id_list_element: value.semantic_id_list_element.as_ref().map(Into::into),
+    type_value_list_element: value.type_value_list_element.to_string(),
+    value_type_list_element: value.value_type_list_element.as_ref().map(ToString::to_string),
+    value: try_proxies(value.value.iter().flatten())?,
+}, |value| {
+    is_order_relevant: value.order_relevant.unwrap_or(true),
+    semantic_id_list_element: value.semantic_id_list_element.map(TryInto::try_into).transpose()?,
+    type_value_list_element: parse(
+        "typeValueListElement",
+        &value.type_value_list_element,
+        AasSubmodelElements::from_str,
+    )?,
+    value_type_list_element: value
+        .value_type_list_element
+        .map(|list_type| value_type("valueTypeListElement", &list_type))
+        .transpose()?,
+    value: convert(value.value)?,
+});
+
+#[cfg(test)]
+mod tests {
+    use super::{SubmodelElements, convert, model};
+    use crate::{XMLError, Xml};
+    use aas::part1::v3_1::LangString;
+    use aas::part1::v3_1::attributes::qualifiable::{Qualifiable, Qualifier, QualifierInner};
+    use aas::part1::v3_1::attributes::referable::Referable;
+    use aas::part1::v3_1::attributes::semantics::HasSemantics;
+    use aas::part1::v3_1::key::Key;
+    use aas::part1::v3_1::primitives::data_type_def_xs::DataTypeXSDef;
+    use aas::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use aas::part1::v3_1::primitives::{Identifier, Iri};
+    use aas::part1::v3_1::reference::{Reference, ReferenceInner};
+    use aas::part1::v3_1::submodel_elements::{
+        AasSubmodelElements, Blob, Entity, MultiLanguageProperty, OperationVariable, Property,
+        RangeInner, RangeValue, SubmodelElement,
+    };
+
+    /// Reads the element and writes it again unchanged
+    fn round_trip(xml: &str) -> SubmodelElement {
+        let element = SubmodelElement::from_xml(xml).unwrap();
+        assert_eq!(element.to_xml().unwrap(), xml);
+        element
+    }
+
+    fn invalid_attribute(xml: &str) -> &'static str {
+        match SubmodelElement::from_xml(xml) {
+            Err(XMLError::InvalidAttribute { attribute, .. }) => attribute,
+            other => panic!("expected an invalid attribute, got {other:?}"),
+        }
+    }
+
+    fn variable(element: &SubmodelElement) -> (&str, &DataXsd) {
+        let SubmodelElement::Property(property) = element else {
+            panic!("expected a property");
+        };
+        (
+            property.referable.id_short.as_ref().unwrap().as_ref(),
+            &property.value,
+        )
+    }
+
+    #[test]
+    fn blob_value_is_base64_encoded() {
+        let element = round_trip(
+            "<blob><value>iVBORw0KGgo=</value><contentType>image/png</contentType></blob>",
+        );
+
+        assert_eq!(
+            element,
+            SubmodelElement::Blob(Blob::new(
+                Some(b"\x89PNG\r\n\x1a\n".to_vec()),
+                "image/png".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn serialize_blob() {
+        let blob = SubmodelElement::Blob(Blob {
+            referable: Referable {
+                id_short: Some(Identifier::try_from("AnShortId").unwrap()),
+                display_name: Some(vec![
+                    LangString::try_new("en", "Sample text".into()).unwrap(),
+                ]),
+                description: Some(vec![
+                    LangString::try_new("en", "Sample description".into()).unwrap(),
+                ]),
+                ..Default::default()
+            },
+            value: Some(b"sample".to_vec()),
+            content_type: "application/json".to_string(),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            blob.to_xml().unwrap(),
+            "<blob><idShort>AnShortId</idShort><displayName><langStringNameType><language>en</language><text>Sample text</text></langStringNameType></displayName><description><langStringTextType><language>en</language><text>Sample description</text></langStringTextType></description><value>c2FtcGxl</value><contentType>application/json</contentType></blob>"
+        );
+    }
+
+    #[test]
+    fn blob_default() {
+        assert_eq!(
+            SubmodelElement::from_xml("<blob><contentType></contentType></blob>").unwrap(),
+            SubmodelElement::Blob(Blob::default())
+        );
+    }
+
+    #[test]
+    fn list_of_blobs() {
+        let xml = r#"
+            <submodelElements>
+                <blob>
+                    <value>iVBORw0KGgo=</value>
+                    <contentType>image/png</contentType>
+                </blob>
+                <blob>
+                    <contentType>image/png</contentType>
+                </blob>
+            </submodelElements>
+            "#;
+
+        let elements = quick_xml::de::from_str::<SubmodelElements>(xml).unwrap();
+        let elements = convert::<_, model::SubmodelElement>(Some(elements)).unwrap();
+
+        assert_eq!(
+            elements,
+            Some(vec![
+                SubmodelElement::Blob(Blob::new(
+                    Some(b"\x89PNG\r\n\x1a\n".to_vec()),
+                    "image/png".into()
+                )),
+                SubmodelElement::Blob(Blob::new(None, "image/png".into())),
+            ])
+        );
+    }
+
+    #[test]
+    fn blob_rejects_invalid_base64() {
+        assert!(
+            SubmodelElement::from_xml(
+                "<blob><value>not base64!</value><contentType>image/png</contentType></blob>"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn blob_without_value() {
+        let blob = SubmodelElement::Blob(Blob::new(
+            Some(vec![0, 1, 2]),
+            "application/octet-stream".into(),
+        ));
+
+        assert_eq!(
+            blob.without_blob_values().to_xml().unwrap(),
+            "<blob><contentType>application/octet-stream</contentType></blob>"
+        );
+    }
+
+    #[test]
+    fn operation_variables() {
+        let SubmodelElement::Operation(operation) = SubmodelElement::from_xml(
+            r#"
+            <operation>
+                <idShort>SetSpeed</idShort>
+                <inputVariables>
+                    <operationVariable>
+                        <value>
+                            <property>
+                                <idShort>speed</idShort>
+                                <valueType>xs:int</valueType>
+                            </property>
+                        </value>
+                    </operationVariable>
+                    <operationVariable>
+                        <value>
+                            <property>
+                                <idShort>ramp</idShort>
+                                <valueType>xs:double</valueType>
+                            </property>
+                        </value>
+                    </operationVariable>
+                </inputVariables>
+            </operation>
+            "#,
+        )
+        .unwrap() else {
+            panic!("expected an operation");
+        };
+
+        let input: Vec<_> = operation
+            .input_variables
+            .iter()
+            .flatten()
+            .map(|v| variable(&v.value))
+            .collect();
+        assert_eq!(
+            input,
+            [
+                ("speed", &DataXsd::Int(None)),
+                ("ramp", &DataXsd::Double(None))
+            ]
+        );
+        assert_eq!(operation.output_variables, None);
+    }
+
+    #[test]
+    fn operation_round_trip() {
+        let element = round_trip(
+            "<operation><idShort>Add</idShort><inputVariables><operationVariable><value><property><idShort>a</idShort><valueType>xs:int</valueType><value>1</value></property></value></operationVariable></inputVariables><inoutputVariables><operationVariable><value><property><idShort>c</idShort><valueType>xs:int</valueType><value>3</value></property></value></operationVariable></inoutputVariables></operation>",
+        );
+
+        let SubmodelElement::Operation(operation) = &element else {
+            panic!("expected an operation");
+        };
+        assert_eq!(operation.output_variables, None);
+        let inoutput: &[OperationVariable] = operation.inoutput_variables.as_ref().unwrap();
+        assert_eq!(variable(&inoutput[0].value), ("c", &DataXsd::Int(Some(3))));
+    }
+
+    #[test]
+    fn property() {
+        let element = round_trip(
+            "<property><idShort>URIOfTheProduct</idShort><semanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>0112/2///61987#ABN590#002</value></key></keys></semanticId><supplementalSemanticIds><reference><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>0173-1#02-ABH173#003</value></key></keys></reference></supplementalSemanticIds><qualifiers><qualifier><kind>TemplateQualifier</kind><type>SMT/Cardinality</type><valueType>xs:string</valueType><value>One</value></qualifier></qualifiers><valueType>xs:anyURI</valueType><value>https://smartfactory-owl.de/3dl/__turtle/__00000001</value></property>",
+        );
+
+        let SubmodelElement::Property(property) = element else {
+            panic!("expected a property");
+        };
+        assert_eq!(
+            property
+                .semantics
+                .supplemental_semantic_ids
+                .map(|s| s.len()),
+            Some(1)
+        );
+        assert_eq!(
+            Option::<String>::from(property.value).as_deref(),
+            Some("https://smartfactory-owl.de/3dl/__turtle/__00000001")
+        );
+    }
+
+    #[test]
+    fn property_any_uri() {
+        let xml = r#"
+            <property>
+                <valueType>xs:anyURI</valueType>
+                <value>https://smartfactory-owl.de/3dl/__turtle/__00000001</value>
+            </property>
+        "#;
+
+        assert_eq!(
+            SubmodelElement::from_xml(xml).unwrap(),
+            SubmodelElement::Property(property_with(DataXsd::AnyURI(Some(turtle_uri()))))
+        );
+    }
+
+    #[test]
+    fn property_complex() {
+        let xml = r#"
+        <property>
+          <idShort>URIOfTheProduct</idShort>
+          <semanticId>
+            <type>ExternalReference</type>
+            <keys>
+              <key>
+                <type>GlobalReference</type>
+                <value>0112/2///61987#ABN590#002</value>
+              </key>
+            </keys>
+          </semanticId>
+          <supplementalSemanticIds>
+            <reference>
+              <type>ExternalReference</type>
+              <keys>
+                <key>
+                  <type>GlobalReference</type>
+                  <value>0173-1#02-ABH173#003</value>
+                </key>
+              </keys>
+            </reference>
+          </supplementalSemanticIds>
+          <qualifiers>
+            <qualifier>
+              <semanticId>
+                <type>ExternalReference</type>
+                <keys>
+                  <key>
+                    <type>GlobalReference</type>
+                    <value>https://admin-shell.io/SubmodelTemplates/Cardinality/1/0</value>
+                  </key>
+                </keys>
+              </semanticId>
+              <kind>TemplateQualifier</kind>
+              <type>SMT/Cardinality</type>
+              <valueType>xs:string</valueType>
+              <value>One</value>
+            </qualifier>
+          </qualifiers>
+          <valueType>xs:anyURI</valueType>
+          <value>https://smartfactory-owl.de/3dl/__turtle/__00000001</value>
+        </property>
+        "#;
+
+        let mut expected = property_with(DataXsd::AnyURI(Some(turtle_uri())));
+        expected.referable.id_short = Some(Identifier::try_from("URIOfTheProduct").unwrap());
+        expected.semantics = HasSemantics {
+            semantic_id: Some(global("0112/2///61987#ABN590#002")),
+            supplemental_semantic_ids: Some(vec![global("0173-1#02-ABH173#003")]),
+        };
+        expected.qualifiable = Qualifiable {
+            qualifiers: Some(vec![Qualifier::TemplateQualifier(QualifierInner {
+                semantics: HasSemantics {
+                    semantic_id: Some(global(
+                        "https://admin-shell.io/SubmodelTemplates/Cardinality/1/0",
+                    )),
+                    supplemental_semantic_ids: None,
+                },
+                ty: "SMT/Cardinality".to_string(),
+                value: DataXsd::String(Some("One".into())),
+                value_id: None,
+            })]),
+        };
+
+        assert_eq!(
+            SubmodelElement::from_xml(xml).unwrap(),
+            SubmodelElement::Property(expected)
+        );
+    }
+
+    fn property_with(value: DataXsd) -> Property {
+        Property {
+            referable: Default::default(),
+            semantics: Default::default(),
+            qualifiable: Default::default(),
+            embedded_data_specifications: Default::default(),
+            value,
+            value_id: None,
+        }
+    }
+
+    fn turtle_uri() -> Iri {
+        Iri::new("https://smartfactory-owl.de/3dl/__turtle/__00000001".to_string()).unwrap()
+    }
+
+    fn global(value: &str) -> Reference {
+        Reference::ExternalReference(ReferenceInner::new(Key::GlobalReference(value.into())))
+    }
+
+    #[test]
+    fn range_with_id_short() {
+        let element = round_trip(
+            "<range><idShort>Temperature</idShort><valueType>xs:int</valueType><min>-5</min><max>40</max></range>",
+        );
+
+        let SubmodelElement::Range(range) = element else {
+            panic!("expected a range");
+        };
+        assert_eq!(range.referable.id_short.unwrap().as_ref(), "Temperature");
+        assert_eq!(
+            range.value,
+            RangeValue::Int(RangeInner {
+                min: Some(-5),
+                max: Some(40),
+            })
+        );
+    }
+
+    #[test]
+    fn range_value_types() {
+        for (value_type, bounds) in [
+            ("xs:string", "<min>a</min><max>z</max>"),
+            ("xs:boolean", "<min>false</min><max>true</max>"),
+            ("xs:double", "<min>-1.5</min><max>INF</max>"),
+            ("xs:decimal", "<min>0.5</min>"),
+            ("xs:unsignedByte", "<max>255</max>"),
+            ("xs:duration", "<min>PT1M</min><max>P1D</max>"),
+            ("xs:gYear", "<min>-0044</min><max>2024Z</max>"),
+            ("xs:base64Binary", ""),
+        ] {
+            let element = round_trip(&format!(
+                "<range><valueType>{value_type}</valueType>{bounds}</range>"
+            ));
+            let SubmodelElement::Range(range) = element else {
+                panic!("expected a range");
+            };
+            assert_eq!(range.value_type().to_string(), value_type);
+        }
+    }
+
+    #[test]
+    fn range_rejects_invalid_bounds() {
+        for bounds in [
+            "<valueType>xs:int</valueType><min>a</min>",
+            "<valueType>xs:unsignedByte</valueType><max>256</max>",
+            "<valueType>xs:duration</valueType><min>P1W</min>",
+        ] {
+            assert_eq!(
+                invalid_attribute(&format!("<range>{bounds}</range>")),
+                "min/max"
+            );
+        }
+        assert_eq!(
+            invalid_attribute("<range><valueType>xs:float32</valueType></range>"),
+            "valueType"
+        );
+    }
+
+    #[test]
+    fn reference_element() {
+        let element = round_trip(
+            "<referenceElement><idShort>ManufacturerRef</idShort><semanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://example.com/ids/cd/1</value></key></keys></semanticId><value><type>ModelReference</type><keys><key><type>Submodel</type><value>https://example.com/ids/sm/1</value></key></keys></value></referenceElement>",
+        );
+
+        let SubmodelElement::ReferenceElement(reference) = element else {
+            panic!("expected a reference element");
+        };
+        assert_eq!(
+            reference.semantics.semantic_id,
+            Some(Reference::ExternalReference(ReferenceInner::new(
+                Key::GlobalReference("https://example.com/ids/cd/1".into())
+            )))
+        );
+        assert_eq!(
+            reference.value,
+            Some(Reference::ModelReference(ReferenceInner::new(
+                Key::Submodel("https://example.com/ids/sm/1".into())
+            )))
+        );
+    }
+
+    #[test]
+    fn relationship_element_keeps_first_and_second() {
+        let element = round_trip(
+            "<relationshipElement><idShort>relationship_test</idShort><first><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://example.com/1</value></key></keys></first><second><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>https://example.com/2</value></key></keys></second></relationshipElement>",
+        );
+
+        let SubmodelElement::RelationshipElement(relationship) = element else {
+            panic!("expected a relationship element");
+        };
+        assert!(relationship.first.is_some() && relationship.second.is_some());
+    }
+
+    #[test]
+    fn entity() {
+        let element = round_trip(
+            "<entity><idShort>Motor</idShort><statements><property><idShort>speed</idShort><valueType>xs:int</valueType><value>3000</value></property></statements><entityType>SelfManagedEntity</entityType><globalAssetId>https://example.com/assets/motor</globalAssetId></entity>",
+        );
+
+        let SubmodelElement::Entity(Entity::SelfManagedEntity(entity)) = element else {
+            panic!("expected a self-managed entity");
+        };
+        assert_eq!(entity.statements.map(|s| s.len()), Some(1));
+
+        assert_eq!(
+            invalid_attribute("<entity><entityType>Managed</entityType></entity>"),
+            "entityType"
+        );
+    }
+
+    #[test]
+    fn file() {
+        let element = round_trip(
+            r"<file><idShort>AssemblyInstructions_DigitalFile</idShort><description><langStringTextType><language>en</language><text>Note: each DigitalFile represents the same content or Document version.</text></langStringTextType></description><semanticId><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>0173-1#02-ABK126#003</value></key></keys></semanticId><qualifiers><qualifier><type>Cardinality</type><valueType>xs:string</valueType><value>OneToMany</value></qualifier><qualifier><type>AllowedIdShort</type><valueType>xs:string</valueType><value>DigitalFile[\d{2,3}]</value></qualifier></qualifiers><value>/aasx/files/safety_information.pdf</value><contentType>application/pdf</contentType></file>",
+        );
+
+        let SubmodelElement::File(file) = element else {
+            panic!("expected a file");
+        };
+        assert_eq!(
+            file.value.unwrap().as_str(),
+            "/aasx/files/safety_information.pdf"
+        );
+        assert_eq!(file.qualifiable.qualifiers.map(|q| q.len()), Some(2));
+    }
+
+    #[test]
+    fn file_complex() {
+        let xml = r#"
+            <file>
+                  <idShort>AssemblyInstructions_DigitalFile</idShort>
+                  <semanticId>
+                    <type>ExternalReference</type>
+                    <keys>
+                      <key>
+                        <type>GlobalReference</type>
+                        <value>0173-1#02-ABK126#003</value>
+                      </key>
+                    </keys>
+                  </semanticId>
+                  <qualifiers>
+                    <qualifier>
+                      <semanticId>
+                        <type>ExternalReference</type>
+                        <keys>
+                          <key>
+                            <type>GlobalReference</type>
+                            <value>https://admin-shell.io/SubmodelTemplates/Cardinality/1/0</value>
+                          </key>
+                        </keys>
+                      </semanticId>
+                      <type>Cardinality</type>
+                      <valueType>xs:string</valueType>
+                      <value>OneToMany</value>
+                    </qualifier>
+                    <qualifier>
+                      <semanticId>
+                        <type>ExternalReference</type>
+                        <keys>
+                          <key>
+                            <type>GlobalReference</type>
+                            <value>https://admin-shell.io/SubmodelTemplates/ExampleValue/1/0</value>
+                          </key>
+                        </keys>
+                      </semanticId>
+                      <type>ExampleValue</type>
+                      <valueType>xs:string</valueType>
+                      <value>docu_cecc_fullmanual_DE.PDF</value>
+                    </qualifier>
+                    <qualifier>
+                      <semanticId>
+                        <type>ExternalReference</type>
+                        <keys>
+                          <key>
+                            <type>GlobalReference</type>
+                            <value>https://admin-shell.io/SubmodelTemplates/AllowedIdShort/1/0</value>
+                          </key>
+                        </keys>
+                      </semanticId>
+                      <type>AllowedIdShort</type>
+                      <valueType>xs:string</valueType>
+                      <value>DigitalFile[\d{2,3}]</value>
+                    </qualifier>
+                  </qualifiers>
+                  <value>/aasx/files/safety_information.pdf</value>
+                  <contentType>application/pdf</contentType>
+                </file>"#;
+
+        let SubmodelElement::File(file) = SubmodelElement::from_xml(xml).unwrap() else {
+            panic!("expected a file");
+        };
+        assert_eq!(
+            file.referable.id_short.unwrap().as_ref(),
+            "AssemblyInstructions_DigitalFile"
+        );
+        assert_eq!(
+            file.semantics.semantic_id,
+            Some(global("0173-1#02-ABK126#003"))
+        );
+        let qualifiers: Vec<_> = file
+            .qualifiable
+            .qualifiers
+            .iter()
+            .flatten()
+            .map(|qualifier| match qualifier {
+                Qualifier::Unknown(inner) => (inner.ty.as_str(), &inner.value),
+                other => panic!("expected a qualifier without kind, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            qualifiers,
+            [
+                ("Cardinality", &DataXsd::String(Some("OneToMany".into()))),
+                (
+                    "ExampleValue",
+                    &DataXsd::String(Some("docu_cecc_fullmanual_DE.PDF".into()))
+                ),
+                (
+                    "AllowedIdShort",
+                    &DataXsd::String(Some(r"DigitalFile[\d{2,3}]".into()))
+                ),
+            ]
+        );
+        assert_eq!(
+            file.value.unwrap().as_str(),
+            "/aasx/files/safety_information.pdf"
+        );
+        assert_eq!(file.content_type.as_deref(), Some("application/pdf"));
+    }
+
+    #[test]
+    fn serialize_multi_language_property() {
+        let property = SubmodelElement::MultiLanguageProperty(MultiLanguageProperty {
+            value: Some(vec![
+                LangString::try_new("de", "Das ist ein deutscher Bezeichner".into()).unwrap(),
+                LangString::try_new("en", "That's an English label".into()).unwrap(),
+            ]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            property.to_xml().unwrap(),
+            "<multiLanguageProperty><value><langStringTextType><language>de</language><text>Das ist ein deutscher Bezeichner</text></langStringTextType><langStringTextType><language>en</language><text>That's an English label</text></langStringTextType></value></multiLanguageProperty>"
+        );
+    }
+
+    #[test]
+    fn multi_language_property_complex() {
+        let xml = r#"
+            <multiLanguageProperty>
+              <idShort>CityTown</idShort>
+              <displayName>
+                <langStringNameType>
+                  <language>en</language>
+                  <text>city</text>
+                </langStringNameType>
+              </displayName>
+              <semanticId>
+                <type>ExternalReference</type>
+                <keys>
+                  <key>
+                    <type>GlobalReference</type>
+                    <value>0173-1#02-AAO132#002</value>
+                  </key>
+                </keys>
+              </semanticId>
+              <qualifiers>
+                <qualifier>
+                  <kind>ConceptQualifier</kind>
+                  <type>Multiplicity</type>
+                  <valueType>xs:string</valueType>
+                  <value>ZeroToOne</value>
+                </qualifier>
+              </qualifiers>
+              <value>
+                <langStringTextType>
+                  <language>de</language>
+                  <text>Lemgo</text>
+                </langStringTextType>
+              </value>
+            </multiLanguageProperty>
+        "#;
+
+        assert_eq!(
+            SubmodelElement::from_xml(xml).unwrap(),
+            SubmodelElement::MultiLanguageProperty(MultiLanguageProperty {
+                referable: Referable {
+                    id_short: Some(Identifier::try_from("CityTown").unwrap()),
+                    display_name: Some(vec![LangString::try_new("en", "city".into()).unwrap()]),
+                    ..Default::default()
+                },
+                semantics: HasSemantics {
+                    semantic_id: Some(global("0173-1#02-AAO132#002")),
+                    supplemental_semantic_ids: None,
+                },
+                qualifiable: Qualifiable {
+                    qualifiers: Some(vec![Qualifier::ConceptQualifier(QualifierInner {
+                        semantics: Default::default(),
+                        ty: "Multiplicity".to_string(),
+                        value: DataXsd::String(Some("ZeroToOne".into())),
+                        value_id: None,
+                    })]),
+                },
+                value: Some(vec![LangString::try_new("de", "Lemgo".into()).unwrap()]),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn multi_language_property() {
+        let element = round_trip(
+            "<multiLanguageProperty><idShort>CityTown</idShort><displayName><langStringNameType><language>en</language><text>city</text></langStringNameType></displayName><value><langStringTextType><language>de</language><text>Das ist ein deutscher Bezeichner</text></langStringTextType><langStringTextType><language>en</language><text>That's an English label</text></langStringTextType></value></multiLanguageProperty>",
+        );
+
+        let SubmodelElement::MultiLanguageProperty(property) = element else {
+            panic!("expected a multi language property");
+        };
+        assert_eq!(property.value.map(|v| v.len()), Some(2));
+    }
+
+    #[test]
+    fn submodel_element_list() {
+        let element = round_trip(
+            "<submodelElementList><idShort>PcfCalculationMethods</idShort><orderRelevant>false</orderRelevant><semanticIdListElement><type>ExternalReference</type><keys><key><type>GlobalReference</type><value>0173-1#02-ABG854#003</value></key></keys></semanticIdListElement><typeValueListElement>Property</typeValueListElement><valueTypeListElement>xs:string</valueTypeListElement><value><property><category>PARAMETER</category><valueType>xs:string</valueType><value>in-house method - see documentation</value></property></value></submodelElementList>",
+        );
+
+        let SubmodelElement::SubmodelElementList(list) = element else {
+            panic!("expected a list");
+        };
+        assert!(!list.is_order_relevant);
+        assert_eq!(list.value.map(|v| v.len()), Some(1));
+
+        assert_eq!(
+            invalid_attribute(
+                "<submodelElementList><typeValueListElement>Properties</typeValueListElement></submodelElementList>"
+            ),
+            "typeValueListElement"
+        );
+    }
+
+    #[test]
+    fn submodel_element_list_complex() {
+        let xml = r#"
+            <submodelElementList>
+              <idShort>PcfCalculationMethods</idShort>
+              <displayName>
+                <langStringNameType>
+                  <language>en</language>
+                  <text>impact assessment methods</text>
+                </langStringNameType>
+              </displayName>
+              <description>
+                <langStringTextType>
+                  <language>en</language>
+                  <text>Standards, methods for determining the greenhouse gas emissions of a product.</text>
+                </langStringTextType>
+              </description>
+              <semanticId>
+                <type>ExternalReference</type>
+                <keys>
+                  <key>
+                    <type>GlobalReference</type>
+                    <value>https://admin-shell.io/idta/CarbonFootprint/PcfCalculationMethods/1/0</value>
+                  </key>
+                </keys>
+              </semanticId>
+              <qualifiers>
+                <qualifier>
+                  <type>SMT/Cardinality</type>
+                  <valueType>xs:string</valueType>
+                  <value>One</value>
+                </qualifier>
+              </qualifiers>
+              <orderRelevant>true</orderRelevant>
+              <semanticIdListElement>
+                <type>ExternalReference</type>
+                <keys>
+                  <key>
+                    <type>GlobalReference</type>
+                    <value>0173-1#02-ABG854#003</value>
+                  </key>
+                </keys>
+              </semanticIdListElement>
+              <typeValueListElement>Property</typeValueListElement>
+              <valueTypeListElement>xs:string</valueTypeListElement>
+              <value>
+                <property>
+                  <semanticId>
+                    <type>ExternalReference</type>
+                    <keys>
+                      <key>
+                        <type>GlobalReference</type>
+                        <value>0173-1#02-ABG854#003</value>
+                      </key>
+                    </keys>
+                  </semanticId>
+                  <valueType>xs:string</valueType>
+                  <value>in-house method - see documentation</value>
+                </property>
+              </value>
+            </submodelElementList>
+        "#;
+
+        let SubmodelElement::SubmodelElementList(list) = SubmodelElement::from_xml(xml).unwrap()
+        else {
+            panic!("expected a list");
+        };
+        assert_eq!(
+            list.referable.id_short.unwrap().as_ref(),
+            "PcfCalculationMethods"
+        );
+        assert!(list.is_order_relevant);
+        assert_eq!(
+            list.semantic_id_list_element,
+            Some(global("0173-1#02-ABG854#003"))
+        );
+        assert_eq!(list.type_value_list_element, AasSubmodelElements::Property);
+        assert_eq!(list.value_type_list_element, Some(DataTypeXSDef::String));
+
+        let mut expected = property_with(DataXsd::String(Some(
+            "in-house method - see documentation".into(),
+        )));
+        expected.semantics.semantic_id = Some(global("0173-1#02-ABG854#003"));
+        assert_eq!(list.value, Some(vec![SubmodelElement::Property(expected)]));
+    }
+
+    #[test]
+    fn basic_event_element() {
+        round_trip(
+            "<basicEventElement><idShort>Overheated</idShort><observed><type>ModelReference</type><keys><key><type>Submodel</type><value>https://example.com/ids/sm/1</value></key></keys></observed><direction>output</direction><state>on</state></basicEventElement>",
+        );
+
+        assert_eq!(
+            invalid_attribute(
+                "<basicEventElement><observed><type>ModelReference</type><keys><key><type>Submodel</type><value>https://example.com/ids/sm/1</value></key></keys></observed><direction>sideways</direction><state>on</state></basicEventElement>"
+            ),
+            "direction"
+        );
+    }
+}
@@ -1,5 +0,0 @@
-//! Here are (axum) endpoints defined regarding the aas part 2 spec.
-//! To implement custom logic over these endpoints, define structs
-//! implementing the respective Service-Trait.
-
-pub mod v3_1;
@@ -1,4 +0,0 @@
-pub mod endpoints;
-pub mod error;
-pub mod services;
-pub mod types;
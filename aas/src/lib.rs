@@ -5,6 +5,10 @@
 //! multiple modules for each part as well as version.
 //! Because each spec is versioned on their own, the modules are ordered `specs/version` instead of
 //! `version/specs`, i.e. `aas::part1::v3.1`.
+//!
+//! The metamodel types are only defined here. The serialization formats live in their own crates,
+//! `aas-json` and `aas-xml`, which map these types to the shape of their schema, so one parsed
+//! environment can be written in either format.
 
 /// Part1: Metamodel
 pub mod part1;
@@ -17,16 +21,3 @@ pub mod part2;
 /// Utility functions like validating text to specific formats and deserializers to specific needs,
 /// like text with defined constraints.
 pub mod utilities;
-
-// Aufbau:
-// if feature xml: pub use xml::Struct
-// if feature json: pub use json::Struct
-// if both: pub use xml::Struct as StructXML,
-//          pub use json::Struct as StructJSON
-//          pub Struct (without Des, Ser)
-// dabei wird das macro generate.. genutzt.
-// in den jeweiligen modulen (xml, json) wird dann impl Ser, Der
-// implementiert. Dabei kann innerhalb der implementierung das geeignete
-// struct (z.B. für flatten) benutzt werden.
-
-// das wird erst umgesetzt, nachdem die erste Version von XML steht.
@@ -10,12 +10,26 @@ pub trait ToJsonValue {
     fn to_json_value(&self) -> Result<String, Self::Error>;
 }
 
+/// Counterpart of [ToJsonValue]. The value-only format is not self-describing,
+/// so an element with the expected structure serves as schema.
+pub trait FromJsonValue: Sized {
+    type Error;
+    fn from_json_value(schema: &Self, value: &str) -> Result<Self, Self::Error>;
+}
+
 /// see https://industrialdigitaltwin.io/aas-specifications/IDTA-01001/v3.1.1/mappings/mappings.html#_format_metadata_metadata_serialization
 pub trait ToJsonMetamodel {
     type Error;
     fn to_json_metamodel(&self) -> Result<String, Self::Error>;
 }
 
+/// XML counterpart of [ToJsonMetamodel]. The element is written as root element of the
+/// `https://admin-shell.io/aas/3/1` namespace.
+pub trait ToXmlMetamodel {
+    type Error;
+    fn to_xml_metamodel(&self) -> Result<String, Self::Error>;
+}
+
 #[derive(Debug, Error)]
 pub enum MetamodelError {
     #[error(transparent)]
@@ -24,6 +38,9 @@ pub enum MetamodelError {
     #[error(transparent)]
     FailedSerialisation(serde_json::Error),
 
+    #[error(transparent)]
+    InvalidValue(#[from] v3_1::primitives::data_type_def_xs::ConversionError),
+
     #[error("Struct does not support json metamodel")]
     MetamodelNotSupported,
 
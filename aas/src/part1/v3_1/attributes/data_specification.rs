@@ -20,6 +20,36 @@ pub struct EmbeddedDataSpecification {
 #[derive(Clone, PartialEq, Debug)]
 pub enum DataSpecificationContent {
     DataSpecificationIec61360(DataSpecificationIec61360),
+
+    DataSpecificationPhysicalUnit(DataSpecificationPhysicalUnit),
+
+    /// Content of a data specification template that is not known to this crate,
+    /// e.g. a company specific one. It is kept as is, so it survives a round trip.
+    Unknown(UnknownDataSpecificationContent),
+}
+
+impl DataSpecificationContent {
+    /// The modelType of the content, e.g. `DataSpecificationIec61360`
+    pub fn model_type(&self) -> &str {
+        match self {
+            DataSpecificationContent::DataSpecificationIec61360(_) => "DataSpecificationIec61360",
+            DataSpecificationContent::DataSpecificationPhysicalUnit(_) => {
+                "DataSpecificationPhysicalUnit"
+            }
+            DataSpecificationContent::Unknown(content) => &content.model_type,
+        }
+    }
+}
+
+/// Raw content of an unknown data specification template.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct UnknownDataSpecificationContent {
+    /// modelType in JSON, name of the content element in XML
+    pub model_type: String,
+
+    /// All other fields of the content as format independent tree.
+    /// XML child elements become entries, their text becomes string values.
+    pub fields: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -49,7 +79,38 @@ pub struct DataSpecificationIec61360 {
     pub level_type: Option<LevelType>,
 }
 
+/// Data specification template for physical units (IDTA-01003-b).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct DataSpecificationPhysicalUnit {
+    pub unit_name: String,
+
+    pub unit_symbol: String,
+
+    pub definition: Vec<LangString>,
+
+    pub si_notation: Option<String>,
+
+    pub si_name: Option<String>,
+
+    pub din_notation: Option<String>,
+
+    pub ece_name: Option<String>,
+
+    pub ece_code: Option<String>,
+
+    pub nist_name: Option<String>,
+
+    pub source_of_definition: Option<String>,
+
+    pub conversion_factor: Option<String>,
+
+    pub registration_authority_id: Option<String>,
+
+    pub supplier: Option<String>,
+}
+
 #[derive(EnumString, Display, Clone, PartialEq, Debug)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum DataTypeIec61360 {
     Blob,
     Boolean,
@@ -1,3 +1,5 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -32,3 +34,12 @@ impl FromStr for ModellingKind {
         })
     }
 }
+
+impl Display for ModellingKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ModellingKind::Instance => write!(f, "Instance"),
+            ModellingKind::Template => write!(f, "Template"),
+        }
+    }
+}
@@ -1,9 +1,6 @@
 use crate::part1::v3_1::attributes::extension::HasExtensions;
 use crate::part1::v3_1::primitives::{Identifier, MultiLanguageNameType};
 
-#[cfg(feature = "json")]
-use crate::utilities::deserialize_empty_identifier_as_none;
-
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct Referable {
     // use case where "" is needed or can this be ignored?
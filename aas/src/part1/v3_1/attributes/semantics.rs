@@ -1,50 +1,37 @@
-use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::reference::{Reference, SemanticMatch};
 
 // HasSemantics
 #[derive(Clone, PartialEq, Debug, Default)]
-#[cfg_attr(
-    feature = "xml",
-    serde(from = "xml::HasSemanticsXMLProxy", into = "xml::HasSemanticsXMLProxy")
-)]
 pub struct HasSemantics {
     pub semantic_id: Option<Reference>,
 
     pub supplemental_semantic_ids: Option<Vec<Reference>>,
 }
 
-pub(crate) mod xml {
-    use crate::part1::v3_1::attributes::semantics::HasSemantics;
-    use crate::part1::v3_1::reference::Reference;
-
-    #[derive(Debug)]
-    pub struct HasSemanticsXMLProxy {
-        pub semantic_id: Option<Reference>,
-
-        pub supplemental_semantic_ids: SupplementalSemanticIdsWrapper,
-    }
-
-    #[derive(Debug)]
-    pub struct SupplementalSemanticIdsWrapper {
-        pub reference: Option<Vec<Reference>>,
+impl HasSemantics {
+    /// Whether the element has the semantics of the reference: its semantic id, or with
+    /// [SemanticMatch::supplemental] any of its supplemental semantic ids, is equivalent.
+    pub fn has_semantic_id(&self, reference: &Reference, options: &SemanticMatch) -> bool {
+        self.references(options)
+            .any(|own| own.is_equivalent(reference, options))
     }
 
-    impl From<HasSemantics> for HasSemanticsXMLProxy {
-        fn from(value: HasSemantics) -> Self {
-            Self {
-                semantic_id: value.semantic_id,
-                supplemental_semantic_ids: SupplementalSemanticIdsWrapper {
-                    reference: value.supplemental_semantic_ids,
-                },
-            }
-        }
+    /// Whether both have equivalent semantic ids. With [SemanticMatch::supplemental], any of
+    /// the semantic and supplemental semantic ids may match.
+    pub fn matches(&self, other: &HasSemantics, options: &SemanticMatch) -> bool {
+        other
+            .references(options)
+            .any(|reference| self.has_semantic_id(reference, options))
     }
 
-    impl From<HasSemanticsXMLProxy> for HasSemantics {
-        fn from(value: HasSemanticsXMLProxy) -> Self {
-            Self {
-                semantic_id: value.semantic_id,
-                supplemental_semantic_ids: value.supplemental_semantic_ids.reference,
-            }
-        }
+    fn references(&self, options: &SemanticMatch) -> impl Iterator<Item = &Reference> {
+        let supplemental = match options.supplemental {
+            true => self
+                .supplemental_semantic_ids
+                .as_deref()
+                .unwrap_or_default(),
+            false => &[],
+        };
+        self.semantic_id.iter().chain(supplemental)
     }
 }
@@ -0,0 +1,1169 @@
+//! Fluent builders for the metamodel types.
+//!
+//! ```
+//! use aas::part1::v3_1::core::Submodel;
+//! use aas::part1::v3_1::primitives::data_type_def_xs::DataTypeXSDef;
+//! use aas::part1::v3_1::submodel_elements::Property;
+//!
+//! let submodel = Submodel::builder("https://example.com/sm/1")
+//!     .id_short("Nameplate")
+//!     .element(
+//!         Property::builder(DataTypeXSDef::Double)
+//!             .id_short("MaxSpeed")
+//!             .value("3000")
+//!             .build()?,
+//!     )
+//!     .build()?;
+//! # Ok::<(), aas::part1::v3_1::builder::BuildError>(())
+//! ```
+//!
+//! Attributes given as text are parsed when they are set, `build()` reports the first one
+//! that failed. Afterwards the built element is checked with [Validate], so it satisfies the
+//! AASd constraints that can be checked without knowing where the element is used.
+//! E.g. AASd-117 (idShort required) depends on the parent and is not checked.
+
+use crate::part1::v3_1::LangString;
+use crate::part1::v3_1::attributes::administrative_information::AdministrativeInformation;
+use crate::part1::v3_1::attributes::data_specification::{
+    EmbeddedDataSpecification, HasDataSpecification,
+};
+use crate::part1::v3_1::attributes::extension::Extension;
+use crate::part1::v3_1::attributes::identifiable::Identifiable;
+use crate::part1::v3_1::attributes::kind::ModellingKind;
+use crate::part1::v3_1::attributes::qualifiable::{Qualifiable, Qualifier};
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::attributes::semantics::HasSemantics;
+use crate::part1::v3_1::concept_description::ConceptDescription;
+use crate::part1::v3_1::core::{
+    AssetAdministrationShell, AssetInformation, AssetInformationInner, SpecificAssetId, Submodel,
+};
+use crate::part1::v3_1::environment::Environment;
+use crate::part1::v3_1::primitives::data_type_def_xs::{DataTypeXSDef, DataXsd};
+use crate::part1::v3_1::primitives::{DateTimeUTC, Duration, Identifier, IdentifierError, Uri};
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::submodel_elements::{
+    AasSubmodelElements, AnnotatedRelationshipElement, BasicEventElement, Blob, Capability,
+    DataElement, Direction, Entity, EntityInner, EntityType, File, MultiLanguageProperty,
+    Operation, OperationVariable, Property, Range, RangeValue, ReferenceElement,
+    RelationshipElement, StateOfEvent, SubmodelElement, SubmodelElementCollection,
+    SubmodelElementFields, SubmodelElementList,
+};
+use crate::part1::v3_1::validation::{Validate, ValidationReport, Validator};
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum BuildError {
+    #[error("Invalid {attribute} '{value}': {reason}")]
+    InvalidAttribute {
+        attribute: &'static str,
+        value: String,
+        reason: String,
+    },
+
+    #[error("The element violates constraints:\n{0}")]
+    Constraints(ValidationReport),
+}
+
+/// Builder of a metamodel element of type `T`, see the [module docs](self).
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Builder<T> {
+    element: T,
+
+    /// First attribute that could not be parsed
+    error: Option<BuildError>,
+}
+
+impl<T> Builder<T> {
+    fn new(element: T) -> Self {
+        Self {
+            element,
+            error: None,
+        }
+    }
+
+    fn with(mut self, f: impl FnOnce(&mut T)) -> Self {
+        f(&mut self.element);
+        self
+    }
+
+    /// Parses `value` and sets it with `f`, or keeps the error for `build()`.
+    fn parse<V, E: Display>(
+        mut self,
+        attribute: &'static str,
+        value: &str,
+        parse: impl FnOnce(&str) -> Result<V, E>,
+        f: impl FnOnce(&mut T, V),
+    ) -> Self {
+        match parse(value) {
+            Ok(parsed) => f(&mut self.element, parsed),
+            Err(e) => {
+                self.error.get_or_insert(BuildError::InvalidAttribute {
+                    attribute,
+                    value: value.to_string(),
+                    reason: e.to_string(),
+                });
+            }
+        }
+        self
+    }
+
+    fn lang_string(
+        self,
+        attribute: &'static str,
+        language: &str,
+        text: &str,
+        f: impl FnOnce(&mut T) -> &mut Option<Vec<LangString>>,
+    ) -> Self {
+        self.parse(
+            attribute,
+            language,
+            |language| LangString::try_new(language, text.to_string()),
+            |element, lang_string| f(element).get_or_insert_default().push(lang_string),
+        )
+    }
+}
+
+impl<T: Validate> Builder<T> {
+    pub fn build(self) -> Result<T, BuildError> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        let mut validator = Validator::detached();
+        self.element.validate_with(&mut validator, "");
+        let report = validator.into_report();
+
+        if report.is_valid() {
+            Ok(self.element)
+        } else {
+            Err(BuildError::Constraints(report))
+        }
+    }
+}
+
+fn identifier(value: &str) -> Result<Identifier, IdentifierError> {
+    Identifier::try_from(value)
+}
+
+// ---------------------------------------------------------------------------
+// shared attributes
+
+/// Elements with Referable attributes
+pub trait HasReferable {
+    fn referable_mut(&mut self) -> &mut Referable;
+}
+
+/// Elements with a semanticId
+pub trait HasSemanticId {
+    fn semantics_mut(&mut self) -> &mut HasSemantics;
+}
+
+pub trait HasQualifiers {
+    fn qualifiable(&self) -> &Qualifiable;
+
+    fn qualifiable_mut(&mut self) -> &mut Qualifiable;
+}
+
+pub trait HasEmbeddedDataSpecifications {
+    fn data_specification_mut(&mut self) -> &mut HasDataSpecification;
+}
+
+pub trait HasIdentifiable {
+    fn identifiable_mut(&mut self) -> &mut Identifiable;
+}
+
+impl<T: HasIdentifiable> HasReferable for T {
+    fn referable_mut(&mut self) -> &mut Referable {
+        &mut self.identifiable_mut().referable
+    }
+}
+
+impl<T: HasReferable> Builder<T> {
+    pub fn id_short(self, id_short: &str) -> Self {
+        self.parse("idShort", id_short, identifier, |element, id_short| {
+            element.referable_mut().id_short = Some(id_short)
+        })
+    }
+
+    /// Adds a display name in the given language.
+    pub fn display_name(self, language: &str, text: &str) -> Self {
+        self.lang_string("displayName", language, text, |element| {
+            &mut element.referable_mut().display_name
+        })
+    }
+
+    /// Adds a description in the given language.
+    pub fn description(self, language: &str, text: &str) -> Self {
+        self.lang_string("description", language, text, |element| {
+            &mut element.referable_mut().description
+        })
+    }
+
+    #[deprecated]
+    pub fn category(self, category: &str) -> Self {
+        #[allow(deprecated)]
+        self.with(|element| element.referable_mut().category = Some(category.to_string()))
+    }
+
+    pub fn extension(self, extension: Extension) -> Self {
+        self.with(|element| {
+            element
+                .referable_mut()
+                .extensions
+                .extension
+                .get_or_insert_default()
+                .push(extension)
+        })
+    }
+}
+
+impl<T: HasSemanticId> Builder<T> {
+    pub fn semantic_id(self, semantic_id: Reference) -> Self {
+        self.with(|element| element.semantics_mut().semantic_id = Some(semantic_id))
+    }
+
+    pub fn supplemental_semantic_id(self, semantic_id: Reference) -> Self {
+        self.with(|element| {
+            element
+                .semantics_mut()
+                .supplemental_semantic_ids
+                .get_or_insert_default()
+                .push(semantic_id)
+        })
+    }
+}
+
+impl<T: HasQualifiers> Builder<T> {
+    pub fn qualifier(self, qualifier: Qualifier) -> Self {
+        self.with(|element| {
+            element
+                .qualifiable_mut()
+                .qualifiers
+                .get_or_insert_default()
+                .push(qualifier)
+        })
+    }
+}
+
+impl<T: HasEmbeddedDataSpecifications> Builder<T> {
+    pub fn embedded_data_specification(
+        self,
+        data_specification: EmbeddedDataSpecification,
+    ) -> Self {
+        self.with(|element| {
+            element
+                .data_specification_mut()
+                .embedded_data_specifications
+                .get_or_insert_default()
+                .push(data_specification)
+        })
+    }
+}
+
+impl<T: HasIdentifiable> Builder<T> {
+    pub fn administration(self, administration: AdministrativeInformation) -> Self {
+        self.with(|element| element.identifiable_mut().administration = Some(administration))
+    }
+}
+
+/// Implements the attribute traits for submodel elements with the usual
+/// `referable`, `semantics`, `qualifiable` and `embedded_data_specifications` fields.
+macro_rules! impl_element_attributes {
+    ($($ty:ty),* $(,)?) => {$(
+        impl HasReferable for $ty {
+            fn referable_mut(&mut self) -> &mut Referable {
+                &mut self.referable
+            }
+        }
+
+        impl HasSemanticId for $ty {
+            fn semantics_mut(&mut self) -> &mut HasSemantics {
+                &mut self.semantics
+            }
+        }
+
+        impl HasQualifiers for $ty {
+            fn qualifiable(&self) -> &Qualifiable {
+                &self.qualifiable
+            }
+
+            fn qualifiable_mut(&mut self) -> &mut Qualifiable {
+                &mut self.qualifiable
+            }
+        }
+
+        impl HasEmbeddedDataSpecifications for $ty {
+            fn data_specification_mut(&mut self) -> &mut HasDataSpecification {
+                &mut self.embedded_data_specifications
+            }
+        }
+    )*};
+}
+
+impl_element_attributes!(
+    RelationshipElement,
+    AnnotatedRelationshipElement,
+    Blob,
+    Capability,
+    File,
+    MultiLanguageProperty,
+    Operation,
+    Property,
+    Range,
+    ReferenceElement,
+    SubmodelElementCollection,
+    SubmodelElementList,
+    SubmodelElementFields,
+    EntityInner,
+);
+
+/// Forwards the attribute traits to an inner value holding the attributes.
+macro_rules! impl_inner_attributes {
+    ($($ty:ty => |$element:ident| $inner:expr),* $(,)?) => {$(
+        impl HasReferable for $ty {
+            fn referable_mut(&mut self) -> &mut Referable {
+                let $element = self;
+                $inner.referable_mut()
+            }
+        }
+
+        impl HasSemanticId for $ty {
+            fn semantics_mut(&mut self) -> &mut HasSemantics {
+                let $element = self;
+                $inner.semantics_mut()
+            }
+        }
+
+        impl HasQualifiers for $ty {
+            fn qualifiable(&self) -> &Qualifiable {
+                let $element = self;
+                $inner.qualifiable()
+            }
+
+            fn qualifiable_mut(&mut self) -> &mut Qualifiable {
+                let $element = self;
+                $inner.qualifiable_mut()
+            }
+        }
+
+        impl HasEmbeddedDataSpecifications for $ty {
+            fn data_specification_mut(&mut self) -> &mut HasDataSpecification {
+                let $element = self;
+                $inner.data_specification_mut()
+            }
+        }
+    )*};
+}
+
+impl_inner_attributes!(
+    BasicEventElement => |element| element.submodel_element_fields,
+    Entity => |element| match element {
+        Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner) => inner,
+    },
+);
+
+/// Forwards the attribute traits of an element enum to its variants.
+macro_rules! impl_enum_attributes {
+    ($enum:ident: $($variant:ident),* $(,)?) => {
+        impl HasReferable for $enum {
+            fn referable_mut(&mut self) -> &mut Referable {
+                match self {
+                    $($enum::$variant(element) => element.referable_mut(),)*
+                }
+            }
+        }
+
+        impl HasSemanticId for $enum {
+            fn semantics_mut(&mut self) -> &mut HasSemantics {
+                match self {
+                    $($enum::$variant(element) => element.semantics_mut(),)*
+                }
+            }
+        }
+
+        impl HasQualifiers for $enum {
+            fn qualifiable(&self) -> &Qualifiable {
+                match self {
+                    $($enum::$variant(element) => element.qualifiable(),)*
+                }
+            }
+
+            fn qualifiable_mut(&mut self) -> &mut Qualifiable {
+                match self {
+                    $($enum::$variant(element) => element.qualifiable_mut(),)*
+                }
+            }
+        }
+
+        impl HasEmbeddedDataSpecifications for $enum {
+            fn data_specification_mut(&mut self) -> &mut HasDataSpecification {
+                match self {
+                    $($enum::$variant(element) => element.data_specification_mut(),)*
+                }
+            }
+        }
+    };
+}
+
+impl_enum_attributes!(
+    DataElement: Blob,
+    File,
+    MultiLanguageProperty,
+    Property,
+    Range,
+    ReferenceElement,
+);
+
+impl_enum_attributes!(
+    SubmodelElement: RelationshipElement,
+    AnnotatedRelationshipElement,
+    BasicEventElement,
+    Blob,
+    Capability,
+    DataElement,
+    Entity,
+    File,
+    MultiLanguageProperty,
+    Operation,
+    Property,
+    Range,
+    ReferenceElement,
+    SubmodelElementCollection,
+    SubmodelElementList,
+);
+
+impl HasIdentifiable for AssetAdministrationShell {
+    fn identifiable_mut(&mut self) -> &mut Identifiable {
+        &mut self.identifiable
+    }
+}
+
+impl HasEmbeddedDataSpecifications for AssetAdministrationShell {
+    fn data_specification_mut(&mut self) -> &mut HasDataSpecification {
+        &mut self.data_specification
+    }
+}
+
+impl HasIdentifiable for Submodel {
+    fn identifiable_mut(&mut self) -> &mut Identifiable {
+        &mut self.identifiable
+    }
+}
+
+impl HasSemanticId for Submodel {
+    fn semantics_mut(&mut self) -> &mut HasSemantics {
+        &mut self.semantics
+    }
+}
+
+impl HasQualifiers for Submodel {
+    fn qualifiable(&self) -> &Qualifiable {
+        &self.qualifier
+    }
+
+    fn qualifiable_mut(&mut self) -> &mut Qualifiable {
+        &mut self.qualifier
+    }
+}
+
+impl HasEmbeddedDataSpecifications for Submodel {
+    fn data_specification_mut(&mut self) -> &mut HasDataSpecification {
+        &mut self.data_specification
+    }
+}
+
+impl HasIdentifiable for ConceptDescription {
+    fn identifiable_mut(&mut self) -> &mut Identifiable {
+        &mut self.identifiable
+    }
+}
+
+impl HasEmbeddedDataSpecifications for ConceptDescription {
+    fn data_specification_mut(&mut self) -> &mut HasDataSpecification {
+        self.data_specification.get_or_insert_default()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// identifiables
+
+/// Element created from an Identifiable with a placeholder id, the callers set the parsed id.
+fn identifiable<T>(element: impl FnOnce(Identifiable) -> T) -> Builder<T> {
+    let placeholder = Identifier::try_from("_").expect("valid identifier");
+    Builder::new(element(Identifiable {
+        id: placeholder,
+        administration: None,
+        referable: Referable::default(),
+    }))
+}
+
+impl Environment {
+    pub fn builder() -> Builder<Environment> {
+        Builder::new(Environment {
+            asset_administration_shells: None,
+            submodels: None,
+            concept_descriptions: None,
+        })
+    }
+}
+
+impl Builder<Environment> {
+    pub fn shell(self, shell: AssetAdministrationShell) -> Self {
+        self.with(|env| {
+            env.asset_administration_shells
+                .get_or_insert_default()
+                .push(shell)
+        })
+    }
+
+    pub fn submodel(self, submodel: Submodel) -> Self {
+        self.with(|env| env.submodels.get_or_insert_default().push(submodel))
+    }
+
+    pub fn concept_description(self, concept_description: ConceptDescription) -> Self {
+        self.with(|env| {
+            env.concept_descriptions
+                .get_or_insert_default()
+                .push(concept_description)
+        })
+    }
+}
+
+impl AssetAdministrationShell {
+    /// Builder of a shell for an asset of the given kind, e.g. `AssetInformation::Instance`.
+    /// AASd-131 requires a globalAssetId or at least one specificAssetId.
+    pub fn builder(
+        id: &str,
+        asset_kind: fn(AssetInformationInner) -> AssetInformation,
+    ) -> Builder<AssetAdministrationShell> {
+        identifiable(|identifiable| AssetAdministrationShell {
+            asset_information: asset_kind(AssetInformationInner::default()),
+            identifiable,
+            data_specification: HasDataSpecification::default(),
+            derived_from: None,
+            submodels: None,
+        })
+        .parse("id", id, identifier, |shell, id| shell.identifiable.id = id)
+    }
+}
+
+fn asset_information(shell: &mut AssetAdministrationShell) -> &mut AssetInformationInner {
+    match &mut shell.asset_information {
+        AssetInformation::Instance(inner)
+        | AssetInformation::NotApplicable(inner)
+        | AssetInformation::Role(inner)
+        | AssetInformation::Type(inner) => inner,
+    }
+}
+
+impl Builder<AssetAdministrationShell> {
+    pub fn global_asset_id(self, global_asset_id: &str) -> Self {
+        self.parse("globalAssetId", global_asset_id, identifier, |shell, id| {
+            asset_information(shell).global_asset_id = Some(id)
+        })
+    }
+
+    pub fn specific_asset_id(self, specific_asset_id: SpecificAssetId) -> Self {
+        self.with(|shell| {
+            asset_information(shell)
+                .specific_asset_ids
+                .get_or_insert_default()
+                .push(specific_asset_id)
+        })
+    }
+
+    pub fn asset_type(self, asset_type: &str) -> Self {
+        self.parse("assetType", asset_type, identifier, |shell, ty| {
+            asset_information(shell).asset_type = Some(ty)
+        })
+    }
+
+    pub fn derived_from(self, derived_from: Reference) -> Self {
+        self.with(|shell| shell.derived_from = Some(derived_from))
+    }
+
+    /// Adds a reference to a submodel of the shell.
+    pub fn submodel(self, submodel: Reference) -> Self {
+        self.with(|shell| shell.submodels.get_or_insert_default().push(submodel))
+    }
+}
+
+impl Submodel {
+    pub fn builder(id: &str) -> Builder<Submodel> {
+        identifiable(|identifiable| Submodel {
+            identifiable,
+            kind: None,
+            semantics: HasSemantics::default(),
+            qualifier: Qualifiable::default(),
+            data_specification: HasDataSpecification::default(),
+            submodel_elements: None,
+        })
+        .parse("id", id, identifier, |submodel, id| {
+            submodel.identifiable.id = id
+        })
+    }
+}
+
+impl Builder<Submodel> {
+    pub fn kind(self, kind: ModellingKind) -> Self {
+        self.with(|submodel| submodel.kind = Some(kind))
+    }
+
+    pub fn element(self, element: impl Into<SubmodelElement>) -> Self {
+        self.with(|submodel| {
+            submodel
+                .submodel_elements
+                .get_or_insert_default()
+                .push(element.into())
+        })
+    }
+}
+
+impl ConceptDescription {
+    pub fn builder(id: &str) -> Builder<ConceptDescription> {
+        identifiable(|identifiable| ConceptDescription {
+            identifiable,
+            data_specification: None,
+            is_case_of: None,
+        })
+        .parse("id", id, identifier, |cd, id| cd.identifiable.id = id)
+    }
+}
+
+impl Builder<ConceptDescription> {
+    pub fn is_case_of(self, reference: Reference) -> Self {
+        self.with(|cd| cd.is_case_of.get_or_insert_default().push(reference))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// submodel elements
+
+impl RelationshipElement {
+    pub fn builder() -> Builder<RelationshipElement> {
+        Builder::new(RelationshipElement {
+            referable: Referable::default(),
+            semantics: HasSemantics::default(),
+            qualifiable: Qualifiable::default(),
+            embedded_data_specifications: HasDataSpecification::default(),
+            first: None,
+            second: None,
+        })
+    }
+}
+
+impl Builder<RelationshipElement> {
+    pub fn first(self, first: Reference) -> Self {
+        self.with(|element| element.first = Some(first))
+    }
+
+    pub fn second(self, second: Reference) -> Self {
+        self.with(|element| element.second = Some(second))
+    }
+}
+
+impl AnnotatedRelationshipElement {
+    pub fn builder() -> Builder<AnnotatedRelationshipElement> {
+        Builder::new(AnnotatedRelationshipElement {
+            referable: Referable::default(),
+            semantics: HasSemantics::default(),
+            qualifiable: Qualifiable::default(),
+            embedded_data_specifications: HasDataSpecification::default(),
+            first: None,
+            second: None,
+            annotations: None,
+        })
+    }
+}
+
+impl Builder<AnnotatedRelationshipElement> {
+    pub fn first(self, first: Reference) -> Self {
+        self.with(|element| element.first = Some(first))
+    }
+
+    pub fn second(self, second: Reference) -> Self {
+        self.with(|element| element.second = Some(second))
+    }
+
+    pub fn annotation(self, annotation: impl Into<DataElement>) -> Self {
+        self.with(|element| {
+            element
+                .annotations
+                .get_or_insert_default()
+                .push(annotation.into())
+        })
+    }
+}
+
+impl BasicEventElement {
+    pub fn builder(
+        observed: Reference,
+        direction: Direction,
+        state: StateOfEvent,
+    ) -> Builder<BasicEventElement> {
+        Builder::new(BasicEventElement::new(
+            SubmodelElementFields::default(),
+            observed,
+            direction,
+            state,
+        ))
+    }
+}
+
+impl Builder<BasicEventElement> {
+    pub fn message_topic(self, message_topic: &str) -> Self {
+        self.parse(
+            "messageTopic",
+            message_topic,
+            |topic| topic.try_into(),
+            |element, topic| element.message_topic = Some(topic),
+        )
+    }
+
+    pub fn message_broker(self, message_broker: Reference) -> Self {
+        self.with(|element| element.message_broker = Some(message_broker))
+    }
+
+    pub fn last_update(self, last_update: DateTimeUTC) -> Self {
+        self.with(|element| element.last_update = Some(last_update))
+    }
+
+    /// Minimal interval between two events as xs:duration, e.g. `PT1S`
+    pub fn min_interval(self, min_interval: &str) -> Self {
+        self.parse(
+            "minInterval",
+            min_interval,
+            Duration::from_str,
+            |element, interval| element.min_interval = Some(interval.to_string()),
+        )
+    }
+
+    /// Maximal interval between two events as xs:duration, e.g. `PT1M`
+    pub fn max_interval(self, max_interval: &str) -> Self {
+        self.parse(
+            "maxInterval",
+            max_interval,
+            Duration::from_str,
+            |element, interval| element.max_interval = Some(interval.to_string()),
+        )
+    }
+}
+
+impl Blob {
+    pub fn builder(content_type: &str) -> Builder<Blob> {
+        Builder::new(Blob::new(None, content_type.to_string()))
+    }
+}
+
+impl Builder<Blob> {
+    pub fn value(self, value: Vec<u8>) -> Self {
+        self.with(|blob| blob.value = Some(value))
+    }
+}
+
+impl Capability {
+    pub fn builder() -> Builder<Capability> {
+        Builder::new(Capability::new())
+    }
+}
+
+impl Entity {
+    pub fn builder(entity_type: EntityType) -> Builder<Entity> {
+        let inner = EntityInner::default();
+        Builder::new(match entity_type {
+            EntityType::CoManagedEntity => Entity::CoManagedEntity(inner),
+            EntityType::SelfManagedEntity => Entity::SelfManagedEntity(inner),
+        })
+    }
+}
+
+fn entity_inner(entity: &mut Entity) -> &mut EntityInner {
+    match entity {
+        Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner) => inner,
+    }
+}
+
+impl Builder<Entity> {
+    pub fn statement(self, statement: impl Into<SubmodelElement>) -> Self {
+        self.with(|entity| {
+            entity_inner(entity)
+                .statements
+                .get_or_insert_default()
+                .push(statement.into())
+        })
+    }
+
+    pub fn global_asset_id(self, global_asset_id: &str) -> Self {
+        self.parse(
+            "globalAssetId",
+            global_asset_id,
+            identifier,
+            |entity, id| entity_inner(entity).global_asset_id = Some(id),
+        )
+    }
+
+    pub fn specific_asset_id(self, specific_asset_id: SpecificAssetId) -> Self {
+        self.with(|entity| {
+            entity_inner(entity)
+                .specific_asset_id
+                .get_or_insert_default()
+                .push(specific_asset_id)
+        })
+    }
+}
+
+impl File {
+    pub fn builder(content_type: &str) -> Builder<File> {
+        Builder::new(File {
+            content_type: Some(content_type.to_string()),
+            ..Default::default()
+        })
+    }
+}
+
+impl Builder<File> {
+    /// Path or URI of the file
+    pub fn value(self, value: &str) -> Self {
+        self.parse(
+            "value",
+            value,
+            |value| Uri::new(value.as_bytes().to_vec()).map_err(|_| "not a valid URI reference"),
+            |file, uri| file.value = Some(uri),
+        )
+    }
+}
+
+impl MultiLanguageProperty {
+    pub fn builder() -> Builder<MultiLanguageProperty> {
+        Builder::new(MultiLanguageProperty::default())
+    }
+}
+
+impl Builder<MultiLanguageProperty> {
+    /// Adds the text in the given language to the value.
+    pub fn value(self, language: &str, text: &str) -> Self {
+        self.lang_string("value", language, text, |property| &mut property.value)
+    }
+}
+
+impl Operation {
+    pub fn builder() -> Builder<Operation> {
+        Builder::new(Operation::default())
+    }
+}
+
+impl Builder<Operation> {
+    pub fn input_variable(self, value: impl Into<SubmodelElement>) -> Self {
+        self.with(|operation| {
+            operation
+                .input_variables
+                .get_or_insert_default()
+                .push(OperationVariable::new(value.into()))
+        })
+    }
+
+    pub fn output_variable(self, value: impl Into<SubmodelElement>) -> Self {
+        self.with(|operation| {
+            operation
+                .output_variables
+                .get_or_insert_default()
+                .push(OperationVariable::new(value.into()))
+        })
+    }
+
+    pub fn inoutput_variable(self, value: impl Into<SubmodelElement>) -> Self {
+        self.with(|operation| {
+            operation
+                .inoutput_variables
+                .get_or_insert_default()
+                .push(OperationVariable::new(value.into()))
+        })
+    }
+}
+
+impl Property {
+    /// Builder of a property with the given value type and without value.
+    pub fn builder(value_type: DataTypeXSDef) -> Builder<Property> {
+        Builder::new(Property {
+            referable: Referable::default(),
+            semantics: HasSemantics::default(),
+            qualifiable: Qualifiable::default(),
+            embedded_data_specifications: HasDataSpecification::default(),
+            value: DataXsd::empty(value_type),
+            value_id: None,
+        })
+    }
+}
+
+impl Builder<Property> {
+    /// Sets the value from its lexical representation, which has to match the value type.
+    pub fn value(self, value: &str) -> Self {
+        let value_type = DataTypeXSDef::from(&self.element.value);
+        self.parse(
+            "value",
+            value,
+            |value| DataXsd::try_from((value_type, Some(value.to_string()))),
+            |property, value| property.value = value,
+        )
+    }
+}
+
+impl Range {
+    /// Builder of a range with the given value type, without min and max.
+    pub fn builder(value_type: DataTypeXSDef) -> Builder<Range> {
+        let value = RangeValue::try_from((value_type, None, None))
+            .expect("a range without bounds is valid for every value type");
+        Builder::new(Range::new(value))
+    }
+}
+
+impl Builder<Range> {
+    /// Replaces one bound with `value`, `set` selects the bound.
+    fn bound(
+        self,
+        attribute: &'static str,
+        value: &str,
+        set: impl FnOnce(&mut (Option<String>, Option<String>), String),
+    ) -> Self {
+        let mut bounds = self
+            .element
+            .value
+            .to_lexical()
+            .expect("the bounds were checked when they were set");
+        set(&mut bounds, value.to_string());
+        let value_type = self.element.value_type();
+
+        self.parse(
+            attribute,
+            value,
+            |_| RangeValue::try_from((value_type, bounds.0, bounds.1)),
+            |range, value| range.value = value,
+        )
+    }
+
+    /// Sets the lower bound from its lexical representation.
+    pub fn min(self, min: &str) -> Self {
+        self.bound("min", min, |bounds, min| bounds.0 = Some(min))
+    }
+
+    /// Sets the upper bound from its lexical representation.
+    pub fn max(self, max: &str) -> Self {
+        self.bound("max", max, |bounds, max| bounds.1 = Some(max))
+    }
+}
+
+impl ReferenceElement {
+    pub fn builder() -> Builder<ReferenceElement> {
+        Builder::new(ReferenceElement::default())
+    }
+}
+
+impl Builder<ReferenceElement> {
+    pub fn value(self, value: Reference) -> Self {
+        self.with(|element| element.value = Some(value))
+    }
+}
+
+impl SubmodelElementCollection {
+    pub fn builder() -> Builder<SubmodelElementCollection> {
+        Builder::new(SubmodelElementCollection::default())
+    }
+}
+
+impl Builder<SubmodelElementCollection> {
+    pub fn element(self, element: impl Into<SubmodelElement>) -> Self {
+        self.with(|collection| {
+            collection
+                .value
+                .get_or_insert_default()
+                .push(element.into())
+        })
+    }
+}
+
+impl SubmodelElementList {
+    /// Builder of an ordered list of elements of the given type.
+    pub fn builder(type_value_list_element: AasSubmodelElements) -> Builder<SubmodelElementList> {
+        Builder::new(SubmodelElementList {
+            referable: Referable::default(),
+            semantics: HasSemantics::default(),
+            qualifiable: Qualifiable::default(),
+            embedded_data_specifications: HasDataSpecification::default(),
+            is_order_relevant: true,
+            semantic_id_list_element: None,
+            value: None,
+            type_value_list_element,
+            value_type_list_element: None,
+        })
+    }
+}
+
+impl Builder<SubmodelElementList> {
+    pub fn order_relevant(self, is_order_relevant: bool) -> Self {
+        self.with(|list| list.is_order_relevant = is_order_relevant)
+    }
+
+    pub fn semantic_id_list_element(self, semantic_id: Reference) -> Self {
+        self.with(|list| list.semantic_id_list_element = Some(semantic_id))
+    }
+
+    /// Required for lists of Properties and Ranges (AASd-109)
+    pub fn value_type_list_element(self, value_type: DataTypeXSDef) -> Self {
+        self.with(|list| list.value_type_list_element = Some(value_type))
+    }
+
+    pub fn element(self, element: impl Into<SubmodelElement>) -> Self {
+        self.with(|list| list.value.get_or_insert_default().push(element.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::key::Key;
+    use crate::part1::v3_1::reference::ReferenceInner;
+
+    fn speed(value: &str) -> Builder<Property> {
+        Property::builder(DataTypeXSDef::Int).value(value)
+    }
+
+    #[test]
+    fn build_submodel() {
+        let submodel = Submodel::builder("https://example.com/sm/1")
+            .id_short("Motor")
+            .description("en", "Main motor")
+            .kind(ModellingKind::Instance)
+            .element(speed("100").id_short("Speed").build().unwrap())
+            .element(
+                SubmodelElementList::builder(AasSubmodelElements::Property)
+                    .id_short("Limits")
+                    .value_type_list_element(DataTypeXSDef::Int)
+                    .element(speed("1").build().unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            submodel.identifiable.id.to_string(),
+            "https://example.com/sm/1"
+        );
+        assert_eq!(
+            submodel
+                .identifiable
+                .referable
+                .description
+                .as_ref()
+                .unwrap()[0]
+                .text,
+            "Main motor"
+        );
+        assert_eq!(submodel.submodel_elements.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn invalid_attributes() {
+        assert!(matches!(
+            speed("fast").build(),
+            Err(BuildError::InvalidAttribute {
+                attribute: "value",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Submodel::builder("").build(),
+            Err(BuildError::InvalidAttribute {
+                attribute: "id",
+                ..
+            })
+        ));
+        assert_eq!(
+            Range::builder(DataTypeXSDef::Int)
+                .min("1")
+                .max("x")
+                .min("2")
+                .build()
+                .unwrap_err()
+                .to_string(),
+            "Invalid max 'x': 'x' is not a valid lexical representation of xs:int"
+        );
+        let observed = Reference::ModelReference(ReferenceInner::new(Key::Submodel(
+            "https://example.com/sm/1".into(),
+        )));
+        let event = BasicEventElement::builder(observed, Direction::Output, StateOfEvent::On)
+            .id_short("Alarm")
+            .min_interval("PT1S");
+        assert_eq!(
+            event
+                .clone()
+                .max_interval("PT1M")
+                .build()
+                .unwrap()
+                .max_interval,
+            Some("PT1M".into())
+        );
+        assert_eq!(
+            event
+                .max_interval("1 minute")
+                .max_interval("PT1M")
+                .build()
+                .unwrap_err()
+                .to_string(),
+            "Invalid maxInterval '1 minute': '1 minute' does not match the lexical representation of xs:duration"
+        );
+        let range = Range::builder(DataTypeXSDef::Int)
+            .min("1")
+            .max("5")
+            .build()
+            .unwrap();
+        assert_eq!(
+            range.value.to_lexical().unwrap(),
+            (Some("1".into()), Some("5".into()))
+        );
+    }
+
+    #[test]
+    fn build_checks_constraints() {
+        // AASd-108: all elements of a list have the type of typeValueListElement
+        let error = SubmodelElementList::builder(AasSubmodelElements::Property)
+            .value_type_list_element(DataTypeXSDef::Int)
+            .element(speed("1").build().unwrap())
+            .element(Capability::builder().build().unwrap())
+            .build()
+            .unwrap_err();
+        let BuildError::Constraints(report) = error else {
+            panic!("expected a constraint violation");
+        };
+        assert_eq!(report.violations[0].constraint, "AASd-108");
+
+        // AASd-131: the asset has to be identified
+        let shell = AssetAdministrationShell::builder(
+            "https://example.com/aas/1",
+            AssetInformation::Instance,
+        );
+        assert!(matches!(
+            shell.clone().build(),
+            Err(BuildError::Constraints(_))
+        ));
+        let shell = shell
+            .global_asset_id("https://example.com/asset/1")
+            .submodel(Reference::ModelReference(ReferenceInner::new(
+                Key::Submodel("https://example.com/sm/1".into()),
+            )))
+            .build()
+            .unwrap();
+        assert_eq!(
+            shell
+                .asset_information
+                .global_asset_id
+                .as_ref()
+                .unwrap()
+                .to_string(),
+            "https://example.com/asset/1"
+        );
+    }
+}
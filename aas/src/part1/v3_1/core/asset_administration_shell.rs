@@ -1,11 +1,10 @@
-use crate::part1::ToJsonMetamodel;
 use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::identifiable::Identifiable;
 use crate::part1::v3_1::attributes::semantics::HasSemantics;
 use crate::part1::v3_1::primitives::{ContentType, Identifier, Label, Uri};
 use crate::part1::v3_1::reference::Reference;
 
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 use strum::{Display, EnumString};
 
 #[derive(Clone, PartialEq, Debug)]
@@ -26,7 +25,7 @@ pub struct AssetAdministrationShell {
 
 #[derive(Clone, PartialEq, Debug)]
 
-pub struct AssetAdministrationShellMetamodel {
+pub struct AssetAdministrationShellMeta {
     pub identifiable: Identifiable,
 
     pub data_specification: HasDataSpecification,
@@ -34,7 +33,7 @@ pub struct AssetAdministrationShellMetamodel {
     pub derived_from: Option<Reference>,
 }
 
-impl From<AssetAdministrationShell> for AssetAdministrationShellMetamodel {
+impl From<AssetAdministrationShell> for AssetAdministrationShellMeta {
     fn from(value: AssetAdministrationShell) -> Self {
         Self {
             identifiable: value.identifiable,
@@ -44,6 +43,12 @@ impl From<AssetAdministrationShell> for AssetAdministrationShellMetamodel {
     }
 }
 
+impl From<&AssetAdministrationShell> for AssetAdministrationShellMeta {
+    fn from(value: &AssetAdministrationShell) -> Self {
+        value.clone().into()
+    }
+}
+
 #[derive(Clone, PartialEq, Debug, EnumString, Display)]
 pub enum AssetInformation {
     Instance(AssetInformationInner),
@@ -65,6 +70,17 @@ impl Deref for AssetInformation {
     }
 }
 
+impl DerefMut for AssetInformation {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            AssetInformation::Instance(i)
+            | AssetInformation::NotApplicable(i)
+            | AssetInformation::Role(i)
+            | AssetInformation::Type(i) => i,
+        }
+    }
+}
+
 // TODO: Skip option serialization
 #[derive(Clone, PartialEq, Debug, Default)]
 
@@ -1,4 +1,3 @@
-use crate::part1::ToJsonMetamodel;
 use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::identifiable::Identifiable;
 use crate::part1::v3_1::attributes::kind::ModellingKind;
@@ -22,6 +21,24 @@ pub struct Submodel {
     pub submodel_elements: Option<Vec<SubmodelElement>>,
 }
 
+impl Submodel {
+    /// Removes the values of all Blobs in the submodel,
+    /// as the Part 2 modifier `extent=withoutBlobValue` requires.
+    pub fn strip_blob_values(&mut self) {
+        self.submodel_elements
+            .iter_mut()
+            .flatten()
+            .for_each(SubmodelElement::strip_blob_values);
+    }
+
+    /// Same as [Submodel::strip_blob_values], but takes and returns the submodel,
+    /// e.g. to serialize it afterwards.
+    pub fn without_blob_values(mut self) -> Self {
+        self.strip_blob_values();
+        self
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct SubmodelMeta {
     pub identifiable: Identifiable,
@@ -45,3 +62,9 @@ impl From<Submodel> for SubmodelMeta {
         }
     }
 }
+
+impl From<&Submodel> for SubmodelMeta {
+    fn from(value: &Submodel) -> Self {
+        value.clone().into()
+    }
+}
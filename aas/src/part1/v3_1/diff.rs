@@ -0,0 +1,894 @@
+//! Structural diff of environments, submodels and submodel elements.
+//!
+//! Identifiables are matched by id, submodel elements by idShortPath. Elements in a
+//! SubmodelElementList are matched by index, so inserting in the middle of a list shows up
+//! as modified elements followed by an added one. An element whose type changed is removed
+//! and added again.
+//!
+//! A [Diff] can be applied to the old state to get the new one, and serialized as JSON
+//! report with the `$metadata` and ValueOnly representation of the changed elements.
+//! The report is not meant to be read back, the `aas-json` crate stores a diff with the
+//! complete new state of every change, so it can be applied later.
+
+use crate::part1::v3_1::attributes::identifiable::Identifiable;
+use crate::part1::v3_1::concept_description::ConceptDescription;
+use crate::part1::v3_1::core::{
+    AssetAdministrationShell, AssetAdministrationShellMeta, Submodel, SubmodelMeta,
+};
+use crate::part1::v3_1::environment::Environment;
+use crate::part1::v3_1::id_short_path::{ElementMut, ElementRef, IdShortPath, InsertError, Level};
+use crate::part1::v3_1::metadata::ToMetadata;
+use crate::part1::v3_1::primitives::Identifier;
+use crate::part1::v3_1::primitives::data_type_def_xs::ConversionError;
+use crate::part1::v3_1::resolver::Referred;
+use crate::part1::v3_1::submodel_elements::{DataElement, Entity, SubmodelElement};
+use crate::part1::v3_1::value_only::{ValueOnlyError, element_value, named};
+use serde::{Serialize, Serializer, ser};
+use serde_json::{Map, Value, json};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::mem;
+use thiserror::Error;
+
+/// What a [Change] applies to.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Target {
+    Shell(Identifier),
+    Submodel(Identifier),
+    ConceptDescription(Identifier),
+
+    /// Element of a submodel. Without submodel for the diff of two submodel elements,
+    /// the path then starts with the idShort of the compared element.
+    Element {
+        submodel: Option<Identifier>,
+        path: IdShortPath,
+    },
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Target::Shell(id) => write!(f, "shell '{id}'"),
+            Target::Submodel(id) => write!(f, "submodel '{id}'"),
+            Target::ConceptDescription(id) => write!(f, "concept description '{id}'"),
+            Target::Element {
+                submodel: Some(id),
+                path,
+            } => write!(f, "element '{path}' of submodel '{id}'"),
+            Target::Element {
+                submodel: None,
+                path,
+            } => write!(f, "element '{path}'"),
+        }
+    }
+}
+
+/// The new state of a changed or added target.
+/// Submodels and container elements of modifications come without their elements,
+/// changes below them are separate [Change]s.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Item {
+    Shell(AssetAdministrationShell),
+    Submodel(Submodel),
+    ConceptDescription(ConceptDescription),
+    Element(SubmodelElement),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Change {
+    Added {
+        target: Target,
+        item: Item,
+    },
+    Removed {
+        target: Target,
+    },
+
+    /// The value changed, see the ValueOnly serialization for what is a value.
+    ValueChanged {
+        target: Target,
+        item: Item,
+    },
+
+    /// Any attribute except the value and child elements changed.
+    MetadataChanged {
+        target: Target,
+        item: Item,
+    },
+}
+
+impl Change {
+    pub fn target(&self) -> &Target {
+        match self {
+            Change::Added { target, .. }
+            | Change::Removed { target }
+            | Change::ValueChanged { target, .. }
+            | Change::MetadataChanged { target, .. } => target,
+        }
+    }
+}
+
+/// Changes in the order they have to be applied.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ApplyError {
+    #[error("There is no {0}")]
+    NotFound(Target),
+
+    #[error("The {0} exists already")]
+    Exists(Target),
+
+    #[error("Cannot add the {target}: {error}")]
+    Insert { target: Target, error: InsertError },
+
+    #[error("The change of the {0} does not fit its target")]
+    Mismatch(Target),
+}
+
+// ---------------------------------------------------------------------------
+// diff
+
+impl Environment {
+    /// Changes from `self` to `new`.
+    pub fn diff(&self, new: &Environment) -> Diff {
+        let mut changes = Vec::new();
+
+        identifiables(
+            &self.asset_administration_shells,
+            &new.asset_administration_shells,
+            &mut changes,
+        );
+        identifiables(&self.submodels, &new.submodels, &mut changes);
+        identifiables(
+            &self.concept_descriptions,
+            &new.concept_descriptions,
+            &mut changes,
+        );
+
+        Diff { changes }
+    }
+}
+
+trait DiffIdentifiable: PartialEq + Sized {
+    fn id(&self) -> &Identifier;
+
+    fn target(&self) -> Target;
+
+    fn item(&self) -> Item;
+
+    fn from_item(item: &Item) -> Option<Self>;
+
+    /// Takes over a modification.
+    fn replace(&mut self, new: Self) {
+        *self = new;
+    }
+
+    /// Identifiables are compared as a whole by default.
+    fn changes(&self, new: &Self, changes: &mut Vec<Change>) {
+        if self != new {
+            changes.push(Change::MetadataChanged {
+                target: new.target(),
+                item: new.item(),
+            });
+        }
+    }
+}
+
+impl DiffIdentifiable for AssetAdministrationShell {
+    fn id(&self) -> &Identifier {
+        &self.identifiable.id
+    }
+
+    fn target(&self) -> Target {
+        Target::Shell(self.identifiable.id.clone())
+    }
+
+    fn item(&self) -> Item {
+        Item::Shell(self.clone())
+    }
+
+    fn from_item(item: &Item) -> Option<Self> {
+        match item {
+            Item::Shell(shell) => Some(shell.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl DiffIdentifiable for Submodel {
+    fn id(&self) -> &Identifier {
+        &self.identifiable.id
+    }
+
+    fn target(&self) -> Target {
+        Target::Submodel(self.identifiable.id.clone())
+    }
+
+    fn item(&self) -> Item {
+        Item::Submodel(self.clone())
+    }
+
+    fn from_item(item: &Item) -> Option<Self> {
+        match item {
+            Item::Submodel(submodel) => Some(submodel.clone()),
+            _ => None,
+        }
+    }
+
+    /// Modifications of a submodel come without elements.
+    fn replace(&mut self, new: Self) {
+        replace_keeping_elements(self, new);
+    }
+
+    fn changes(&self, new: &Self, changes: &mut Vec<Change>) {
+        changes.extend(self.diff(new).changes)
+    }
+}
+
+impl DiffIdentifiable for ConceptDescription {
+    fn id(&self) -> &Identifier {
+        &self.identifiable.id
+    }
+
+    fn target(&self) -> Target {
+        Target::ConceptDescription(self.identifiable.id.clone())
+    }
+
+    fn item(&self) -> Item {
+        Item::ConceptDescription(self.clone())
+    }
+
+    fn from_item(item: &Item) -> Option<Self> {
+        match item {
+            Item::ConceptDescription(cd) => Some(cd.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Matches identifiables by id: removed ones first, then modified, then added.
+fn identifiables<T: DiffIdentifiable>(
+    old: &Option<Vec<T>>,
+    new: &Option<Vec<T>>,
+    changes: &mut Vec<Change>,
+) {
+    fn find<'a, T: DiffIdentifiable>(items: &'a Option<Vec<T>>, id: &Identifier) -> Option<&'a T> {
+        items.iter().flatten().find(|item| item.id() == id)
+    }
+
+    for old_item in old.iter().flatten() {
+        if find(new, old_item.id()).is_none() {
+            changes.push(Change::Removed {
+                target: old_item.target(),
+            });
+        }
+    }
+    for new_item in new.iter().flatten() {
+        match find(old, new_item.id()) {
+            Some(old_item) => old_item.changes(new_item, changes),
+            None => changes.push(Change::Added {
+                target: new_item.target(),
+                item: new_item.item(),
+            }),
+        }
+    }
+}
+
+impl Submodel {
+    /// Changes from `self` to `new`, which should have the same id.
+    pub fn diff(&self, new: &Submodel) -> Diff {
+        let mut changes = Vec::new();
+
+        if without_elements(self) != without_elements(new) {
+            changes.push(Change::MetadataChanged {
+                target: Target::Submodel(new.identifiable.id.clone()),
+                item: Item::Submodel(without_elements(new)),
+            });
+        }
+
+        let submodel = Some(&self.identifiable.id);
+        elements(submodel, self, new, &mut changes);
+        Diff { changes }
+    }
+}
+
+impl SubmodelElement {
+    /// Changes from `self` to `new`. The paths start with the idShort of the element.
+    pub fn diff(&self, new: &SubmodelElement) -> Diff {
+        let mut changes = Vec::new();
+        elements(None, &wrap(self.clone()), &wrap(new.clone()), &mut changes);
+        Diff { changes }
+    }
+}
+
+/// Compares the elements of two submodels by idShortPath.
+fn elements(
+    submodel: Option<&Identifier>,
+    old: &Submodel,
+    new: &Submodel,
+    changes: &mut Vec<Change>,
+) {
+    let target = |path: &IdShortPath| Target::Element {
+        submodel: submodel.cloned(),
+        path: path.clone(),
+    };
+    let old_paths = old.id_short_paths(Level::Deep);
+    let new_paths = new.id_short_paths(Level::Deep);
+    let old_elements: HashMap<_, _> = old_paths
+        .iter()
+        .filter_map(|path| Some((path, shallow(old.get(path)?))))
+        .collect();
+    let new_elements: HashMap<_, _> = new_paths
+        .iter()
+        .filter_map(|path| Some((path, shallow(new.get(path)?))))
+        .collect();
+
+    let same_type = |path: &IdShortPath| match (old_elements.get(path), new_elements.get(path)) {
+        (Some(old), Some(new)) => model_type(old) == model_type(new),
+        _ => false,
+    };
+    // only the topmost element of a removed or added subtree is reported
+    let below = |path: &IdShortPath, paths: &HashSet<&IdShortPath>| {
+        path.parent().is_some_and(|parent| paths.contains(&parent))
+    };
+
+    // removed in reverse order, so indices of list elements stay valid
+    let mut removed = HashSet::new();
+    for path in &old_paths {
+        if !same_type(path) || below(path, &removed) {
+            removed.insert(path);
+        }
+    }
+    for path in old_paths.iter().rev() {
+        if removed.contains(path) && !below(path, &removed) {
+            changes.push(Change::Removed {
+                target: target(path),
+            });
+        }
+    }
+
+    for path in &old_paths {
+        if removed.contains(path) {
+            continue;
+        }
+        let (old, new) = (&old_elements[path], &new_elements[path]);
+        if old == new {
+            continue;
+        }
+
+        let value_changed =
+            element_value(Referred::from(old)).ok() != element_value(Referred::from(new)).ok();
+        let metadata_changed = old.to_metadata().map(|m| m.to_json()).ok()
+            != new.to_metadata().map(|m| m.to_json()).ok();
+        if value_changed {
+            changes.push(Change::ValueChanged {
+                target: target(path),
+                item: Item::Element(new.clone()),
+            });
+        }
+        if metadata_changed || !value_changed {
+            changes.push(Change::MetadataChanged {
+                target: target(path),
+                item: Item::Element(new.clone()),
+            });
+        }
+    }
+
+    let mut added = HashSet::new();
+    for path in &new_paths {
+        if !same_type(path) || below(path, &added) {
+            added.insert(path);
+        }
+    }
+    for path in &new_paths {
+        if added.contains(path) && !below(path, &added) {
+            let element = new.get(path).expect("enumerated path");
+            changes.push(Change::Added {
+                target: target(path),
+                item: Item::Element(owned(element)),
+            });
+        }
+    }
+}
+
+fn model_type(element: &SubmodelElement) -> mem::Discriminant<Referred<'_>> {
+    mem::discriminant(&Referred::from(element))
+}
+
+fn owned(element: ElementRef) -> SubmodelElement {
+    match element {
+        ElementRef::SubmodelElement(element) => element.clone(),
+        ElementRef::Annotation(annotation) => annotation.clone().into(),
+    }
+}
+
+/// The element without its child elements.
+fn shallow(element: ElementRef) -> SubmodelElement {
+    let mut element = match owned(element) {
+        SubmodelElement::DataElement(element) => element.into(),
+        element => element,
+    };
+    take_children(&mut element);
+    element
+}
+
+/// Removes the child elements of a container and returns them.
+fn take_children(element: &mut SubmodelElement) -> Children {
+    match element {
+        SubmodelElement::SubmodelElementCollection(collection) => {
+            Children::Elements(collection.value.take())
+        }
+        SubmodelElement::SubmodelElementList(list) => Children::Elements(list.value.take()),
+        SubmodelElement::Entity(Entity::CoManagedEntity(inner))
+        | SubmodelElement::Entity(Entity::SelfManagedEntity(inner)) => {
+            Children::Elements(inner.statements.take())
+        }
+        SubmodelElement::AnnotatedRelationshipElement(element) => {
+            Children::Annotations(element.annotations.take())
+        }
+        _ => Children::None,
+    }
+}
+
+enum Children {
+    None,
+    Elements(Option<Vec<SubmodelElement>>),
+    Annotations(Option<Vec<DataElement>>),
+}
+
+fn without_elements(submodel: &Submodel) -> Submodel {
+    Submodel {
+        submodel_elements: None,
+        ..submodel.clone()
+    }
+}
+
+/// Submodel with the element as only child, to address the element by idShortPath.
+fn wrap(element: SubmodelElement) -> Submodel {
+    Submodel {
+        identifiable: Identifiable {
+            id: Identifier::try_from("_").expect("valid identifier"),
+            administration: None,
+            referable: Default::default(),
+        },
+        kind: None,
+        semantics: Default::default(),
+        qualifier: Default::default(),
+        data_specification: Default::default(),
+        submodel_elements: Some(vec![element]),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// apply
+
+impl Environment {
+    /// Applies the changes in order. Nothing is changed if one of them fails.
+    pub fn apply(&mut self, diff: &Diff) -> Result<(), ApplyError> {
+        let mut environment = self.clone();
+        for change in &diff.changes {
+            environment.apply_change(change)?;
+        }
+        *self = environment;
+        Ok(())
+    }
+
+    fn apply_change(&mut self, change: &Change) -> Result<(), ApplyError> {
+        let target = change.target();
+        match target {
+            Target::Shell(id) => {
+                apply_identifiable(&mut self.asset_administration_shells, id, change)
+            }
+            Target::Submodel(id) => apply_identifiable(&mut self.submodels, id, change),
+            Target::ConceptDescription(id) => {
+                apply_identifiable(&mut self.concept_descriptions, id, change)
+            }
+            Target::Element {
+                submodel: Some(id), ..
+            } => self
+                .submodels
+                .iter_mut()
+                .flatten()
+                .find(|submodel| &submodel.identifiable.id == id)
+                .ok_or_else(|| ApplyError::NotFound(Target::Submodel(id.clone())))?
+                .apply_change(change),
+            Target::Element { submodel: None, .. } => Err(ApplyError::NotFound(target.clone())),
+        }
+    }
+}
+
+fn replace_keeping_elements(submodel: &mut Submodel, new: Submodel) {
+    let elements = submodel.submodel_elements.take();
+    *submodel = Submodel {
+        submodel_elements: elements,
+        ..new
+    };
+}
+
+fn apply_identifiable<T: DiffIdentifiable>(
+    items: &mut Option<Vec<T>>,
+    id: &Identifier,
+    change: &Change,
+) -> Result<(), ApplyError> {
+    let target = change.target();
+    let position = items.iter().flatten().position(|item| item.id() == id);
+    let new = |item: &Item| T::from_item(item).ok_or_else(|| ApplyError::Mismatch(target.clone()));
+
+    match (change, position) {
+        (Change::Added { item, .. }, None) => items.get_or_insert_default().push(new(item)?),
+        (Change::Added { .. }, Some(_)) => return Err(ApplyError::Exists(target.clone())),
+        (Change::Removed { .. }, Some(i)) => {
+            items.as_mut().expect("found").remove(i);
+        }
+        (Change::ValueChanged { item, .. } | Change::MetadataChanged { item, .. }, Some(i)) => {
+            items.as_mut().expect("found")[i].replace(new(item)?)
+        }
+        (_, None) => return Err(ApplyError::NotFound(target.clone())),
+    }
+    Ok(())
+}
+
+impl Submodel {
+    /// Applies the changes in order. Nothing is changed if one of them fails.
+    pub fn apply(&mut self, diff: &Diff) -> Result<(), ApplyError> {
+        let mut submodel = self.clone();
+        for change in &diff.changes {
+            submodel.apply_change(change)?;
+        }
+        *self = submodel;
+        Ok(())
+    }
+
+    fn apply_change(&mut self, change: &Change) -> Result<(), ApplyError> {
+        let target = change.target();
+        let path = match target {
+            Target::Submodel(id) if *id == self.identifiable.id => {
+                return match change {
+                    Change::ValueChanged {
+                        item: Item::Submodel(new),
+                        ..
+                    }
+                    | Change::MetadataChanged {
+                        item: Item::Submodel(new),
+                        ..
+                    } => {
+                        replace_keeping_elements(self, new.clone());
+                        Ok(())
+                    }
+                    _ => Err(ApplyError::Mismatch(target.clone())),
+                };
+            }
+            Target::Element { submodel, path }
+                if submodel
+                    .as_ref()
+                    .is_none_or(|id| *id == self.identifiable.id) =>
+            {
+                path
+            }
+            _ => return Err(ApplyError::NotFound(target.clone())),
+        };
+
+        match change {
+            Change::Added {
+                item: Item::Element(element),
+                ..
+            } => {
+                if self.get(path).is_some() {
+                    return Err(ApplyError::Exists(target.clone()));
+                }
+                self.insert(path, element.clone())
+                    .map_err(|error| ApplyError::Insert {
+                        target: target.clone(),
+                        error,
+                    })
+            }
+            Change::Removed { .. } => self
+                .remove(path)
+                .map(|_| ())
+                .ok_or_else(|| ApplyError::NotFound(target.clone())),
+            Change::ValueChanged {
+                item: Item::Element(new),
+                ..
+            }
+            | Change::MetadataChanged {
+                item: Item::Element(new),
+                ..
+            } => match self.get_mut(path) {
+                Some(ElementMut::SubmodelElement(element)) => {
+                    if model_type(element) != model_type(new) {
+                        return Err(ApplyError::Mismatch(target.clone()));
+                    }
+                    let children = take_children(element);
+                    *element = new.clone();
+                    set_children(element, children);
+                    Ok(())
+                }
+                Some(ElementMut::Annotation(annotation)) => {
+                    *annotation = DataElement::try_from(new.clone())
+                        .map_err(|_| ApplyError::Mismatch(target.clone()))?;
+                    Ok(())
+                }
+                None => Err(ApplyError::NotFound(target.clone())),
+            },
+            _ => Err(ApplyError::Mismatch(target.clone())),
+        }
+    }
+}
+
+fn set_children(element: &mut SubmodelElement, children: Children) {
+    match (element, children) {
+        (SubmodelElement::SubmodelElementCollection(collection), Children::Elements(children)) => {
+            collection.value = children
+        }
+        (SubmodelElement::SubmodelElementList(list), Children::Elements(children)) => {
+            list.value = children
+        }
+        (
+            SubmodelElement::Entity(Entity::CoManagedEntity(inner))
+            | SubmodelElement::Entity(Entity::SelfManagedEntity(inner)),
+            Children::Elements(children),
+        ) => inner.statements = children,
+        (
+            SubmodelElement::AnnotatedRelationshipElement(element),
+            Children::Annotations(children),
+        ) => element.annotations = children,
+        _ => {}
+    }
+}
+
+impl SubmodelElement {
+    /// Applies the changes of [SubmodelElement::diff] in order.
+    /// Nothing is changed if one of them fails.
+    pub fn apply(&mut self, diff: &Diff) -> Result<(), ApplyError> {
+        let mut submodel = wrap(self.clone());
+        submodel.apply(diff)?;
+
+        match submodel.submodel_elements.take().as_deref() {
+            Some([element]) => {
+                *self = element.clone();
+                Ok(())
+            }
+            // the element itself was removed
+            _ => Err(ApplyError::Mismatch(
+                diff.changes
+                    .first()
+                    .map(|change| change.target().clone())
+                    .expect("a diff changing the element has changes"),
+            )),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// json
+
+impl Target {
+    fn to_json(&self) -> Value {
+        match self {
+            Target::Shell(id) => json!({ "shell": id }),
+            Target::Submodel(id) => json!({ "submodel": id }),
+            Target::ConceptDescription(id) => json!({ "conceptDescription": id }),
+            Target::Element { submodel, path } => {
+                let mut object = Map::new();
+                if let Some(id) = submodel {
+                    object.insert("submodel".into(), json!(id));
+                }
+                object.insert("idShortPath".into(), json!(path.to_string()));
+                Value::Object(object)
+            }
+        }
+    }
+}
+
+impl Item {
+    fn metadata(&self) -> Result<Value, ConversionError> {
+        Ok(match self {
+            Item::Shell(shell) => AssetAdministrationShellMeta::from(shell).to_metadata(),
+            Item::Submodel(submodel) => SubmodelMeta::from(submodel).to_metadata(),
+            Item::ConceptDescription(cd) => cd.to_metadata(),
+            Item::Element(element) => element.to_metadata(),
+        }?
+        .to_json())
+    }
+
+    fn value(&self) -> Result<Option<Value>, ValueOnlyError> {
+        Ok(match self {
+            Item::Shell(_) | Item::ConceptDescription(_) => None,
+            Item::Submodel(submodel) => Some(named(
+                submodel
+                    .submodel_elements
+                    .iter()
+                    .flatten()
+                    .map(Referred::from),
+            )?),
+            Item::Element(element) => element_value(Referred::from(element))?,
+        })
+    }
+}
+
+/// One entry per change with `op`, `target` and, depending on the change,
+/// the ValueOnly `value` and the `$metadata` of the new state.
+impl Serialize for Change {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (op, value, metadata) = match self {
+            Change::Added { item, .. } => {
+                let value = item.value().map_err(ser::Error::custom)?;
+                let metadata = item.metadata().map_err(ser::Error::custom)?;
+                ("added", value, Some(metadata))
+            }
+            Change::Removed { .. } => ("removed", None, None),
+            Change::ValueChanged { item, .. } => {
+                let value = item.value().map_err(ser::Error::custom)?;
+                ("valueChanged", Some(value.unwrap_or(Value::Null)), None)
+            }
+            Change::MetadataChanged { item, .. } => {
+                let metadata = item.metadata().map_err(ser::Error::custom)?;
+                ("metadataChanged", None, Some(metadata))
+            }
+        };
+
+        let mut object = Map::new();
+        object.insert("op".into(), json!(op));
+        object.insert("target".into(), self.target().to_json());
+        if let Some(value) = value {
+            object.insert("value".into(), value);
+        }
+        if let Some(metadata) = metadata {
+            object.insert("metadata".into(), metadata);
+        }
+        Value::Object(object).serialize(serializer)
+    }
+}
+
+impl Serialize for Diff {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.changes.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::fixtures::{self, environment, int_property};
+    use crate::part1::v3_1::primitives::data_type_def_xs::DataTypeXSDef;
+    use crate::part1::v3_1::submodel_elements::{
+        AasSubmodelElements, Property, SubmodelElementCollection, SubmodelElementList,
+    };
+
+    fn submodel(
+        speed: i32,
+        description: &str,
+        limits: &[i32],
+        extra: Option<Property>,
+    ) -> Submodel {
+        let mut list = SubmodelElementList::builder(AasSubmodelElements::Property)
+            .id_short("Limits")
+            .value_type_list_element(DataTypeXSDef::Int);
+        for limit in limits {
+            list = list.element(int_property(None, *limit));
+        }
+        let mut motor = SubmodelElementCollection::builder()
+            .id_short("Motor")
+            .description("en", description)
+            .element(int_property(Some("Speed"), speed))
+            .element(list.build().unwrap());
+        if let Some(extra) = extra {
+            motor = motor.element(extra);
+        }
+
+        fixtures::submodel(vec![motor.build().unwrap().into()])
+    }
+
+    fn paths(diff: &Diff) -> Vec<(&'static str, String)> {
+        diff.changes
+            .iter()
+            .map(|change| {
+                let op = match change {
+                    Change::Added { .. } => "added",
+                    Change::Removed { .. } => "removed",
+                    Change::ValueChanged { .. } => "value",
+                    Change::MetadataChanged { .. } => "metadata",
+                };
+                let Target::Element { path, .. } = change.target() else {
+                    panic!("expected an element change");
+                };
+                (op, path.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_and_apply_submodel() {
+        let old = submodel(1, "Motor", &[1, 2, 3], None);
+        let new = submodel(
+            2,
+            "Main motor",
+            &[1, 5],
+            Some(int_property(Some("Torque"), 7)),
+        );
+        let diff = old.diff(&new);
+
+        assert_eq!(
+            paths(&diff),
+            vec![
+                ("removed", "Motor.Limits[2]".into()),
+                ("metadata", "Motor".into()),
+                ("value", "Motor.Speed".into()),
+                ("value", "Motor.Limits[1]".into()),
+                ("added", "Motor.Torque".into()),
+            ]
+        );
+        assert!(old.diff(&old).is_empty());
+
+        let mut applied = old.clone();
+        applied.apply(&diff).unwrap();
+        assert_eq!(applied, new);
+
+        // the diff does not fit the new state, which is left unchanged
+        let mut unchanged = new.clone();
+        assert!(matches!(
+            unchanged.apply(&diff),
+            Err(ApplyError::NotFound(_))
+        ));
+        assert_eq!(unchanged, new);
+    }
+
+    #[test]
+    fn diff_elements_and_environment() {
+        let old = SubmodelElement::from(int_property(Some("Speed"), 1));
+        let renamed = SubmodelElement::from(int_property(Some("Velocity"), 1));
+        assert_eq!(
+            paths(&old.diff(&renamed)),
+            vec![("removed", "Speed".into()), ("added", "Velocity".into())]
+        );
+        let mut applied = old.clone();
+        applied.apply(&old.diff(&renamed)).unwrap();
+        assert_eq!(applied, renamed);
+
+        let changed = SubmodelElement::from(int_property(Some("Speed"), 3));
+        let mut applied = old.clone();
+        applied.apply(&old.diff(&changed)).unwrap();
+        assert_eq!(applied, changed);
+
+        let old_env = environment(vec![submodel(1, "Motor", &[], None)]);
+        let mut new_env = environment(vec![submodel(2, "Motor", &[], None)]);
+        new_env.concept_descriptions = Some(vec![
+            ConceptDescription::builder("https://example.com/cd/1")
+                .build()
+                .unwrap(),
+        ]);
+        let diff = old_env.diff(&new_env);
+        assert_eq!(diff.changes.len(), 2);
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(
+            json[0],
+            json!({
+                "op": "valueChanged",
+                "target": { "submodel": "https://example.com/sm/1", "idShortPath": "Motor.Speed" },
+                "value": 2,
+            })
+        );
+        assert_eq!(json[1]["op"], "added");
+        assert_eq!(
+            json[1]["target"],
+            json!({ "conceptDescription": "https://example.com/cd/1" })
+        );
+        assert_eq!(json[1]["metadata"]["modelType"], "ConceptDescription");
+
+        let mut applied = old_env.clone();
+        applied.apply(&diff).unwrap();
+        assert_eq!(applied, new_env);
+    }
+}
@@ -0,0 +1,62 @@
+//! Model fixtures shared by the test suites, so every suite builds its models the same way.
+
+use crate::part1::v3_1::attributes::identifiable::Identifiable;
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::core::Submodel;
+use crate::part1::v3_1::environment::Environment;
+use crate::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+use crate::part1::v3_1::submodel_elements::{Property, SubmodelElement};
+
+/// Id of the submodel built by [submodel]
+pub(crate) const SUBMODEL_ID: &str = "https://example.com/sm/1";
+
+pub(crate) fn referable(id_short: &str) -> Referable {
+    Referable {
+        id_short: Some(id_short.try_into().unwrap()),
+        ..Default::default()
+    }
+}
+
+/// Property without idShort if `id_short` is `None`, e.g. for list elements
+pub(crate) fn property(id_short: Option<&str>, value: DataXsd) -> Property {
+    Property {
+        referable: id_short.map(referable).unwrap_or_default(),
+        semantics: Default::default(),
+        qualifiable: Default::default(),
+        embedded_data_specifications: Default::default(),
+        value,
+        value_id: None,
+    }
+}
+
+pub(crate) fn int_property(id_short: Option<&str>, value: i32) -> Property {
+    property(id_short, DataXsd::Int(Some(value)))
+}
+
+pub(crate) fn identifiable(id: &str) -> Identifiable {
+    Identifiable {
+        id: id.try_into().unwrap(),
+        administration: None,
+        referable: Referable::default(),
+    }
+}
+
+/// Submodel with the id [SUBMODEL_ID]
+pub(crate) fn submodel(elements: Vec<SubmodelElement>) -> Submodel {
+    Submodel {
+        identifiable: identifiable(SUBMODEL_ID),
+        kind: None,
+        semantics: Default::default(),
+        qualifier: Default::default(),
+        data_specification: Default::default(),
+        submodel_elements: Some(elements),
+    }
+}
+
+pub(crate) fn environment(submodels: Vec<Submodel>) -> Environment {
+    Environment {
+        asset_administration_shells: None,
+        submodels: Some(submodels),
+        concept_descriptions: None,
+    }
+}
@@ -0,0 +1,1190 @@
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::core::Submodel;
+use crate::part1::v3_1::key::Key;
+use crate::part1::v3_1::reference::{Reference, ReferenceInner};
+use crate::part1::v3_1::resolver::Referred;
+use crate::part1::v3_1::submodel_elements::{
+    AnnotatedRelationshipElement, DataElement, Entity, Operation, OperationVariable,
+    SubmodelElement, SubmodelElementCollection, SubmodelElementList,
+};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use strum::{Display as StrumDisplay, EnumString};
+use thiserror::Error;
+
+/// Maximum length of an idShort (NameType)
+const ID_SHORT_MAX_LENGTH: usize = 128;
+
+/// Path to a submodel element relative to a Submodel or a container element,
+/// as used by the Part 2 API, e.g. `Motor.Params[2].Speed`.
+///
+/// Elements of a SubmodelElementList are addressed by their index, all others by idShort.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct IdShortPath(Vec<PathSegment>);
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PathSegment {
+    IdShort(String),
+    Index(usize),
+}
+
+/// Depth of the returned structure, the `level` query parameter of the Part 2 API.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, StrumDisplay, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Level {
+    /// Only the direct children
+    Core,
+
+    /// The whole subtree
+    #[default]
+    Deep,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum IdShortPathError {
+    #[error("The idShortPath is empty")]
+    Empty,
+
+    #[error("Expected an idShort at position {position}")]
+    MissingIdShort { position: usize },
+
+    #[error(
+        "Invalid idShort '{id_short}' at position {position}: it has to start with a letter, \
+         contain only letters, digits, '_' and '-' and be at most 128 characters long"
+    )]
+    InvalidIdShort { position: usize, id_short: String },
+
+    #[error("Invalid list index '{index}' at position {position}")]
+    InvalidIndex { position: usize, index: String },
+
+    #[error("Missing ']' for the '[' at position {position}")]
+    UnclosedBracket { position: usize },
+
+    #[error("Unexpected character '{found}' at position {position}, expected '.' or '['")]
+    UnexpectedCharacter { position: usize, found: char },
+
+    #[error(
+        "The reference has to be a ModelReference to a Submodel followed by at least one element key"
+    )]
+    NoSubmodelElementReference,
+
+    #[error("Key {index} ('{value}') is neither a valid idShort nor a list index")]
+    InvalidKey { index: usize, value: String },
+}
+
+impl IdShortPath {
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Path of the element one level above, `None` for a single segment path.
+    pub fn parent(&self) -> Option<IdShortPath> {
+        match self.0.split_last() {
+            Some((_, parent)) if !parent.is_empty() => Some(IdShortPath(parent.to_vec())),
+            _ => None,
+        }
+    }
+
+    /// Appends a segment, e.g. to build the path of a child element.
+    pub fn join(&self, segment: PathSegment) -> IdShortPath {
+        let mut segments = self.0.clone();
+        segments.push(segment);
+        IdShortPath(segments)
+    }
+
+    fn split_last(&self) -> (&PathSegment, &[PathSegment]) {
+        self.0
+            .split_last()
+            .expect("an IdShortPath has at least one segment")
+    }
+}
+
+/// Path of a single segment, e.g. a top level element of a submodel.
+impl From<PathSegment> for IdShortPath {
+    fn from(segment: PathSegment) -> Self {
+        IdShortPath(vec![segment])
+    }
+}
+
+/// AASd-002: starts with a letter, continues with letters, digits, '_' and '-', and does not end
+/// with '-'.
+pub(crate) fn is_valid_id_short(id_short: &str) -> bool {
+    let mut chars = id_short.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && !id_short.ends_with('-')
+        && id_short.len() <= ID_SHORT_MAX_LENGTH
+}
+
+impl FromStr for IdShortPath {
+    type Err = IdShortPathError;
+
+    /// Parses paths like `Motor.Params[2].Speed`.
+    /// A path may start with an index when it is relative to a SubmodelElementList, e.g. `[0].Speed`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(IdShortPathError::Empty);
+        }
+
+        let mut segments = Vec::new();
+        let mut position = 0;
+
+        while position < s.len() {
+            let rest = &s[position..];
+
+            if let Some(rest) = rest.strip_prefix('[') {
+                let end = rest
+                    .find(']')
+                    .ok_or(IdShortPathError::UnclosedBracket { position })?;
+                let index = &rest[..end];
+                let parsed = index
+                    .bytes()
+                    .all(|b| b.is_ascii_digit())
+                    .then(|| index.parse().ok())
+                    .flatten()
+                    .ok_or_else(|| IdShortPathError::InvalidIndex {
+                        position: position + 1,
+                        index: index.to_string(),
+                    })?;
+
+                segments.push(PathSegment::Index(parsed));
+                position += end + 2;
+            } else {
+                // idShorts follow the start of the path or a '.'
+                let start = match rest.strip_prefix('.') {
+                    Some(_) if !segments.is_empty() => position + 1,
+                    None if segments.is_empty() => position,
+                    _ => {
+                        return Err(IdShortPathError::UnexpectedCharacter {
+                            position,
+                            found: rest.chars().next().unwrap_or_default(),
+                        });
+                    }
+                };
+                let end = s[start..]
+                    .find(['.', '['])
+                    .map_or(s.len(), |end| start + end);
+                let id_short = &s[start..end];
+
+                if id_short.is_empty() {
+                    return Err(IdShortPathError::MissingIdShort { position: start });
+                }
+                if !is_valid_id_short(id_short) {
+                    return Err(IdShortPathError::InvalidIdShort {
+                        position: start,
+                        id_short: id_short.to_string(),
+                    });
+                }
+
+                segments.push(PathSegment::IdShort(id_short.to_string()));
+                position = end;
+            }
+        }
+
+        Ok(IdShortPath(segments))
+    }
+}
+
+impl Display for PathSegment {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PathSegment::IdShort(id_short) => write!(f, "{id_short}"),
+            PathSegment::Index(index) => write!(f, "[{index}]"),
+        }
+    }
+}
+
+impl Display for IdShortPath {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            if i > 0 && matches!(segment, PathSegment::IdShort(_)) {
+                write!(f, ".")?;
+            }
+            write!(f, "{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the element keys of a ModelReference starting with a Submodel key.
+/// Key values consisting of digits only are list indices, since idShorts have to start with a letter.
+impl TryFrom<&Reference> for IdShortPath {
+    type Error = IdShortPathError;
+
+    fn try_from(reference: &Reference) -> Result<Self, Self::Error> {
+        let Reference::ModelReference(inner) = reference else {
+            return Err(IdShortPathError::NoSubmodelElementReference);
+        };
+        let Some((Key::Submodel(_), keys)) = inner.keys.split_first() else {
+            return Err(IdShortPathError::NoSubmodelElementReference);
+        };
+        if keys.is_empty() {
+            return Err(IdShortPathError::NoSubmodelElementReference);
+        }
+
+        keys.iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let value = key.value();
+                if matches!(key, Key::FragmentReference(_) | Key::GlobalReference(_)) {
+                    Err(())
+                } else if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+                    value.parse().map(PathSegment::Index).map_err(|_| ())
+                } else if is_valid_id_short(value) {
+                    Ok(PathSegment::IdShort(value.clone()))
+                } else {
+                    Err(())
+                }
+                .map_err(|_| IdShortPathError::InvalidKey {
+                    index: i + 1,
+                    value: value.clone(),
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(IdShortPath)
+    }
+}
+
+/// Element found at an idShortPath.
+/// Annotations of an AnnotatedRelationshipElement are DataElements, all other children SubmodelElements.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ElementRef<'a> {
+    SubmodelElement(&'a SubmodelElement),
+    Annotation(&'a DataElement),
+}
+
+#[derive(PartialEq, Debug)]
+pub enum ElementMut<'a> {
+    SubmodelElement(&'a mut SubmodelElement),
+    Annotation(&'a mut DataElement),
+}
+
+impl<'a> ElementRef<'a> {
+    pub fn referable(&self) -> &'a Referable {
+        match *self {
+            ElementRef::SubmodelElement(element) => element.referable(),
+            ElementRef::Annotation(annotation) => annotation.referable(),
+        }
+    }
+}
+
+impl<'a> From<ElementRef<'a>> for Referred<'a> {
+    fn from(element: ElementRef<'a>) -> Self {
+        match element {
+            ElementRef::SubmodelElement(element) => Referred::from(element),
+            ElementRef::Annotation(annotation) => Referred::from(annotation),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum InsertError {
+    #[error("The parent of the path does not exist or can not contain elements")]
+    ParentNotFound,
+
+    #[error("The idShort of the element does not match the last segment of the path")]
+    IdShortMismatch,
+
+    #[error("An element with idShort '{0}' already exists")]
+    Duplicate(String),
+
+    #[error("Index {index} is out of range for a list with {len} elements")]
+    IndexOutOfRange { index: usize, len: usize },
+
+    #[error("List elements are addressed by index, all other elements by idShort")]
+    SegmentMismatch,
+
+    #[error("Annotations have to be DataElements")]
+    NoDataElement,
+
+    #[error("Operation variables can not be inserted by path, it does not tell their direction")]
+    OperationVariable,
+}
+
+/// The child elements of a Submodel or container element.
+#[derive(Clone, Copy)]
+enum Children<'a> {
+    Named(&'a Option<Vec<SubmodelElement>>),
+    Indexed(&'a Option<Vec<SubmodelElement>>),
+    Annotations(&'a Option<Vec<DataElement>>),
+    /// The input, output and inoutput variables of an Operation, addressed by idShort
+    Variables([&'a Option<Vec<OperationVariable>>; 3]),
+}
+
+enum ChildrenMut<'a> {
+    Named(&'a mut Option<Vec<SubmodelElement>>),
+    Indexed(&'a mut Option<Vec<SubmodelElement>>),
+    Annotations(&'a mut Option<Vec<DataElement>>),
+    Variables([&'a mut Option<Vec<OperationVariable>>; 3]),
+}
+
+fn has_id_short(referable: &Referable, id_short: &str) -> bool {
+    referable.id_short.as_deref() == Some(id_short)
+}
+
+fn children(element: &SubmodelElement) -> Option<Children<'_>> {
+    match element {
+        SubmodelElement::SubmodelElementCollection(collection) => Some(collection.children()),
+        SubmodelElement::SubmodelElementList(list) => Some(list.children()),
+        SubmodelElement::Entity(entity) => Some(entity.children()),
+        SubmodelElement::AnnotatedRelationshipElement(element) => Some(element.children()),
+        SubmodelElement::Operation(operation) => Some(operation.children()),
+        _ => None,
+    }
+}
+
+fn children_mut(element: &mut SubmodelElement) -> Option<ChildrenMut<'_>> {
+    match element {
+        SubmodelElement::SubmodelElementCollection(collection) => Some(collection.children_mut()),
+        SubmodelElement::SubmodelElementList(list) => Some(list.children_mut()),
+        SubmodelElement::Entity(entity) => Some(entity.children_mut()),
+        SubmodelElement::AnnotatedRelationshipElement(element) => Some(element.children_mut()),
+        SubmodelElement::Operation(operation) => Some(operation.children_mut()),
+        _ => None,
+    }
+}
+
+impl<'a> Children<'a> {
+    fn child(self, segment: &PathSegment) -> Option<ElementRef<'a>> {
+        match (self, segment) {
+            (Children::Named(elements), PathSegment::IdShort(id_short)) => elements
+                .iter()
+                .flatten()
+                .find(|element| has_id_short(element.referable(), id_short))
+                .map(ElementRef::SubmodelElement),
+            (Children::Indexed(elements), PathSegment::Index(index)) => elements
+                .as_ref()?
+                .get(*index)
+                .map(ElementRef::SubmodelElement),
+            (Children::Annotations(annotations), PathSegment::IdShort(id_short)) => annotations
+                .iter()
+                .flatten()
+                .find(|annotation| has_id_short(annotation.referable(), id_short))
+                .map(ElementRef::Annotation),
+            (Children::Variables(variables), PathSegment::IdShort(id_short)) => variables
+                .into_iter()
+                .flatten()
+                .flatten()
+                .find(|variable| has_id_short(variable.value.referable(), id_short))
+                .map(|variable| ElementRef::SubmodelElement(&variable.value)),
+            _ => None,
+        }
+    }
+
+    fn iter(self) -> impl Iterator<Item = (PathSegment, ElementRef<'a>)> {
+        let (named, indexed, annotations, variables) = match self {
+            Children::Named(elements) => (elements.as_deref(), None, None, None),
+            Children::Indexed(elements) => (None, elements.as_deref(), None, None),
+            Children::Annotations(annotations) => (None, None, annotations.as_deref(), None),
+            Children::Variables(variables) => (None, None, None, Some(variables)),
+        };
+
+        // elements without idShort can not be addressed, they violate AASd-117 anyway
+        let named = named.into_iter().flatten().filter_map(|element| {
+            let id_short = element.referable().id_short.as_ref()?;
+            Some((
+                PathSegment::IdShort(id_short.to_string()),
+                ElementRef::SubmodelElement(element),
+            ))
+        });
+        let indexed = indexed
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, element)| (PathSegment::Index(i), ElementRef::SubmodelElement(element)));
+        let annotations = annotations.into_iter().flatten().filter_map(|annotation| {
+            let id_short = annotation.referable().id_short.as_ref()?;
+            Some((
+                PathSegment::IdShort(id_short.to_string()),
+                ElementRef::Annotation(annotation),
+            ))
+        });
+
+        let variables = variables
+            .into_iter()
+            .flatten()
+            .flatten()
+            .flatten()
+            .filter_map(|variable| {
+                let id_short = variable.value.referable().id_short.as_ref()?;
+                Some((
+                    PathSegment::IdShort(id_short.to_string()),
+                    ElementRef::SubmodelElement(&variable.value),
+                ))
+            });
+
+        named.chain(indexed).chain(annotations).chain(variables)
+    }
+
+    /// Collects the paths of the children below `prefix` in pre-order, the children of
+    /// children only for [Level::Deep].
+    fn paths(self, prefix: &IdShortPath, level: Level, paths: &mut Vec<IdShortPath>) {
+        for (segment, element) in self.iter() {
+            let path = prefix.join(segment);
+            paths.push(path.clone());
+
+            if let (Level::Deep, ElementRef::SubmodelElement(element)) = (level, element)
+                && let Some(children) = children(element)
+            {
+                children.paths(&path, level, paths);
+            }
+        }
+    }
+
+    fn id_short_paths(self, level: Level) -> Vec<IdShortPath> {
+        let mut paths = Vec::new();
+        self.paths(&IdShortPath(Vec::new()), level, &mut paths);
+        paths
+    }
+
+    fn get(self, path: &IdShortPath) -> Option<ElementRef<'a>> {
+        let (first, rest) = path.0.split_first()?;
+        let mut found = self.child(first)?;
+
+        for segment in rest {
+            let ElementRef::SubmodelElement(element) = found else {
+                return None;
+            };
+            found = children(element)?.child(segment)?;
+        }
+
+        Some(found)
+    }
+}
+
+impl<'a> ChildrenMut<'a> {
+    fn child(self, segment: &PathSegment) -> Option<ElementMut<'a>> {
+        match (self, segment) {
+            (ChildrenMut::Named(elements), PathSegment::IdShort(id_short)) => elements
+                .iter_mut()
+                .flatten()
+                .find(|element| has_id_short(element.referable(), id_short))
+                .map(ElementMut::SubmodelElement),
+            (ChildrenMut::Indexed(elements), PathSegment::Index(index)) => elements
+                .as_mut()?
+                .get_mut(*index)
+                .map(ElementMut::SubmodelElement),
+            (ChildrenMut::Annotations(annotations), PathSegment::IdShort(id_short)) => annotations
+                .iter_mut()
+                .flatten()
+                .find(|annotation| has_id_short(annotation.referable(), id_short))
+                .map(ElementMut::Annotation),
+            (ChildrenMut::Variables(variables), PathSegment::IdShort(id_short)) => variables
+                .into_iter()
+                .flatten()
+                .flatten()
+                .find(|variable| has_id_short(variable.value.referable(), id_short))
+                .map(|variable| ElementMut::SubmodelElement(&mut variable.value)),
+            _ => None,
+        }
+    }
+
+    fn descend(self, segments: &[PathSegment]) -> Option<ElementMut<'a>> {
+        let (first, rest) = segments.split_first()?;
+        let mut found = self.child(first)?;
+
+        for segment in rest {
+            let ElementMut::SubmodelElement(element) = found else {
+                return None;
+            };
+            found = children_mut(element)?.child(segment)?;
+        }
+
+        Some(found)
+    }
+
+    fn get(self, path: &IdShortPath) -> Option<ElementMut<'a>> {
+        self.descend(&path.0)
+    }
+
+    /// Children of the element the path points to.
+    fn parent(self, segments: &[PathSegment]) -> Option<ChildrenMut<'a>> {
+        if segments.is_empty() {
+            return Some(self);
+        }
+
+        match self.descend(segments)? {
+            ElementMut::SubmodelElement(element) => children_mut(element),
+            ElementMut::Annotation(_) => None,
+        }
+    }
+
+    fn insert(self, path: &IdShortPath, element: SubmodelElement) -> Result<(), InsertError> {
+        let (last, parent) = path.split_last();
+        let children = self.parent(parent).ok_or(InsertError::ParentNotFound)?;
+
+        let check_id_short = |id_short: &str, referable: &Referable| {
+            if has_id_short(referable, id_short) {
+                Ok(())
+            } else {
+                Err(InsertError::IdShortMismatch)
+            }
+        };
+
+        match (children, last) {
+            (ChildrenMut::Named(elements), PathSegment::IdShort(id_short)) => {
+                check_id_short(id_short, element.referable())?;
+
+                let elements = elements.get_or_insert_with(Vec::new);
+                if elements
+                    .iter()
+                    .any(|e| has_id_short(e.referable(), id_short))
+                {
+                    return Err(InsertError::Duplicate(id_short.clone()));
+                }
+                elements.push(element);
+            }
+            (ChildrenMut::Indexed(elements), PathSegment::Index(index)) => {
+                let elements = elements.get_or_insert_with(Vec::new);
+                if *index > elements.len() {
+                    return Err(InsertError::IndexOutOfRange {
+                        index: *index,
+                        len: elements.len(),
+                    });
+                }
+                elements.insert(*index, element);
+            }
+            (ChildrenMut::Annotations(annotations), PathSegment::IdShort(id_short)) => {
+                check_id_short(id_short, element.referable())?;
+                let annotation =
+                    DataElement::try_from(element).map_err(|_| InsertError::NoDataElement)?;
+
+                let annotations = annotations.get_or_insert_with(Vec::new);
+                if annotations
+                    .iter()
+                    .any(|a| has_id_short(a.referable(), id_short))
+                {
+                    return Err(InsertError::Duplicate(id_short.clone()));
+                }
+                annotations.push(annotation);
+            }
+            (ChildrenMut::Variables(_), _) => return Err(InsertError::OperationVariable),
+            _ => return Err(InsertError::SegmentMismatch),
+        }
+
+        Ok(())
+    }
+
+    fn remove(self, path: &IdShortPath) -> Option<SubmodelElement> {
+        let (last, parent) = path.split_last();
+
+        match (self.parent(parent)?, last) {
+            (ChildrenMut::Named(elements), PathSegment::IdShort(id_short)) => {
+                let elements = elements.as_mut()?;
+                let position = elements
+                    .iter()
+                    .position(|e| has_id_short(e.referable(), id_short))?;
+                Some(elements.remove(position))
+            }
+            (ChildrenMut::Indexed(elements), PathSegment::Index(index)) => {
+                let elements = elements.as_mut()?;
+                (*index < elements.len()).then(|| elements.remove(*index))
+            }
+            (ChildrenMut::Annotations(annotations), PathSegment::IdShort(id_short)) => {
+                let annotations = annotations.as_mut()?;
+                let position = annotations
+                    .iter()
+                    .position(|a| has_id_short(a.referable(), id_short))?;
+                Some(annotations.remove(position).into())
+            }
+            (ChildrenMut::Variables(variables), PathSegment::IdShort(id_short)) => {
+                variables.into_iter().flatten().find_map(|variables| {
+                    let position = variables
+                        .iter()
+                        .position(|v| has_id_short(v.value.referable(), id_short))?;
+                    Some(variables.remove(position).value)
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn key(element: ElementRef, value: String) -> Key {
+    match Referred::from(element) {
+        Referred::RelationshipElement(_) => Key::RelationshipElement(value),
+        Referred::AnnotatedRelationshipElement(_) => Key::AnnotatedRelationshipElement(value),
+        Referred::BasicEventElement(_) => Key::BasicEventElement(value),
+        Referred::Blob(_) => Key::Blob(value),
+        Referred::Capability(_) => Key::Capability(value),
+        Referred::Entity(_) => Key::Entity(value),
+        Referred::File(_) => Key::File(value),
+        Referred::MultiLanguageProperty(_) => Key::MultiLanguageProperty(value),
+        Referred::Operation(_) => Key::Operation(value),
+        Referred::Property(_) => Key::Property(value),
+        Referred::Range(_) => Key::Range(value),
+        Referred::ReferenceElement(_) => Key::ReferenceElement(value),
+        Referred::SubmodelElementCollection(_) => Key::SubmodelElementCollection(value),
+        Referred::SubmodelElementList(_) => Key::SubmodelElementList(value),
+        Referred::AssetAdministrationShell(_)
+        | Referred::Submodel(_)
+        | Referred::ConceptDescription(_) => Key::SubmodelElement(value),
+    }
+}
+
+impl Submodel {
+    fn children(&self) -> Children<'_> {
+        Children::Named(&self.submodel_elements)
+    }
+
+    fn children_mut(&mut self) -> ChildrenMut<'_> {
+        ChildrenMut::Named(&mut self.submodel_elements)
+    }
+
+    /// Returns the element at the path.
+    pub fn get(&self, path: &IdShortPath) -> Option<ElementRef<'_>> {
+        self.children().get(path)
+    }
+
+    pub fn get_mut(&mut self, path: &IdShortPath) -> Option<ElementMut<'_>> {
+        self.children_mut().get(path)
+    }
+
+    /// Inserts the element at the path. The last segment has to be the idShort of the element,
+    /// or the position it is inserted at in a SubmodelElementList.
+    pub fn insert(
+        &mut self,
+        path: &IdShortPath,
+        element: SubmodelElement,
+    ) -> Result<(), InsertError> {
+        self.children_mut().insert(path, element)
+    }
+
+    /// Removes the element at the path and returns it.
+    pub fn remove(&mut self, path: &IdShortPath) -> Option<SubmodelElement> {
+        self.children_mut().remove(path)
+    }
+
+    /// The idShortPaths of the submodel elements as returned by `GET /submodel/$path`:
+    /// the top level elements for [Level::Core], all elements for [Level::Deep].
+    pub fn id_short_paths(&self, level: Level) -> Vec<IdShortPath> {
+        self.children().id_short_paths(level)
+    }
+
+    /// The idShortPaths as returned by `GET /submodel-elements/{idShortPath}/$path`:
+    /// the path itself, followed by the paths of the elements below it.
+    /// `None` if there is no element at the path.
+    pub fn id_short_paths_below(
+        &self,
+        path: &IdShortPath,
+        level: Level,
+    ) -> Option<Vec<IdShortPath>> {
+        let element = self.get(path)?;
+        let mut paths = vec![path.clone()];
+
+        if let ElementRef::SubmodelElement(element) = element
+            && let Some(children) = children(element)
+        {
+            children.paths(path, level, &mut paths);
+        }
+
+        Some(paths)
+    }
+
+    /// Builds the ModelReference to the element at the path,
+    /// with keys typed after the elements along the path.
+    pub fn reference_to(&self, path: &IdShortPath) -> Option<Reference> {
+        let mut keys = vec![Key::Submodel(self.identifiable.id.to_string())];
+        let mut children = self.children();
+
+        for (i, segment) in path.0.iter().enumerate() {
+            let element = children.child(segment)?;
+            let value = match segment {
+                PathSegment::IdShort(id_short) => id_short.clone(),
+                PathSegment::Index(index) => index.to_string(),
+            };
+            keys.push(key(element, value));
+
+            if i + 1 < path.0.len() {
+                let ElementRef::SubmodelElement(element) = element else {
+                    return None;
+                };
+                children = self::children(element)?;
+            }
+        }
+
+        Some(Reference::ModelReference(ReferenceInner::from_vec(keys)))
+    }
+}
+
+impl SubmodelElementCollection {
+    fn children(&self) -> Children<'_> {
+        Children::Named(&self.value)
+    }
+
+    fn children_mut(&mut self) -> ChildrenMut<'_> {
+        ChildrenMut::Named(&mut self.value)
+    }
+
+    /// Returns the element at the path, relative to the collection.
+    pub fn get(&self, path: &IdShortPath) -> Option<ElementRef<'_>> {
+        self.children().get(path)
+    }
+
+    pub fn get_mut(&mut self, path: &IdShortPath) -> Option<ElementMut<'_>> {
+        self.children_mut().get(path)
+    }
+
+    /// See [Submodel::insert]
+    pub fn insert(
+        &mut self,
+        path: &IdShortPath,
+        element: SubmodelElement,
+    ) -> Result<(), InsertError> {
+        self.children_mut().insert(path, element)
+    }
+
+    pub fn remove(&mut self, path: &IdShortPath) -> Option<SubmodelElement> {
+        self.children_mut().remove(path)
+    }
+
+    /// The idShortPaths relative to the collection. See [Submodel::id_short_paths]
+    pub fn id_short_paths(&self, level: Level) -> Vec<IdShortPath> {
+        self.children().id_short_paths(level)
+    }
+}
+
+impl SubmodelElementList {
+    fn children(&self) -> Children<'_> {
+        Children::Indexed(&self.value)
+    }
+
+    fn children_mut(&mut self) -> ChildrenMut<'_> {
+        ChildrenMut::Indexed(&mut self.value)
+    }
+
+    /// Returns the element at the path, relative to the list. The path starts with an index, e.g. `[0].Speed`.
+    pub fn get(&self, path: &IdShortPath) -> Option<ElementRef<'_>> {
+        self.children().get(path)
+    }
+
+    pub fn get_mut(&mut self, path: &IdShortPath) -> Option<ElementMut<'_>> {
+        self.children_mut().get(path)
+    }
+
+    /// See [Submodel::insert]
+    pub fn insert(
+        &mut self,
+        path: &IdShortPath,
+        element: SubmodelElement,
+    ) -> Result<(), InsertError> {
+        self.children_mut().insert(path, element)
+    }
+
+    pub fn remove(&mut self, path: &IdShortPath) -> Option<SubmodelElement> {
+        self.children_mut().remove(path)
+    }
+
+    /// The idShortPaths relative to the list, i.e. starting with the index, e.g. `[0]`. See [Submodel::id_short_paths]
+    pub fn id_short_paths(&self, level: Level) -> Vec<IdShortPath> {
+        self.children().id_short_paths(level)
+    }
+}
+
+impl Entity {
+    fn children(&self) -> Children<'_> {
+        match self {
+            Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner) => {
+                Children::Named(&inner.statements)
+            }
+        }
+    }
+
+    fn children_mut(&mut self) -> ChildrenMut<'_> {
+        match self {
+            Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner) => {
+                ChildrenMut::Named(&mut inner.statements)
+            }
+        }
+    }
+
+    /// Returns the statement at the path, relative to the entity.
+    pub fn get(&self, path: &IdShortPath) -> Option<ElementRef<'_>> {
+        self.children().get(path)
+    }
+
+    pub fn get_mut(&mut self, path: &IdShortPath) -> Option<ElementMut<'_>> {
+        self.children_mut().get(path)
+    }
+
+    /// See [Submodel::insert]
+    pub fn insert(
+        &mut self,
+        path: &IdShortPath,
+        element: SubmodelElement,
+    ) -> Result<(), InsertError> {
+        self.children_mut().insert(path, element)
+    }
+
+    pub fn remove(&mut self, path: &IdShortPath) -> Option<SubmodelElement> {
+        self.children_mut().remove(path)
+    }
+
+    /// The idShortPaths of the statements, relative to the entity. See [Submodel::id_short_paths]
+    pub fn id_short_paths(&self, level: Level) -> Vec<IdShortPath> {
+        self.children().id_short_paths(level)
+    }
+}
+
+impl AnnotatedRelationshipElement {
+    fn children(&self) -> Children<'_> {
+        Children::Annotations(&self.annotations)
+    }
+
+    fn children_mut(&mut self) -> ChildrenMut<'_> {
+        ChildrenMut::Annotations(&mut self.annotations)
+    }
+
+    /// Returns the annotation with the idShort of the (single segment) path.
+    pub fn get(&self, path: &IdShortPath) -> Option<ElementRef<'_>> {
+        self.children().get(path)
+    }
+
+    pub fn get_mut(&mut self, path: &IdShortPath) -> Option<ElementMut<'_>> {
+        self.children_mut().get(path)
+    }
+
+    /// See [Submodel::insert], the element has to be a DataElement.
+    pub fn insert(
+        &mut self,
+        path: &IdShortPath,
+        element: SubmodelElement,
+    ) -> Result<(), InsertError> {
+        self.children_mut().insert(path, element)
+    }
+
+    pub fn remove(&mut self, path: &IdShortPath) -> Option<SubmodelElement> {
+        self.children_mut().remove(path)
+    }
+
+    /// The idShortPaths of the annotations, relative to the element. See [Submodel::id_short_paths]
+    pub fn id_short_paths(&self, level: Level) -> Vec<IdShortPath> {
+        self.children().id_short_paths(level)
+    }
+}
+
+impl Operation {
+    fn children(&self) -> Children<'_> {
+        Children::Variables([
+            &self.input_variables,
+            &self.output_variables,
+            &self.inoutput_variables,
+        ])
+    }
+
+    fn children_mut(&mut self) -> ChildrenMut<'_> {
+        ChildrenMut::Variables([
+            &mut self.input_variables,
+            &mut self.output_variables,
+            &mut self.inoutput_variables,
+        ])
+    }
+
+    /// Returns the value of the input, output or inoutput variable at the path,
+    /// relative to the operation.
+    pub fn get(&self, path: &IdShortPath) -> Option<ElementRef<'_>> {
+        self.children().get(path)
+    }
+
+    pub fn get_mut(&mut self, path: &IdShortPath) -> Option<ElementMut<'_>> {
+        self.children_mut().get(path)
+    }
+
+    /// Removes the variable at the path and returns its value.
+    pub fn remove(&mut self, path: &IdShortPath) -> Option<SubmodelElement> {
+        self.children_mut().remove(path)
+    }
+
+    /// The idShortPaths of the variables, relative to the operation. See [Submodel::id_short_paths]
+    pub fn id_short_paths(&self, level: Level) -> Vec<IdShortPath> {
+        self.children().id_short_paths(level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::fixtures::{self, SUBMODEL_ID, int_property, referable};
+    use crate::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use crate::part1::v3_1::submodel_elements::AasSubmodelElements;
+
+    fn submodel() -> Submodel {
+        let params = SubmodelElement::SubmodelElementList(SubmodelElementList {
+            referable: referable("Params"),
+            semantics: Default::default(),
+            qualifiable: Default::default(),
+            embedded_data_specifications: Default::default(),
+            is_order_relevant: true,
+            semantic_id_list_element: None,
+            value: Some(vec![SubmodelElement::SubmodelElementCollection(
+                SubmodelElementCollection {
+                    value: Some(vec![int_property(Some("Speed"), 100).into()]),
+                    ..Default::default()
+                },
+            )]),
+            type_value_list_element: AasSubmodelElements::SubmodelElementCollection,
+            value_type_list_element: None,
+        });
+        let motor = SubmodelElement::SubmodelElementCollection(SubmodelElementCollection {
+            referable: referable("Motor"),
+            value: Some(vec![params]),
+            ..Default::default()
+        });
+
+        fixtures::submodel(vec![motor])
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let path: IdShortPath = "Motor.Params[2].Speed".parse().unwrap();
+        assert_eq!(
+            path.segments(),
+            [
+                PathSegment::IdShort("Motor".into()),
+                PathSegment::IdShort("Params".into()),
+                PathSegment::Index(2),
+                PathSegment::IdShort("Speed".into()),
+            ]
+        );
+        assert_eq!(path.to_string(), "Motor.Params[2].Speed");
+        assert_eq!(
+            "[0][1].Speed".parse::<IdShortPath>().unwrap().to_string(),
+            "[0][1].Speed"
+        );
+        assert_eq!(path.parent().unwrap().to_string(), "Motor.Params[2]");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<IdShortPath>().unwrap_err();
+
+        assert_eq!(parse(""), IdShortPathError::Empty);
+        assert_eq!(
+            parse("Motor..Speed"),
+            IdShortPathError::MissingIdShort { position: 6 }
+        );
+        assert_eq!(
+            parse("Motor.1Speed"),
+            IdShortPathError::InvalidIdShort {
+                position: 6,
+                id_short: "1Speed".into()
+            }
+        );
+        assert_eq!(
+            parse("Motor.Speed-"),
+            IdShortPathError::InvalidIdShort {
+                position: 6,
+                id_short: "Speed-".into()
+            }
+        );
+        assert_eq!(
+            parse("Params[x]"),
+            IdShortPathError::InvalidIndex {
+                position: 7,
+                index: "x".into()
+            }
+        );
+        assert_eq!(
+            parse("Params[2"),
+            IdShortPathError::UnclosedBracket { position: 6 }
+        );
+        assert_eq!(
+            parse("Params[2]Speed"),
+            IdShortPathError::UnexpectedCharacter {
+                position: 9,
+                found: 'S'
+            }
+        );
+        assert_eq!(
+            parse(".Speed").to_string(),
+            "Unexpected character '.' at position 0, expected '.' or '['"
+        );
+    }
+
+    #[test]
+    fn get_insert_remove() {
+        let mut submodel = submodel();
+        let speed: IdShortPath = "Motor.Params[0].Speed".parse().unwrap();
+
+        assert!(matches!(
+            submodel.get(&speed),
+            Some(ElementRef::SubmodelElement(SubmodelElement::Property(p))) if p.value == DataXsd::Int(Some(100))
+        ));
+        assert_eq!(submodel.get(&"Motor.Params.Speed".parse().unwrap()), None);
+
+        if let Some(ElementMut::SubmodelElement(SubmodelElement::Property(p))) =
+            submodel.get_mut(&speed)
+        {
+            p.value = DataXsd::Int(Some(200));
+        }
+        assert_eq!(
+            submodel.remove(&speed),
+            Some(int_property(Some("Speed"), 200).into())
+        );
+        assert_eq!(submodel.get(&speed), None);
+
+        assert_eq!(
+            submodel.insert(&speed, int_property(Some("Torque"), 1).into()),
+            Err(InsertError::IdShortMismatch)
+        );
+        assert_eq!(
+            submodel.insert(&speed, int_property(Some("Speed"), 300).into()),
+            Ok(())
+        );
+        assert_eq!(
+            submodel.insert(&speed, int_property(Some("Speed"), 300).into()),
+            Err(InsertError::Duplicate("Speed".into()))
+        );
+        assert_eq!(
+            submodel.insert(
+                &"Motor.Params[5]".parse().unwrap(),
+                int_property(Some("Speed"), 1).into()
+            ),
+            Err(InsertError::IndexOutOfRange { index: 5, len: 1 })
+        );
+        assert_eq!(
+            submodel.insert(
+                &"Missing.Speed".parse().unwrap(),
+                int_property(Some("Speed"), 1).into()
+            ),
+            Err(InsertError::ParentNotFound)
+        );
+
+        let ElementRef::SubmodelElement(SubmodelElement::SubmodelElementList(params)) =
+            submodel.get(&"Motor.Params".parse().unwrap()).unwrap()
+        else {
+            panic!("Params is a list");
+        };
+        assert!(params.get(&"[0].Speed".parse().unwrap()).is_some());
+    }
+
+    #[test]
+    fn enumerate_paths() {
+        let submodel = submodel();
+        let paths =
+            |paths: Vec<IdShortPath>| paths.iter().map(IdShortPath::to_string).collect::<Vec<_>>();
+
+        assert_eq!(paths(submodel.id_short_paths(Level::Core)), ["Motor"]);
+        assert_eq!(
+            paths(submodel.id_short_paths(Level::Deep)),
+            [
+                "Motor",
+                "Motor.Params",
+                "Motor.Params[0]",
+                "Motor.Params[0].Speed"
+            ]
+        );
+
+        let params: IdShortPath = "Motor.Params".parse().unwrap();
+        assert_eq!(
+            paths(submodel.id_short_paths_below(&params, Level::Core).unwrap()),
+            ["Motor.Params", "Motor.Params[0]"]
+        );
+        assert!(
+            submodel
+                .id_short_paths_below(&"Motor.Missing".parse().unwrap(), Level::Deep)
+                .is_none()
+        );
+
+        let Some(ElementRef::SubmodelElement(SubmodelElement::SubmodelElementList(list))) =
+            submodel.get(&params)
+        else {
+            panic!("Params is a list");
+        };
+        assert_eq!(
+            paths(list.id_short_paths(Level::Deep)),
+            ["[0]", "[0].Speed"]
+        );
+        assert_eq!("core".parse::<Level>().unwrap(), Level::Core);
+    }
+
+    #[test]
+    fn operation_variables() {
+        let variable = |id_short, value| OperationVariable {
+            value: int_property(Some(id_short), value).into(),
+        };
+        let operation = SubmodelElement::Operation(Operation {
+            referable: referable("Start"),
+            input_variables: Some(vec![variable("Speed", 1)]),
+            inoutput_variables: Some(vec![variable("Mode", 2)]),
+            ..Default::default()
+        });
+        let mut submodel = fixtures::submodel(vec![operation]);
+        let paths =
+            |paths: Vec<IdShortPath>| paths.iter().map(IdShortPath::to_string).collect::<Vec<_>>();
+
+        assert_eq!(
+            paths(submodel.id_short_paths(Level::Deep)),
+            ["Start", "Start.Speed", "Start.Mode"]
+        );
+
+        let mode: IdShortPath = "Start.Mode".parse().unwrap();
+        assert_eq!(
+            submodel.get(&mode),
+            Some(ElementRef::SubmodelElement(
+                &int_property(Some("Mode"), 2).into()
+            ))
+        );
+
+        // the same element as resolved from the ModelReference
+        let reference = submodel.reference_to(&mode).unwrap();
+        assert_eq!(
+            reference.keys[1..],
+            [Key::Operation("Start".into()), Key::Property("Mode".into())]
+        );
+        let environment = fixtures::environment(vec![submodel.clone()]);
+        assert_eq!(
+            environment.resolve(&reference).unwrap().target,
+            Referred::from(submodel.get(&mode).unwrap())
+        );
+
+        assert_eq!(
+            submodel.insert(
+                &"Start.Torque".parse().unwrap(),
+                int_property(Some("Torque"), 3).into()
+            ),
+            Err(InsertError::OperationVariable)
+        );
+        assert_eq!(
+            submodel.remove(&mode),
+            Some(int_property(Some("Mode"), 2).into())
+        );
+        assert_eq!(
+            paths(submodel.id_short_paths(Level::Deep)),
+            ["Start", "Start.Speed"]
+        );
+    }
+
+    #[test]
+    fn key_chain_conversion() {
+        let submodel = submodel();
+        let path: IdShortPath = "Motor.Params[0].Speed".parse().unwrap();
+
+        let reference = submodel.reference_to(&path).unwrap();
+        assert_eq!(
+            reference.keys,
+            vec![
+                Key::Submodel(SUBMODEL_ID.into()),
+                Key::SubmodelElementCollection("Motor".into()),
+                Key::SubmodelElementList("Params".into()),
+                Key::SubmodelElementCollection("0".into()),
+                Key::Property("Speed".into()),
+            ]
+        );
+        assert_eq!(IdShortPath::try_from(&reference), Ok(path));
+
+        let reference = Reference::ModelReference(ReferenceInner::from_vec(vec![
+            Key::Submodel(SUBMODEL_ID.into()),
+            Key::Property("no idShort".into()),
+        ]));
+        assert_eq!(
+            IdShortPath::try_from(&reference),
+            Err(IdShortPathError::InvalidKey {
+                index: 1,
+                value: "no idShort".into()
+            })
+        );
+    }
+}
@@ -44,3 +44,64 @@ pub enum Key {
     SubmodelElementCollection(KeyReference),
     SubmodelElementList(KeyReference),
 }
+
+impl Key {
+    /// The value of the key, i.e. the id, idShort or list index of the referred element
+    pub fn value(&self) -> &KeyReference {
+        match self {
+            Key::AnnotatedRelationshipElement(value)
+            | Key::AssetAdministrationShell(value)
+            | Key::BasicEventElement(value)
+            | Key::Blob(value)
+            | Key::Capability(value)
+            | Key::ConceptDescription(value)
+            | Key::DataElement(value)
+            | Key::Entity(value)
+            | Key::EventElement(value)
+            | Key::File(value)
+            | Key::FragmentReference(value)
+            | Key::GlobalReference(value)
+            | Key::Identifiable(value)
+            | Key::MultiLanguageProperty(value)
+            | Key::Operation(value)
+            | Key::Property(value)
+            | Key::Range(value)
+            | Key::Referable(value)
+            | Key::ReferenceElement(value)
+            | Key::RelationshipElement(value)
+            | Key::Submodel(value)
+            | Key::SubmodelElement(value)
+            | Key::SubmodelElementCollection(value)
+            | Key::SubmodelElementList(value) => value,
+        }
+    }
+
+    pub fn value_mut(&mut self) -> &mut KeyReference {
+        match self {
+            Key::AnnotatedRelationshipElement(value)
+            | Key::AssetAdministrationShell(value)
+            | Key::BasicEventElement(value)
+            | Key::Blob(value)
+            | Key::Capability(value)
+            | Key::ConceptDescription(value)
+            | Key::DataElement(value)
+            | Key::Entity(value)
+            | Key::EventElement(value)
+            | Key::File(value)
+            | Key::FragmentReference(value)
+            | Key::GlobalReference(value)
+            | Key::Identifiable(value)
+            | Key::MultiLanguageProperty(value)
+            | Key::Operation(value)
+            | Key::Property(value)
+            | Key::Range(value)
+            | Key::Referable(value)
+            | Key::ReferenceElement(value)
+            | Key::RelationshipElement(value)
+            | Key::Submodel(value)
+            | Key::SubmodelElement(value)
+            | Key::SubmodelElementCollection(value)
+            | Key::SubmodelElementList(value) => value,
+        }
+    }
+}
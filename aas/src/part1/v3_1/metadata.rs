@@ -0,0 +1,1064 @@
+//! `$metadata` serialization of all Referables, in JSON and XML.
+//!
+//! The metadata of an element consists of all its attributes except the ones
+//! that carry its value (see [ToJsonValue](crate::part1::ToJsonValue)),
+//! e.g. `value` of a Property or `statements` of an Entity.
+//! Each element is projected onto its `*Meta` type first, which is then written
+//! into an ordered [Node] tree. Both formats are rendered from that tree,
+//! so JSON and XML always contain the same attributes.
+
+use crate::part1::v3_1::LangString;
+use crate::part1::v3_1::attributes::administrative_information::AdministrativeInformation;
+use crate::part1::v3_1::attributes::data_specification::{
+    DataSpecificationContent, DataSpecificationIec61360, DataSpecificationPhysicalUnit,
+    EmbeddedDataSpecification, HasDataSpecification,
+};
+use crate::part1::v3_1::attributes::extension::{Extension, HasExtensions};
+use crate::part1::v3_1::attributes::identifiable::Identifiable;
+use crate::part1::v3_1::attributes::qualifiable::{Qualifiable, Qualifier};
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::attributes::semantics::HasSemantics;
+use crate::part1::v3_1::concept_description::ConceptDescription;
+use crate::part1::v3_1::core::{
+    AssetAdministrationShell, AssetAdministrationShellMeta, Submodel, SubmodelMeta,
+};
+use crate::part1::v3_1::primitives::data_type_def_xs::{ConversionError, DataTypeXSDef, DataXsd};
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::submodel_elements::{
+    AnnotatedRelationshipElement, AnnotatedRelationshipElementMeta, BasicEventElement,
+    BasicEventElementMeta, Blob, BlobMeta, Capability, DataElement, Entity, EntityMeta, File,
+    FileMeta, MultiLanguageProperty, MultiLanguagePropertyMeta, Operation, OperationMeta, Property,
+    PropertyMeta, Range, RangeMeta, ReferenceElement, ReferenceElementMeta, RelationshipElement,
+    RelationshipElementMeta, SubmodelElement, SubmodelElementCollection,
+    SubmodelElementCollectionMeta, SubmodelElementList, SubmodelElementListMeta,
+};
+use crate::part1::{MetamodelError, ToJsonMetamodel, ToXmlMetamodel};
+use serde_json::{Map, Value};
+use std::convert::Infallible;
+use std::fmt::Display;
+
+/// Namespace of the XML mapping of the metamodel
+pub const XML_NAMESPACE: &str = "https://admin-shell.io/aas/3/1";
+
+/// Serialized attribute, independent of the output format.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum Node {
+    Text(String),
+    Bool(bool),
+    Object(Object),
+    /// Items of a list. In XML, items without a modelType are wrapped in an element of the given name.
+    List(&'static str, Vec<Node>),
+    /// Content that is not part of the metamodel, e.g. of an unknown data specification.
+    Raw(Value),
+}
+
+/// Attributes of a (possibly typed) object, in the order the XML schema expects them.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub(crate) struct Object {
+    model_type: Option<String>,
+    fields: Vec<(String, Node)>,
+}
+
+impl Object {
+    pub(crate) fn typed(model_type: impl Into<String>) -> Self {
+        Self {
+            model_type: Some(model_type.into()),
+            fields: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, name: &str, node: Node) {
+        self.fields.push((name.to_string(), node));
+    }
+
+    pub(crate) fn text(&mut self, name: &str, value: impl Display) {
+        self.push(name, Node::Text(value.to_string()));
+    }
+
+    pub(crate) fn opt_text(&mut self, name: &str, value: Option<impl Display>) {
+        if let Some(value) = value {
+            self.text(name, value);
+        }
+    }
+
+    pub(crate) fn opt_object(&mut self, name: &str, value: Option<Object>) {
+        if let Some(value) = value {
+            self.push(name, Node::Object(value));
+        }
+    }
+
+    /// Adds a list, unless it is empty. The spec does not allow empty lists.
+    pub(crate) fn list<'a, T: 'a>(
+        &mut self,
+        name: &str,
+        item: &'static str,
+        values: impl IntoIterator<Item = &'a T>,
+        f: impl Fn(&'a T) -> Object,
+    ) {
+        let Ok(()) = self.try_list(name, item, values, |v| Ok::<_, Infallible>(f(v)));
+    }
+
+    /// Like [Object::list], for items that may fail to be written.
+    pub(crate) fn try_list<'a, T: 'a, E>(
+        &mut self,
+        name: &str,
+        item: &'static str,
+        values: impl IntoIterator<Item = &'a T>,
+        f: impl Fn(&'a T) -> Result<Object, E>,
+    ) -> Result<(), E> {
+        let items = values
+            .into_iter()
+            .map(|v| f(v).map(Node::Object))
+            .collect::<Result<Vec<_>, _>>()?;
+        if !items.is_empty() {
+            self.push(name, Node::List(item, items));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        let mut map = Map::new();
+        if let Some(model_type) = &self.model_type {
+            map.insert("modelType".into(), model_type.as_str().into());
+        }
+        for (name, node) in &self.fields {
+            map.insert(name.clone(), node.to_json());
+        }
+        Value::Object(map)
+    }
+
+    /// Writes the object as XML root element, named after its modelType.
+    pub(crate) fn to_xml(&self) -> String {
+        let mut out = String::new();
+        let name = xml_name(self.model_type.as_deref().unwrap_or("object"));
+        out.push_str(&format!(r#"<{name} xmlns="{XML_NAMESPACE}">"#));
+        self.write_fields(&mut out);
+        out.push_str(&format!("</{name}>"));
+        out
+    }
+
+    fn write_xml(&self, out: &mut String) {
+        match &self.model_type {
+            Some(model_type) => {
+                write_element(out, &xml_name(model_type), |out| self.write_fields(out))
+            }
+            None => self.write_fields(out),
+        }
+    }
+
+    fn write_fields(&self, out: &mut String) {
+        for (name, node) in &self.fields {
+            write_element(out, name, |out| node.write_xml(out));
+        }
+    }
+}
+
+impl Node {
+    fn to_json(&self) -> Value {
+        match self {
+            Node::Text(text) => Value::String(text.clone()),
+            Node::Bool(value) => Value::Bool(*value),
+            Node::Object(object) => object.to_json(),
+            Node::List(_, items) => Value::Array(items.iter().map(Node::to_json).collect()),
+            Node::Raw(value) => value.clone(),
+        }
+    }
+
+    fn write_xml(&self, out: &mut String) {
+        match self {
+            Node::Text(text) => escape(out, text),
+            Node::Bool(value) => out.push_str(&value.to_string()),
+            Node::Object(object) => object.write_xml(out),
+            Node::List(item, items) => {
+                for node in items {
+                    match node {
+                        Node::Object(object) if object.model_type.is_some() => {
+                            object.write_xml(out)
+                        }
+                        node => write_element(out, item, |out| node.write_xml(out)),
+                    }
+                }
+            }
+            Node::Raw(value) => write_raw(out, value),
+        }
+    }
+}
+
+fn write_element(out: &mut String, name: &str, content: impl FnOnce(&mut String)) {
+    out.push('<');
+    out.push_str(name);
+    out.push('>');
+    content(out);
+    out.push_str("</");
+    out.push_str(name);
+    out.push('>');
+}
+
+fn write_raw(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => {}
+        Value::String(text) => escape(out, text),
+        Value::Array(items) => {
+            for item in items {
+                write_element(out, "item", |out| write_raw(out, item));
+            }
+        }
+        Value::Object(map) => {
+            for (name, item) in map {
+                write_element(out, name, |out| write_raw(out, item));
+            }
+        }
+        value => out.push_str(&value.to_string()),
+    }
+}
+
+fn escape(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// XML element name of a modelType, e.g. `submodelElementCollection`
+pub(crate) fn xml_name(model_type: &str) -> String {
+    let mut chars = model_type.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Elements that can be written in the `$metadata` format.
+pub(crate) trait ToMetadata {
+    /// Fails if a value of the element, e.g. of a qualifier, is outside its value space.
+    fn to_metadata(&self) -> Result<Object, ConversionError>;
+}
+
+// ---------------------------------------------------------------------------
+// shared attributes
+
+fn lang_strings(object: &mut Object, name: &str, item: &'static str, values: &[LangString]) {
+    object.list(name, item, values, |lang_string| {
+        let mut object = Object::default();
+        object.text("language", &lang_string.language);
+        object.text("text", &lang_string.text);
+        object
+    });
+}
+
+pub(crate) fn reference(reference: &Reference) -> Object {
+    let mut object = Object::default();
+    object.text(
+        "type",
+        match reference {
+            Reference::ExternalReference(_) => "ExternalReference",
+            Reference::ModelReference(_) => "ModelReference",
+        },
+    );
+    object.opt_object(
+        "referredSemanticId",
+        reference
+            .referred_semantic_id
+            .as_deref()
+            .map(self::reference),
+    );
+    object.list("keys", "key", &reference.keys, |key| {
+        let mut object = Object::default();
+        object.text("type", key);
+        object.text("value", key.value());
+        object
+    });
+    object
+}
+
+fn references(object: &mut Object, name: &str, values: Option<&Vec<Reference>>) {
+    object.list(name, "reference", values.into_iter().flatten(), reference);
+}
+
+fn data_value(object: &mut Object, value: &DataXsd) -> Result<(), ConversionError> {
+    object.text("valueType", DataTypeXSDef::from(value));
+    object.opt_text("value", value.to_lexical()?);
+    Ok(())
+}
+
+fn extensions(object: &mut Object, extensions: &HasExtensions) -> Result<(), ConversionError> {
+    object.try_list(
+        "extensions",
+        "extension",
+        extensions.extension.iter().flatten(),
+        |extension: &Extension| {
+            let mut object = Object::default();
+            object.opt_object("semanticId", extension.semantic_id.as_ref().map(reference));
+            references(
+                &mut object,
+                "supplementalSemanticIds",
+                extension.supplemental_semantic_ids.as_ref(),
+            );
+            object.text("name", &extension.name);
+            data_value(&mut object, &extension.value)?;
+            references(&mut object, "refersTo", extension.refers_to.as_ref());
+            Ok(object)
+        },
+    )
+}
+
+pub(crate) fn referable(object: &mut Object, referable: &Referable) -> Result<(), ConversionError> {
+    extensions(object, &referable.extensions)?;
+    #[allow(deprecated)]
+    object.opt_text("category", referable.category.as_ref());
+    object.opt_text("idShort", referable.id_short.as_ref());
+    lang_strings(
+        object,
+        "displayName",
+        "langStringNameType",
+        referable.display_name.as_deref().unwrap_or_default(),
+    );
+    lang_strings(
+        object,
+        "description",
+        "langStringTextType",
+        referable.description.as_deref().unwrap_or_default(),
+    );
+    Ok(())
+}
+
+pub(crate) fn identifiable(
+    object: &mut Object,
+    identifiable: &Identifiable,
+) -> Result<(), ConversionError> {
+    referable(object, &identifiable.referable)?;
+    object.opt_object(
+        "administration",
+        identifiable.administration.as_ref().map(administration),
+    );
+    object.text("id", &identifiable.id);
+    Ok(())
+}
+
+fn administration(administration: &AdministrativeInformation) -> Object {
+    let mut object = Object::default();
+    data_specifications(&mut object, &administration.data_specification);
+    object.opt_text("version", administration.version.version.as_ref());
+    object.opt_text("revision", administration.version.revision.as_ref());
+    object.opt_object("creator", administration.creator.as_ref().map(reference));
+    object.opt_text("templateId", administration.template_id.as_ref());
+    object
+}
+
+pub(crate) fn semantics(object: &mut Object, semantics: &HasSemantics) {
+    object.opt_object("semanticId", semantics.semantic_id.as_ref().map(reference));
+    references(
+        object,
+        "supplementalSemanticIds",
+        semantics.supplemental_semantic_ids.as_ref(),
+    );
+}
+
+pub(crate) fn qualifiers(
+    object: &mut Object,
+    qualifiable: &Qualifiable,
+) -> Result<(), ConversionError> {
+    object.try_list(
+        "qualifiers",
+        "qualifier",
+        qualifiable.qualifiers.iter().flatten(),
+        |qualifier| {
+            let (kind, inner) = match qualifier {
+                Qualifier::ConceptQualifier(inner) => (Some("ConceptQualifier"), inner),
+                Qualifier::TemplateQualifier(inner) => (Some("TemplateQualifier"), inner),
+                Qualifier::ValueQualifier(inner) => (Some("ValueQualifier"), inner),
+                Qualifier::Unknown(inner) => (None, inner),
+            };
+            let mut object = Object::default();
+            semantics(&mut object, &inner.semantics);
+            object.opt_text("kind", kind);
+            object.text("type", &inner.ty);
+            data_value(&mut object, &inner.value)?;
+            object.opt_object("valueId", inner.value_id.as_ref().map(reference));
+            Ok(object)
+        },
+    )
+}
+
+pub(crate) fn data_specifications(object: &mut Object, data_specifications: &HasDataSpecification) {
+    object.list(
+        "embeddedDataSpecifications",
+        "embeddedDataSpecification",
+        data_specifications
+            .embedded_data_specifications
+            .iter()
+            .flatten(),
+        |embedded: &EmbeddedDataSpecification| {
+            let mut object = Object::default();
+            object.push(
+                "dataSpecification",
+                Node::Object(reference(&embedded.data_specification)),
+            );
+            object.push(
+                "dataSpecificationContent",
+                Node::Object(data_specification_content(
+                    &embedded.data_specification_content,
+                )),
+            );
+            object
+        },
+    );
+}
+
+fn data_specification_content(content: &DataSpecificationContent) -> Object {
+    match content {
+        DataSpecificationContent::DataSpecificationIec61360(content) => iec61360(content),
+        DataSpecificationContent::DataSpecificationPhysicalUnit(content) => physical_unit(content),
+        DataSpecificationContent::Unknown(content) => {
+            let mut object = Object::typed(&content.model_type);
+            for (name, value) in &content.fields {
+                object.push(name, Node::Raw(value.clone()));
+            }
+            object
+        }
+    }
+}
+
+fn iec61360(content: &DataSpecificationIec61360) -> Object {
+    let mut object = Object::typed("DataSpecificationIec61360");
+    lang_strings(
+        &mut object,
+        "preferredName",
+        "langStringPreferredNameTypeIec61360",
+        &content.preferred_name,
+    );
+    lang_strings(
+        &mut object,
+        "shortName",
+        "langStringShortNameTypeIec61360",
+        content.short_name.as_deref().unwrap_or_default(),
+    );
+    object.opt_text("unit", content.unit.as_ref());
+    object.opt_object("unitId", content.unit_id.as_ref().map(reference));
+    object.opt_text("sourceOfDefinition", content.source_of_definition.as_ref());
+    object.opt_text("symbol", content.symbol.as_ref());
+    object.opt_text("dataType", content.data_type.as_ref());
+    lang_strings(
+        &mut object,
+        "definition",
+        "langStringDefinitionTypeIec61360",
+        content.definition.as_deref().unwrap_or_default(),
+    );
+    object.opt_text("valueFormat", content.value_format.as_ref());
+    if let Some(value_list) = &content.value_list {
+        let mut list = Object::default();
+        list.list(
+            "valueReferencePairs",
+            "valueReferencePair",
+            value_list
+                .iter()
+                .flat_map(|list| &list.value_reference_pairs),
+            |pair| {
+                let mut object = Object::default();
+                object.text("value", &pair.value);
+                object.push("valueId", Node::Object(reference(&pair.value_id)));
+                object
+            },
+        );
+        object.push("valueList", Node::Object(list));
+    }
+    object.opt_text("value", content.value.as_ref());
+    if let Some(level_type) = &content.level_type {
+        let mut level = Object::default();
+        level.push("min", Node::Bool(level_type.min));
+        level.push("nom", Node::Bool(level_type.nom));
+        level.push("typ", Node::Bool(level_type.typ));
+        level.push("max", Node::Bool(level_type.max));
+        object.push("levelType", Node::Object(level));
+    }
+    object
+}
+
+fn physical_unit(content: &DataSpecificationPhysicalUnit) -> Object {
+    let mut object = Object::typed("DataSpecificationPhysicalUnit");
+    object.text("unitName", &content.unit_name);
+    object.text("unitSymbol", &content.unit_symbol);
+    lang_strings(
+        &mut object,
+        "definition",
+        "langStringDefinitionTypeIec61360",
+        &content.definition,
+    );
+    object.opt_text("siNotation", content.si_notation.as_ref());
+    object.opt_text("siName", content.si_name.as_ref());
+    object.opt_text("dinNotation", content.din_notation.as_ref());
+    object.opt_text("eceName", content.ece_name.as_ref());
+    object.opt_text("eceCode", content.ece_code.as_ref());
+    object.opt_text("nistName", content.nist_name.as_ref());
+    object.opt_text("sourceOfDefinition", content.source_of_definition.as_ref());
+    object.opt_text("conversionFactor", content.conversion_factor.as_ref());
+    object.opt_text(
+        "registrationAuthorityId",
+        content.registration_authority_id.as_ref(),
+    );
+    object.opt_text("supplier", content.supplier.as_ref());
+    object
+}
+
+/// Attributes every submodel element has
+fn submodel_element(
+    model_type: &str,
+    referable: &Referable,
+    semantics: &HasSemantics,
+    qualifiable: &Qualifiable,
+    data_specification: &HasDataSpecification,
+) -> Result<Object, ConversionError> {
+    let mut object = Object::typed(model_type);
+    self::referable(&mut object, referable)?;
+    self::semantics(&mut object, semantics);
+    qualifiers(&mut object, qualifiable)?;
+    data_specifications(&mut object, data_specification);
+    Ok(object)
+}
+
+// ---------------------------------------------------------------------------
+// projections
+
+impl ToMetadata for AssetAdministrationShellMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = Object::typed("AssetAdministrationShell");
+        identifiable(&mut object, &self.identifiable)?;
+        data_specifications(&mut object, &self.data_specification);
+        object.opt_object("derivedFrom", self.derived_from.as_ref().map(reference));
+        Ok(object)
+    }
+}
+
+impl ToMetadata for SubmodelMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = Object::typed("Submodel");
+        identifiable(&mut object, &self.identifiable)?;
+        object.opt_text("kind", self.kind.as_ref());
+        semantics(&mut object, &self.semantics);
+        qualifiers(&mut object, &self.qualifier)?;
+        data_specifications(&mut object, &self.data_specification);
+        Ok(object)
+    }
+}
+
+impl ToMetadata for ConceptDescription {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = Object::typed("ConceptDescription");
+        identifiable(&mut object, &self.identifiable)?;
+        if let Some(data_specification) = &self.data_specification {
+            data_specifications(&mut object, data_specification);
+        }
+        references(&mut object, "isCaseOf", self.is_case_of.as_ref());
+        Ok(object)
+    }
+}
+
+impl ToMetadata for RelationshipElementMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "RelationshipElement",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for AnnotatedRelationshipElementMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "AnnotatedRelationshipElement",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for BasicEventElementMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let fields = &self.submodel_element_fields;
+        let mut object = submodel_element(
+            "BasicEventElement",
+            &fields.referable,
+            &fields.semantics,
+            &fields.qualifiable,
+            &fields.embedded_data_specifications,
+        )?;
+        object.text("direction", self.direction.to_string().to_lowercase());
+        object.text("state", self.state.to_string().to_lowercase());
+        object.opt_text("messageTopic", self.message_topic.as_ref());
+        object.opt_object("messageBroker", self.message_broker.as_ref().map(reference));
+        object.opt_text("lastUpdate", self.last_update.as_ref());
+        object.opt_text("minInterval", self.min_interval.as_ref());
+        object.opt_text("maxInterval", self.max_interval.as_ref());
+        Ok(object)
+    }
+}
+
+impl ToMetadata for BlobMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "Blob",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for Capability {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "Capability",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for EntityMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = submodel_element(
+            "Entity",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )?;
+        object.text("entityType", &self.entity_type);
+        Ok(object)
+    }
+}
+
+impl ToMetadata for FileMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "File",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for MultiLanguagePropertyMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "MultiLanguageProperty",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for OperationMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "Operation",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for PropertyMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = submodel_element(
+            "Property",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )?;
+        object.text("valueType", &self.value_type);
+        Ok(object)
+    }
+}
+
+impl ToMetadata for RangeMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = submodel_element(
+            "Range",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )?;
+        object.text("valueType", &self.value_type);
+        Ok(object)
+    }
+}
+
+impl ToMetadata for ReferenceElementMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "ReferenceElement",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for SubmodelElementCollectionMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        submodel_element(
+            "SubmodelElementCollection",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )
+    }
+}
+
+impl ToMetadata for SubmodelElementListMeta {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        let mut object = submodel_element(
+            "SubmodelElementList",
+            &self.referable,
+            &self.semantics,
+            &self.qualifiable,
+            &self.embedded_data_specifications,
+        )?;
+        object.push("orderRelevant", Node::Bool(self.is_order_relevant));
+        object.opt_object(
+            "semanticIdListElement",
+            self.semantic_id_list_element.as_ref().map(reference),
+        );
+        object.text("typeValueListElement", &self.type_value_list_element);
+        object.opt_text(
+            "valueTypeListElement",
+            self.value_type_list_element.as_ref(),
+        );
+        Ok(object)
+    }
+}
+
+impl ToMetadata for DataElement {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        match self {
+            DataElement::Blob(element) => BlobMeta::from(element).to_metadata(),
+            DataElement::File(element) => FileMeta::from(element).to_metadata(),
+            DataElement::MultiLanguageProperty(element) => {
+                MultiLanguagePropertyMeta::from(element).to_metadata()
+            }
+            DataElement::Property(element) => PropertyMeta::from(element).to_metadata(),
+            DataElement::Range(element) => RangeMeta::from(element).to_metadata(),
+            DataElement::ReferenceElement(element) => {
+                ReferenceElementMeta::from(element).to_metadata()
+            }
+        }
+    }
+}
+
+impl ToMetadata for SubmodelElement {
+    fn to_metadata(&self) -> Result<Object, ConversionError> {
+        match self {
+            SubmodelElement::RelationshipElement(element) => {
+                RelationshipElementMeta::from(element).to_metadata()
+            }
+            SubmodelElement::AnnotatedRelationshipElement(element) => {
+                AnnotatedRelationshipElementMeta::from(element).to_metadata()
+            }
+            SubmodelElement::BasicEventElement(element) => {
+                BasicEventElementMeta::from(element).to_metadata()
+            }
+            SubmodelElement::Blob(element) => BlobMeta::from(element).to_metadata(),
+            SubmodelElement::Capability(element) => element.to_metadata(),
+            SubmodelElement::DataElement(element) => element.to_metadata(),
+            SubmodelElement::Entity(element) => EntityMeta::from(element).to_metadata(),
+            SubmodelElement::File(element) => FileMeta::from(element).to_metadata(),
+            SubmodelElement::MultiLanguageProperty(element) => {
+                MultiLanguagePropertyMeta::from(element).to_metadata()
+            }
+            SubmodelElement::Operation(element) => OperationMeta::from(element).to_metadata(),
+            SubmodelElement::Property(element) => PropertyMeta::from(element).to_metadata(),
+            SubmodelElement::Range(element) => RangeMeta::from(element).to_metadata(),
+            SubmodelElement::ReferenceElement(element) => {
+                ReferenceElementMeta::from(element).to_metadata()
+            }
+            SubmodelElement::SubmodelElementCollection(element) => {
+                SubmodelElementCollectionMeta::from(element).to_metadata()
+            }
+            SubmodelElement::SubmodelElementList(element) => {
+                SubmodelElementListMeta::from(element).to_metadata()
+            }
+        }
+    }
+}
+
+/// Implements [ToJsonMetamodel] and [ToXmlMetamodel] for elements,
+/// either through their `*Meta` projection or directly, if they have no value attributes.
+macro_rules! impl_metamodel {
+    ($($ty:ty $(=> $meta:ty)?),* $(,)?) => {$(
+        impl ToJsonMetamodel for $ty {
+            type Error = MetamodelError;
+
+            fn to_json_metamodel(&self) -> Result<String, Self::Error> {
+                serde_json::to_string(&impl_metamodel!(@object self $(, $meta)?)?.to_json())
+                    .map_err(MetamodelError::FailedSerialisation)
+            }
+        }
+
+        impl ToXmlMetamodel for $ty {
+            type Error = MetamodelError;
+
+            fn to_xml_metamodel(&self) -> Result<String, Self::Error> {
+                Ok(impl_metamodel!(@object self $(, $meta)?)?.to_xml())
+            }
+        }
+    )*};
+    (@object $element:ident) => { $element.to_metadata() };
+    (@object $element:ident, $meta:ty) => { <$meta>::from($element).to_metadata() };
+}
+
+impl_metamodel!(
+    AssetAdministrationShell => AssetAdministrationShellMeta,
+    Submodel => SubmodelMeta,
+    ConceptDescription,
+    SubmodelElement,
+    DataElement,
+    RelationshipElement => RelationshipElementMeta,
+    AnnotatedRelationshipElement => AnnotatedRelationshipElementMeta,
+    BasicEventElement => BasicEventElementMeta,
+    Blob => BlobMeta,
+    Capability,
+    Entity => EntityMeta,
+    File => FileMeta,
+    MultiLanguageProperty => MultiLanguagePropertyMeta,
+    Operation => OperationMeta,
+    Property => PropertyMeta,
+    Range => RangeMeta,
+    ReferenceElement => ReferenceElementMeta,
+    SubmodelElementCollection => SubmodelElementCollectionMeta,
+    SubmodelElementList => SubmodelElementListMeta,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::attributes::data_specification::DataTypeIec61360;
+    use crate::part1::v3_1::attributes::qualifiable::QualifierInner;
+    use crate::part1::v3_1::fixtures::{property, referable};
+    use crate::part1::v3_1::key::Key;
+    use crate::part1::v3_1::reference::ReferenceInner;
+    use crate::part1::v3_1::submodel_elements::{AasSubmodelElements, EntityInner};
+    use serde_json::json;
+
+    fn semantic_id() -> Reference {
+        Reference::ExternalReference(ReferenceInner::new(Key::GlobalReference(
+            "https://example.com/ids/cd/1".into(),
+        )))
+    }
+
+    #[test]
+    fn property_metadata_omits_value() {
+        let mut property = property(Some("Speed"), DataXsd::Double(Some(1.5)));
+        property.semantics.semantic_id = Some(semantic_id());
+        property.qualifiable.qualifiers = Some(vec![Qualifier::ValueQualifier(QualifierInner {
+            semantics: Default::default(),
+            ty: "Unit".into(),
+            value: DataXsd::String(Some("rpm".into())),
+            value_id: None,
+        })]);
+
+        let metadata: Value = serde_json::from_str(&property.to_json_metamodel().unwrap()).unwrap();
+
+        assert_eq!(
+            metadata,
+            json!({
+                "modelType": "Property",
+                "idShort": "Speed",
+                "semanticId": {
+                    "type": "ExternalReference",
+                    "keys": [{"type": "GlobalReference", "value": "https://example.com/ids/cd/1"}]
+                },
+                "qualifiers": [{
+                    "kind": "ValueQualifier",
+                    "type": "Unit",
+                    "valueType": "xs:string",
+                    "value": "rpm"
+                }],
+                "valueType": "xs:double"
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_qualifier_value() {
+        let mut property = property(Some("Speed"), DataXsd::Double(Some(1.5)));
+        property.qualifiable.qualifiers = Some(vec![Qualifier::ValueQualifier(QualifierInner {
+            semantics: Default::default(),
+            ty: "Offset".into(),
+            value: DataXsd::NegativeInteger(Some(5.into())),
+            value_id: None,
+        })]);
+
+        assert!(matches!(
+            property.to_json_metamodel(),
+            Err(MetamodelError::InvalidValue(
+                ConversionError::OutOfRange { .. }
+            ))
+        ));
+        assert!(matches!(
+            property.to_xml_metamodel(),
+            Err(MetamodelError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn reference_element_metadata_omits_value() {
+        let element = ReferenceElement {
+            referable: referable("ManufacturerRef"),
+            semantics: HasSemantics {
+                semantic_id: Some(semantic_id()),
+                supplemental_semantic_ids: None,
+            },
+            value: Some(Reference::ModelReference(ReferenceInner::new(
+                Key::Submodel("https://example.com/ids/sm/1".into()),
+            ))),
+            ..Default::default()
+        };
+
+        let metadata: Value = serde_json::from_str(&element.to_json_metamodel().unwrap()).unwrap();
+
+        assert_eq!(
+            metadata,
+            json!({
+                "modelType": "ReferenceElement",
+                "idShort": "ManufacturerRef",
+                "semanticId": {
+                    "type": "ExternalReference",
+                    "keys": [{"type": "GlobalReference", "value": "https://example.com/ids/cd/1"}]
+                }
+            })
+        );
+        assert_eq!(
+            element.to_xml_metamodel().unwrap(),
+            concat!(
+                r#"<referenceElement xmlns="https://admin-shell.io/aas/3/1">"#,
+                "<idShort>ManufacturerRef</idShort>",
+                "<semanticId><type>ExternalReference</type><keys><key>",
+                "<type>GlobalReference</type><value>https://example.com/ids/cd/1</value>",
+                "</key></keys></semanticId>",
+                "</referenceElement>"
+            )
+        );
+    }
+
+    #[test]
+    fn every_element_type_has_a_model_type() {
+        let elements = [
+            SubmodelElement::Capability(Capability::new()),
+            SubmodelElement::Entity(Entity::CoManagedEntity(EntityInner::default())),
+            SubmodelElement::Operation(Operation::default()),
+            SubmodelElement::SubmodelElementCollection(SubmodelElementCollection::default()),
+        ];
+
+        for element in elements {
+            let metadata: Value =
+                serde_json::from_str(&element.to_json_metamodel().unwrap()).unwrap();
+            let model_type = metadata["modelType"].as_str().unwrap();
+            assert!(element.to_string().starts_with(model_type));
+            assert!(
+                element
+                    .to_xml_metamodel()
+                    .unwrap()
+                    .starts_with(&format!("<{} xmlns=", xml_name(model_type)))
+            );
+        }
+    }
+
+    #[test]
+    fn entity_metadata_keeps_entity_type() {
+        let entity = Entity::SelfManagedEntity(EntityInner {
+            referable: referable("Part"),
+            global_asset_id: Some("https://example.com/asset/1".try_into().unwrap()),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            entity.to_json_metamodel().unwrap(),
+            r#"{"entityType":"SelfManagedEntity","idShort":"Part","modelType":"Entity"}"#
+        );
+    }
+
+    #[test]
+    fn list_metadata_as_xml() {
+        let list = SubmodelElementList {
+            referable: referable("Speeds"),
+            semantics: Default::default(),
+            qualifiable: Default::default(),
+            embedded_data_specifications: HasDataSpecification {
+                embedded_data_specifications: Some(vec![EmbeddedDataSpecification {
+                    data_specification: semantic_id(),
+                    data_specification_content: DataSpecificationContent::DataSpecificationIec61360(
+                        DataSpecificationIec61360 {
+                            preferred_name: vec![
+                                LangString::try_new("en", "Speeds & more".into()).unwrap(),
+                            ],
+                            short_name: None,
+                            unit: None,
+                            unit_id: None,
+                            source_of_definition: None,
+                            symbol: None,
+                            data_type: Some(DataTypeIec61360::RealMeasure),
+                            definition: None,
+                            value_format: None,
+                            value_list: None,
+                            value: None,
+                            level_type: None,
+                        },
+                    ),
+                }]),
+            },
+            is_order_relevant: false,
+            semantic_id_list_element: None,
+            value: Some(vec![]),
+            type_value_list_element: AasSubmodelElements::Property,
+            value_type_list_element: Some(DataTypeXSDef::Double),
+        };
+
+        assert_eq!(
+            list.to_xml_metamodel().unwrap(),
+            concat!(
+                r#"<submodelElementList xmlns="https://admin-shell.io/aas/3/1">"#,
+                "<idShort>Speeds</idShort>",
+                "<embeddedDataSpecifications><embeddedDataSpecification>",
+                "<dataSpecification><type>ExternalReference</type><keys><key>",
+                "<type>GlobalReference</type><value>https://example.com/ids/cd/1</value>",
+                "</key></keys></dataSpecification>",
+                "<dataSpecificationContent><dataSpecificationIec61360>",
+                "<preferredName><langStringPreferredNameTypeIec61360>",
+                "<language>en</language><text>Speeds &amp; more</text>",
+                "</langStringPreferredNameTypeIec61360></preferredName>",
+                "<dataType>REAL_MEASURE</dataType>",
+                "</dataSpecificationIec61360></dataSpecificationContent>",
+                "</embeddedDataSpecification></embeddedDataSpecifications>",
+                "<orderRelevant>false</orderRelevant>",
+                "<typeValueListElement>Property</typeValueListElement>",
+                "<valueTypeListElement>xs:double</valueTypeListElement>",
+                "</submodelElementList>"
+            )
+        );
+    }
+}
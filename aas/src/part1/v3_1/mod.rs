@@ -1,11 +1,22 @@
 pub mod environment;
+#[cfg(test)]
+mod fixtures;
+pub mod id_short_path;
 pub use primitives::lang_string::*;
 pub mod attributes;
+pub mod builder;
 pub mod concept_description;
 pub mod core;
+pub mod diff;
 pub mod key;
 pub mod level_type;
+pub mod metadata;
 pub mod primitives;
 pub mod reference;
+pub mod resolver;
 pub mod submodel_elements;
+pub mod template;
+pub mod validation;
 pub mod value_list;
+pub mod value_only;
+pub mod visitor;
@@ -1,5 +1,9 @@
-use crate::part1::v3_1::primitives::Iri;
-use bigdecimal::BigDecimal;
+use crate::part1::v3_1::primitives::{Duration, GDay, GMonth, GMonthDay, GYear, GYearMonth, Iri};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bigdecimal::num_bigint::Sign;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use std::str::FromStr;
 use strum::{Display, EnumString};
 use thiserror::Error;
 
@@ -24,7 +28,7 @@ pub enum DataXsd {
 
     PositiveInteger(Option<BigDecimal>),
 
-    Short(Option<u16>),
+    Short(Option<i16>),
 
     String(Option<String>),
 
@@ -52,22 +56,17 @@ pub enum DataXsd {
 
     DateTime(Option<iso8601::DateTime>),
 
-    Duration(Option<iso8601::Duration>),
+    Duration(Option<Duration>),
 
-    /// TODO: using proper type or parsing
-    GDay(Option<String>),
+    GDay(Option<GDay>),
 
-    /// TODO: using proper type or parsing
-    GMonth(Option<String>),
+    GMonth(Option<GMonth>),
 
-    /// TODO: using proper type or parsing
-    GMonthDay(Option<String>),
+    GMonthDay(Option<GMonthDay>),
 
-    /// TODO: using proper type or parsing
-    GYear(Option<String>),
+    GYear(Option<GYear>),
 
-    /// TODO: using proper type or parsing
-    GYearMonth(Option<String>),
+    GYearMonth(Option<GYearMonth>),
 
     // binary
     Base64Binary(Option<Vec<u8>>),
@@ -76,11 +75,12 @@ pub enum DataXsd {
 
     // Miscellaneous types
     /// URI and IRI possible
+    #[strum(serialize = "anyURI")]
     AnyURI(Option<Iri>),
 }
 
 /// Type mapping of XSDef types.
-#[derive(Clone, PartialEq, Debug, Display)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Display, EnumString)]
 #[strum(prefix = "xs:", serialize_all = "camelCase")]
 pub enum DataTypeXSDef {
     // basic types
@@ -117,189 +117,317 @@ pub enum DataTypeXSDef {
     HexBinary,
     // Miscellaneous types
     /// URI and IRI possible
+    #[strum(serialize = "anyURI")]
     AnyURI,
 }
 
-#[derive(Debug, Clone, Error)]
+#[derive(Debug, Clone, PartialEq, Error)]
 pub enum ConversionError {
-    #[error("Value is not parseable")]
-    ParseError,
+    #[error("'{value}' is not a valid lexical representation of {data_type}")]
+    InvalidLexical {
+        data_type: DataTypeXSDef,
+        value: String,
+    },
+
+    #[error("{value} is outside the value space of {data_type}")]
+    OutOfRange {
+        data_type: DataTypeXSDef,
+        value: String,
+    },
+}
+
+impl ConversionError {
+    fn invalid(data_type: DataTypeXSDef, value: &str) -> Self {
+        ConversionError::InvalidLexical {
+            data_type,
+            value: value.to_string(),
+        }
+    }
+
+    fn out_of_range(data_type: DataTypeXSDef, value: &str) -> Self {
+        ConversionError::OutOfRange {
+            data_type,
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Parses a bounded integer (xs:int, xs:short, xs:unsignedByte, ...) in the lexical space of
+/// xs:integer, so `-0` is a valid xs:unsignedInt and `-1` lies outside its value space.
+fn parse_bounded_integer<T>(data_type: DataTypeXSDef, value: &str) -> Result<T, ConversionError>
+where
+    T: TryFrom<i128>,
+{
+    let integer = parse_unbounded_integer(data_type.clone(), value)?;
+
+    integer
+        .to_i128()
+        .and_then(|integer| T::try_from(integer).ok())
+        .ok_or_else(|| ConversionError::out_of_range(data_type, value))
+}
+
+/// Parses the unbounded integer types (xs:integer and its sign restricted derivations).
+/// The lexical space is `[\-+]?[0-9]+`, so decimals or exponents are rejected.
+fn parse_unbounded_integer(
+    data_type: DataTypeXSDef,
+    value: &str,
+) -> Result<BigDecimal, ConversionError> {
+    let digits = value.strip_prefix(['+', '-']).unwrap_or(value);
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ConversionError::invalid(data_type, value));
+    }
+
+    let integer: BigDecimal = value
+        .parse()
+        .map_err(|_| ConversionError::invalid(data_type.clone(), value))?;
+
+    check_integer_sign(&data_type, &integer)?;
+
+    Ok(integer)
+}
+
+/// Checks the sign restrictions of xs:negativeInteger, xs:positiveInteger, ...
+fn check_integer_sign(
+    data_type: &DataTypeXSDef,
+    value: &BigDecimal,
+) -> Result<(), ConversionError> {
+    let in_range = match data_type {
+        DataTypeXSDef::NegativeInteger => value.sign() == Sign::Minus,
+        DataTypeXSDef::NonPositiveInteger => value.sign() != Sign::Plus,
+        DataTypeXSDef::PositiveInteger => value.sign() == Sign::Plus,
+        DataTypeXSDef::NonNegativeInteger => value.sign() != Sign::Minus,
+        _ => true,
+    };
+
+    if !value.is_integer() {
+        return Err(ConversionError::invalid(
+            data_type.clone(),
+            &value.to_string(),
+        ));
+    }
+
+    match in_range {
+        true => Ok(()),
+        false => Err(ConversionError::out_of_range(
+            data_type.clone(),
+            &value.to_string(),
+        )),
+    }
+}
+
+/// xs:decimal does not allow exponents, which are accepted by [BigDecimal].
+fn parse_decimal(value: &str) -> Result<BigDecimal, ConversionError> {
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let valid = !unsigned.is_empty()
+        && unsigned != "."
+        && unsigned.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && unsigned.chars().filter(|c| *c == '.').count() <= 1;
+
+    if !valid {
+        return Err(ConversionError::invalid(DataTypeXSDef::Decimal, value));
+    }
+
+    value
+        .parse()
+        .map_err(|_| ConversionError::invalid(DataTypeXSDef::Decimal, value))
+}
+
+/// xs:float and xs:double use `INF`, `-INF` and `NaN` for the special values.
+/// Rust spellings like `inf` or `infinity` are rejected.
+fn parse_floating<T>(data_type: DataTypeXSDef, value: &str) -> Result<T, ConversionError>
+where
+    T: FromStr,
+{
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let special = unsigned
+        .chars()
+        .any(|c| c.is_ascii_alphabetic() && c != 'e' && c != 'E');
+
+    if special && !matches!(value, "INF" | "+INF" | "-INF" | "NaN") {
+        return Err(ConversionError::invalid(data_type, value));
+    }
+
+    value
+        .parse()
+        .map_err(|_| ConversionError::invalid(data_type, value))
+}
+
+/// Formats xs:float and xs:double in their canonical spelling of the special values.
+fn format_floating<T>(value: T) -> String
+where
+    T: Into<f64> + ToString + Copy,
+{
+    let double: f64 = value.into();
+
+    if double.is_nan() {
+        "NaN".to_string()
+    } else if double == f64::INFINITY {
+        "INF".to_string()
+    } else if double == f64::NEG_INFINITY {
+        "-INF".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Decodes xs:base64Binary, which may contain whitespace between the characters.
+fn parse_base64(value: &str) -> Result<Vec<u8>, ConversionError> {
+    BASE64
+        .decode(value.split_ascii_whitespace().collect::<String>())
+        .map_err(|_| ConversionError::invalid(DataTypeXSDef::Base64Binary, value))
+}
+
+fn parse_lexical<T>(data_type: DataTypeXSDef, value: &str) -> Result<T, ConversionError>
+where
+    T: FromStr,
+{
+    value
+        .parse()
+        .map_err(|_| ConversionError::invalid(data_type, value))
 }
 
 impl TryFrom<(DataTypeXSDef, Option<String>)> for DataXsd {
     type Error = ConversionError;
 
+    /// Parses the lexical representation of a value with the given value type.
+    /// Values outside the value space of the type are rejected.
+    ///
+    /// # Example
+    /// ```
+    /// use aas::part1::v3_1::primitives::data_type_def_xs::{ConversionError, DataTypeXSDef, DataXsd};
+    ///
+    /// let short = DataXsd::try_from((DataTypeXSDef::Short, Some("-5".to_string())));
+    /// assert_eq!(short.unwrap(), DataXsd::Short(Some(-5)));
+    ///
+    /// let negative = DataXsd::try_from((DataTypeXSDef::NegativeInteger, Some("5".to_string())));
+    /// assert!(matches!(negative, Err(ConversionError::OutOfRange { .. })));
+    /// ```
     fn try_from(value: (DataTypeXSDef, Option<String>)) -> Result<Self, Self::Error> {
-        match value.0 {
-            DataTypeXSDef::Int => Ok(DataXsd::Int(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Long => Ok(DataXsd::Long(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Integer => Ok(DataXsd::Integer(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::NegativeInteger => Ok(DataXsd::NegativeInteger(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::NonNegativeInteger => Ok(DataXsd::NonNegativeInteger(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::NonPositiveInteger => Ok(DataXsd::NonPositiveInteger(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::PositiveInteger => Ok(DataXsd::PositiveInteger(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Short => Ok(DataXsd::Short(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::String => Ok(DataXsd::String(value.1)),
-            DataTypeXSDef::Boolean => Ok(DataXsd::Boolean(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Byte => Ok(DataXsd::Byte(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::UnsignedByte => Ok(DataXsd::UnsignedByte(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::UnsignedInt => Ok(DataXsd::UnsignedInt(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::UnsignedLong => Ok(DataXsd::UnsignedLong(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::UnsignedShort => Ok(DataXsd::UnsignedShort(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Decimal => Ok(DataXsd::Decimal(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Float => Ok(DataXsd::Float(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Double => Ok(DataXsd::Double(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Time => Ok(DataXsd::Time(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Date => Ok(DataXsd::Date(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::DateTime => Ok(DataXsd::DateTime(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Duration => Ok(DataXsd::Duration(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::GDay => Ok(DataXsd::GDay(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::GMonth => Ok(DataXsd::GMonth(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::GMonthDay => Ok(DataXsd::GMonthDay(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::GYear => Ok(DataXsd::GYear(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::GYearMonth => Ok(DataXsd::GYearMonth(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
-            )),
-            DataTypeXSDef::Base64Binary => {
-                Ok(DataXsd::Base64Binary(value.1.map(|v| v.into_bytes())))
+        let (data_type, value) = value;
+        let Some(value) = value else {
+            return Ok(DataXsd::empty(data_type));
+        };
+        let v = value.as_str();
+
+        Ok(match data_type {
+            DataTypeXSDef::Int => DataXsd::Int(Some(parse_bounded_integer(data_type, v)?)),
+            DataTypeXSDef::Long => DataXsd::Long(Some(parse_bounded_integer(data_type, v)?)),
+            DataTypeXSDef::Integer => {
+                DataXsd::Integer(Some(parse_unbounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::NegativeInteger => {
+                DataXsd::NegativeInteger(Some(parse_unbounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::NonNegativeInteger => {
+                DataXsd::NonNegativeInteger(Some(parse_unbounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::NonPositiveInteger => {
+                DataXsd::NonPositiveInteger(Some(parse_unbounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::PositiveInteger => {
+                DataXsd::PositiveInteger(Some(parse_unbounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::Short => DataXsd::Short(Some(parse_bounded_integer(data_type, v)?)),
+            DataTypeXSDef::String => DataXsd::String(Some(value)),
+            DataTypeXSDef::Boolean => DataXsd::Boolean(Some(match v {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return Err(ConversionError::invalid(data_type, v)),
+            })),
+            DataTypeXSDef::Byte => DataXsd::Byte(Some(parse_bounded_integer(data_type, v)?)),
+            DataTypeXSDef::UnsignedByte => {
+                DataXsd::UnsignedByte(Some(parse_bounded_integer(data_type, v)?))
             }
-            DataTypeXSDef::HexBinary => Ok(DataXsd::HexBinary(value.1.map(|v| v.into_bytes()))),
-            DataTypeXSDef::AnyURI => Ok(DataXsd::AnyURI(
-                value
-                    .1
-                    .map(|v| v.parse().map_err(|_| ConversionError::ParseError))
-                    .transpose()?,
+            DataTypeXSDef::UnsignedInt => {
+                DataXsd::UnsignedInt(Some(parse_bounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::UnsignedLong => {
+                DataXsd::UnsignedLong(Some(parse_bounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::UnsignedShort => {
+                DataXsd::UnsignedShort(Some(parse_bounded_integer(data_type, v)?))
+            }
+            DataTypeXSDef::Decimal => DataXsd::Decimal(Some(parse_decimal(v)?)),
+            DataTypeXSDef::Float => DataXsd::Float(Some(parse_floating(data_type, v)?)),
+            DataTypeXSDef::Double => DataXsd::Double(Some(parse_floating(data_type, v)?)),
+            DataTypeXSDef::Time => DataXsd::Time(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::Date => DataXsd::Date(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::DateTime => DataXsd::DateTime(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::Duration => DataXsd::Duration(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::GDay => DataXsd::GDay(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::GMonth => DataXsd::GMonth(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::GMonthDay => DataXsd::GMonthDay(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::GYear => DataXsd::GYear(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::GYearMonth => DataXsd::GYearMonth(Some(parse_lexical(data_type, v)?)),
+            DataTypeXSDef::Base64Binary => DataXsd::Base64Binary(Some(parse_base64(v)?)),
+            DataTypeXSDef::HexBinary => DataXsd::HexBinary(Some(
+                hex::decode(v).map_err(|_| ConversionError::invalid(data_type, v))?,
             )),
+            DataTypeXSDef::AnyURI => DataXsd::AnyURI(Some(parse_lexical(data_type, v)?)),
+        })
+    }
+}
+
+impl DataXsd {
+    /// Returns the variant of the given value type without a value.
+    pub fn empty(data_type: DataTypeXSDef) -> Self {
+        match data_type {
+            DataTypeXSDef::Int => DataXsd::Int(None),
+            DataTypeXSDef::Long => DataXsd::Long(None),
+            DataTypeXSDef::Integer => DataXsd::Integer(None),
+            DataTypeXSDef::NegativeInteger => DataXsd::NegativeInteger(None),
+            DataTypeXSDef::NonNegativeInteger => DataXsd::NonNegativeInteger(None),
+            DataTypeXSDef::NonPositiveInteger => DataXsd::NonPositiveInteger(None),
+            DataTypeXSDef::PositiveInteger => DataXsd::PositiveInteger(None),
+            DataTypeXSDef::Short => DataXsd::Short(None),
+            DataTypeXSDef::String => DataXsd::String(None),
+            DataTypeXSDef::Boolean => DataXsd::Boolean(None),
+            DataTypeXSDef::Byte => DataXsd::Byte(None),
+            DataTypeXSDef::UnsignedByte => DataXsd::UnsignedByte(None),
+            DataTypeXSDef::UnsignedInt => DataXsd::UnsignedInt(None),
+            DataTypeXSDef::UnsignedLong => DataXsd::UnsignedLong(None),
+            DataTypeXSDef::UnsignedShort => DataXsd::UnsignedShort(None),
+            DataTypeXSDef::Decimal => DataXsd::Decimal(None),
+            DataTypeXSDef::Float => DataXsd::Float(None),
+            DataTypeXSDef::Double => DataXsd::Double(None),
+            DataTypeXSDef::Time => DataXsd::Time(None),
+            DataTypeXSDef::Date => DataXsd::Date(None),
+            DataTypeXSDef::DateTime => DataXsd::DateTime(None),
+            DataTypeXSDef::Duration => DataXsd::Duration(None),
+            DataTypeXSDef::GDay => DataXsd::GDay(None),
+            DataTypeXSDef::GMonth => DataXsd::GMonth(None),
+            DataTypeXSDef::GMonthDay => DataXsd::GMonthDay(None),
+            DataTypeXSDef::GYear => DataXsd::GYear(None),
+            DataTypeXSDef::GYearMonth => DataXsd::GYearMonth(None),
+            DataTypeXSDef::Base64Binary => DataXsd::Base64Binary(None),
+            DataTypeXSDef::HexBinary => DataXsd::HexBinary(None),
+            DataTypeXSDef::AnyURI => DataXsd::AnyURI(None),
+        }
+    }
+
+    /// Checks that the value lies in the value space of its type.
+    /// Only the unbounded integer types can hold values outside their value space,
+    /// e.g. `DataXsd::NegativeInteger(Some(5))`; all other types are restricted by their Rust type.
+    pub fn validate(&self) -> Result<(), ConversionError> {
+        match self {
+            DataXsd::Integer(Some(v))
+            | DataXsd::NegativeInteger(Some(v))
+            | DataXsd::NonNegativeInteger(Some(v))
+            | DataXsd::NonPositiveInteger(Some(v))
+            | DataXsd::PositiveInteger(Some(v)) => {
+                check_integer_sign(&DataTypeXSDef::from(self), v)
+            }
+            _ => Ok(()),
         }
     }
+
+    /// Returns the lexical representation of the value after checking it against the value space.
+    /// Use this instead of the infallible `Option<String>::from` when serializing.
+    pub fn to_lexical(&self) -> Result<Option<String>, ConversionError> {
+        self.validate()?;
+        Ok(self.clone().into())
+    }
 }
 
 impl From<DataXsd> for Option<String> {
@@ -321,8 +449,8 @@ impl From<DataXsd> for Option<String> {
             DataXsd::UnsignedLong(v) => v.map(|v| v.to_string()),
             DataXsd::UnsignedShort(v) => v.map(|v| v.to_string()),
             DataXsd::Decimal(v) => v.map(|v| v.to_string()),
-            DataXsd::Float(v) => v.map(|v| v.to_string()),
-            DataXsd::Double(v) => v.map(|v| v.to_string()),
+            DataXsd::Float(v) => v.map(format_floating),
+            DataXsd::Double(v) => v.map(format_floating),
             DataXsd::Time(v) => v.map(|v| v.to_string()),
             DataXsd::Date(v) => v.map(|v| v.to_string()),
             DataXsd::DateTime(v) => v.map(|v| v.to_string()),
@@ -332,8 +460,8 @@ impl From<DataXsd> for Option<String> {
             DataXsd::GMonthDay(v) => v.map(|v| v.to_string()),
             DataXsd::GYear(v) => v.map(|v| v.to_string()),
             DataXsd::GYearMonth(v) => v.map(|v| v.to_string()),
-            DataXsd::Base64Binary(v) => v.map(|v| String::from_utf8_lossy(&v).to_string()),
-            DataXsd::HexBinary(v) => v.map(|v| String::from_utf8_lossy(&v).to_string()),
+            DataXsd::Base64Binary(v) => v.map(|v| BASE64.encode(v)),
+            DataXsd::HexBinary(v) => v.map(hex::encode_upper),
             DataXsd::AnyURI(v) => v.map(|v| v.to_string()),
         }
     }
@@ -341,6 +469,12 @@ impl From<DataXsd> for Option<String> {
 
 impl From<DataXsd> for DataTypeXSDef {
     fn from(value: DataXsd) -> Self {
+        DataTypeXSDef::from(&value)
+    }
+}
+
+impl From<&DataXsd> for DataTypeXSDef {
+    fn from(value: &DataXsd) -> Self {
         match value {
             DataXsd::Int(_) => DataTypeXSDef::Int,
             DataXsd::Long(_) => DataTypeXSDef::Long,
@@ -381,3 +515,209 @@ impl Default for DataXsd {
         DataXsd::String(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data_type: DataTypeXSDef, value: &str) -> Result<DataXsd, ConversionError> {
+        DataXsd::try_from((data_type, Some(value.to_string())))
+    }
+
+    #[test]
+    fn short_is_signed() {
+        assert_eq!(
+            parse(DataTypeXSDef::Short, "-32768"),
+            Ok(DataXsd::Short(Some(i16::MIN)))
+        );
+        assert!(matches!(
+            parse(DataTypeXSDef::Short, "32768"),
+            Err(ConversionError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn gregorian_types_are_validated() {
+        assert_eq!(
+            parse(DataTypeXSDef::GYearMonth, "2024-05Z")
+                .unwrap()
+                .to_lexical(),
+            Ok(Some("2024-05Z".to_string()))
+        );
+        assert!(matches!(
+            parse(DataTypeXSDef::GMonthDay, "--02-30"),
+            Err(ConversionError::InvalidLexical { .. })
+        ));
+    }
+
+    #[test]
+    fn duration_follows_the_xsd_grammar() {
+        assert_eq!(
+            parse(DataTypeXSDef::Duration, "-P1D").unwrap().to_lexical(),
+            Ok(Some("-P1D".to_string()))
+        );
+        for value in ["P1W", "P", "PT", "P1DT"] {
+            assert!(matches!(
+                parse(DataTypeXSDef::Duration, value),
+                Err(ConversionError::InvalidLexical { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn binary_values_are_decoded() {
+        let base64 = parse(DataTypeXSDef::Base64Binary, "aGVs bG8=").unwrap();
+        assert_eq!(base64, DataXsd::Base64Binary(Some(b"hello".to_vec())));
+        assert_eq!(base64.to_lexical(), Ok(Some("aGVsbG8=".to_string())));
+
+        let hex = parse(DataTypeXSDef::HexBinary, "0fB7").unwrap();
+        assert_eq!(hex, DataXsd::HexBinary(Some(vec![0x0f, 0xb7])));
+        assert_eq!(hex.to_lexical(), Ok(Some("0FB7".to_string())));
+
+        for (data_type, value) in [
+            (DataTypeXSDef::HexBinary, "zz"),
+            (DataTypeXSDef::HexBinary, "0FB"),
+            (DataTypeXSDef::Base64Binary, "aGVsbG8"),
+            (DataTypeXSDef::Base64Binary, "a!=="),
+        ] {
+            assert!(matches!(
+                parse(data_type, value),
+                Err(ConversionError::InvalidLexical { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn bounded_integer_overflow_is_out_of_range() {
+        assert!(matches!(
+            parse(DataTypeXSDef::UnsignedByte, "256"),
+            Err(ConversionError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            parse(DataTypeXSDef::UnsignedInt, "-1"),
+            Err(ConversionError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            parse(
+                DataTypeXSDef::Long,
+                "99999999999999999999999999999999999999999"
+            ),
+            Err(ConversionError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            parse(DataTypeXSDef::Int, "1.0"),
+            Err(ConversionError::InvalidLexical { .. })
+        ));
+    }
+
+    #[test]
+    fn bounded_integers_use_the_integer_lexical_space() {
+        assert_eq!(
+            parse(DataTypeXSDef::UnsignedInt, "-0"),
+            Ok(DataXsd::UnsignedInt(Some(0)))
+        );
+        assert_eq!(
+            parse(DataTypeXSDef::UnsignedLong, "+18446744073709551615"),
+            Ok(DataXsd::UnsignedLong(Some(u64::MAX)))
+        );
+        assert_eq!(
+            parse(DataTypeXSDef::Byte, "-128"),
+            Ok(DataXsd::Byte(Some(i8::MIN)))
+        );
+        assert_eq!(
+            parse(DataTypeXSDef::Short, "007"),
+            Ok(DataXsd::Short(Some(7)))
+        );
+    }
+
+    #[test]
+    fn unbounded_integers_check_sign() {
+        let big = "123456789012345678901234567890";
+        assert_eq!(
+            parse(DataTypeXSDef::PositiveInteger, big),
+            Ok(DataXsd::PositiveInteger(Some(big.parse().unwrap())))
+        );
+
+        for (data_type, value) in [
+            (DataTypeXSDef::NegativeInteger, "0"),
+            (DataTypeXSDef::NegativeInteger, "5"),
+            (DataTypeXSDef::PositiveInteger, "0"),
+            (DataTypeXSDef::NonNegativeInteger, "-1"),
+            (DataTypeXSDef::NonPositiveInteger, "1"),
+        ] {
+            assert_eq!(
+                parse(data_type.clone(), value),
+                Err(ConversionError::OutOfRange {
+                    data_type,
+                    value: value.to_string()
+                })
+            );
+        }
+
+        assert!(parse(DataTypeXSDef::NonPositiveInteger, "0").is_ok());
+        assert!(parse(DataTypeXSDef::NonNegativeInteger, "+0").is_ok());
+    }
+
+    #[test]
+    fn integer_rejects_fractions_and_exponents() {
+        for value in ["1.5", "1.0", "1e3", "", "+"] {
+            assert!(matches!(
+                parse(DataTypeXSDef::Integer, value),
+                Err(ConversionError::InvalidLexical { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn decimal_rejects_exponent() {
+        assert!(parse(DataTypeXSDef::Decimal, "-1.50").is_ok());
+        assert!(parse(DataTypeXSDef::Decimal, ".5").is_ok());
+        assert!(parse(DataTypeXSDef::Decimal, "1e3").is_err());
+    }
+
+    #[test]
+    fn floating_special_values() {
+        assert_eq!(
+            parse(DataTypeXSDef::Double, "-INF"),
+            Ok(DataXsd::Double(Some(f64::NEG_INFINITY)))
+        );
+        assert!(parse(DataTypeXSDef::Double, "infinity").is_err());
+        assert_eq!(
+            Option::<String>::from(DataXsd::Float(Some(f32::INFINITY))),
+            Some("INF".to_string())
+        );
+    }
+
+    #[test]
+    fn boolean_lexical_space() {
+        assert_eq!(
+            parse(DataTypeXSDef::Boolean, "1"),
+            Ok(DataXsd::Boolean(Some(true)))
+        );
+        assert!(parse(DataTypeXSDef::Boolean, "True").is_err());
+    }
+
+    #[test]
+    fn error_message_is_descriptive() {
+        let err = parse(DataTypeXSDef::Int, "twelve").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "'twelve' is not a valid lexical representation of xs:int"
+        );
+    }
+
+    #[test]
+    fn to_lexical_rejects_invalid_values() {
+        let invalid = DataXsd::NegativeInteger(Some(5.into()));
+
+        assert!(matches!(
+            invalid.to_lexical(),
+            Err(ConversionError::OutOfRange { .. })
+        ));
+        assert_eq!(
+            DataXsd::NegativeInteger(Some((-5).into())).to_lexical(),
+            Ok(Some("-5".to_string()))
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use bigdecimal::BigDecimal;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("'{0}' does not match the lexical representation of xs:duration")]
+pub struct DurationError(String);
+
+/// xs:duration, e.g. `P1Y2M3DT4H5M6.7S` or `-P1D`
+///
+/// The fields are kept as written, so `PT60S` and `PT1M` are different values.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Duration {
+    negative: bool,
+    years: Option<u64>,
+    months: Option<u64>,
+    days: Option<u64>,
+    hours: Option<u64>,
+    minutes: Option<u64>,
+    seconds: Option<BigDecimal>,
+}
+
+impl Duration {
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn years(&self) -> Option<u64> {
+        self.years
+    }
+
+    pub fn months(&self) -> Option<u64> {
+        self.months
+    }
+
+    pub fn days(&self) -> Option<u64> {
+        self.days
+    }
+
+    pub fn hours(&self) -> Option<u64> {
+        self.hours
+    }
+
+    pub fn minutes(&self) -> Option<u64> {
+        self.minutes
+    }
+
+    pub fn seconds(&self) -> Option<&BigDecimal> {
+        self.seconds.as_ref()
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+
+        for (value, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if let Some(value) = value {
+                write!(f, "{value}{designator}")?;
+            }
+        }
+
+        if self.hours.is_some() || self.minutes.is_some() || self.seconds.is_some() {
+            write!(f, "T")?;
+            for (value, designator) in [(self.hours, 'H'), (self.minutes, 'M')] {
+                if let Some(value) = value {
+                    write!(f, "{value}{designator}")?;
+                }
+            }
+            if let Some(seconds) = &self.seconds {
+                write!(f, "{}S", seconds.to_plain_string())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Duration {
+    type Err = DurationError;
+
+    /// Parses `-?P(nY)?(nM)?(nD)?(T(nH)?(nM)?(n(.n)?S)?)?` with at least one field,
+    /// and at least one field after `T`. Weeks (`P1W`) are not part of xs:duration.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DurationError(s.to_string());
+
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let rest = rest.strip_prefix('P').ok_or_else(invalid)?;
+        let (date, time) = match rest.split_once('T') {
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None),
+        };
+
+        let [years, months, days] = split_fields(date, ['Y', 'M', 'D']).ok_or_else(invalid)?;
+        let [hours, minutes, seconds] = match time {
+            Some(time) => split_fields(time, ['H', 'M', 'S'])
+                .filter(|fields| fields.iter().any(Option::is_some))
+                .ok_or_else(invalid)?,
+            None => [None; 3],
+        };
+
+        let integer = |value: Option<&str>| match value {
+            Some(value) if value.chars().all(|c| c.is_ascii_digit()) => {
+                value.parse().map(Some).map_err(|_| invalid())
+            }
+            Some(_) => Err(invalid()),
+            None => Ok(None),
+        };
+        let duration = Duration {
+            negative,
+            years: integer(years)?,
+            months: integer(months)?,
+            days: integer(days)?,
+            hours: integer(hours)?,
+            minutes: integer(minutes)?,
+            seconds: match seconds {
+                Some(seconds) => Some(parse_seconds(seconds).ok_or_else(invalid)?),
+                None => None,
+            },
+        };
+
+        match [years, months, days].iter().all(Option::is_none) && time.is_none() {
+            true => Err(invalid()),
+            false => Ok(duration),
+        }
+    }
+}
+
+/// Splits `1Y2D` into the numerals of the given designators, which have to appear in order.
+fn split_fields<const N: usize>(value: &str, designators: [char; N]) -> Option<[Option<&str>; N]> {
+    let mut fields = [None; N];
+    let mut next = 0;
+    let mut rest = value;
+
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+        let (numeral, tail) = rest.split_at(end);
+        let designator = tail.chars().next()?;
+        let position = designators[next..].iter().position(|&d| d == designator)? + next;
+
+        if numeral.is_empty() {
+            return None;
+        }
+        fields[position] = Some(numeral);
+        next = position + 1;
+        rest = &tail[designator.len_utf8()..];
+    }
+
+    Some(fields)
+}
+
+/// Seconds are the only field with a fraction, e.g. `1.5`, `.5` or `1.`
+fn parse_seconds(value: &str) -> Option<BigDecimal> {
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    if integer.is_empty() && fraction.is_empty() || fraction.contains('.') {
+        return None;
+    }
+    match fraction.is_empty() {
+        true => BigDecimal::from_str(&format!("0{integer}")).ok(),
+        false => BigDecimal::from_str(&format!("0{integer}.{fraction}")).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for value in [
+            "P1Y2M3DT4H5M6.5S",
+            "-P1D",
+            "PT1M",
+            "P0Y",
+            "PT0.0000001S",
+            "P1MT1M",
+        ] {
+            assert_eq!(value.parse::<Duration>().unwrap().to_string(), value);
+        }
+
+        let duration: Duration = "-P1D".parse().unwrap();
+        assert!(duration.is_negative());
+        assert_eq!(duration.days(), Some(1));
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        for value in [
+            "", "P", "-P", "PT", "P1DT", "P1W", "1D", "+P1D", "P-1D", "P1D1Y", "P1H", "PT1D",
+            "P1.5D", "PT1.5M", "PT.S", "PT1..5S", "P1Y1Y", "PT1H1D",
+        ] {
+            assert!(value.parse::<Duration>().is_err(), "{value} is invalid");
+        }
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        let seconds = |value: &str| value.parse::<Duration>().unwrap().seconds().cloned();
+
+        assert_eq!(seconds("PT.5S"), Some(BigDecimal::from_str("0.5").unwrap()));
+        assert_eq!(seconds("PT1.S"), Some(BigDecimal::from(1)));
+    }
+}
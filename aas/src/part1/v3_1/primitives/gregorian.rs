@@ -0,0 +1,550 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum GregorianError {
+    #[error("'{0}' is not a valid year")]
+    InvalidYear(String),
+
+    #[error("'{0}' is not a valid month")]
+    InvalidMonth(String),
+
+    #[error("'{0}' is not a valid day")]
+    InvalidDay(String),
+
+    #[error("'{0}' is not a valid timezone")]
+    InvalidTimezone(String),
+
+    #[error("'{0}' does not match the lexical representation of {1}")]
+    InvalidFormat(String, &'static str),
+}
+
+/// Timezone offset from UTC in minutes, restricted to -14:00 to +14:00.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Timezone(i16);
+
+impl Timezone {
+    pub const UTC: Timezone = Timezone(0);
+
+    pub fn from_offset_minutes(minutes: i16) -> Result<Self, GregorianError> {
+        // abs() overflows for i16::MIN
+        match minutes.unsigned_abs() <= 14 * 60 {
+            true => Ok(Timezone(minutes)),
+            false => Err(GregorianError::InvalidTimezone(minutes.to_string())),
+        }
+    }
+
+    pub fn offset_minutes(&self) -> i16 {
+        self.0
+    }
+}
+
+impl Display for Timezone {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "Z");
+        }
+
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let minutes = self.0.abs();
+        write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = GregorianError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GregorianError::InvalidTimezone(s.to_string());
+
+        if s == "Z" {
+            return Ok(Timezone::UTC);
+        }
+
+        let (sign, rest) = match s.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => return Err(invalid()),
+        };
+        let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+        let hours: i16 = parse_digits(hours, 2).ok_or_else(invalid)?;
+        let minutes: i16 = parse_digits(minutes, 2).ok_or_else(invalid)?;
+
+        if minutes > 59 || hours > 14 || (hours == 14 && minutes != 0) {
+            return Err(invalid());
+        }
+
+        Ok(Timezone(sign * (hours * 60 + minutes)))
+    }
+}
+
+// The recurring and partial Gregorian calendar types of XML Schema
+// (xs:gYear, xs:gYearMonth, xs:gMonth, xs:gMonthDay, xs:gDay).
+//
+// All of them can carry an optional timezone. They are ordered by the point in time
+// they start at (the recurring types use the reference year 1972 like XML Schema does),
+// values without timezone are treated as UTC.
+// Values starting at the same instant, e.g. `---02+12:00` and `---01-12:00`, are ordered by their fields.
+
+/// xs:gYear, e.g. `2024` or `-0044+01:00`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GYear {
+    year: i32,
+    timezone: Option<Timezone>,
+}
+
+/// xs:gYearMonth, e.g. `2024-05`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GYearMonth {
+    year: i32,
+    month: u8,
+    timezone: Option<Timezone>,
+}
+
+/// xs:gMonth, e.g. `--05`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GMonth {
+    month: u8,
+    timezone: Option<Timezone>,
+}
+
+/// xs:gMonthDay, e.g. `--02-29`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GMonthDay {
+    month: u8,
+    day: u8,
+    timezone: Option<Timezone>,
+}
+
+/// xs:gDay, e.g. `---31Z`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GDay {
+    day: u8,
+    timezone: Option<Timezone>,
+}
+
+impl GYear {
+    pub fn new(year: i32, timezone: Option<Timezone>) -> Self {
+        Self { year, timezone }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn timezone(&self) -> Option<Timezone> {
+        self.timezone
+    }
+}
+
+impl GYearMonth {
+    pub fn new(year: i32, month: u8, timezone: Option<Timezone>) -> Result<Self, GregorianError> {
+        check_month(month)?;
+        Ok(Self {
+            year,
+            month,
+            timezone,
+        })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn timezone(&self) -> Option<Timezone> {
+        self.timezone
+    }
+}
+
+impl GMonth {
+    pub fn new(month: u8, timezone: Option<Timezone>) -> Result<Self, GregorianError> {
+        check_month(month)?;
+        Ok(Self { month, timezone })
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn timezone(&self) -> Option<Timezone> {
+        self.timezone
+    }
+}
+
+impl GMonthDay {
+    /// February 29 is allowed, as a recurring day it exists in leap years.
+    pub fn new(month: u8, day: u8, timezone: Option<Timezone>) -> Result<Self, GregorianError> {
+        check_month(month)?;
+        if day == 0 || day > days_in_month(REFERENCE_YEAR, month) {
+            return Err(GregorianError::InvalidDay(day.to_string()));
+        }
+
+        Ok(Self {
+            month,
+            day,
+            timezone,
+        })
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn timezone(&self) -> Option<Timezone> {
+        self.timezone
+    }
+}
+
+impl GDay {
+    pub fn new(day: u8, timezone: Option<Timezone>) -> Result<Self, GregorianError> {
+        if day == 0 || day > 31 {
+            return Err(GregorianError::InvalidDay(day.to_string()));
+        }
+
+        Ok(Self { day, timezone })
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn timezone(&self) -> Option<Timezone> {
+        self.timezone
+    }
+}
+
+impl Display for GYear {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_year(f, self.year)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl Display for GYearMonth {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_year(f, self.year)?;
+        write!(f, "-{:02}", self.month)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl Display for GMonth {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "--{:02}", self.month)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl Display for GMonthDay {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "--{:02}-{:02}", self.month, self.day)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl Display for GDay {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "---{:02}", self.day)?;
+        write_timezone(f, self.timezone)
+    }
+}
+
+impl FromStr for GYear {
+    type Err = GregorianError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, timezone) = split_timezone(s)?;
+        Ok(GYear::new(parse_year(value)?, timezone))
+    }
+}
+
+impl FromStr for GYearMonth {
+    type Err = GregorianError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, timezone) = split_timezone(s)?;
+        let (year, month) = value
+            .rsplit_once('-')
+            .ok_or_else(|| GregorianError::InvalidFormat(s.to_string(), "xs:gYearMonth"))?;
+
+        GYearMonth::new(parse_year(year)?, parse_month(month)?, timezone)
+    }
+}
+
+impl FromStr for GMonth {
+    type Err = GregorianError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, timezone) = split_timezone(s)?;
+        let month = value
+            .strip_prefix("--")
+            .ok_or_else(|| GregorianError::InvalidFormat(s.to_string(), "xs:gMonth"))?;
+
+        GMonth::new(parse_month(month)?, timezone)
+    }
+}
+
+impl FromStr for GMonthDay {
+    type Err = GregorianError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || GregorianError::InvalidFormat(s.to_string(), "xs:gMonthDay");
+        let (value, timezone) = split_timezone(s)?;
+        let (month, day) = value
+            .strip_prefix("--")
+            .and_then(|v| v.split_once('-'))
+            .ok_or_else(invalid)?;
+
+        GMonthDay::new(parse_month(month)?, parse_day(day)?, timezone)
+    }
+}
+
+impl FromStr for GDay {
+    type Err = GregorianError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, timezone) = split_timezone(s)?;
+        let day = value
+            .strip_prefix("---")
+            .ok_or_else(|| GregorianError::InvalidFormat(s.to_string(), "xs:gDay"))?;
+
+        GDay::new(parse_day(day)?, timezone)
+    }
+}
+
+macro_rules! impl_gregorian_conversions {
+    ($($name:ident => |$v:ident| ($year:expr, $month:expr, $day:expr)),* $(,)?) => {$(
+        impl $name {
+            /// Minutes since 1970-01-01T00:00Z at which the value starts.
+            fn start_instant(&self) -> i64 {
+                let $v = self;
+                let days = days_from_civil($year, $month, $day);
+                let offset = self.timezone.map(|tz| tz.offset_minutes()).unwrap_or(0);
+                days * 24 * 60 - i64::from(offset)
+            }
+
+            fn fields(&self) -> (i32, u8, u8, Option<Timezone>) {
+                let $v = self;
+                ($year, $month, $day, self.timezone)
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.start_instant()
+                    .cmp(&other.start_instant())
+                    .then_with(|| self.fields().cmp(&other.fields()))
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = GregorianError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = GregorianError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+    )*};
+}
+
+impl_gregorian_conversions!(
+    GYear => |v| (v.year, 1, 1),
+    GYearMonth => |v| (v.year, v.month, 1),
+    GMonth => |v| (REFERENCE_YEAR, v.month, 1),
+    GMonthDay => |v| (REFERENCE_YEAR, v.month, v.day),
+    GDay => |v| (REFERENCE_YEAR, 1, v.day),
+);
+
+/// Leap year used by XML Schema to compare recurring values.
+const REFERENCE_YEAR: i32 = 1972;
+
+fn check_month(month: u8) -> Result<(), GregorianError> {
+    match (1..=12).contains(&month) {
+        true => Ok(()),
+        false => Err(GregorianError::InvalidMonth(month.to_string())),
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of the given proleptic Gregorian date.
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// Parses exactly `len` ascii digits.
+fn parse_digits<T: FromStr>(value: &str, len: usize) -> Option<T> {
+    match value.len() == len && value.chars().all(|c| c.is_ascii_digit()) {
+        true => value.parse().ok(),
+        false => None,
+    }
+}
+
+/// Splits off a trailing `Z` or `(+|-)hh:mm` timezone.
+fn split_timezone(value: &str) -> Result<(&str, Option<Timezone>), GregorianError> {
+    if let Some(value) = value.strip_suffix('Z') {
+        return Ok((value, Some(Timezone::UTC)));
+    }
+
+    let bytes = value.as_bytes();
+    let len = bytes.len();
+    if len >= 6 && matches!(bytes[len - 6], b'+' | b'-') && bytes[len - 3] == b':' {
+        let (value, timezone) = value.split_at(len - 6);
+        return Ok((value, Some(timezone.parse()?)));
+    }
+
+    Ok((value, None))
+}
+
+/// Years have at least four digits, leading zeros are only allowed to reach four digits.
+fn parse_year(value: &str) -> Result<i32, GregorianError> {
+    let invalid = || GregorianError::InvalidYear(value.to_string());
+    let digits = value.strip_prefix('-').unwrap_or(value);
+
+    if digits.len() < 4
+        || (digits.len() > 4 && digits.starts_with('0'))
+        || !digits.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    value.parse().map_err(|_| invalid())
+}
+
+fn parse_month(value: &str) -> Result<u8, GregorianError> {
+    let month =
+        parse_digits(value, 2).ok_or_else(|| GregorianError::InvalidMonth(value.to_string()))?;
+    check_month(month)?;
+    Ok(month)
+}
+
+fn parse_day(value: &str) -> Result<u8, GregorianError> {
+    match parse_digits(value, 2) {
+        Some(day @ 1..=31) => Ok(day),
+        _ => Err(GregorianError::InvalidDay(value.to_string())),
+    }
+}
+
+fn write_year(f: &mut Formatter, year: i32) -> fmt::Result {
+    if year < 0 {
+        write!(f, "-")?;
+    }
+    write!(f, "{:04}", year.unsigned_abs())
+}
+
+fn write_timezone(f: &mut Formatter, timezone: Option<Timezone>) -> fmt::Result {
+    match timezone {
+        Some(timezone) => write!(f, "{}", timezone),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_round_trip() {
+        assert_eq!("2024".parse::<GYear>().unwrap().to_string(), "2024");
+        assert_eq!("-0044".parse::<GYear>().unwrap().to_string(), "-0044");
+        assert_eq!("12024Z".parse::<GYear>().unwrap().to_string(), "12024Z");
+        assert_eq!(
+            "2024-05+02:00".parse::<GYearMonth>().unwrap().to_string(),
+            "2024-05+02:00"
+        );
+        assert_eq!("--05".parse::<GMonth>().unwrap().to_string(), "--05");
+        assert_eq!(
+            "--02-29-14:00".parse::<GMonthDay>().unwrap().to_string(),
+            "--02-29-14:00"
+        );
+        assert_eq!("---31Z".parse::<GDay>().unwrap().to_string(), "---31Z");
+    }
+
+    #[test]
+    fn normalizes_utc_offset() {
+        let year: GYear = "2024+00:00".parse().unwrap();
+        assert_eq!(year.to_string(), "2024Z");
+        assert_eq!(year, "2024Z".parse().unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_values() {
+        assert!("24".parse::<GYear>().is_err());
+        assert!("02024".parse::<GYear>().is_err());
+        assert!("2024-13".parse::<GYearMonth>().is_err());
+        assert!("--00".parse::<GMonth>().is_err());
+        assert!("--04-31".parse::<GMonthDay>().is_err());
+        assert!("---32".parse::<GDay>().is_err());
+        assert!("---01+14:30".parse::<GDay>().is_err());
+        assert!("2024-05".parse::<GYear>().is_err());
+    }
+
+    #[test]
+    fn timezone_offset_range() {
+        assert_eq!(
+            Timezone::from_offset_minutes(-14 * 60).unwrap().to_string(),
+            "-14:00"
+        );
+        assert!(Timezone::from_offset_minutes(14 * 60 + 1).is_err());
+        assert!(Timezone::from_offset_minutes(i16::MIN).is_err());
+        assert!(Timezone::from_offset_minutes(i16::MAX).is_err());
+    }
+
+    #[test]
+    fn ordered_by_start_instant() {
+        let mut years: Vec<GYear> = ["2024", "-0001", "2023", "0000"]
+            .into_iter()
+            .map(|v| v.parse().unwrap())
+            .collect();
+        years.sort();
+        let years: Vec<String> = years.iter().map(ToString::to_string).collect();
+        assert_eq!(years, ["-0001", "0000", "2023", "2024"]);
+
+        let east: GMonthDay = "--05-01+02:00".parse().unwrap();
+        let utc: GMonthDay = "--05-01Z".parse().unwrap();
+        assert!(east < utc);
+        assert!("--04-30".parse::<GMonthDay>().unwrap() < east);
+        assert!("--01".parse::<GMonth>().unwrap() < "--12".parse::<GMonth>().unwrap());
+        assert!("2024-01".parse::<GYearMonth>().unwrap() > "2023-12".parse().unwrap());
+    }
+}
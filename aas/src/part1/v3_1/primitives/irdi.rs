@@ -0,0 +1,248 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Base of the IRIs the ECLASS content delivery platform uses for its IRDIs,
+/// e.g. `https://api.eclass-cdp.com/0173-1-02-AAO677-002` for `0173-1#02-AAO677#002`.
+pub const ECLASS_IRI_BASE: &str = "https://api.eclass-cdp.com/";
+
+/// Registration authority identifier of ECLASS.
+const ECLASS_RAI: &str = "0173-1";
+
+/// International Registration Data Identifier (ISO 29002-5), as used by ECLASS and IEC CDD.
+///
+/// An IRDI consists of the registration authority identifier (RAI), the data identifier (DI)
+/// and an optional version identifier (VI), separated by `#`:
+/// `0173-1#02-AAO677#002` or `0112/2///61360_4#AAA001#001`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Irdi {
+    registration_authority: String,
+    data_identifier: String,
+    version: Option<String>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum IrdiError {
+    #[error("An IRDI needs a registration authority and a data identifier separated by '#'")]
+    MissingSeparator,
+
+    #[error("The registration authority of an IRDI must not be empty")]
+    EmptyRegistrationAuthority,
+
+    #[error("The data identifier of an IRDI must not be empty")]
+    EmptyDataIdentifier,
+
+    #[error("The version of an IRDI must consist of digits, found '{0}'")]
+    InvalidVersion(String),
+
+    #[error("Invalid character found")]
+    InvalidCharacter,
+}
+
+impl Irdi {
+    pub fn registration_authority(&self) -> &str {
+        &self.registration_authority
+    }
+
+    pub fn data_identifier(&self) -> &str {
+        &self.data_identifier
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// The IRDI without its version identifier, e.g. `0173-1#02-AAO677` for `0173-1#02-AAO677#002`
+    pub fn without_version(&self) -> Irdi {
+        Irdi {
+            version: None,
+            ..self.clone()
+        }
+    }
+
+    /// Reads an ECLASS IRDI in its IRI form, e.g. `https://api.eclass-cdp.com/0173-1-02-AAO677-002`.
+    /// The scheme and host are compared case-insensitive, http is accepted as well.
+    pub fn from_eclass_iri(iri: &str) -> Option<Irdi> {
+        let (_, base) = ECLASS_IRI_BASE.split_once("://")?;
+        let (scheme, rest) = iri.trim().split_once("://")?;
+        if !(scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("http"))
+            || rest.len() < base.len()
+            || !rest.is_char_boundary(base.len())
+            || !rest[..base.len()].eq_ignore_ascii_case(base)
+        {
+            return None;
+        }
+
+        // {RAI}-{code space}-{item code}[-{version}], the RAI of ECLASS contains a dash itself
+        let code = rest[base.len()..]
+            .strip_prefix(ECLASS_RAI)?
+            .strip_prefix('-')?;
+        let parts: Vec<_> = code.trim_end_matches('/').split('-').collect();
+        let (code_space, item, version) = match parts[..] {
+            [code_space, item] => (code_space, item, None),
+            [code_space, item, version] => (code_space, item, Some(version)),
+            _ => return None,
+        };
+
+        let irdi = Irdi {
+            registration_authority: ECLASS_RAI.into(),
+            data_identifier: format!("{code_space}-{item}"),
+            version: version.map(Into::into),
+        };
+        irdi.validate().ok().map(|_| irdi)
+    }
+
+    /// The IRI form of an ECLASS IRDI, `None` for IRDIs of other registration authorities.
+    pub fn to_eclass_iri(&self) -> Option<String> {
+        if self.registration_authority != ECLASS_RAI {
+            return None;
+        }
+
+        let mut iri = format!("{ECLASS_IRI_BASE}{}-{}", ECLASS_RAI, self.data_identifier);
+        if let Some(version) = &self.version {
+            iri.push('-');
+            iri.push_str(version);
+        }
+        Some(iri)
+    }
+
+    fn validate(&self) -> Result<(), IrdiError> {
+        if self.registration_authority.is_empty() {
+            return Err(IrdiError::EmptyRegistrationAuthority);
+        }
+
+        if self.data_identifier.is_empty() {
+            return Err(IrdiError::EmptyDataIdentifier);
+        }
+
+        if let Some(version) = &self.version
+            && (version.is_empty() || !version.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(IrdiError::InvalidVersion(version.clone()));
+        }
+
+        // no ':' so IRIs with a fragment are not taken for IRDIs
+        let valid = |part: &str, extra: &[char]| {
+            part.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c) || extra.contains(&c))
+        };
+        if !valid(&self.registration_authority, &['/']) || !valid(&self.data_identifier, &[]) {
+            return Err(IrdiError::InvalidCharacter);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Irdi {
+    type Err = IrdiError;
+
+    /// Reads `RAI#DI#VI` or `RAI#DI`. A percent-encoded separator (`%23`) is accepted as well.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().replace("%23", "#");
+        let mut parts = value.split('#');
+        let registration_authority = parts.next().unwrap_or_default();
+        let data_identifier = parts.next().ok_or(IrdiError::MissingSeparator)?;
+        let version = match (parts.next(), parts.next()) {
+            // a trailing '#' without a version
+            (Some(""), None) | (None, _) => None,
+            (Some(version), None) => Some(version),
+            (Some(_), Some(_)) => return Err(IrdiError::InvalidCharacter),
+        };
+
+        let irdi = Irdi {
+            registration_authority: registration_authority.into(),
+            data_identifier: data_identifier.into(),
+            version: version.map(Into::into),
+        };
+        irdi.validate()?;
+        Ok(irdi)
+    }
+}
+
+impl TryFrom<&str> for Irdi {
+    type Error = IrdiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for Irdi {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}#{}",
+            self.registration_authority, self.data_identifier
+        )?;
+        if let Some(version) = &self.version {
+            write!(f, "#{version}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::part1::v3_1::primitives::{Irdi, IrdiError};
+
+    #[test]
+    fn test_parse() {
+        let irdi: Irdi = "0173-1#02-AAO677#002".parse().unwrap();
+        assert_eq!(irdi.registration_authority(), "0173-1");
+        assert_eq!(irdi.data_identifier(), "02-AAO677");
+        assert_eq!(irdi.version(), Some("002"));
+        assert_eq!(irdi.to_string(), "0173-1#02-AAO677#002");
+
+        let irdi: Irdi = "0112/2///61360_4#AAA001".parse().unwrap();
+        assert_eq!(irdi.version(), None);
+        assert_eq!(irdi.to_string(), "0112/2///61360_4#AAA001");
+
+        let irdi: Irdi = "0173-1%2302-AAO677%23002".parse().unwrap();
+        assert_eq!(irdi.to_string(), "0173-1#02-AAO677#002");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            "https://example.com/id".parse::<Irdi>(),
+            Err(IrdiError::MissingSeparator)
+        );
+        assert_eq!(
+            "#02-AAO677#002".parse::<Irdi>(),
+            Err(IrdiError::EmptyRegistrationAuthority)
+        );
+        assert_eq!(
+            "0173-1#02-AAO677#v2".parse::<Irdi>(),
+            Err(IrdiError::InvalidVersion("v2".into()))
+        );
+        assert_eq!(
+            "0173-1#02 AAO677#002".parse::<Irdi>(),
+            Err(IrdiError::InvalidCharacter)
+        );
+        assert_eq!(
+            "https://example.com/unit#meter".parse::<Irdi>(),
+            Err(IrdiError::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn test_eclass_iri() {
+        let irdi =
+            Irdi::from_eclass_iri("https://api.eclass-cdp.com/0173-1-02-AAO677-002").unwrap();
+        assert_eq!(irdi, "0173-1#02-AAO677#002".parse().unwrap());
+        assert_eq!(
+            irdi.to_eclass_iri().as_deref(),
+            Some("https://api.eclass-cdp.com/0173-1-02-AAO677-002")
+        );
+
+        let irdi = Irdi::from_eclass_iri("HTTP://API.eclass-cdp.com/0173-1-02-AAO677/").unwrap();
+        assert_eq!(irdi.to_string(), "0173-1#02-AAO677");
+
+        assert_eq!(
+            Irdi::from_eclass_iri("https://example.com/0173-1-02-AAO677-002"),
+            None
+        );
+    }
+}
@@ -1,16 +1,22 @@
 pub mod data_type_def_xs;
+mod duration;
+mod gregorian;
 mod identifier;
+mod irdi;
 mod label;
 pub mod lang_string;
 mod message_topic;
 
+pub use duration::*;
+pub use gregorian::*;
 pub use identifier::*;
+pub use irdi::*;
 pub use label::*;
 pub use message_topic::*;
 
 use crate::part1::v3_1::LangString;
 
-// TODO: Base64 parsing
+/// Decoded bytes of a Blob, base64 is only used by the serializations.
 pub type BlobType = Vec<u8>;
 
 // TODO: Mime Parsing?
@@ -26,28 +32,3 @@ pub type MultiLanguageNameType = LangStringSet;
 
 pub type Uri = iref::UriRefBuf;
 pub type Iri = iref::IriRefBuf;
-
-pub(crate) mod xml {
-    use crate::part1::v3_1::primitives::MultiLanguageNameType;
-
-    // needed for e.g.
-    // <displayName>
-    //      <langStringTextType>...</langStringTextType>
-    //      <langStringTextType>...</langStringTextType>
-    // </displayName>
-    #[derive(Clone, PartialEq, Debug)]
-    pub struct LangStringTextType {
-        pub(crate) values: MultiLanguageNameType,
-    }
-
-    impl From<MultiLanguageNameType> for LangStringTextType {
-        fn from(value: MultiLanguageNameType) -> Self {
-            Self { values: value }
-        }
-    }
-    impl From<LangStringTextType> for MultiLanguageNameType {
-        fn from(value: LangStringTextType) -> Self {
-            value.values
-        }
-    }
-}
@@ -1,6 +1,11 @@
 use crate::part1::v3_1::key::Key;
+use crate::part1::v3_1::primitives::Irdi;
+use std::fmt;
+use std::fmt::Formatter;
+use std::mem;
 use std::ops::Deref;
-use strum::{Display, EnumString};
+use std::str::FromStr;
+use thiserror::Error;
 
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct ReferenceInner {
@@ -25,7 +30,11 @@ pub struct ReferenceInner {
 ///
 /// A `Reference` supports multi-level navigation through composite structures by chaining multiple keys,
 /// enabling precise targeting of nested submodels, submodel elements, or fragments.
-#[derive(EnumString, Clone, PartialEq, Debug, Display)]
+///
+/// `Display` and `FromStr` use the text notation of the specification, e.g.
+/// `[ModelRef](Submodel)urn:x, (Property)temp` or, with a referredSemanticId,
+/// `[ExternalRef- [ExternalRef](GlobalReference)urn:y -](GlobalReference)urn:z`.
+#[derive(Clone, PartialEq, Debug)]
 pub enum Reference {
     ExternalReference(ReferenceInner),
     ModelReference(ReferenceInner),
@@ -56,3 +65,494 @@ impl Deref for Reference {
         }
     }
 }
+
+/// Error of the reference text notation, the position counts characters from 0.
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum ReferenceTextError {
+    #[error("expected {expected} at position {position}")]
+    Expected {
+        position: usize,
+        expected: &'static str,
+    },
+
+    #[error(
+        "unknown reference type '{value}' at position {position}, expected ModelRef or ExternalRef"
+    )]
+    UnknownReferenceType { position: usize, value: String },
+
+    #[error("unknown key type '{value}' at position {position}")]
+    UnknownKeyType { position: usize, value: String },
+
+    #[error("empty key value at position {position}")]
+    EmptyKeyValue { position: usize },
+}
+
+impl ReferenceTextError {
+    pub fn position(&self) -> usize {
+        match self {
+            ReferenceTextError::Expected { position, .. }
+            | ReferenceTextError::UnknownReferenceType { position, .. }
+            | ReferenceTextError::UnknownKeyType { position, .. }
+            | ReferenceTextError::EmptyKeyValue { position } => *position,
+        }
+    }
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let ty = match self {
+            Reference::ExternalReference(_) => "ExternalRef",
+            Reference::ModelReference(_) => "ModelRef",
+        };
+        match &self.referred_semantic_id {
+            Some(referred) => write!(f, "[{ty}- {referred} -]")?,
+            None => write!(f, "[{ty}]")?,
+        }
+
+        for (i, key) in self.keys.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "({key}){}", key.value())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Reference {
+    type Err = ReferenceTextError;
+
+    /// Reads the text notation. Without the leading `[ModelRef]` or `[ExternalRef]`, a
+    /// reference starting with a GlobalReference or FragmentReference key is an
+    /// ExternalReference, any other a ModelReference.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parser = TextParser { text, position: 0 };
+        let reference = parser.reference(false)?;
+        parser.skip_whitespace();
+        match parser.rest().is_empty() {
+            true => Ok(reference),
+            false => Err(parser.expected("the end of the reference")),
+        }
+    }
+}
+
+impl Key {
+    /// The key in the text notation, e.g. `(Property)temp`
+    pub fn to_text(&self) -> String {
+        format!("({self}){}", self.value())
+    }
+
+    /// Reads a single key in the text notation, e.g. `(Property)temp`
+    pub fn from_text(text: &str) -> Result<Key, ReferenceTextError> {
+        let mut parser = TextParser { text, position: 0 };
+        parser.skip_whitespace();
+        let key = parser.key(false)?;
+        match parser.rest().is_empty() {
+            true => Ok(key),
+            false => Err(parser.expected("the end of the key")),
+        }
+    }
+}
+
+struct TextParser<'a> {
+    text: &'a str,
+
+    /// byte offset into text
+    position: usize,
+}
+
+impl<'a> TextParser<'a> {
+    fn rest(&self) -> &str {
+        &self.text[self.position..]
+    }
+
+    /// Character position for the errors
+    fn char_position(&self, position: usize) -> usize {
+        self.text[..position].chars().count()
+    }
+
+    fn expected(&self, expected: &'static str) -> ReferenceTextError {
+        ReferenceTextError::Expected {
+            position: self.char_position(self.position),
+            expected,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.position = self.text.len() - self.rest().trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.rest().starts_with(c);
+        if found {
+            self.position += c.len_utf8();
+        }
+        found
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), ReferenceTextError> {
+        match self.eat(c) {
+            true => Ok(()),
+            false => Err(self.expected(expected)),
+        }
+    }
+
+    /// Reads up to the next occurrence of one of the characters, not consuming it
+    fn until(&mut self, end: &[char]) -> (usize, &'a str) {
+        let start = self.position;
+        let length = self.rest().find(end).unwrap_or(self.rest().len());
+        self.position += length;
+        let text = self.text;
+        (start, &text[start..self.position])
+    }
+
+    fn reference(&mut self, nested: bool) -> Result<Reference, ReferenceTextError> {
+        self.skip_whitespace();
+        let mut model = None;
+        let mut referred_semantic_id = None;
+        if self.eat('[') {
+            let (start, ty) = self.until(&['-', ']']);
+            model = Some(match ty.trim() {
+                "ModelRef" | "ModelReference" => true,
+                "ExternalRef" | "ExternalReference" => false,
+                ty => {
+                    return Err(ReferenceTextError::UnknownReferenceType {
+                        position: self.char_position(start),
+                        value: ty.to_string(),
+                    });
+                }
+            });
+
+            if self.eat('-') {
+                referred_semantic_id = Some(Box::new(self.reference(true)?));
+                self.skip_whitespace();
+                self.expect('-', "'-' closing the referredSemanticId")?;
+            }
+            self.skip_whitespace();
+            self.expect(']', "']'")?;
+        }
+
+        let mut keys = vec![self.key(nested)?];
+        while self.next_key() {
+            self.skip_whitespace();
+            self.expect(',', "','")?;
+            self.skip_whitespace();
+            keys.push(self.key(nested)?);
+        }
+
+        let inner = ReferenceInner {
+            referred_semantic_id,
+            keys,
+        };
+        let model = model.unwrap_or_else(|| {
+            !matches!(
+                inner.keys[0],
+                Key::GlobalReference(_) | Key::FragmentReference(_)
+            )
+        });
+        Ok(match model {
+            true => Reference::ModelReference(inner),
+            false => Reference::ExternalReference(inner),
+        })
+    }
+
+    fn key(&mut self, nested: bool) -> Result<Key, ReferenceTextError> {
+        self.skip_whitespace();
+        self.expect('(', "'(' starting a key")?;
+        let (start, ty) = self.until(&[')']);
+        let mut key = Key::from_str(ty.trim()).map_err(|_| ReferenceTextError::UnknownKeyType {
+            position: self.char_position(start),
+            value: ty.to_string(),
+        })?;
+        self.expect(')', "')'")?;
+
+        // values may contain ',' or '-', so they end only before a further key or,
+        // in a referredSemanticId, before the closing '-]'
+        let start = self.position;
+        let mut end = self.text.len();
+        for (i, _) in self.rest().char_indices() {
+            let rest = self.text[start + i..].trim_start();
+            let next_key = rest
+                .strip_prefix(',')
+                .is_some_and(|rest| starts_with_key(rest.trim_start()));
+            let closing = nested
+                && rest
+                    .strip_prefix('-')
+                    .is_some_and(|rest| rest.trim_start().starts_with(']'));
+            if next_key || closing {
+                end = start + i;
+                break;
+            }
+        }
+
+        let value = self.text[start..end].trim();
+        if value.is_empty() {
+            return Err(ReferenceTextError::EmptyKeyValue {
+                position: self.char_position(start),
+            });
+        }
+        *key.value_mut() = value.to_string();
+        self.position = end;
+        Ok(key)
+    }
+
+    fn next_key(&self) -> bool {
+        self.rest()
+            .trim_start()
+            .strip_prefix(',')
+            .is_some_and(|rest| starts_with_key(rest.trim_start()))
+    }
+}
+
+/// Whether the text starts like a key, `(` letters `)`. The key type is checked by
+/// [TextParser::key], so typos are reported instead of becoming part of the previous value.
+fn starts_with_key(text: &str) -> bool {
+    text.strip_prefix('(')
+        .and_then(|text| text.split_once(')'))
+        .is_some_and(|(ty, _)| {
+            let ty = ty.trim();
+            !ty.is_empty() && ty.chars().all(|c| c.is_ascii_alphabetic())
+        })
+}
+
+/// Options for comparing semantic ids, see [Reference::is_equivalent].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct SemanticMatch {
+    /// Ignore the version of IRDIs (`0173-1#02-AAO677#002`) and the IDTA version segments
+    /// `/<major>/<minor>[/<patch>]` before the name of IRIs
+    /// (`https://admin-shell.io/zvei/nameplate/2/0/Nameplate`)
+    pub ignore_version: bool,
+
+    /// Let any semantic id or supplemental semantic id match, see
+    /// [HasSemantics::matches](crate::part1::v3_1::attributes::semantics::HasSemantics::matches)
+    pub supplemental: bool,
+}
+
+impl SemanticMatch {
+    pub fn ignore_version(mut self) -> Self {
+        self.ignore_version = true;
+        self
+    }
+
+    pub fn supplemental(mut self) -> Self {
+        self.supplemental = true;
+        self
+    }
+
+    /// Normalizes the value of a key for comparison:
+    /// - IRDIs, also in their ECLASS IRI form or with `%23` separators, are written as `RAI#DI#VI`
+    /// - IRIs get a lowercase scheme and host, `http` becomes `https` and trailing slashes are removed
+    /// - surrounding whitespace is removed
+    pub fn normalize(&self, value: &str) -> String {
+        let value = value.trim();
+        if let Some(irdi) = Irdi::from_eclass_iri(value).or_else(|| value.parse().ok()) {
+            return match self.ignore_version {
+                true => irdi.without_version().to_string(),
+                false => irdi.to_string(),
+            };
+        }
+
+        let Some((scheme, rest)) = value.split_once("://") else {
+            return value.to_string();
+        };
+        let scheme = match scheme.to_ascii_lowercase() {
+            scheme if scheme == "http" => "https".to_string(),
+            scheme => scheme,
+        };
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let mut segments: Vec<_> = path.split('/').filter(|s| !s.is_empty()).collect();
+        if self.ignore_version {
+            strip_version(&mut segments);
+        }
+
+        let mut normalized = format!("{scheme}://{}", host.to_ascii_lowercase());
+        for segment in segments {
+            normalized.push('/');
+            normalized.push_str(segment);
+        }
+        normalized
+    }
+}
+
+/// Removes `/<major>/<minor>[/<patch>]` directly before the final name segment.
+/// Other numeric segments, e.g. of `https://example.com/parts/4711`, are part of the id.
+fn strip_version(segments: &mut Vec<&str>) {
+    let Some((name, path)) = segments.split_last() else {
+        return;
+    };
+    if is_number(name) {
+        return;
+    }
+
+    let version = path
+        .iter()
+        .rev()
+        .take(3)
+        .take_while(|s| is_number(s))
+        .count();
+    if version >= 2 {
+        segments.drain(path.len() - version..path.len());
+    }
+}
+
+fn is_number(segment: &str) -> bool {
+    !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())
+}
+
+impl Reference {
+    /// Whether both references denote the same semantics, regardless of whether they are
+    /// an ExternalReference or a ModelReference, of the type of their first key and of
+    /// trivial differences in the key values, see [SemanticMatch::normalize].
+    ///
+    /// Further keys, e.g. a FragmentReference, have to be of the same type.
+    pub fn is_equivalent(&self, other: &Reference, options: &SemanticMatch) -> bool {
+        !self.keys.is_empty()
+            && self.keys.len() == other.keys.len()
+            && self
+                .keys
+                .iter()
+                .zip(&other.keys)
+                .enumerate()
+                .all(|(i, (a, b))| {
+                    (i == 0 || mem::discriminant(a) == mem::discriminant(b))
+                        && options.normalize(a.value()) == options.normalize(b.value())
+                })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn external(value: &str) -> Reference {
+        Reference::ExternalReference(ReferenceInner::new(Key::GlobalReference(value.into())))
+    }
+
+    #[test]
+    fn test_text() {
+        let reference: Reference = "[ModelRef](Submodel)urn:x,(Property)temp".parse().unwrap();
+        assert_eq!(
+            reference,
+            Reference::ModelReference(ReferenceInner::from_vec(vec![
+                Key::Submodel("urn:x".into()),
+                Key::Property("temp".into()),
+            ]))
+        );
+        assert_eq!(
+            reference.to_string(),
+            "[ModelRef](Submodel)urn:x, (Property)temp"
+        );
+
+        let text = "[ExternalRef- [ExternalRef](GlobalReference)0173-1#01-ADS698#010 -](GlobalReference)https://example.com/a,b, (FragmentReference)x-y";
+        let reference: Reference = text.parse().unwrap();
+        let referred = reference.referred_semantic_id.as_deref().unwrap();
+        assert_eq!(
+            referred.keys,
+            vec![Key::GlobalReference("0173-1#01-ADS698#010".into())]
+        );
+        assert_eq!(
+            reference.keys,
+            vec![
+                Key::GlobalReference("https://example.com/a,b".into()),
+                Key::FragmentReference("x-y".into()),
+            ]
+        );
+        assert_eq!(reference.to_string(), text);
+        assert!(matches!(
+            "(GlobalReference)urn:x".parse(),
+            Ok(Reference::ExternalReference(_))
+        ));
+        assert_eq!(
+            Key::from_text("(Property)temp"),
+            Ok(Key::Property("temp".into()))
+        );
+    }
+
+    #[test]
+    fn test_text_errors() {
+        let error = |text: &str| text.parse::<Reference>().unwrap_err();
+        assert_eq!(
+            error("[ModelRef](Submodel)urn:x, (Propety)temp").to_string(),
+            "unknown key type 'Propety' at position 28"
+        );
+        assert_eq!(
+            error("[ModelRef](Submodell)urn:x"),
+            ReferenceTextError::UnknownKeyType {
+                position: 11,
+                value: "Submodell".into()
+            }
+        );
+        assert_eq!(
+            error("[Model](Submodel)urn:x").to_string(),
+            "unknown reference type 'Model' at position 1, expected ModelRef or ExternalRef"
+        );
+        assert_eq!(error("[ModelRef](Submodel)").position(), 20);
+        assert_eq!(error("[ModelRef]Submodel").position(), 10);
+        assert_eq!(
+            error("[ModelRef- [ExternalRef](GlobalReference)urn:y](Submodel)urn:x").position(),
+            62
+        );
+    }
+
+    #[test]
+    fn test_equivalent() {
+        let options = SemanticMatch::default();
+        let irdi = external("0173-1#02-AAO677#002");
+        for other in [
+            external("https://api.eclass-cdp.com/0173-1-02-AAO677-002"),
+            external(" 0173-1%2302-AAO677%23002"),
+            Reference::ModelReference(ReferenceInner::new(Key::ConceptDescription(
+                "0173-1#02-AAO677#002".into(),
+            ))),
+        ] {
+            assert!(irdi.is_equivalent(&other, &options), "{other:?}");
+        }
+        assert!(!irdi.is_equivalent(&external("0173-1#02-AAO677#003"), &options));
+        assert!(irdi.is_equivalent(&external("0173-1#02-AAO677#003"), &options.ignore_version()));
+
+        let iri = external("https://admin-shell.io/zvei/nameplate/2/0/Nameplate");
+        assert!(iri.is_equivalent(
+            &external("http://Admin-Shell.io/zvei/nameplate/2/0/Nameplate/"),
+            &options
+        ));
+        assert!(!iri.is_equivalent(
+            &external("https://admin-shell.io/zvei/nameplate/1/0/Nameplate"),
+            &options
+        ));
+        assert!(iri.is_equivalent(
+            &external("https://admin-shell.io/zvei/nameplate/1/0/Nameplate"),
+            &options.ignore_version()
+        ));
+        assert!(!iri.is_equivalent(
+            &external("https://admin-shell.io/zvei/Nameplate/2/0/Nameplate"),
+            &options
+        ));
+        assert!(
+            external("https://admin-shell.io/idta/nameplate/3/0/1/Nameplate").is_equivalent(
+                &external("https://admin-shell.io/idta/nameplate/3/1/Nameplate"),
+                &options.ignore_version()
+            )
+        );
+
+        // numeric ids are no versions
+        for (a, b) in [
+            (
+                "https://example.com/parts/4711",
+                "https://example.com/parts/4712",
+            ),
+            (
+                "https://example.com/parts/4711/Motor",
+                "https://example.com/parts/4712/Motor",
+            ),
+            (
+                "https://example.com/parts/1/2/3",
+                "https://example.com/parts/1/2/4",
+            ),
+        ] {
+            assert!(
+                !external(a).is_equivalent(&external(b), &options.ignore_version()),
+                "{a}"
+            );
+        }
+    }
+}
@@ -0,0 +1,533 @@
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::concept_description::ConceptDescription;
+use crate::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use crate::part1::v3_1::environment::Environment;
+use crate::part1::v3_1::key::Key;
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::submodel_elements::{
+    AnnotatedRelationshipElement, BasicEventElement, Blob, Capability, DataElement, Entity, File,
+    MultiLanguageProperty, Operation, Property, Range, ReferenceElement, RelationshipElement,
+    SubmodelElement, SubmodelElementCollection, SubmodelElementList,
+};
+use strum::Display;
+use thiserror::Error;
+
+/// Borrowed, typed view on an element of an [Environment] a reference was resolved to.
+#[derive(Clone, Copy, PartialEq, Debug, Display)]
+pub enum Referred<'a> {
+    AssetAdministrationShell(&'a AssetAdministrationShell),
+    Submodel(&'a Submodel),
+    ConceptDescription(&'a ConceptDescription),
+    RelationshipElement(&'a RelationshipElement),
+    AnnotatedRelationshipElement(&'a AnnotatedRelationshipElement),
+    BasicEventElement(&'a BasicEventElement),
+    Blob(&'a Blob),
+    Capability(&'a Capability),
+    Entity(&'a Entity),
+    File(&'a File),
+    MultiLanguageProperty(&'a MultiLanguageProperty),
+    Operation(&'a Operation),
+    Property(&'a Property),
+    Range(&'a Range),
+    ReferenceElement(&'a ReferenceElement),
+    SubmodelElementCollection(&'a SubmodelElementCollection),
+    SubmodelElementList(&'a SubmodelElementList),
+}
+
+impl<'a> Referred<'a> {
+    /// The Referable attributes of the referred element.
+    /// For Identifiables these are the ones of their `identifiable` field.
+    pub fn referable(&self) -> &'a Referable {
+        match *self {
+            Referred::AssetAdministrationShell(shell) => &shell.identifiable.referable,
+            Referred::Submodel(submodel) => &submodel.identifiable.referable,
+            Referred::ConceptDescription(cd) => &cd.identifiable.referable,
+            Referred::RelationshipElement(element) => &element.referable,
+            Referred::AnnotatedRelationshipElement(element) => &element.referable,
+            Referred::BasicEventElement(element) => &element.submodel_element_fields.referable,
+            Referred::Blob(element) => &element.referable,
+            Referred::Capability(element) => &element.referable,
+            Referred::Entity(
+                Entity::CoManagedEntity(element) | Entity::SelfManagedEntity(element),
+            ) => &element.referable,
+            Referred::File(element) => &element.referable,
+            Referred::MultiLanguageProperty(element) => &element.referable,
+            Referred::Operation(element) => &element.referable,
+            Referred::Property(element) => &element.referable,
+            Referred::Range(element) => &element.referable,
+            Referred::ReferenceElement(element) => &element.referable,
+            Referred::SubmodelElementCollection(element) => &element.referable,
+            Referred::SubmodelElementList(element) => &element.referable,
+        }
+    }
+
+    /// Whether the referred element is an AssetAdministrationShell, Submodel or ConceptDescription.
+    pub fn is_identifiable(&self) -> bool {
+        matches!(
+            self,
+            Referred::AssetAdministrationShell(_)
+                | Referred::Submodel(_)
+                | Referred::ConceptDescription(_)
+        )
+    }
+
+    /// Checks whether the type of the key is fulfilled by the referred element,
+    /// taking the abstract key types (e.g. DataElement, EventElement) into account.
+    fn matches(&self, key: &Key) -> bool {
+        match key {
+            Key::Referable(_) => true,
+            Key::Identifiable(_) => self.is_identifiable(),
+            Key::SubmodelElement(_) => !self.is_identifiable(),
+            Key::DataElement(_) => matches!(
+                self,
+                Referred::Blob(_)
+                    | Referred::File(_)
+                    | Referred::MultiLanguageProperty(_)
+                    | Referred::Property(_)
+                    | Referred::Range(_)
+                    | Referred::ReferenceElement(_)
+            ),
+            Key::EventElement(_) => matches!(self, Referred::BasicEventElement(_)),
+            Key::RelationshipElement(_) => matches!(
+                self,
+                Referred::RelationshipElement(_) | Referred::AnnotatedRelationshipElement(_)
+            ),
+            Key::AssetAdministrationShell(_) => {
+                matches!(self, Referred::AssetAdministrationShell(_))
+            }
+            Key::Submodel(_) => matches!(self, Referred::Submodel(_)),
+            Key::ConceptDescription(_) => matches!(self, Referred::ConceptDescription(_)),
+            Key::AnnotatedRelationshipElement(_) => {
+                matches!(self, Referred::AnnotatedRelationshipElement(_))
+            }
+            Key::BasicEventElement(_) => matches!(self, Referred::BasicEventElement(_)),
+            Key::Blob(_) => matches!(self, Referred::Blob(_)),
+            Key::Capability(_) => matches!(self, Referred::Capability(_)),
+            Key::Entity(_) => matches!(self, Referred::Entity(_)),
+            Key::File(_) => matches!(self, Referred::File(_)),
+            Key::MultiLanguageProperty(_) => matches!(self, Referred::MultiLanguageProperty(_)),
+            Key::Operation(_) => matches!(self, Referred::Operation(_)),
+            Key::Property(_) => matches!(self, Referred::Property(_)),
+            Key::Range(_) => matches!(self, Referred::Range(_)),
+            Key::ReferenceElement(_) => matches!(self, Referred::ReferenceElement(_)),
+            Key::SubmodelElementCollection(_) => {
+                matches!(self, Referred::SubmodelElementCollection(_))
+            }
+            Key::SubmodelElementList(_) => matches!(self, Referred::SubmodelElementList(_)),
+            Key::FragmentReference(_) | Key::GlobalReference(_) => false,
+        }
+    }
+
+    /// Looks up the child addressed by `key`.
+    /// Children of a SubmodelElementList are addressed by their index, all others by idShort.
+    fn child(self, key: &Key) -> Result<Referred<'a>, KeyError> {
+        let children: Vec<Referred<'a>> = match self {
+            Referred::Submodel(submodel) => submodel
+                .submodel_elements
+                .iter()
+                .flatten()
+                .map(Referred::from)
+                .collect(),
+            Referred::SubmodelElementCollection(collection) => collection
+                .value
+                .iter()
+                .flatten()
+                .map(Referred::from)
+                .collect(),
+            Referred::Entity(
+                Entity::CoManagedEntity(entity) | Entity::SelfManagedEntity(entity),
+            ) => entity
+                .statements
+                .iter()
+                .flatten()
+                .map(Referred::from)
+                .collect(),
+            Referred::Operation(operation) => [
+                &operation.input_variables,
+                &operation.output_variables,
+                &operation.inoutput_variables,
+            ]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|variable| Referred::from(&variable.value))
+            .collect(),
+            Referred::AnnotatedRelationshipElement(element) => element
+                .annotations
+                .iter()
+                .flatten()
+                .map(Referred::from)
+                .collect(),
+            Referred::SubmodelElementList(list) => {
+                let elements = list.value.as_deref().unwrap_or_default();
+                let index: usize = key.value().parse().map_err(|_| KeyError::InvalidIndex)?;
+                let child = elements.get(index).ok_or(KeyError::IndexOutOfRange {
+                    len: elements.len(),
+                })?;
+
+                return Referred::from(child).checked(key);
+            }
+            parent => {
+                return Err(KeyError::NoChildren {
+                    parent: parent.to_string(),
+                });
+            }
+        };
+
+        children
+            .into_iter()
+            .find(|child| child.referable().id_short.as_deref() == Some(key.value().as_str()))
+            .ok_or(KeyError::NotFound)?
+            .checked(key)
+    }
+
+    fn checked(self, key: &Key) -> Result<Referred<'a>, KeyError> {
+        if self.matches(key) {
+            Ok(self)
+        } else {
+            Err(KeyError::WrongType {
+                found: self.to_string(),
+            })
+        }
+    }
+}
+
+impl<'a> From<&'a SubmodelElement> for Referred<'a> {
+    fn from(element: &'a SubmodelElement) -> Self {
+        match element {
+            SubmodelElement::RelationshipElement(e) => Referred::RelationshipElement(e),
+            SubmodelElement::AnnotatedRelationshipElement(e) => {
+                Referred::AnnotatedRelationshipElement(e)
+            }
+            SubmodelElement::BasicEventElement(e) => Referred::BasicEventElement(e),
+            SubmodelElement::Blob(e) => Referred::Blob(e),
+            SubmodelElement::Capability(e) => Referred::Capability(e),
+            SubmodelElement::DataElement(e) => Referred::from(e),
+            SubmodelElement::Entity(e) => Referred::Entity(e),
+            SubmodelElement::File(e) => Referred::File(e),
+            SubmodelElement::MultiLanguageProperty(e) => Referred::MultiLanguageProperty(e),
+            SubmodelElement::Operation(e) => Referred::Operation(e),
+            SubmodelElement::Property(e) => Referred::Property(e),
+            SubmodelElement::Range(e) => Referred::Range(e),
+            SubmodelElement::ReferenceElement(e) => Referred::ReferenceElement(e),
+            SubmodelElement::SubmodelElementCollection(e) => Referred::SubmodelElementCollection(e),
+            SubmodelElement::SubmodelElementList(e) => Referred::SubmodelElementList(e),
+        }
+    }
+}
+
+impl<'a> From<&'a DataElement> for Referred<'a> {
+    fn from(element: &'a DataElement) -> Self {
+        match element {
+            DataElement::Blob(e) => Referred::Blob(e),
+            DataElement::File(e) => Referred::File(e),
+            DataElement::MultiLanguageProperty(e) => Referred::MultiLanguageProperty(e),
+            DataElement::Property(e) => Referred::Property(e),
+            DataElement::Range(e) => Referred::Range(e),
+            DataElement::ReferenceElement(e) => Referred::ReferenceElement(e),
+        }
+    }
+}
+
+/// Result of resolving a ModelReference.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Resolved<'a> {
+    /// The element the keys point to
+    pub target: Referred<'a>,
+
+    /// Value of a trailing FragmentReference key, e.g. a position inside a File
+    pub fragment: Option<String>,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ResolveError {
+    #[error("External references can not be resolved against an environment")]
+    ExternalReference,
+
+    #[error("The reference has no keys")]
+    NoKeys,
+
+    #[error("Key {index} ({key} '{}'): {reason}", .key.value())]
+    Key {
+        /// Position of the failing key in the reference
+        index: usize,
+        key: Key,
+        reason: KeyError,
+    },
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum KeyError {
+    #[error("the first key has to refer to an Identifiable")]
+    NotIdentifiable,
+
+    #[error("no element with this id or idShort exists")]
+    NotFound,
+
+    #[error("the referred element is a {found}")]
+    WrongType { found: String },
+
+    #[error("a {parent} has no child elements")]
+    NoChildren { parent: String },
+
+    #[error("the key of a SubmodelElementList child has to be an index")]
+    InvalidIndex,
+
+    #[error("the index is out of range for a list with {len} elements")]
+    IndexOutOfRange { len: usize },
+
+    #[error("a FragmentReference is only allowed as last key following a File or Blob")]
+    MisplacedFragment,
+}
+
+impl Environment {
+    /// Resolves a ModelReference to the element of this environment it points to.
+    ///
+    /// The first key has to refer to an AssetAdministrationShell, Submodel or ConceptDescription
+    /// by its id; all following keys walk down the submodel elements by idShort, or by index for
+    /// the children of a SubmodelElementList.
+    /// A final FragmentReference key after a File or Blob is returned as [Resolved::fragment].
+    pub fn resolve(&self, reference: &Reference) -> Result<Resolved<'_>, ResolveError> {
+        let Reference::ModelReference(inner) = reference else {
+            return Err(ResolveError::ExternalReference);
+        };
+        let (first, rest) = inner.keys.split_first().ok_or(ResolveError::NoKeys)?;
+
+        let mut target = self
+            .find_identifiable(first)
+            .map_err(|reason| ResolveError::Key {
+                index: 0,
+                key: first.clone(),
+                reason,
+            })?;
+        let mut fragment = None;
+
+        for (index, key) in rest.iter().enumerate().map(|(i, key)| (i + 1, key)) {
+            let error = |reason| ResolveError::Key {
+                index,
+                key: key.clone(),
+                reason,
+            };
+
+            if let Key::FragmentReference(value) = key {
+                if index != rest.len() || !matches!(target, Referred::File(_) | Referred::Blob(_)) {
+                    return Err(error(KeyError::MisplacedFragment));
+                }
+                fragment = Some(value.clone());
+                continue;
+            }
+
+            target = target.child(key).map_err(error)?;
+        }
+
+        Ok(Resolved { target, fragment })
+    }
+
+    fn find_identifiable(&self, key: &Key) -> Result<Referred<'_>, KeyError> {
+        if !matches!(
+            key,
+            Key::AssetAdministrationShell(_)
+                | Key::Submodel(_)
+                | Key::ConceptDescription(_)
+                | Key::Identifiable(_)
+                | Key::Referable(_)
+        ) {
+            return Err(KeyError::NotIdentifiable);
+        }
+
+        let shells = self
+            .asset_administration_shells
+            .iter()
+            .flatten()
+            .map(Referred::AssetAdministrationShell);
+        let submodels = self.submodels.iter().flatten().map(Referred::Submodel);
+        let concept_descriptions = self
+            .concept_descriptions
+            .iter()
+            .flatten()
+            .map(Referred::ConceptDescription);
+
+        shells
+            .chain(submodels)
+            .chain(concept_descriptions)
+            .find(|identifiable| identifiable.id() == Some(key.value().as_str()))
+            .ok_or(KeyError::NotFound)?
+            .checked(key)
+    }
+}
+
+impl Referred<'_> {
+    fn id(&self) -> Option<&str> {
+        match self {
+            Referred::AssetAdministrationShell(shell) => Some(&shell.identifiable.id),
+            Referred::Submodel(submodel) => Some(&submodel.identifiable.id),
+            Referred::ConceptDescription(cd) => Some(&cd.identifiable.id),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::fixtures::{SUBMODEL_ID, environment, property, referable, submodel};
+    use crate::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use crate::part1::v3_1::reference::ReferenceInner;
+    use crate::part1::v3_1::submodel_elements::AasSubmodelElements;
+
+    /// Property with its idShort as value
+    fn named(id_short: &str) -> SubmodelElement {
+        property(Some(id_short), DataXsd::String(Some(id_short.into()))).into()
+    }
+
+    fn env() -> Environment {
+        let collection = SubmodelElement::SubmodelElementCollection(SubmodelElementCollection {
+            referable: referable("Collection"),
+            value: Some(vec![named("Inner")]),
+            ..Default::default()
+        });
+        let list = SubmodelElement::SubmodelElementList(SubmodelElementList {
+            referable: referable("List"),
+            semantics: Default::default(),
+            qualifiable: Default::default(),
+            embedded_data_specifications: Default::default(),
+            is_order_relevant: true,
+            semantic_id_list_element: None,
+            value: Some(vec![named("First"), named("Second")]),
+            type_value_list_element: AasSubmodelElements::Property,
+            value_type_list_element: None,
+        });
+        let file = SubmodelElement::File(File {
+            referable: referable("Manual"),
+            ..Default::default()
+        });
+
+        environment(vec![submodel(vec![collection, list, file])])
+    }
+
+    fn model_reference(keys: Vec<Key>) -> Reference {
+        Reference::ModelReference(ReferenceInner::from_vec(keys))
+    }
+
+    fn submodel_key() -> Key {
+        Key::Submodel(SUBMODEL_ID.into())
+    }
+
+    #[test]
+    fn resolves_nested_elements() {
+        let env = env();
+
+        let resolved = env
+            .resolve(&model_reference(vec![
+                submodel_key(),
+                Key::SubmodelElementCollection("Collection".into()),
+                Key::DataElement("Inner".into()),
+            ]))
+            .unwrap();
+        assert!(
+            matches!(resolved.target, Referred::Property(p) if p.value == DataXsd::String(Some("Inner".into())))
+        );
+        assert_eq!(resolved.fragment, None);
+
+        let resolved = env
+            .resolve(&model_reference(vec![
+                submodel_key(),
+                Key::SubmodelElementList("List".into()),
+                Key::Property("1".into()),
+            ]))
+            .unwrap();
+        assert_eq!(
+            resolved.target.referable().id_short.as_deref(),
+            Some("Second")
+        );
+
+        let resolved = env
+            .resolve(&model_reference(vec![
+                submodel_key(),
+                Key::File("Manual".into()),
+                Key::FragmentReference("page=3".into()),
+            ]))
+            .unwrap();
+        assert!(matches!(resolved.target, Referred::File(_)));
+        assert_eq!(resolved.fragment.as_deref(), Some("page=3"));
+    }
+
+    #[test]
+    fn reports_the_failing_key() {
+        let env = env();
+        let resolve = |keys| env.resolve(&model_reference(keys)).unwrap_err();
+
+        assert_eq!(
+            resolve(vec![submodel_key(), Key::Property("Collection".into())]),
+            ResolveError::Key {
+                index: 1,
+                key: Key::Property("Collection".into()),
+                reason: KeyError::WrongType {
+                    found: "SubmodelElementCollection".into()
+                },
+            }
+        );
+        assert_eq!(
+            resolve(vec![
+                submodel_key(),
+                Key::SubmodelElementCollection("Collection".into()),
+                Key::Property("Missing".into()),
+            ]),
+            ResolveError::Key {
+                index: 2,
+                key: Key::Property("Missing".into()),
+                reason: KeyError::NotFound,
+            }
+        );
+        assert_eq!(
+            resolve(vec![
+                submodel_key(),
+                Key::SubmodelElementList("List".into()),
+                Key::Property("2".into()),
+            ]),
+            ResolveError::Key {
+                index: 2,
+                key: Key::Property("2".into()),
+                reason: KeyError::IndexOutOfRange { len: 2 },
+            }
+        );
+        assert_eq!(
+            resolve(vec![
+                submodel_key(),
+                Key::SubmodelElementList("List".into()),
+                Key::Property("First".into()),
+            ])
+            .to_string(),
+            "Key 2 (Property 'First'): the key of a SubmodelElementList child has to be an index"
+        );
+        assert_eq!(
+            resolve(vec![Key::AssetAdministrationShell(
+                "https://example.com/sm/1".into()
+            )]),
+            ResolveError::Key {
+                index: 0,
+                key: Key::AssetAdministrationShell("https://example.com/sm/1".into()),
+                reason: KeyError::WrongType {
+                    found: "Submodel".into()
+                },
+            }
+        );
+        assert_eq!(
+            resolve(vec![
+                submodel_key(),
+                Key::FragmentReference("page=3".into())
+            ]),
+            ResolveError::Key {
+                index: 1,
+                key: Key::FragmentReference("page=3".into()),
+                reason: KeyError::MisplacedFragment,
+            }
+        );
+        assert_eq!(
+            env.resolve(&Reference::ExternalReference(ReferenceInner::new(
+                submodel_key()
+            ))),
+            Err(ResolveError::ExternalReference)
+        );
+    }
+}
@@ -6,7 +6,7 @@ use strum::{Display, EnumString};
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct BasicEventElement {
-    submodel_element_fields: SubmodelElementFields,
+    pub(crate) submodel_element_fields: SubmodelElementFields,
 
     pub observed: Reference,
 
@@ -30,7 +30,7 @@ pub struct BasicEventElement {
 #[derive(Clone, PartialEq, Debug)]
 
 pub struct BasicEventElementMeta {
-    submodel_element_fields: SubmodelElementFields,
+    pub(crate) submodel_element_fields: SubmodelElementFields,
 
     pub direction: Direction,
 
@@ -49,6 +49,43 @@ pub struct BasicEventElementMeta {
     pub max_interval: Option<String>,
 }
 
+impl BasicEventElement {
+    pub fn new(
+        submodel_element_fields: SubmodelElementFields,
+        observed: Reference,
+        direction: Direction,
+        state: StateOfEvent,
+    ) -> Self {
+        Self {
+            submodel_element_fields,
+            observed,
+            direction,
+            state,
+            message_topic: None,
+            message_broker: None,
+            last_update: None,
+            min_interval: None,
+            max_interval: None,
+        }
+    }
+
+    /// idShort, semanticId, qualifiers and data specifications of the element
+    pub fn submodel_element_fields(&self) -> &SubmodelElementFields {
+        &self.submodel_element_fields
+    }
+
+    pub fn submodel_element_fields_mut(&mut self) -> &mut SubmodelElementFields {
+        &mut self.submodel_element_fields
+    }
+}
+
+impl BasicEventElementMeta {
+    /// idShort, semanticId, qualifiers and data specifications of the element
+    pub fn submodel_element_fields(&self) -> &SubmodelElementFields {
+        &self.submodel_element_fields
+    }
+}
+
 impl From<BasicEventElement> for BasicEventElementMeta {
     fn from(element: BasicEventElement) -> Self {
         Self {
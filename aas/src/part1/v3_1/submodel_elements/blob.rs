@@ -1,10 +1,8 @@
-use crate::part1::MetamodelError;
-use crate::part1::ToJsonMetamodel;
 use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
 use crate::part1::v3_1::attributes::referable::Referable;
 use crate::part1::v3_1::attributes::semantics::HasSemantics;
-use crate::part1::v3_1::primitives::ContentType;
+use crate::part1::v3_1::primitives::{BlobType, ContentType};
 
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct Blob {
@@ -17,16 +15,16 @@ pub struct Blob {
 
     pub embedded_data_specifications: HasDataSpecification,
     // ----- end inheritance
-
-    // TODO "contentEncoding": "base64"
-    pub value: Option<String>,
+    /// The decoded content of the blob.
+    /// Serializations encode it as base64 ("contentEncoding": "base64").
+    pub value: Option<BlobType>,
 
     // TODO typing. Add constraints. New type..
     pub content_type: ContentType,
 }
 
 impl Blob {
-    pub fn new(value: Option<String>, content_type: String) -> Self {
+    pub fn new(value: Option<BlobType>, content_type: ContentType) -> Self {
         Self {
             referable: Referable::default(),
             semantics: HasSemantics::default(),
@@ -67,3 +65,31 @@ impl From<&Blob> for BlobMeta {
         blob.clone().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::submodel_elements::{Operation, SubmodelElement};
+
+    #[test]
+    fn strips_nested_blob_values() {
+        let blob = Blob::new(Some(vec![0xde, 0xad]), "application/octet-stream".into());
+        let operation = SubmodelElement::Operation(Operation {
+            input_variables: Some(vec![SubmodelElement::Blob(blob.clone()).into()]),
+            ..Default::default()
+        });
+
+        let SubmodelElement::Operation(operation) = operation.without_blob_values() else {
+            unreachable!()
+        };
+        let stripped = &operation.input_variables.unwrap()[0].value;
+
+        assert_eq!(
+            stripped,
+            &SubmodelElement::Blob(Blob {
+                value: None,
+                ..blob
+            })
+        );
+    }
+}
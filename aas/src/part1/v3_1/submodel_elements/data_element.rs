@@ -1,10 +1,11 @@
-use crate::part1::v3_1::submodel_elements::Blob;
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::attributes::semantics::HasSemantics;
 use crate::part1::v3_1::submodel_elements::file::File;
 use crate::part1::v3_1::submodel_elements::multi_language_property::MultiLanguageProperty;
 use crate::part1::v3_1::submodel_elements::property::Property;
 use crate::part1::v3_1::submodel_elements::range::Range;
 use crate::part1::v3_1::submodel_elements::reference_element::ReferenceElement;
-use crate::part1::{MetamodelError, ToJsonMetamodel};
+use crate::part1::v3_1::submodel_elements::{Blob, SubmodelElement, impl_from_element};
 
 use strum::Display;
 
@@ -17,3 +18,69 @@ pub enum DataElement {
     Range(Range),
     ReferenceElement(ReferenceElement),
 }
+
+impl DataElement {
+    /// The Referable attributes (idShort, displayName, ...) of the element
+    pub fn referable(&self) -> &Referable {
+        match self {
+            DataElement::Blob(element) => &element.referable,
+            DataElement::File(element) => &element.referable,
+            DataElement::MultiLanguageProperty(element) => &element.referable,
+            DataElement::Property(element) => &element.referable,
+            DataElement::Range(element) => &element.referable,
+            DataElement::ReferenceElement(element) => &element.referable,
+        }
+    }
+
+    /// The semanticId and supplementalSemanticIds of the element
+    pub fn semantics(&self) -> &HasSemantics {
+        match self {
+            DataElement::Blob(element) => &element.semantics,
+            DataElement::File(element) => &element.semantics,
+            DataElement::MultiLanguageProperty(element) => &element.semantics,
+            DataElement::Property(element) => &element.semantics,
+            DataElement::Range(element) => &element.semantics,
+            DataElement::ReferenceElement(element) => &element.semantics,
+        }
+    }
+}
+
+impl_from_element!(
+    DataElement: Blob,
+    File,
+    MultiLanguageProperty,
+    Property,
+    Range,
+    ReferenceElement,
+);
+
+impl From<DataElement> for SubmodelElement {
+    fn from(element: DataElement) -> Self {
+        match element {
+            DataElement::Blob(e) => SubmodelElement::Blob(e),
+            DataElement::File(e) => SubmodelElement::File(e),
+            DataElement::MultiLanguageProperty(e) => SubmodelElement::MultiLanguageProperty(e),
+            DataElement::Property(e) => SubmodelElement::Property(e),
+            DataElement::Range(e) => SubmodelElement::Range(e),
+            DataElement::ReferenceElement(e) => SubmodelElement::ReferenceElement(e),
+        }
+    }
+}
+
+/// Returns the element unchanged if it is no DataElement.
+impl TryFrom<SubmodelElement> for DataElement {
+    type Error = SubmodelElement;
+
+    fn try_from(element: SubmodelElement) -> Result<Self, Self::Error> {
+        match element {
+            SubmodelElement::Blob(e) => Ok(DataElement::Blob(e)),
+            SubmodelElement::DataElement(e) => Ok(e),
+            SubmodelElement::File(e) => Ok(DataElement::File(e)),
+            SubmodelElement::MultiLanguageProperty(e) => Ok(DataElement::MultiLanguageProperty(e)),
+            SubmodelElement::Property(e) => Ok(DataElement::Property(e)),
+            SubmodelElement::Range(e) => Ok(DataElement::Range(e)),
+            SubmodelElement::ReferenceElement(e) => Ok(DataElement::ReferenceElement(e)),
+            other => Err(other),
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use crate::part1::ToJsonMetamodel;
 use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
 use crate::part1::v3_1::attributes::referable::Referable;
@@ -43,14 +42,53 @@ pub struct EntityInner {
     pub specific_asset_id: Option<Vec<SpecificAssetId>>,
 }
 
-impl ToJsonMetamodel for Entity {
-    type Error = ();
+/// Distinguishes whether an [Entity] is co-managed or self-managed.
+#[derive(Clone, PartialEq, Debug, Display, EnumString)]
+pub enum EntityType {
+    CoManagedEntity,
+
+    SelfManagedEntity,
+}
 
-    fn to_json_metamodel(&self) -> Result<String, Self::Error> {
-        // TODO: Add modelType
+impl Entity {
+    pub fn entity_type(&self) -> EntityType {
         match self {
-            Entity::CoManagedEntity(_) => Ok(r#"{"entityType":"CoManagedEntity"}"#.into()),
-            Entity::SelfManagedEntity(_) => Ok(r#"{"entityType":"SelfManagedEntity"}"#.into()),
+            Entity::CoManagedEntity(_) => EntityType::CoManagedEntity,
+            Entity::SelfManagedEntity(_) => EntityType::SelfManagedEntity,
         }
     }
 }
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct EntityMeta {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+    pub entity_type: EntityType,
+}
+
+impl From<Entity> for EntityMeta {
+    fn from(entity: Entity) -> Self {
+        let entity_type = entity.entity_type();
+        let (Entity::CoManagedEntity(element) | Entity::SelfManagedEntity(element)) = entity;
+        Self {
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
+            entity_type,
+        }
+    }
+}
+
+impl From<&Entity> for EntityMeta {
+    fn from(entity: &Entity) -> Self {
+        entity.clone().into()
+    }
+}
@@ -12,7 +12,6 @@ mod reference_element;
 mod relationship_element;
 mod submodel_element_collection;
 
-use std::collections::HashMap;
 pub use submodel_element_collection::*;
 mod submodel_element_list;
 pub use submodel_element_list::*;
@@ -21,21 +20,33 @@ use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
 use crate::part1::v3_1::attributes::referable::Referable;
 use crate::part1::v3_1::attributes::semantics::HasSemantics;
-use crate::part1::v3_1::submodel_elements::basic_event::BasicEventElement;
-pub use crate::part1::v3_1::submodel_elements::blob::Blob;
+pub use crate::part1::v3_1::submodel_elements::basic_event::{
+    BasicEventElement, BasicEventElementMeta, Direction, StateOfEvent,
+};
+pub use crate::part1::v3_1::submodel_elements::blob::{Blob, BlobMeta};
 pub use crate::part1::v3_1::submodel_elements::capability::Capability;
-use crate::part1::v3_1::submodel_elements::data_element::DataElement;
-use crate::part1::v3_1::submodel_elements::entity::Entity;
-use crate::part1::v3_1::submodel_elements::file::File;
-use crate::part1::v3_1::submodel_elements::multi_language_property::MultiLanguageProperty;
-use crate::part1::v3_1::submodel_elements::operation::Operation;
-use crate::part1::v3_1::submodel_elements::property::Property;
-use crate::part1::v3_1::submodel_elements::range::Range;
-use crate::part1::v3_1::submodel_elements::reference_element::ReferenceElement;
-use crate::part1::v3_1::submodel_elements::relationship_element::{
-    AnnotatedRelationshipElement, RelationshipElement,
+pub use crate::part1::v3_1::submodel_elements::data_element::DataElement;
+pub use crate::part1::v3_1::submodel_elements::entity::{
+    Entity, EntityInner, EntityMeta, EntityType,
+};
+pub use crate::part1::v3_1::submodel_elements::file::{File, FileMeta};
+pub use crate::part1::v3_1::submodel_elements::multi_language_property::{
+    MultiLanguageProperty, MultiLanguagePropertyMeta,
+};
+pub use crate::part1::v3_1::submodel_elements::operation::{
+    Operation, OperationMeta, OperationVariable,
+};
+pub use crate::part1::v3_1::submodel_elements::property::{Property, PropertyMeta};
+pub use crate::part1::v3_1::submodel_elements::range::{
+    Range, RangeError, RangeInner, RangeMeta, RangeValue,
+};
+pub use crate::part1::v3_1::submodel_elements::reference_element::{
+    ReferenceElement, ReferenceElementMeta,
+};
+pub use crate::part1::v3_1::submodel_elements::relationship_element::{
+    AnnotatedRelationshipElement, AnnotatedRelationshipElementMeta, RelationshipElement,
+    RelationshipElementMeta,
 };
-use crate::part1::{MetamodelError, ToJsonMetamodel};
 
 use strum::{Display, EnumString};
 
@@ -72,6 +83,131 @@ pub enum SubmodelElement {
     SubmodelElementList(SubmodelElementList),
 }
 
+impl SubmodelElement {
+    /// The Referable attributes (idShort, displayName, ...) of the element
+    pub fn referable(&self) -> &Referable {
+        match self {
+            SubmodelElement::RelationshipElement(element) => &element.referable,
+            SubmodelElement::AnnotatedRelationshipElement(element) => &element.referable,
+            SubmodelElement::BasicEventElement(element) => {
+                &element.submodel_element_fields.referable
+            }
+            SubmodelElement::Blob(element) => &element.referable,
+            SubmodelElement::Capability(element) => &element.referable,
+            SubmodelElement::DataElement(element) => element.referable(),
+            SubmodelElement::Entity(
+                Entity::CoManagedEntity(element) | Entity::SelfManagedEntity(element),
+            ) => &element.referable,
+            SubmodelElement::File(element) => &element.referable,
+            SubmodelElement::MultiLanguageProperty(element) => &element.referable,
+            SubmodelElement::Operation(element) => &element.referable,
+            SubmodelElement::Property(element) => &element.referable,
+            SubmodelElement::Range(element) => &element.referable,
+            SubmodelElement::ReferenceElement(element) => &element.referable,
+            SubmodelElement::SubmodelElementCollection(element) => &element.referable,
+            SubmodelElement::SubmodelElementList(element) => &element.referable,
+        }
+    }
+
+    /// The semanticId and supplementalSemanticIds of the element
+    pub fn semantics(&self) -> &HasSemantics {
+        match self {
+            SubmodelElement::RelationshipElement(element) => &element.semantics,
+            SubmodelElement::AnnotatedRelationshipElement(element) => &element.semantics,
+            SubmodelElement::BasicEventElement(element) => {
+                &element.submodel_element_fields.semantics
+            }
+            SubmodelElement::Blob(element) => &element.semantics,
+            SubmodelElement::Capability(element) => &element.semantics,
+            SubmodelElement::DataElement(element) => element.semantics(),
+            SubmodelElement::Entity(
+                Entity::CoManagedEntity(element) | Entity::SelfManagedEntity(element),
+            ) => &element.semantics,
+            SubmodelElement::File(element) => &element.semantics,
+            SubmodelElement::MultiLanguageProperty(element) => &element.semantics,
+            SubmodelElement::Operation(element) => &element.semantics,
+            SubmodelElement::Property(element) => &element.semantics,
+            SubmodelElement::Range(element) => &element.semantics,
+            SubmodelElement::ReferenceElement(element) => &element.semantics,
+            SubmodelElement::SubmodelElementCollection(element) => &element.semantics,
+            SubmodelElement::SubmodelElementList(element) => &element.semantics,
+        }
+    }
+
+    /// Removes the values of all Blobs in this element and its children,
+    /// as the Part 2 modifier `extent=withoutBlobValue` requires.
+    pub fn strip_blob_values(&mut self) {
+        match self {
+            SubmodelElement::Blob(blob) => blob.value = None,
+            SubmodelElement::DataElement(DataElement::Blob(blob)) => blob.value = None,
+            SubmodelElement::AnnotatedRelationshipElement(element) => {
+                for annotation in element.annotations.iter_mut().flatten() {
+                    if let DataElement::Blob(blob) = annotation {
+                        blob.value = None;
+                    }
+                }
+            }
+            SubmodelElement::Entity(
+                Entity::CoManagedEntity(entity) | Entity::SelfManagedEntity(entity),
+            ) => entity
+                .statements
+                .iter_mut()
+                .flatten()
+                .for_each(SubmodelElement::strip_blob_values),
+            SubmodelElement::Operation(operation) => [
+                &mut operation.input_variables,
+                &mut operation.output_variables,
+                &mut operation.inoutput_variables,
+            ]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .for_each(|variable| variable.value.strip_blob_values()),
+            SubmodelElement::SubmodelElementCollection(collection) => {
+                collection.strip_blob_values()
+            }
+            SubmodelElement::SubmodelElementList(list) => list.strip_blob_values(),
+            _ => {}
+        }
+    }
+
+    /// Same as [SubmodelElement::strip_blob_values], but takes and returns the element,
+    /// e.g. to serialize it afterwards.
+    pub fn without_blob_values(mut self) -> Self {
+        self.strip_blob_values();
+        self
+    }
+}
+
+macro_rules! impl_from_element {
+    ($enum:ident: $($variant:ident),* $(,)?) => {$(
+        impl From<$variant> for $enum {
+            fn from(element: $variant) -> Self {
+                $enum::$variant(element)
+            }
+        }
+    )*};
+}
+
+pub(crate) use impl_from_element;
+
+impl_from_element!(
+    SubmodelElement: RelationshipElement,
+    AnnotatedRelationshipElement,
+    BasicEventElement,
+    Blob,
+    Capability,
+    Entity,
+    File,
+    MultiLanguageProperty,
+    Operation,
+    Property,
+    Range,
+    ReferenceElement,
+    SubmodelElementCollection,
+    SubmodelElementList,
+);
+
 /// Every SubmodelElement has these
 #[derive(Debug, Clone, PartialEq, Default)]
 
@@ -1,5 +1,3 @@
-use crate::part1::MetamodelError;
-use crate::part1::ToJsonMetamodel;
 use crate::part1::v3_1::LangString;
 use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
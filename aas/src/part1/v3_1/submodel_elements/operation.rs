@@ -3,7 +3,6 @@ use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
 use crate::part1::v3_1::attributes::referable::Referable;
 use crate::part1::v3_1::attributes::semantics::HasSemantics;
 use crate::part1::v3_1::submodel_elements::SubmodelElement;
-use crate::part1::{MetamodelError, ToJsonMetamodel};
 
 #[derive(Clone, PartialEq, Debug, Default)]
 pub struct Operation {
@@ -16,9 +15,62 @@ pub struct Operation {
 
     pub embedded_data_specifications: HasDataSpecification,
     // ----- end inheritance
-    pub input_variable: Option<Box<SubmodelElement>>,
+    /// Input parameters of the operation
+    pub input_variables: Option<Vec<OperationVariable>>,
 
-    pub output_variable: Option<Box<SubmodelElement>>,
+    /// Output parameters of the operation
+    pub output_variables: Option<Vec<OperationVariable>>,
 
-    pub inoutput_variable: Option<Box<SubmodelElement>>,
+    /// Parameters that are input and output of the operation
+    pub inoutput_variables: Option<Vec<OperationVariable>>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct OperationMeta {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+}
+
+impl From<Operation> for OperationMeta {
+    fn from(element: Operation) -> Self {
+        Self {
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
+        }
+    }
+}
+
+impl From<&Operation> for OperationMeta {
+    fn from(element: &Operation) -> Self {
+        element.clone().into()
+    }
+}
+
+/// The value of an operation variable is a submodel element that is used as input and/or output
+/// variable of an operation.
+#[derive(Clone, PartialEq, Debug)]
+pub struct OperationVariable {
+    /// Describes an argument or result of an operation via a submodel element
+    pub value: SubmodelElement,
+}
+
+impl OperationVariable {
+    pub fn new(value: SubmodelElement) -> Self {
+        Self { value }
+    }
+}
+
+impl From<SubmodelElement> for OperationVariable {
+    fn from(value: SubmodelElement) -> Self {
+        Self { value }
+    }
 }
@@ -1,10 +1,9 @@
-use crate::part1::MetamodelError;
-use crate::part1::ToJsonMetamodel;
 use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
 use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
 use crate::part1::v3_1::attributes::referable::Referable;
 use crate::part1::v3_1::attributes::semantics::HasSemantics;
-use crate::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+use crate::part1::v3_1::primitives::data_type_def_xs::{DataTypeXSDef, DataXsd};
+use crate::part1::v3_1::reference::Reference;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Property {
@@ -18,6 +17,8 @@ pub struct Property {
     pub embedded_data_specifications: HasDataSpecification,
     // ----- end inheritance
     pub value: DataXsd,
+
+    pub value_id: Option<Reference>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -31,11 +32,13 @@ pub struct PropertyMeta {
 
     pub embedded_data_specifications: HasDataSpecification,
     // ----- end inheritance
+    pub value_type: DataTypeXSDef,
 }
 
 impl From<Property> for PropertyMeta {
     fn from(prop: Property) -> Self {
         Self {
+            value_type: DataTypeXSDef::from(&prop.value),
             referable: prop.referable,
             semantics: prop.semantics,
             qualifiable: prop.qualifiable,
@@ -46,12 +49,6 @@ impl From<Property> for PropertyMeta {
 
 impl From<&Property> for PropertyMeta {
     fn from(prop: &Property) -> Self {
-        let prop = prop.clone();
-        Self {
-            referable: prop.referable,
-            semantics: prop.semantics,
-            qualifiable: prop.qualifiable,
-            embedded_data_specifications: prop.embedded_data_specifications,
-        }
+        prop.clone().into()
     }
 }
@@ -1,38 +1,90 @@
-use crate::part1::ToJsonMetamodel;
+use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
+use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::attributes::semantics::HasSemantics;
+use crate::part1::v3_1::primitives::data_type_def_xs::{ConversionError, DataTypeXSDef, DataXsd};
+use crate::part1::v3_1::primitives::{Duration, GDay, GMonth, GMonthDay, GYear, GYearMonth};
 use bigdecimal::BigDecimal;
 use iref::IriRefBuf;
 
 use strum::{Display, EnumString};
+use thiserror::Error;
+
+/// A range data element is a data element that defines a range with min and max.
+///
+/// Like every other submodel element it has the inherited attributes (idShort, semanticId, ...),
+/// the value type and the bounds are its [RangeValue].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Range {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+    /// Value type together with the min and max value of the range
+    pub value: RangeValue,
+}
+
+impl Range {
+    pub fn new(value: RangeValue) -> Self {
+        Self {
+            referable: Referable::default(),
+            semantics: HasSemantics::default(),
+            qualifiable: Qualifiable::default(),
+            embedded_data_specifications: HasDataSpecification::default(),
+            value,
+        }
+    }
+
+    /// The value type of the range, e.g. `xs:int`
+    pub fn value_type(&self) -> DataTypeXSDef {
+        self.value.value_type()
+    }
+}
 
 // TODO: If the min value is missing, the value is assumed to be negative infinite.
 // TODO: If the max value is missing, the value is assumed to be positive infinite.
-#[derive(Clone, PartialEq, Debug, Default)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct RangeInner<T> {
     pub min: Option<T>,
     pub max: Option<T>,
 }
 
-// TODO: update to big decimal
+// Derived Default would require T: Default, an empty range needs no value.
+impl<T> Default for RangeInner<T> {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+        }
+    }
+}
+
+/// Value type together with the min and max value of a [Range]
 // TODO: Only allow xsd atomic types.
 #[derive(Clone, PartialEq, Debug, Display, EnumString)]
 #[strum(prefix = "xs:", serialize_all = "camelCase")]
-pub enum Range {
+pub enum RangeValue {
     // basic types
     Int(RangeInner<i32>),
 
-    Integer(RangeInner<i32>),
+    Integer(RangeInner<BigDecimal>),
 
     Long(RangeInner<i64>),
 
-    NegativeInteger(RangeInner<i32>),
+    NegativeInteger(RangeInner<BigDecimal>),
 
-    NonNegativeInteger(RangeInner<u32>),
+    NonNegativeInteger(RangeInner<BigDecimal>),
 
-    NonPositiveInteger(RangeInner<i32>),
+    NonPositiveInteger(RangeInner<BigDecimal>),
 
-    PositiveInteger(RangeInner<u32>),
+    PositiveInteger(RangeInner<BigDecimal>),
 
-    Short(RangeInner<u16>),
+    Short(RangeInner<i16>),
 
     String(RangeInner<String>),
 
@@ -58,23 +110,17 @@ pub enum Range {
     Date(RangeInner<iso8601::Date>),
     DateTime(RangeInner<iso8601::DateTime>),
 
-    /// TODO: using proper type
-    Duration(RangeInner<String>),
+    Duration(RangeInner<Duration>),
 
-    /// TODO: using proper type or parsing
-    GDay(RangeInner<String>),
+    GDay(RangeInner<GDay>),
 
-    /// TODO: using proper type or parsing
-    GMonth(RangeInner<String>),
+    GMonth(RangeInner<GMonth>),
 
-    /// TODO: using proper type or parsing
-    GMonthDay(RangeInner<String>),
+    GMonthDay(RangeInner<GMonthDay>),
 
-    /// TODO: using proper type or parsing
-    GYear(RangeInner<String>),
+    GYear(RangeInner<GYear>),
 
-    /// TODO: using proper type or parsing
-    GYearMonth(RangeInner<String>),
+    GYearMonth(RangeInner<GYearMonth>),
 
     // binary
     Base64Binary(RangeInner<Vec<u8>>),
@@ -83,13 +129,295 @@ pub enum Range {
 
     // string related
     // TODO: is this supported??
+    #[strum(serialize = "anyURI")]
     AnyURI(RangeInner<IriRefBuf>),
 }
 
-impl ToJsonMetamodel for Range {
-    type Error = ();
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum RangeError {
+    #[error(transparent)]
+    Conversion(#[from] ConversionError),
+
+    #[error("The bounds of a range need the same value type, found {min} and {max}")]
+    MismatchedValueTypes {
+        min: DataTypeXSDef,
+        max: DataTypeXSDef,
+    },
+}
+
+/// Creates a range from the lexical min and max values of the given value type.
+/// Both bounds are checked against the value space of the type.
+impl TryFrom<(DataTypeXSDef, Option<String>, Option<String>)> for RangeValue {
+    type Error = RangeError;
+
+    fn try_from(
+        value: (DataTypeXSDef, Option<String>, Option<String>),
+    ) -> Result<Self, Self::Error> {
+        let (value_type, min, max) = value;
+        let min = DataXsd::try_from((value_type.clone(), min))?;
+        let max = DataXsd::try_from((value_type, max))?;
+
+        RangeValue::from_bounds(min, max)
+    }
+}
+
+impl RangeValue {
+    /// Creates a range from two values of the same value type.
+    pub fn from_bounds(min: DataXsd, max: DataXsd) -> Result<Self, RangeError> {
+        min.validate()?;
+        max.validate()?;
+
+        Ok(match (min, max) {
+            (DataXsd::Int(min), DataXsd::Int(max)) => RangeValue::Int(RangeInner { min, max }),
+            (DataXsd::Integer(min), DataXsd::Integer(max)) => {
+                RangeValue::Integer(RangeInner { min, max })
+            }
+            (DataXsd::Long(min), DataXsd::Long(max)) => RangeValue::Long(RangeInner { min, max }),
+            (DataXsd::NegativeInteger(min), DataXsd::NegativeInteger(max)) => {
+                RangeValue::NegativeInteger(RangeInner { min, max })
+            }
+            (DataXsd::NonNegativeInteger(min), DataXsd::NonNegativeInteger(max)) => {
+                RangeValue::NonNegativeInteger(RangeInner { min, max })
+            }
+            (DataXsd::NonPositiveInteger(min), DataXsd::NonPositiveInteger(max)) => {
+                RangeValue::NonPositiveInteger(RangeInner { min, max })
+            }
+            (DataXsd::PositiveInteger(min), DataXsd::PositiveInteger(max)) => {
+                RangeValue::PositiveInteger(RangeInner { min, max })
+            }
+            (DataXsd::Short(min), DataXsd::Short(max)) => {
+                RangeValue::Short(RangeInner { min, max })
+            }
+            (DataXsd::String(min), DataXsd::String(max)) => {
+                RangeValue::String(RangeInner { min, max })
+            }
+            (DataXsd::Boolean(min), DataXsd::Boolean(max)) => {
+                RangeValue::Boolean(RangeInner { min, max })
+            }
+            (DataXsd::Byte(min), DataXsd::Byte(max)) => RangeValue::Byte(RangeInner { min, max }),
+            (DataXsd::UnsignedByte(min), DataXsd::UnsignedByte(max)) => {
+                RangeValue::UnsignedByte(RangeInner { min, max })
+            }
+            (DataXsd::UnsignedInt(min), DataXsd::UnsignedInt(max)) => {
+                RangeValue::UnsignedInt(RangeInner { min, max })
+            }
+            (DataXsd::UnsignedLong(min), DataXsd::UnsignedLong(max)) => {
+                RangeValue::UnsignedLong(RangeInner { min, max })
+            }
+            (DataXsd::UnsignedShort(min), DataXsd::UnsignedShort(max)) => {
+                RangeValue::UnsignedShort(RangeInner { min, max })
+            }
+            (DataXsd::Decimal(min), DataXsd::Decimal(max)) => {
+                RangeValue::Decimal(RangeInner { min, max })
+            }
+            (DataXsd::Float(min), DataXsd::Float(max)) => {
+                RangeValue::Float(RangeInner { min, max })
+            }
+            (DataXsd::Double(min), DataXsd::Double(max)) => {
+                RangeValue::Double(RangeInner { min, max })
+            }
+            (DataXsd::Time(min), DataXsd::Time(max)) => RangeValue::Time(RangeInner { min, max }),
+            (DataXsd::Date(min), DataXsd::Date(max)) => RangeValue::Date(RangeInner { min, max }),
+            (DataXsd::DateTime(min), DataXsd::DateTime(max)) => {
+                RangeValue::DateTime(RangeInner { min, max })
+            }
+            (DataXsd::Duration(min), DataXsd::Duration(max)) => {
+                RangeValue::Duration(RangeInner { min, max })
+            }
+            (DataXsd::GDay(min), DataXsd::GDay(max)) => RangeValue::GDay(RangeInner { min, max }),
+            (DataXsd::GMonth(min), DataXsd::GMonth(max)) => {
+                RangeValue::GMonth(RangeInner { min, max })
+            }
+            (DataXsd::GMonthDay(min), DataXsd::GMonthDay(max)) => {
+                RangeValue::GMonthDay(RangeInner { min, max })
+            }
+            (DataXsd::GYear(min), DataXsd::GYear(max)) => {
+                RangeValue::GYear(RangeInner { min, max })
+            }
+            (DataXsd::GYearMonth(min), DataXsd::GYearMonth(max)) => {
+                RangeValue::GYearMonth(RangeInner { min, max })
+            }
+            (DataXsd::Base64Binary(min), DataXsd::Base64Binary(max)) => {
+                RangeValue::Base64Binary(RangeInner { min, max })
+            }
+            (DataXsd::HexBinary(min), DataXsd::HexBinary(max)) => {
+                RangeValue::HexBinary(RangeInner { min, max })
+            }
+            (DataXsd::AnyURI(min), DataXsd::AnyURI(max)) => {
+                RangeValue::AnyURI(RangeInner { min, max })
+            }
+            (min, max) => {
+                return Err(RangeError::MismatchedValueTypes {
+                    min: min.into(),
+                    max: max.into(),
+                });
+            }
+        })
+    }
+
+    /// Splits the range into its min and max value, both typed with the value type of the range.
+    pub fn into_bounds(self) -> (DataXsd, DataXsd) {
+        match self {
+            RangeValue::Int(r) => (DataXsd::Int(r.min), DataXsd::Int(r.max)),
+            RangeValue::Integer(r) => (DataXsd::Integer(r.min), DataXsd::Integer(r.max)),
+            RangeValue::Long(r) => (DataXsd::Long(r.min), DataXsd::Long(r.max)),
+            RangeValue::NegativeInteger(r) => (
+                DataXsd::NegativeInteger(r.min),
+                DataXsd::NegativeInteger(r.max),
+            ),
+            RangeValue::NonNegativeInteger(r) => (
+                DataXsd::NonNegativeInteger(r.min),
+                DataXsd::NonNegativeInteger(r.max),
+            ),
+            RangeValue::NonPositiveInteger(r) => (
+                DataXsd::NonPositiveInteger(r.min),
+                DataXsd::NonPositiveInteger(r.max),
+            ),
+            RangeValue::PositiveInteger(r) => (
+                DataXsd::PositiveInteger(r.min),
+                DataXsd::PositiveInteger(r.max),
+            ),
+            RangeValue::Short(r) => (DataXsd::Short(r.min), DataXsd::Short(r.max)),
+            RangeValue::String(r) => (DataXsd::String(r.min), DataXsd::String(r.max)),
+            RangeValue::Boolean(r) => (DataXsd::Boolean(r.min), DataXsd::Boolean(r.max)),
+            RangeValue::Byte(r) => (DataXsd::Byte(r.min), DataXsd::Byte(r.max)),
+            RangeValue::UnsignedByte(r) => {
+                (DataXsd::UnsignedByte(r.min), DataXsd::UnsignedByte(r.max))
+            }
+            RangeValue::UnsignedInt(r) => {
+                (DataXsd::UnsignedInt(r.min), DataXsd::UnsignedInt(r.max))
+            }
+            RangeValue::UnsignedLong(r) => {
+                (DataXsd::UnsignedLong(r.min), DataXsd::UnsignedLong(r.max))
+            }
+            RangeValue::UnsignedShort(r) => {
+                (DataXsd::UnsignedShort(r.min), DataXsd::UnsignedShort(r.max))
+            }
+            RangeValue::Decimal(r) => (DataXsd::Decimal(r.min), DataXsd::Decimal(r.max)),
+            RangeValue::Float(r) => (DataXsd::Float(r.min), DataXsd::Float(r.max)),
+            RangeValue::Double(r) => (DataXsd::Double(r.min), DataXsd::Double(r.max)),
+            RangeValue::Time(r) => (DataXsd::Time(r.min), DataXsd::Time(r.max)),
+            RangeValue::Date(r) => (DataXsd::Date(r.min), DataXsd::Date(r.max)),
+            RangeValue::DateTime(r) => (DataXsd::DateTime(r.min), DataXsd::DateTime(r.max)),
+            RangeValue::Duration(r) => (DataXsd::Duration(r.min), DataXsd::Duration(r.max)),
+            RangeValue::GDay(r) => (DataXsd::GDay(r.min), DataXsd::GDay(r.max)),
+            RangeValue::GMonth(r) => (DataXsd::GMonth(r.min), DataXsd::GMonth(r.max)),
+            RangeValue::GMonthDay(r) => (DataXsd::GMonthDay(r.min), DataXsd::GMonthDay(r.max)),
+            RangeValue::GYear(r) => (DataXsd::GYear(r.min), DataXsd::GYear(r.max)),
+            RangeValue::GYearMonth(r) => (DataXsd::GYearMonth(r.min), DataXsd::GYearMonth(r.max)),
+            RangeValue::Base64Binary(r) => {
+                (DataXsd::Base64Binary(r.min), DataXsd::Base64Binary(r.max))
+            }
+            RangeValue::HexBinary(r) => (DataXsd::HexBinary(r.min), DataXsd::HexBinary(r.max)),
+            RangeValue::AnyURI(r) => (DataXsd::AnyURI(r.min), DataXsd::AnyURI(r.max)),
+        }
+    }
+
+    /// The value type of the range, e.g. `xs:int`
+    pub fn value_type(&self) -> DataTypeXSDef {
+        DataTypeXSDef::from(self.clone().into_bounds().0)
+    }
+
+    /// Returns the lexical min and max value after checking them against the value space.
+    pub fn to_lexical(&self) -> Result<(Option<String>, Option<String>), ConversionError> {
+        let (min, max) = self.clone().into_bounds();
+        Ok((min.to_lexical()?, max.to_lexical()?))
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RangeMeta {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+    pub value_type: DataTypeXSDef,
+}
+
+impl From<Range> for RangeMeta {
+    fn from(element: Range) -> Self {
+        Self {
+            value_type: element.value_type(),
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
+        }
+    }
+}
+
+impl From<&Range> for RangeMeta {
+    fn from(element: &Range) -> Self {
+        element.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lexical_bounds() {
+        let range = RangeValue::try_from((
+            DataTypeXSDef::Duration,
+            Some("PT1M".to_string()),
+            Some("P1D".to_string()),
+        ))
+        .unwrap();
+
+        let RangeValue::Duration(RangeInner {
+            min: Some(min),
+            max: Some(max),
+        }) = &range
+        else {
+            panic!("expected a duration range");
+        };
+        assert_eq!(min.to_string(), "PT1M");
+        assert_eq!(max.to_string(), "P1D");
+        assert_eq!(range.value_type(), DataTypeXSDef::Duration);
+    }
+
+    #[test]
+    fn reject_out_of_range_bounds() {
+        let range = RangeValue::try_from((
+            DataTypeXSDef::PositiveInteger,
+            Some("0".to_string()),
+            Some("100".to_string()),
+        ));
+
+        assert!(matches!(
+            range,
+            Err(RangeError::Conversion(ConversionError::OutOfRange { .. }))
+        ));
+    }
+
+    #[test]
+    fn reject_mismatched_bounds() {
+        let range = RangeValue::from_bounds(DataXsd::Int(Some(1)), DataXsd::Long(Some(2)));
+
+        assert_eq!(
+            range,
+            Err(RangeError::MismatchedValueTypes {
+                min: DataTypeXSDef::Int,
+                max: DataTypeXSDef::Long,
+            })
+        );
+    }
+
+    #[test]
+    fn bounds_round_trip() {
+        let expected = RangeValue::Short(RangeInner {
+            min: Some(-10),
+            max: None,
+        });
+
+        let (min, max) = expected.clone().into_bounds();
 
-    fn to_json_metamodel(&self) -> Result<String, Self::Error> {
-        Ok(format!(r#"{{"valueType":"{}"}}"#, self.to_string()))
+        assert_eq!(RangeValue::from_bounds(min, max), Ok(expected));
     }
 }
@@ -1,20 +1,52 @@
-use crate::part1::ToJsonMetamodel;
+use crate::part1::v3_1::attributes::data_specification::HasDataSpecification;
+use crate::part1::v3_1::attributes::qualifiable::Qualifiable;
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::attributes::semantics::HasSemantics;
 use crate::part1::v3_1::reference::Reference;
 
-// ToJsonMetadata implemented from upper enum.
 #[derive(Clone, PartialEq, Debug, Default)]
-
 pub struct ReferenceElement {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
     /// External reference to an external object or entity or a logical reference
     /// to another element within the same or another Asset Administration Shell
     /// (i.e. a model reference to a Referable)
     pub value: Option<Reference>,
 }
 
-impl ToJsonMetamodel for ReferenceElement {
-    type Error = ();
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ReferenceElementMeta {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+}
+
+impl From<ReferenceElement> for ReferenceElementMeta {
+    fn from(element: ReferenceElement) -> Self {
+        Self {
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
+        }
+    }
+}
 
-    fn to_json_metamodel(&self) -> Result<String, Self::Error> {
-        Ok(format!(r#"{{"modelType":"ReferenceElement"}}"#))
+impl From<&ReferenceElement> for ReferenceElementMeta {
+    fn from(element: &ReferenceElement) -> Self {
+        element.clone().into()
     }
 }
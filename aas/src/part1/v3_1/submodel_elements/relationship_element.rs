@@ -16,9 +16,9 @@ pub struct RelationshipElement {
 
     pub embedded_data_specifications: HasDataSpecification,
     // ----- end inheritance
-    first: Option<Reference>,
+    pub first: Option<Reference>,
 
-    second: Option<Reference>,
+    pub second: Option<Reference>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -38,3 +38,63 @@ pub struct AnnotatedRelationshipElement {
     // --- end inheritance
     pub annotations: Option<Vec<DataElement>>,
 }
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RelationshipElementMeta {
+    // Inherited from DataElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+}
+
+impl From<RelationshipElement> for RelationshipElementMeta {
+    fn from(element: RelationshipElement) -> Self {
+        Self {
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
+        }
+    }
+}
+
+impl From<&RelationshipElement> for RelationshipElementMeta {
+    fn from(element: &RelationshipElement) -> Self {
+        element.clone().into()
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AnnotatedRelationshipElementMeta {
+    // Inherited from RelationshipElement
+    pub referable: Referable,
+
+    pub semantics: HasSemantics,
+
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+    // ----- end inheritance
+}
+
+impl From<AnnotatedRelationshipElement> for AnnotatedRelationshipElementMeta {
+    fn from(element: AnnotatedRelationshipElement) -> Self {
+        Self {
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
+        }
+    }
+}
+
+impl From<&AnnotatedRelationshipElement> for AnnotatedRelationshipElementMeta {
+    fn from(element: &AnnotatedRelationshipElement) -> Self {
+        element.clone().into()
+    }
+}
@@ -24,46 +24,65 @@ pub struct SubmodelElementList {
     pub embedded_data_specifications: HasDataSpecification,
 
     /// Defines whether order in list is relevant. If orderRelevant = false, the list represents a set or a bag.
-    is_order_relevant: bool,
+    pub is_order_relevant: bool,
 
     /// Semantic ID which the submodel elements contained in the list match
-    semantic_id_list_element: Option<Reference>,
+    pub semantic_id_list_element: Option<Reference>,
 
     // Question: can value, type_value_list_element be merged into an enum?
     // maybe together with value_type_list_element?
     // newtype or something for type safety.
     /// Submodel elements contained in the list
-    value: Option<Vec<SubmodelElement>>,
+    pub value: Option<Vec<SubmodelElement>>,
 
     /// The submodel element type of the submodel elements contained in the list
-    type_value_list_element: AasSubmodelElements,
+    pub type_value_list_element: AasSubmodelElements,
 
     /// The value type of the submodel element contained in the list
-    value_type_list_element: Option<DataTypeXSDef>,
+    pub value_type_list_element: Option<DataTypeXSDef>,
+}
+
+impl SubmodelElementList {
+    pub(crate) fn strip_blob_values(&mut self) {
+        self.value
+            .iter_mut()
+            .flatten()
+            .for_each(SubmodelElement::strip_blob_values);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SubmodelElementListMeta {
+    pub referable: Referable,
+
+    // HasSemantics
+    pub semantics: HasSemantics,
+
+    // Qualifiable
+    pub qualifiable: Qualifiable,
+
+    pub embedded_data_specifications: HasDataSpecification,
+
     /// Defines whether order in list is relevant. If orderRelevant = false, the list represents a set or a bag.
-    is_order_relevant: bool,
+    pub is_order_relevant: bool,
 
     /// Semantic ID which the submodel elements contained in the list match
-    semantic_id_list_element: Option<Reference>,
+    pub semantic_id_list_element: Option<Reference>,
 
     /// The submodel element type of the submodel elements contained in the list
-    type_value_list_element: AasSubmodelElements,
+    pub type_value_list_element: AasSubmodelElements,
 
     /// The value type of the submodel element contained in the list
-    value_type_list_element: Option<DataTypeXSDef>,
-}
-
-fn ordering_default() -> bool {
-    true
+    pub value_type_list_element: Option<DataTypeXSDef>,
 }
 
 impl From<SubmodelElementList> for SubmodelElementListMeta {
     fn from(element: SubmodelElementList) -> Self {
         Self {
+            referable: element.referable,
+            semantics: element.semantics,
+            qualifiable: element.qualifiable,
+            embedded_data_specifications: element.embedded_data_specifications,
             is_order_relevant: element.is_order_relevant,
             semantic_id_list_element: element.semantic_id_list_element,
             type_value_list_element: element.type_value_list_element,
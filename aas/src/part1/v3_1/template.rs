@@ -0,0 +1,1137 @@
+//! Instantiation of submodel templates and conformance checks of instances, see IDTA-01001
+//! Annex "Submodel Template Qualifiers" (`SMT/Cardinality`, `SMT/DefaultValue`, ...).
+//!
+//! Elements are kept according to their cardinality: optional ones (`ZeroToOne`,
+//! `ZeroToMany`) only if requested, `One` and `OneToMany` once unless another count is given.
+//! Replicated elements in a submodel, collection or entity get a number appended to their
+//! idShort, starting with 01: `Document` becomes `Document01`, `Document02`, ...
+
+use crate::part1::v3_1::LangString;
+use crate::part1::v3_1::attributes::administrative_information::{
+    AdministrativeInformation, Version,
+};
+use crate::part1::v3_1::attributes::kind::ModellingKind;
+use crate::part1::v3_1::attributes::qualifiable::{Qualifiable, Qualifier};
+use crate::part1::v3_1::builder::{HasQualifiers, HasReferable};
+use crate::part1::v3_1::core::Submodel;
+use crate::part1::v3_1::id_short_path::{ElementMut, IdShortPath, PathSegment};
+use crate::part1::v3_1::primitives::Identifier;
+use crate::part1::v3_1::primitives::data_type_def_xs::{DataTypeXSDef, DataXsd};
+use crate::part1::v3_1::reference::SemanticMatch;
+use crate::part1::v3_1::resolver::Referred;
+use crate::part1::v3_1::submodel_elements::{
+    DataElement, Entity, SubmodelElement, SubmodelElementList,
+};
+use crate::part1::v3_1::visitor::{Flow, Location, VisitorMut};
+use bigdecimal::BigDecimal;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::Formatter;
+use std::mem;
+use std::str::FromStr;
+use strum::{Display, EnumString};
+use thiserror::Error;
+
+/// Qualifier types of the cardinality. Older templates use `Cardinality` or `Multiplicity`.
+const CARDINALITY: [&str; 3] = ["SMT/Cardinality", "Cardinality", "Multiplicity"];
+
+const DEFAULT_VALUE: &str = "SMT/DefaultValue";
+
+/// Number of instances of an element, given by the `SMT/Cardinality` qualifier.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Display, EnumString)]
+pub enum Cardinality {
+    #[default]
+    One,
+    ZeroToOne,
+    ZeroToMany,
+    OneToMany,
+}
+
+impl Cardinality {
+    pub fn allows(self, count: usize) -> bool {
+        match self {
+            Cardinality::One => count == 1,
+            Cardinality::ZeroToOne => count <= 1,
+            Cardinality::ZeroToMany => true,
+            Cardinality::OneToMany => count >= 1,
+        }
+    }
+
+    /// Instances created if the element is not requested explicitly.
+    fn default_count(self) -> usize {
+        match self {
+            Cardinality::One | Cardinality::OneToMany => 1,
+            Cardinality::ZeroToOne | Cardinality::ZeroToMany => 0,
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum TemplateError {
+    #[error("The submodel is not of kind Template")]
+    NotATemplate,
+
+    #[error("Invalid cardinality '{value}' of '{path}'")]
+    InvalidCardinality { path: IdShortPath, value: String },
+
+    #[error("'{path}' has cardinality {cardinality}, {count} instances are not allowed")]
+    CountNotAllowed {
+        path: IdShortPath,
+        cardinality: Cardinality,
+        count: usize,
+    },
+
+    #[error("There is no element '{0}' in the template")]
+    UnknownPath(IdShortPath),
+
+    #[error("Invalid default value '{value}' of '{path}': {reason}")]
+    InvalidDefaultValue {
+        path: IdShortPath,
+        value: String,
+        reason: String,
+    },
+}
+
+/// Which optional or repeatable elements to create. Paths are idShortPaths in the template.
+#[derive(Clone, Debug, Default)]
+pub struct Instantiation {
+    counts: HashMap<IdShortPath, usize>,
+}
+
+impl Instantiation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the optional element at the path once.
+    pub fn include(self, path: IdShortPath) -> Self {
+        self.count(path, 1)
+    }
+
+    /// Creates `count` instances of the element at the path.
+    pub fn count(mut self, path: IdShortPath, count: usize) -> Self {
+        self.counts.insert(path, count);
+        self
+    }
+}
+
+impl Submodel {
+    /// Creates an instance of the template with the given id, see the [module docs](self).
+    /// Template qualifiers are removed, `SMT/DefaultValue`s become the value of empty
+    /// Properties and the id of the template is recorded as `administration.templateId`.
+    pub fn instantiate(
+        &self,
+        id: Identifier,
+        instantiation: &Instantiation,
+    ) -> Result<Submodel, TemplateError> {
+        if self.kind != Some(ModellingKind::Template) {
+            return Err(TemplateError::NotATemplate);
+        }
+
+        let mut instantiator = Instantiator {
+            counts: &instantiation.counts,
+            used: HashSet::new(),
+        };
+        let elements = instantiator.named(&self.submodel_elements, None)?;
+        if let Some(unknown) = instantiation
+            .counts
+            .keys()
+            .find(|path| !instantiator.used.contains(path))
+        {
+            return Err(TemplateError::UnknownPath(unknown.clone()));
+        }
+
+        let mut instance = Submodel {
+            submodel_elements: elements,
+            kind: Some(ModellingKind::Instance),
+            ..self.clone()
+        };
+        instance.identifiable.id = id;
+        instance.identifiable.administration = Some(AdministrativeInformation {
+            version: Version {
+                version: None,
+                revision: None,
+            },
+            creator: None,
+            template_id: Some(self.identifiable.id.clone()),
+            data_specification: Default::default(),
+        });
+        strip_qualifiers(&mut instance.qualifier);
+
+        let mut finisher = Finisher { error: None };
+        instance.walk_mut(&mut finisher);
+        match finisher.error {
+            Some(error) => Err(error),
+            None => Ok(instance),
+        }
+    }
+}
+
+struct Instantiator<'a> {
+    counts: &'a HashMap<IdShortPath, usize>,
+    used: HashSet<IdShortPath>,
+}
+
+impl Instantiator<'_> {
+    /// Children of a submodel, collection or entity, addressed by idShort.
+    fn named(
+        &mut self,
+        template: &Option<Vec<SubmodelElement>>,
+        parent: Option<&IdShortPath>,
+    ) -> Result<Option<Vec<SubmodelElement>>, TemplateError> {
+        let Some(template) = template else {
+            return Ok(None);
+        };
+
+        let mut elements = Vec::new();
+        for element in template {
+            let id_short = element
+                .referable()
+                .id_short
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            let path = child_path(parent, PathSegment::IdShort(id_short.clone()));
+
+            let count = self.count(element, &path)?;
+            for n in 1..=count {
+                let mut instance = self.element(element, &path)?;
+                if let Some(numbered) = numbered(&id_short, n, count)
+                    && let Ok(numbered) = Identifier::try_from(numbered.as_str())
+                {
+                    instance.referable_mut().id_short = Some(numbered);
+                }
+                elements.push(instance);
+            }
+        }
+        Ok(Some(elements))
+    }
+
+    /// Elements of a SubmodelElementList, addressed by their index in the template.
+    fn indexed(
+        &mut self,
+        template: &Option<Vec<SubmodelElement>>,
+        parent: &IdShortPath,
+    ) -> Result<Option<Vec<SubmodelElement>>, TemplateError> {
+        let Some(template) = template else {
+            return Ok(None);
+        };
+
+        let mut elements = Vec::new();
+        for (i, element) in template.iter().enumerate() {
+            let path = parent.join(PathSegment::Index(i));
+            for _ in 0..self.count(element, &path)? {
+                elements.push(self.element(element, &path)?);
+            }
+        }
+        Ok(Some(elements))
+    }
+
+    fn count(
+        &mut self,
+        element: &SubmodelElement,
+        path: &IdShortPath,
+    ) -> Result<usize, TemplateError> {
+        let cardinality = cardinality(element.qualifiable(), path)?;
+        let count = match self.counts.get(path) {
+            Some(count) => {
+                self.used.insert(path.clone());
+                *count
+            }
+            None => cardinality.default_count(),
+        };
+
+        if cardinality.allows(count) {
+            Ok(count)
+        } else {
+            Err(TemplateError::CountNotAllowed {
+                path: path.clone(),
+                cardinality,
+                count,
+            })
+        }
+    }
+
+    /// One instance of the element with its children.
+    fn element(
+        &mut self,
+        template: &SubmodelElement,
+        path: &IdShortPath,
+    ) -> Result<SubmodelElement, TemplateError> {
+        let mut element = template.clone();
+        match &mut element {
+            SubmodelElement::SubmodelElementCollection(collection) => {
+                collection.value = self.named(&collection.value, Some(path))?
+            }
+            SubmodelElement::SubmodelElementList(list) => {
+                list.value = self.indexed(&list.value, path)?
+            }
+            SubmodelElement::Entity(Entity::CoManagedEntity(inner))
+            | SubmodelElement::Entity(Entity::SelfManagedEntity(inner)) => {
+                inner.statements = self.named(&inner.statements, Some(path))?
+            }
+            _ => {}
+        }
+        Ok(element)
+    }
+}
+
+fn child_path(parent: Option<&IdShortPath>, segment: PathSegment) -> IdShortPath {
+    match parent {
+        Some(parent) => parent.join(segment),
+        None => IdShortPath::from(segment),
+    }
+}
+
+/// IdShort of the n-th of `count` instances, `None` if it stays as it is.
+fn numbered(id_short: &str, n: usize, count: usize) -> Option<String> {
+    (count > 1 && !id_short.is_empty()).then(|| format!("{id_short}{n:02}"))
+}
+
+fn qualifier_type(qualifier: &Qualifier) -> &str {
+    let (Qualifier::ConceptQualifier(inner)
+    | Qualifier::TemplateQualifier(inner)
+    | Qualifier::ValueQualifier(inner)
+    | Qualifier::Unknown(inner)) = qualifier;
+    &inner.ty
+}
+
+fn qualifier_value<'q>(qualifiable: &'q Qualifiable, types: &[&str]) -> Option<&'q DataXsd> {
+    qualifiable
+        .qualifiers
+        .iter()
+        .flatten()
+        .find_map(|qualifier| {
+            let (Qualifier::ConceptQualifier(inner)
+            | Qualifier::TemplateQualifier(inner)
+            | Qualifier::ValueQualifier(inner)
+            | Qualifier::Unknown(inner)) = qualifier;
+            types.contains(&inner.ty.as_str()).then_some(&inner.value)
+        })
+}
+
+fn cardinality(
+    qualifiable: &Qualifiable,
+    path: &IdShortPath,
+) -> Result<Cardinality, TemplateError> {
+    let Some(value) = qualifier_value(qualifiable, &CARDINALITY) else {
+        return Ok(Cardinality::default());
+    };
+    let value = value.to_lexical().ok().flatten().unwrap_or_default();
+
+    Cardinality::from_str(&value).map_err(|_| TemplateError::InvalidCardinality {
+        path: path.clone(),
+        value,
+    })
+}
+
+/// Template qualifiers and all SMT qualifiers are only meaningful in the template.
+fn strip_qualifiers(qualifiable: &mut Qualifiable) {
+    if let Some(qualifiers) = &mut qualifiable.qualifiers {
+        qualifiers.retain(|qualifier| {
+            let ty = qualifier_type(qualifier);
+            !matches!(qualifier, Qualifier::TemplateQualifier(_))
+                && !ty.starts_with("SMT/")
+                && !CARDINALITY.contains(&ty)
+        });
+        if qualifiers.is_empty() {
+            qualifiable.qualifiers = None;
+        }
+    }
+}
+
+/// Sets default values and strips the template qualifiers of all elements of the instance.
+struct Finisher {
+    error: Option<TemplateError>,
+}
+
+impl Finisher {
+    fn default_value(&mut self, element: &mut SubmodelElement, location: &Location) {
+        let SubmodelElement::Property(property) = element else {
+            return;
+        };
+        let Some(default) = qualifier_value(&property.qualifiable, &[DEFAULT_VALUE]) else {
+            return;
+        };
+        if !matches!(property.value.to_lexical(), Ok(None)) {
+            return;
+        }
+
+        let value = default.to_lexical().ok().flatten().unwrap_or_default();
+        let value_type = DataTypeXSDef::from(&property.value);
+        match DataXsd::try_from((value_type, Some(value.clone()))) {
+            Ok(parsed) => property.value = parsed,
+            Err(e) => {
+                self.error
+                    .get_or_insert(TemplateError::InvalidDefaultValue {
+                        path: location.path.clone(),
+                        value,
+                        reason: e.to_string(),
+                    });
+            }
+        }
+    }
+}
+
+impl VisitorMut for Finisher {
+    fn visit_element(&mut self, element: ElementMut<'_>, location: &Location) -> Flow {
+        match element {
+            ElementMut::SubmodelElement(element) => {
+                self.default_value(element, location);
+                strip_qualifiers(element.qualifiable_mut());
+            }
+            ElementMut::Annotation(annotation) => {
+                let mut element = SubmodelElement::from(annotation.clone());
+                self.default_value(&mut element, location);
+                strip_qualifiers(element.qualifiable_mut());
+                if let Ok(element) = DataElement::try_from(element) {
+                    *annotation = element;
+                }
+            }
+        }
+        Flow::Continue
+    }
+}
+
+// ---------------------------------------------------------------------------
+// conformance
+
+/// What an instance does differently than its template.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Display)]
+pub enum DeviationKind {
+    /// The number of elements matching a template element is outside its `SMT/Cardinality`
+    Cardinality,
+
+    /// The element has another type than in the template
+    ModelType,
+
+    /// The valueType of a Property or Range, or the value types of a list differ
+    ValueType,
+
+    /// The value does not match the regular expression of `SMT/AllowedValue`
+    AllowedValue,
+
+    /// The value is outside the interval of `SMT/AllowedRange`
+    AllowedRange,
+
+    /// A language of `SMT/RequiredLang` is missing in a MultiLanguageProperty
+    RequiredLang,
+
+    /// An SMT qualifier of the template can not be read
+    InvalidTemplate,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Deviation {
+    pub kind: DeviationKind,
+
+    /// IdShortPath of the element in the instance. For missing elements the path of
+    /// the parent, empty for the top level.
+    pub path: String,
+
+    pub message: String,
+}
+
+impl fmt::Display for Deviation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at '{}': {}", self.kind, self.path, self.message)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ConformanceReport {
+    pub deviations: Vec<Deviation>,
+}
+
+impl ConformanceReport {
+    pub fn is_conformant(&self) -> bool {
+        self.deviations.is_empty()
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for deviation in &self.deviations {
+            writeln!(f, "{deviation}")?;
+        }
+        Ok(())
+    }
+}
+
+const ALLOWED_VALUE: &str = "SMT/AllowedValue";
+const ALLOWED_RANGE: &str = "SMT/AllowedRange";
+const REQUIRED_LANG: &str = "SMT/RequiredLang";
+
+impl Submodel {
+    /// Checks the submodel against a template, e.g. a Digital Nameplate against IDTA 02006.
+    ///
+    /// Elements are matched with the template elements by an equivalent semanticId or by
+    /// idShort, where replicated elements may have a number appended (`Marking01`). Elements
+    /// of a SubmodelElementList are checked against the first element of the template list.
+    /// Elements not in the template are allowed.
+    pub fn check_against(&self, template: &Submodel) -> ConformanceReport {
+        let mut checker = Checker::default();
+        checker.named(&template.submodel_elements, &self.submodel_elements, None);
+        checker.report
+    }
+}
+
+#[derive(Default)]
+struct Checker {
+    report: ConformanceReport,
+}
+
+fn path_string(path: Option<&IdShortPath>) -> String {
+    path.map(ToString::to_string).unwrap_or_default()
+}
+
+impl Checker {
+    fn deviation(&mut self, kind: DeviationKind, path: String, message: impl Into<String>) {
+        self.report.deviations.push(Deviation {
+            kind,
+            path,
+            message: message.into(),
+        });
+    }
+
+    fn cardinality(
+        &mut self,
+        template: &SubmodelElement,
+        path: Option<&IdShortPath>,
+    ) -> Cardinality {
+        let template_path = IdShortPath::from(PathSegment::IdShort(id_short(template)));
+        cardinality(template.qualifiable(), &template_path).unwrap_or_else(|e| {
+            self.deviation(
+                DeviationKind::InvalidTemplate,
+                path_string(path),
+                e.to_string(),
+            );
+            Cardinality::default()
+        })
+    }
+
+    /// Children of a submodel, collection or entity, addressed by idShort.
+    fn named(
+        &mut self,
+        template: &Option<Vec<SubmodelElement>>,
+        instance: &Option<Vec<SubmodelElement>>,
+        parent: Option<&IdShortPath>,
+    ) {
+        let template = template.as_deref().unwrap_or_default();
+        let mut matched = vec![Vec::new(); template.len()];
+        for element in instance.iter().flatten() {
+            let by_semantic_id = template.iter().position(|t| {
+                t.semantics()
+                    .matches(element.semantics(), &SemanticMatch::default())
+            });
+            let by_id_short = || {
+                template
+                    .iter()
+                    .position(|t| is_instance_of(&id_short(element), &id_short(t)))
+            };
+            if let Some(i) = by_semantic_id.or_else(by_id_short) {
+                matched[i].push(element);
+            }
+        }
+
+        for (template, instances) in template.iter().zip(matched) {
+            let cardinality = self.cardinality(template, parent);
+            if !cardinality.allows(instances.len()) {
+                self.deviation(
+                    DeviationKind::Cardinality,
+                    path_string(parent),
+                    format!(
+                        "'{}' has cardinality {cardinality}, found {}",
+                        id_short(template),
+                        instances.len()
+                    ),
+                );
+            }
+
+            for element in instances {
+                let path = child_path(parent, PathSegment::IdShort(id_short(element)));
+                self.element(template, element, &path);
+            }
+        }
+    }
+
+    fn list(
+        &mut self,
+        template: &SubmodelElementList,
+        instance: &SubmodelElementList,
+        path: &IdShortPath,
+    ) {
+        if template.type_value_list_element != instance.type_value_list_element {
+            self.deviation(
+                DeviationKind::ModelType,
+                path.to_string(),
+                format!(
+                    "expected a list of {}, found a list of {}",
+                    template.type_value_list_element, instance.type_value_list_element
+                ),
+            );
+            return;
+        }
+        if template.value_type_list_element != instance.value_type_list_element {
+            self.deviation(
+                DeviationKind::ValueType,
+                path.to_string(),
+                format!(
+                    "expected valueTypeListElement {}, found {}",
+                    optional(&template.value_type_list_element),
+                    optional(&instance.value_type_list_element)
+                ),
+            );
+        }
+
+        let Some(element_template) = template.value.iter().flatten().next() else {
+            return;
+        };
+        let elements = instance.value.as_deref().unwrap_or_default();
+        let cardinality = self.cardinality(element_template, Some(path));
+        if !cardinality.allows(elements.len()) {
+            self.deviation(
+                DeviationKind::Cardinality,
+                path.to_string(),
+                format!(
+                    "the elements have cardinality {cardinality}, found {}",
+                    elements.len()
+                ),
+            );
+        }
+        for (i, element) in elements.iter().enumerate() {
+            self.element(element_template, element, &path.join(PathSegment::Index(i)));
+        }
+    }
+
+    fn element(
+        &mut self,
+        template: &SubmodelElement,
+        instance: &SubmodelElement,
+        path: &IdShortPath,
+    ) {
+        let (template, instance) = (Referred::from(template), Referred::from(instance));
+        if mem::discriminant(&template) != mem::discriminant(&instance) {
+            return self.deviation(
+                DeviationKind::ModelType,
+                path.to_string(),
+                format!("expected a {template}, found a {instance}"),
+            );
+        }
+
+        match (template, instance) {
+            (Referred::Property(template), Referred::Property(instance)) => {
+                let value_type = DataTypeXSDef::from(&template.value);
+                self.value_type(&value_type, &DataTypeXSDef::from(&instance.value), path);
+                if let Ok(Some(value)) = instance.value.to_lexical() {
+                    self.allowed_value(&template.qualifiable, &value, path);
+                    self.allowed_range(&template.qualifiable, &value, path);
+                }
+            }
+            (Referred::Range(template), Referred::Range(instance)) => {
+                self.value_type(&template.value_type(), &instance.value_type(), path);
+                if let Ok((min, max)) = instance.value.to_lexical() {
+                    for value in [min, max].into_iter().flatten() {
+                        self.allowed_range(&template.qualifiable, &value, path);
+                    }
+                }
+            }
+            (
+                Referred::MultiLanguageProperty(template),
+                Referred::MultiLanguageProperty(instance),
+            ) => {
+                self.required_languages(&template.qualifiable, instance.value.as_deref(), path);
+                for text in instance.value.iter().flatten() {
+                    self.allowed_value(&template.qualifiable, &text.text, path);
+                }
+            }
+            (
+                Referred::SubmodelElementCollection(template),
+                Referred::SubmodelElementCollection(instance),
+            ) => self.named(&template.value, &instance.value, Some(path)),
+            (Referred::SubmodelElementList(template), Referred::SubmodelElementList(instance)) => {
+                self.list(template, instance, path)
+            }
+            (Referred::Entity(template), Referred::Entity(instance)) => {
+                let (Entity::CoManagedEntity(template) | Entity::SelfManagedEntity(template)) =
+                    template;
+                let (Entity::CoManagedEntity(instance) | Entity::SelfManagedEntity(instance)) =
+                    instance;
+                self.named(&template.statements, &instance.statements, Some(path))
+            }
+            (
+                Referred::AnnotatedRelationshipElement(template),
+                Referred::AnnotatedRelationshipElement(instance),
+            ) => {
+                let elements = |annotations: &Option<Vec<DataElement>>| {
+                    annotations
+                        .as_ref()
+                        .map(|a| a.iter().cloned().map(SubmodelElement::from).collect())
+                };
+                self.named(
+                    &elements(&template.annotations),
+                    &elements(&instance.annotations),
+                    Some(path),
+                )
+            }
+            _ => {}
+        }
+    }
+
+    fn value_type(&mut self, expected: &DataTypeXSDef, found: &DataTypeXSDef, path: &IdShortPath) {
+        if expected != found {
+            self.deviation(
+                DeviationKind::ValueType,
+                path.to_string(),
+                format!("expected valueType {expected}, found {found}"),
+            );
+        }
+    }
+
+    fn allowed_value(&mut self, template: &Qualifiable, value: &str, path: &IdShortPath) {
+        let Some(pattern) = qualifier_text(template, ALLOWED_VALUE) else {
+            return;
+        };
+
+        // like XML schema patterns, the expression has to match the whole value
+        match Regex::new(&format!("^(?:{pattern})$")) {
+            Ok(regex) if regex.is_match(value) => {}
+            Ok(_) => self.deviation(
+                DeviationKind::AllowedValue,
+                path.to_string(),
+                format!("'{value}' does not match '{pattern}'"),
+            ),
+            Err(e) => self.deviation(
+                DeviationKind::InvalidTemplate,
+                path.to_string(),
+                format!("invalid {ALLOWED_VALUE} '{pattern}': {e}"),
+            ),
+        }
+    }
+
+    fn allowed_range(&mut self, template: &Qualifiable, value: &str, path: &IdShortPath) {
+        let Some(range) = qualifier_text(template, ALLOWED_RANGE) else {
+            return;
+        };
+        let Some(interval) = Interval::parse(&range) else {
+            return self.deviation(
+                DeviationKind::InvalidTemplate,
+                path.to_string(),
+                format!("invalid {ALLOWED_RANGE} '{range}', expected e.g. '[0, 100)' or '1..5'"),
+            );
+        };
+
+        match BigDecimal::from_str(value) {
+            Ok(number) if interval.contains(&number) => {}
+            Ok(_) => self.deviation(
+                DeviationKind::AllowedRange,
+                path.to_string(),
+                format!("{value} is outside of {range}"),
+            ),
+            Err(_) => self.deviation(
+                DeviationKind::AllowedRange,
+                path.to_string(),
+                format!("'{value}' is no number in {range}"),
+            ),
+        }
+    }
+
+    fn required_languages(
+        &mut self,
+        template: &Qualifiable,
+        texts: Option<&[LangString]>,
+        path: &IdShortPath,
+    ) {
+        let Some(required) = qualifier_text(template, REQUIRED_LANG) else {
+            return;
+        };
+
+        for language in required.split([',', ' ']).filter(|l| !l.is_empty()) {
+            let present = texts.unwrap_or_default().iter().any(|text| {
+                text.language.as_str().eq_ignore_ascii_case(language)
+                    || text
+                        .language
+                        .primary_language()
+                        .eq_ignore_ascii_case(language)
+            });
+            if !present {
+                self.deviation(
+                    DeviationKind::RequiredLang,
+                    path.to_string(),
+                    format!("no text in the required language '{language}'"),
+                );
+            }
+        }
+    }
+}
+
+fn id_short(element: &SubmodelElement) -> String {
+    element
+        .referable()
+        .id_short
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_default()
+}
+
+fn optional(value: &Option<impl fmt::Display>) -> String {
+    value
+        .as_ref()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "none".into())
+}
+
+/// Whether the idShort is the one of the template, optionally numbered by [Submodel::instantiate].
+fn is_instance_of(id_short: &str, template: &str) -> bool {
+    !template.is_empty()
+        && id_short
+            .strip_prefix(template)
+            .is_some_and(|number| number.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn qualifier_text(qualifiable: &Qualifiable, ty: &str) -> Option<String> {
+    qualifier_value(qualifiable, &[ty])?
+        .to_lexical()
+        .ok()
+        .flatten()
+}
+
+/// Interval of `SMT/AllowedRange`, written as `[min, max]` with `(` or `)` for exclusive
+/// and empty for unbounded ends, or as `min..max`.
+struct Interval {
+    min: Option<(BigDecimal, bool)>,
+    max: Option<(BigDecimal, bool)>,
+}
+
+impl Interval {
+    fn parse(text: &str) -> Option<Interval> {
+        let text = text.trim();
+        let (min, max, min_inclusive, max_inclusive) = match text.split_once("..") {
+            Some((min, max)) => (min, max, true, true),
+            None => {
+                let min_inclusive = match text.chars().next()? {
+                    '[' => true,
+                    '(' => false,
+                    _ => return None,
+                };
+                let max_inclusive = match text.chars().last()? {
+                    ']' => true,
+                    ')' => false,
+                    _ => return None,
+                };
+                let (min, max) = text.get(1..text.len() - 1)?.split_once(',')?;
+                (min, max, min_inclusive, max_inclusive)
+            }
+        };
+
+        let bound = |bound: &str, inclusive: bool| match bound.trim() {
+            "" => Some(None),
+            bound => BigDecimal::from_str(bound)
+                .ok()
+                .map(|bound| Some((bound, inclusive))),
+        };
+        Some(Interval {
+            min: bound(min, min_inclusive)?,
+            max: bound(max, max_inclusive)?,
+        })
+    }
+
+    fn contains(&self, value: &BigDecimal) -> bool {
+        let above_min = match &self.min {
+            Some((min, true)) => value >= min,
+            Some((min, false)) => value > min,
+            None => true,
+        };
+        let below_max = match &self.max {
+            Some((max, true)) => value <= max,
+            Some((max, false)) => value < max,
+            None => true,
+        };
+        above_min && below_max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::attributes::qualifiable::QualifierInner;
+    use crate::part1::v3_1::builder::Builder;
+    use crate::part1::v3_1::submodel_elements::{
+        AasSubmodelElements, MultiLanguageProperty, Property, SubmodelElementCollection,
+        SubmodelElementList,
+    };
+
+    fn qualifier(ty: &str, value: &str) -> Qualifier {
+        Qualifier::TemplateQualifier(QualifierInner {
+            semantics: Default::default(),
+            ty: ty.into(),
+            value: DataXsd::String(Some(value.into())),
+            value_id: None,
+        })
+    }
+
+    fn property(id_short: &str, cardinality: &str) -> Builder<Property> {
+        Property::builder(DataTypeXSDef::Int)
+            .id_short(id_short)
+            .qualifier(qualifier("SMT/Cardinality", cardinality))
+    }
+
+    fn path(path: &str) -> IdShortPath {
+        path.parse().unwrap()
+    }
+
+    fn template() -> Submodel {
+        let document = SubmodelElementCollection::builder()
+            .id_short("Document")
+            .qualifier(qualifier("SMT/Cardinality", "ZeroToMany"))
+            .element(property("Pages", "One").build().unwrap())
+            .element(property("Size", "ZeroToOne").build().unwrap())
+            .build()
+            .unwrap();
+        let speeds = SubmodelElementList::builder(AasSubmodelElements::Property)
+            .id_short("Speeds")
+            .value_type_list_element(DataTypeXSDef::Int)
+            .qualifier(qualifier("SMT/Cardinality", "ZeroToOne"))
+            .element(
+                Property::builder(DataTypeXSDef::Int)
+                    .qualifier(qualifier("SMT/Cardinality", "OneToMany"))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        Submodel::builder("https://example.com/smt/1")
+            .kind(ModellingKind::Template)
+            .qualifier(qualifier("SMT/Category", "Nameplate"))
+            .element(
+                property("MaxSpeed", "One")
+                    .qualifier(qualifier("SMT/DefaultValue", "3000"))
+                    .build()
+                    .unwrap(),
+            )
+            .element(property("SerialNumber", "ZeroToOne").build().unwrap())
+            .element(document)
+            .element(speeds)
+            .build()
+            .unwrap()
+    }
+
+    fn id(id: &str) -> Identifier {
+        Identifier::try_from(id).unwrap()
+    }
+
+    #[test]
+    fn instantiate_defaults() {
+        let instance = template()
+            .instantiate(id("https://example.com/sm/1"), &Instantiation::new())
+            .unwrap();
+
+        assert_eq!(instance.identifiable.id, id("https://example.com/sm/1"));
+        assert_eq!(instance.kind, Some(ModellingKind::Instance));
+        assert_eq!(
+            instance.identifiable.administration.unwrap().template_id,
+            Some(id("https://example.com/smt/1"))
+        );
+        assert_eq!(instance.qualifier.qualifiers, None);
+
+        let elements = instance.submodel_elements.unwrap();
+        assert_eq!(elements.len(), 1);
+        let SubmodelElement::Property(max_speed) = &elements[0] else {
+            panic!("expected a property");
+        };
+        assert_eq!(max_speed.value, DataXsd::Int(Some(3000)));
+        assert_eq!(max_speed.qualifiable.qualifiers, None);
+    }
+
+    #[test]
+    fn instantiate_requested_elements() {
+        let instantiation = Instantiation::new()
+            .include(path("SerialNumber"))
+            .count(path("Document"), 2)
+            .include(path("Document.Size"))
+            .include(path("Speeds"))
+            .count(path("Speeds[0]"), 3);
+        let instance = template()
+            .instantiate(id("https://example.com/sm/1"), &instantiation)
+            .unwrap();
+
+        let paths: Vec<_> = instance
+            .id_short_paths(crate::part1::v3_1::id_short_path::Level::Deep)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "MaxSpeed",
+                "SerialNumber",
+                "Document01",
+                "Document01.Pages",
+                "Document01.Size",
+                "Document02",
+                "Document02.Pages",
+                "Document02.Size",
+                "Speeds",
+                "Speeds[0]",
+                "Speeds[1]",
+                "Speeds[2]",
+            ]
+        );
+    }
+
+    #[test]
+    fn instantiation_errors() {
+        let template = template();
+        let instantiate = |instantiation: Instantiation| {
+            template.instantiate(id("https://example.com/sm/1"), &instantiation)
+        };
+
+        assert_eq!(
+            instantiate(Instantiation::new().count(path("MaxSpeed"), 2)),
+            Err(TemplateError::CountNotAllowed {
+                path: path("MaxSpeed"),
+                cardinality: Cardinality::One,
+                count: 2,
+            })
+        );
+        assert_eq!(
+            instantiate(Instantiation::new().include(path("Unknown"))),
+            Err(TemplateError::UnknownPath(path("Unknown")))
+        );
+
+        let instance = instantiate(Instantiation::new()).unwrap();
+        assert_eq!(
+            instance.instantiate(id("https://example.com/sm/2"), &Instantiation::new()),
+            Err(TemplateError::NotATemplate)
+        );
+    }
+
+    #[test]
+    fn check_conformance() {
+        let template = Submodel::builder("https://example.com/smt/2")
+            .kind(ModellingKind::Template)
+            .element(
+                property("MaxSpeed", "One")
+                    .qualifier(qualifier("SMT/AllowedRange", "[0, 5000)"))
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                Property::builder(DataTypeXSDef::String)
+                    .id_short("SerialNumber")
+                    .qualifier(qualifier("SMT/AllowedValue", "SN-[0-9]+"))
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                MultiLanguageProperty::builder()
+                    .id_short("Name")
+                    .qualifier(qualifier("SMT/RequiredLang", "en, de"))
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                SubmodelElementCollection::builder()
+                    .id_short("Marking")
+                    .qualifier(qualifier("SMT/Cardinality", "ZeroToMany"))
+                    .element(property("Count", "One").build().unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let marking = |count: Option<&str>| {
+            let mut marking = SubmodelElementCollection::builder();
+            if let Some(count) = count {
+                marking = marking.element(
+                    Property::builder(DataTypeXSDef::Int)
+                        .id_short("Count")
+                        .value(count)
+                        .build()
+                        .unwrap(),
+                );
+            }
+            marking
+        };
+        let conformant = Submodel::builder("https://example.com/sm/2")
+            .element(
+                Property::builder(DataTypeXSDef::Int)
+                    .id_short("MaxSpeed")
+                    .value("3000")
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                Property::builder(DataTypeXSDef::String)
+                    .id_short("SerialNumber")
+                    .value("SN-42")
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                MultiLanguageProperty::builder()
+                    .id_short("Name")
+                    .value("en-US", "Motor")
+                    .value("de", "Motor")
+                    .build()
+                    .unwrap(),
+            )
+            .element(marking(Some("1")).id_short("Marking01").build().unwrap())
+            .element(marking(Some("2")).id_short("Marking02").build().unwrap())
+            .element(
+                Property::builder(DataTypeXSDef::Int)
+                    .id_short("Extra")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let report = conformant.check_against(&template);
+        assert!(report.is_conformant(), "{report}");
+
+        let deviating = Submodel::builder("https://example.com/sm/3")
+            .element(
+                Property::builder(DataTypeXSDef::Int)
+                    .id_short("MaxSpeed")
+                    .value("5000")
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                Property::builder(DataTypeXSDef::Int)
+                    .id_short("SerialNumber")
+                    .value("42")
+                    .build()
+                    .unwrap(),
+            )
+            .element(
+                MultiLanguageProperty::builder()
+                    .id_short("Name")
+                    .value("en", "Motor")
+                    .build()
+                    .unwrap(),
+            )
+            .element(marking(None).id_short("Marking01").build().unwrap())
+            .build()
+            .unwrap();
+        let report = deviating.check_against(&template);
+        let deviations: Vec<_> = report
+            .deviations
+            .iter()
+            .map(|d| (d.kind, d.path.as_str()))
+            .collect();
+        assert_eq!(
+            deviations,
+            vec![
+                (DeviationKind::AllowedRange, "MaxSpeed"),
+                (DeviationKind::ValueType, "SerialNumber"),
+                (DeviationKind::AllowedValue, "SerialNumber"),
+                (DeviationKind::RequiredLang, "Name"),
+                (DeviationKind::Cardinality, "Marking01"),
+            ]
+        );
+    }
+}
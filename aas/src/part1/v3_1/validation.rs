@@ -0,0 +1,985 @@
+//! Checks of the AASd constraints of the metamodel that are not already enforced by the types.
+//!
+//! Paths in the report point into the JSON serialization of the checked element,
+//! e.g. `/submodels/0/submodelElements/2/idShort`.
+
+use crate::part1::v3_1::attributes::administrative_information::AdministrativeInformation;
+use crate::part1::v3_1::attributes::kind::ModellingKind;
+use crate::part1::v3_1::attributes::qualifiable::{Qualifiable, Qualifier};
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::attributes::semantics::HasSemantics;
+use crate::part1::v3_1::concept_description::ConceptDescription;
+use crate::part1::v3_1::core::{AssetAdministrationShell, SpecificAssetId, Submodel};
+use crate::part1::v3_1::environment::Environment;
+use crate::part1::v3_1::id_short_path::is_valid_id_short;
+use crate::part1::v3_1::key::Key;
+use crate::part1::v3_1::primitives::data_type_def_xs::DataTypeXSDef;
+use crate::part1::v3_1::reference::Reference;
+use crate::part1::v3_1::resolver::Referred;
+use crate::part1::v3_1::submodel_elements::{
+    AasSubmodelElements, AnnotatedRelationshipElement, BasicEventElement, Blob, Capability, Entity,
+    File, MultiLanguageProperty, Operation, Property, Range, ReferenceElement, RelationshipElement,
+    SubmodelElement, SubmodelElementCollection, SubmodelElementList,
+};
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+/// A violated constraint
+#[derive(Clone, PartialEq, Debug)]
+pub struct Violation {
+    /// Id of the constraint, e.g. `AASd-022`
+    pub constraint: &'static str,
+
+    /// JSON pointer to the offending attribute or element
+    pub path: String,
+
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} at {}: {}", self.constraint, self.path, self.message)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for violation in &self.violations {
+            writeln!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects violations while walking the model.
+#[derive(Debug, Default)]
+pub struct Validator {
+    report: ValidationReport,
+
+    /// Whether the elements are part of a submodel with kind Template (AASd-129)
+    in_template: bool,
+
+    /// Whether the elements are checked without their submodel.
+    /// Constraints that depend on the submodel, i.e. AASd-129, are skipped then.
+    detached: bool,
+}
+
+impl Validator {
+    /// Validator for elements that are checked without their submodel, e.g. by the builders.
+    pub(crate) fn detached() -> Self {
+        Self {
+            detached: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn into_report(self) -> ValidationReport {
+        self.report
+    }
+
+    fn violation(&mut self, constraint: &'static str, path: String, message: impl Into<String>) {
+        self.report.violations.push(Violation {
+            constraint,
+            path,
+            message: message.into(),
+        });
+    }
+}
+
+pub trait Validate {
+    /// Checks the element and everything it contains.
+    fn validate(&self) -> ValidationReport {
+        let mut validator = Validator::default();
+        self.validate_with(&mut validator, "");
+        validator.into_report()
+    }
+
+    /// Adds the violations of the element, located at `path`, to the validator.
+    fn validate_with(&self, validator: &mut Validator, path: &str);
+}
+
+fn join(path: &str, segment: impl Display) -> String {
+    format!("{path}/{segment}")
+}
+
+impl Validate for Environment {
+    fn validate_with(&self, validator: &mut Validator, path: &str) {
+        let shells = join(path, "assetAdministrationShells");
+        for (i, shell) in self
+            .asset_administration_shells
+            .iter()
+            .flatten()
+            .enumerate()
+        {
+            shell.validate_with(validator, &join(&shells, i));
+        }
+
+        let submodels = join(path, "submodels");
+        for (i, submodel) in self.submodels.iter().flatten().enumerate() {
+            submodel.validate_with(validator, &join(&submodels, i));
+        }
+
+        let concept_descriptions = join(path, "conceptDescriptions");
+        for (i, cd) in self.concept_descriptions.iter().flatten().enumerate() {
+            cd.validate_with(validator, &join(&concept_descriptions, i));
+        }
+    }
+}
+
+impl Validate for AssetAdministrationShell {
+    fn validate_with(&self, validator: &mut Validator, path: &str) {
+        referable(validator, &self.identifiable.referable, path);
+        administration(validator, &self.identifiable.administration, path);
+        optional_reference(validator, &self.derived_from, &join(path, "derivedFrom"));
+
+        let submodels = join(path, "submodels");
+        for (i, reference) in self.submodels.iter().flatten().enumerate() {
+            reference.validate_with(validator, &join(&submodels, i));
+        }
+
+        let asset_information = join(path, "assetInformation");
+        let has_specific_asset_ids = self
+            .asset_information
+            .specific_asset_ids
+            .as_ref()
+            .is_some_and(|ids| !ids.is_empty());
+        if self.asset_information.global_asset_id.is_none() && !has_specific_asset_ids {
+            validator.violation(
+                "AASd-131",
+                asset_information.clone(),
+                "either globalAssetId or at least one specificAssetId has to be defined",
+            );
+        }
+        specific_asset_ids(
+            validator,
+            &self.asset_information.specific_asset_ids,
+            &join(&asset_information, "specificAssetIds"),
+        );
+    }
+}
+
+impl Validate for Submodel {
+    fn validate_with(&self, validator: &mut Validator, path: &str) {
+        let is_template = self.kind == Some(ModellingKind::Template);
+
+        referable(validator, &self.identifiable.referable, path);
+        administration(validator, &self.identifiable.administration, path);
+        semantics(validator, &self.semantics, path);
+        qualifiable(validator, &self.qualifier, path);
+
+        if !is_template && has_template_qualifier(&self.qualifier) {
+            validator.violation(
+                "AASd-119",
+                join(path, "kind"),
+                "a submodel with a TemplateQualifier has to be of kind Template",
+            );
+        }
+
+        let in_template = std::mem::replace(&mut validator.in_template, is_template);
+        let detached = std::mem::replace(&mut validator.detached, false);
+        namespace(
+            validator,
+            self.submodel_elements
+                .iter()
+                .flatten()
+                .map(Referred::from)
+                .collect(),
+            &join(path, "submodelElements"),
+        );
+        validator.in_template = in_template;
+        validator.detached = detached;
+    }
+}
+
+impl Validate for ConceptDescription {
+    fn validate_with(&self, validator: &mut Validator, path: &str) {
+        referable(validator, &self.identifiable.referable, path);
+        administration(validator, &self.identifiable.administration, path);
+
+        let is_case_of = join(path, "isCaseOf");
+        for (i, reference) in self.is_case_of.iter().flatten().enumerate() {
+            reference.validate_with(validator, &join(&is_case_of, i));
+        }
+    }
+}
+
+impl Validate for SubmodelElement {
+    fn validate_with(&self, validator: &mut Validator, path: &str) {
+        element(validator, Referred::from(self), path);
+    }
+}
+
+/// The concrete element types are checked like the SubmodelElement wrapping them.
+macro_rules! impl_validate_element {
+    ($($ty:ident),* $(,)?) => {$(
+        impl Validate for $ty {
+            fn validate_with(&self, validator: &mut Validator, path: &str) {
+                element(validator, Referred::$ty(self), path);
+            }
+        }
+    )*};
+}
+
+impl_validate_element!(
+    RelationshipElement,
+    AnnotatedRelationshipElement,
+    BasicEventElement,
+    Blob,
+    Capability,
+    Entity,
+    File,
+    MultiLanguageProperty,
+    Operation,
+    Property,
+    Range,
+    ReferenceElement,
+    SubmodelElementCollection,
+    SubmodelElementList,
+);
+
+impl Validate for Reference {
+    fn validate_with(&self, validator: &mut Validator, path: &str) {
+        let keys = &self.keys;
+        let key_path = |i: usize| join(&join(path, "keys"), i);
+
+        if let Some(referred_semantic_id) = &self.referred_semantic_id {
+            referred_semantic_id.validate_with(validator, &join(path, "referredSemanticId"));
+        }
+
+        let Some(first) = keys.first() else {
+            return;
+        };
+        if !matches!(
+            first,
+            Key::GlobalReference(_)
+                | Key::AssetAdministrationShell(_)
+                | Key::ConceptDescription(_)
+                | Key::Identifiable(_)
+                | Key::Submodel(_)
+        ) {
+            validator.violation(
+                "AASd-121",
+                key_path(0),
+                format!("the first key has to refer to a globally identifiable, not a {first}"),
+            );
+        }
+
+        match self {
+            Reference::ExternalReference(_) => {
+                if !matches!(first, Key::GlobalReference(_)) {
+                    validator.violation(
+                        "AASd-122",
+                        key_path(0),
+                        "the first key of an external reference has to be a GlobalReference",
+                    );
+                }
+
+                let last = keys.len() - 1;
+                if !matches!(
+                    keys[last],
+                    Key::GlobalReference(_) | Key::FragmentReference(_)
+                ) {
+                    validator.violation(
+                        "AASd-124",
+                        key_path(last),
+                        "the last key of an external reference has to be a GlobalReference or FragmentReference",
+                    );
+                }
+            }
+            Reference::ModelReference(_) => {
+                if !matches!(
+                    first,
+                    Key::AssetAdministrationShell(_)
+                        | Key::ConceptDescription(_)
+                        | Key::Identifiable(_)
+                        | Key::Submodel(_)
+                ) {
+                    validator.violation(
+                        "AASd-123",
+                        key_path(0),
+                        "the first key of a model reference has to be an AasIdentifiable",
+                    );
+                }
+
+                for (i, pair) in keys.windows(2).enumerate() {
+                    let (previous, key) = (&pair[0], &pair[1]);
+                    let i = i + 1;
+
+                    if matches!(
+                        key,
+                        Key::GlobalReference(_)
+                            | Key::AssetAdministrationShell(_)
+                            | Key::ConceptDescription(_)
+                            | Key::Identifiable(_)
+                            | Key::Submodel(_)
+                            | Key::Referable(_)
+                    ) {
+                        validator.violation(
+                            "AASd-125",
+                            key_path(i),
+                            format!("only fragment keys may follow the first key, found {key}"),
+                        );
+                    }
+
+                    if let Key::FragmentReference(_) = key {
+                        if i != keys.len() - 1 {
+                            validator.violation(
+                                "AASd-126",
+                                key_path(i),
+                                "a FragmentReference has to be the last key",
+                            );
+                        }
+                        if !matches!(previous, Key::File(_) | Key::Blob(_)) {
+                            validator.violation(
+                                "AASd-127",
+                                key_path(i),
+                                "a FragmentReference has to follow a File or Blob key",
+                            );
+                        }
+                    }
+
+                    if let Key::SubmodelElementList(_) = previous
+                        && key.value().parse::<usize>().is_err()
+                    {
+                        validator.violation(
+                            "AASd-128",
+                            key_path(i),
+                            format!(
+                                "the key following a SubmodelElementList has to be an index, found '{}'",
+                                key.value()
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn optional_reference(validator: &mut Validator, reference: &Option<Reference>, path: &str) {
+    if let Some(reference) = reference {
+        reference.validate_with(validator, path);
+    }
+}
+
+fn referable(validator: &mut Validator, referable: &Referable, path: &str) {
+    if let Some(id_short) = &referable.id_short
+        && !is_valid_id_short(id_short)
+    {
+        validator.violation(
+            "AASd-002",
+            join(path, "idShort"),
+            format!(
+                "'{id_short}' has to start with a letter, may only contain letters, digits, '_' and '-', and must not end with '-'"
+            ),
+        );
+    }
+
+    let extensions = join(path, "extensions");
+    let mut names = HashSet::new();
+    for (i, extension) in referable.extensions.extension.iter().flatten().enumerate() {
+        if !names.insert(extension.name.as_str()) {
+            validator.violation(
+                "AASd-077",
+                join(&join(&extensions, i), "name"),
+                format!("duplicate extension name '{}'", extension.name),
+            );
+        }
+    }
+}
+
+fn administration(
+    validator: &mut Validator,
+    administration: &Option<AdministrativeInformation>,
+    path: &str,
+) {
+    let Some(administration) = administration else {
+        return;
+    };
+    let path = join(path, "administration");
+
+    if administration.version.version.is_none() && administration.version.revision.is_some() {
+        validator.violation(
+            "AASd-005",
+            join(&path, "revision"),
+            "a revision requires a version",
+        );
+    }
+    optional_reference(validator, &administration.creator, &join(&path, "creator"));
+}
+
+fn semantics(validator: &mut Validator, semantics: &HasSemantics, path: &str) {
+    optional_reference(validator, &semantics.semantic_id, &join(path, "semanticId"));
+
+    let supplemental = join(path, "supplementalSemanticIds");
+    for (i, reference) in semantics
+        .supplemental_semantic_ids
+        .iter()
+        .flatten()
+        .enumerate()
+    {
+        reference.validate_with(validator, &join(&supplemental, i));
+    }
+
+    let has_supplemental = semantics
+        .supplemental_semantic_ids
+        .as_ref()
+        .is_some_and(|ids| !ids.is_empty());
+    if has_supplemental && semantics.semantic_id.is_none() {
+        validator.violation(
+            "AASd-118",
+            join(path, "semanticId"),
+            "a semanticId is required if supplementalSemanticIds are defined",
+        );
+    }
+}
+
+fn has_template_qualifier(qualifiable: &Qualifiable) -> bool {
+    qualifiable
+        .qualifiers
+        .iter()
+        .flatten()
+        .any(|q| matches!(q, Qualifier::TemplateQualifier(_)))
+}
+
+fn qualifiable(validator: &mut Validator, qualifiable: &Qualifiable, path: &str) {
+    let qualifiers = join(path, "qualifiers");
+    let mut types = HashSet::new();
+
+    for (i, qualifier) in qualifiable.qualifiers.iter().flatten().enumerate() {
+        let path = join(&qualifiers, i);
+        let (Qualifier::ConceptQualifier(inner)
+        | Qualifier::TemplateQualifier(inner)
+        | Qualifier::ValueQualifier(inner)
+        | Qualifier::Unknown(inner)) = qualifier;
+
+        if !types.insert(inner.ty.as_str()) {
+            validator.violation(
+                "AASd-021",
+                join(&path, "type"),
+                format!("duplicate qualifier type '{}'", inner.ty),
+            );
+        }
+        if let Err(e) = inner.value.validate() {
+            validator.violation("AASd-020", join(&path, "value"), e.to_string());
+        }
+
+        semantics(validator, &inner.semantics, &path);
+        optional_reference(validator, &inner.value_id, &join(&path, "valueId"));
+    }
+}
+
+fn specific_asset_ids(validator: &mut Validator, ids: &Option<Vec<SpecificAssetId>>, path: &str) {
+    for (i, id) in ids.iter().flatten().enumerate() {
+        let path = join(path, i);
+        let external_subject_id = join(&path, "externalSubjectId");
+
+        semantics(validator, &id.has_semantics, &path);
+        match &id.external_subject_id {
+            Some(reference @ Reference::ExternalReference(_)) => {
+                reference.validate_with(validator, &external_subject_id)
+            }
+            Some(Reference::ModelReference(_)) => validator.violation(
+                "AASd-133",
+                external_subject_id,
+                "the externalSubjectId has to be an external reference",
+            ),
+            None => {}
+        }
+    }
+}
+
+/// Checks the elements of a namespace, i.e. the children of a Submodel, collection, entity
+/// or the annotations of an AnnotatedRelationshipElement.
+fn namespace(validator: &mut Validator, elements: Vec<Referred>, path: &str) {
+    let mut id_shorts = HashSet::new();
+
+    for (i, child) in elements.into_iter().enumerate() {
+        let path = join(path, i);
+        element(validator, child, &path);
+
+        match &child.referable().id_short {
+            None => validator.violation(
+                "AASd-117",
+                join(&path, "idShort"),
+                "elements not contained in a SubmodelElementList need an idShort",
+            ),
+            Some(id_short) if !id_shorts.insert(id_short.as_ref()) => validator.violation(
+                "AASd-022",
+                join(&path, "idShort"),
+                format!("duplicate idShort '{id_short}' within the same namespace"),
+            ),
+            Some(_) => {}
+        }
+    }
+}
+
+/// Attributes shared by all submodel elements
+fn common(
+    validator: &mut Validator,
+    referable_fields: &Referable,
+    semantics_fields: &HasSemantics,
+    qualifiable_fields: &Qualifiable,
+    path: &str,
+) {
+    referable(validator, referable_fields, path);
+    semantics(validator, semantics_fields, path);
+    qualifiable(validator, qualifiable_fields, path);
+
+    if !validator.detached && !validator.in_template && has_template_qualifier(qualifiable_fields) {
+        validator.violation(
+            "AASd-129",
+            join(path, "qualifiers"),
+            "elements with a TemplateQualifier have to be part of a submodel of kind Template",
+        );
+    }
+}
+
+fn element(validator: &mut Validator, element: Referred, path: &str) {
+    match element {
+        Referred::RelationshipElement(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+            optional_reference(validator, &e.first, &join(path, "first"));
+            optional_reference(validator, &e.second, &join(path, "second"));
+        }
+        Referred::AnnotatedRelationshipElement(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+            optional_reference(validator, &e.first, &join(path, "first"));
+            optional_reference(validator, &e.second, &join(path, "second"));
+            namespace(
+                validator,
+                e.annotations.iter().flatten().map(Referred::from).collect(),
+                &join(path, "annotations"),
+            );
+        }
+        Referred::BasicEventElement(e) => {
+            let fields = &e.submodel_element_fields;
+            common(
+                validator,
+                &fields.referable,
+                &fields.semantics,
+                &fields.qualifiable,
+                path,
+            );
+            e.observed.validate_with(validator, &join(path, "observed"));
+            optional_reference(validator, &e.message_broker, &join(path, "messageBroker"));
+        }
+        Referred::Blob(e) => common(validator, &e.referable, &e.semantics, &e.qualifiable, path),
+        Referred::Capability(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path)
+        }
+        Referred::File(e) => common(validator, &e.referable, &e.semantics, &e.qualifiable, path),
+        Referred::MultiLanguageProperty(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path)
+        }
+        Referred::Property(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path)
+        }
+        Referred::Range(e) => common(validator, &e.referable, &e.semantics, &e.qualifiable, path),
+        Referred::ReferenceElement(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+            optional_reference(validator, &e.value, &join(path, "value"));
+        }
+        Referred::Entity(entity) => {
+            let e = match entity {
+                Entity::CoManagedEntity(e) | Entity::SelfManagedEntity(e) => e,
+            };
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+
+            let has_asset_id = e.global_asset_id.is_some()
+                || e.specific_asset_id
+                    .as_ref()
+                    .is_some_and(|ids| !ids.is_empty());
+            match entity {
+                Entity::SelfManagedEntity(_) if !has_asset_id => validator.violation(
+                    "AASd-014",
+                    path.to_string(),
+                    "a self-managed entity needs a globalAssetId or specificAssetIds",
+                ),
+                Entity::CoManagedEntity(_) if has_asset_id => validator.violation(
+                    "AASd-014",
+                    path.to_string(),
+                    "a co-managed entity must not have a globalAssetId or specificAssetIds",
+                ),
+                _ => {}
+            }
+            specific_asset_ids(
+                validator,
+                &e.specific_asset_id,
+                &join(path, "specificAssetIds"),
+            );
+            namespace(
+                validator,
+                e.statements.iter().flatten().map(Referred::from).collect(),
+                &join(path, "statements"),
+            );
+        }
+        Referred::Operation(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+
+            // AASd-134: the idShorts of all variables share one namespace
+            let mut id_shorts = HashSet::new();
+            let variables = [
+                ("inputVariables", &e.input_variables),
+                ("outputVariables", &e.output_variables),
+                ("inoutputVariables", &e.inoutput_variables),
+            ];
+            for (name, variables) in variables {
+                for (i, variable) in variables.iter().flatten().enumerate() {
+                    let path = join(&join(&join(path, name), i), "value");
+                    variable.value.validate_with(validator, &path);
+
+                    match &variable.value.referable().id_short {
+                        None => validator.violation(
+                            "AASd-117",
+                            join(&path, "idShort"),
+                            "elements not contained in a SubmodelElementList need an idShort",
+                        ),
+                        Some(id_short) if !id_shorts.insert(id_short.as_ref()) => validator
+                            .violation(
+                                "AASd-134",
+                                join(&path, "idShort"),
+                                format!(
+                                    "duplicate idShort '{id_short}' among the operation variables"
+                                ),
+                            ),
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        Referred::SubmodelElementCollection(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+            namespace(
+                validator,
+                e.value.iter().flatten().map(Referred::from).collect(),
+                &join(path, "value"),
+            );
+        }
+        Referred::SubmodelElementList(e) => {
+            common(validator, &e.referable, &e.semantics, &e.qualifiable, path);
+            optional_reference(
+                validator,
+                &e.semantic_id_list_element,
+                &join(path, "semanticIdListElement"),
+            );
+            list(validator, e, path);
+        }
+        Referred::AssetAdministrationShell(shell) => shell.validate_with(validator, path),
+        Referred::Submodel(submodel) => submodel.validate_with(validator, path),
+        Referred::ConceptDescription(cd) => cd.validate_with(validator, path),
+    }
+}
+
+fn is_of_type(element: Referred, ty: &AasSubmodelElements) -> bool {
+    match ty {
+        AasSubmodelElements::DataElement => matches!(
+            element,
+            Referred::Blob(_)
+                | Referred::File(_)
+                | Referred::MultiLanguageProperty(_)
+                | Referred::Property(_)
+                | Referred::Range(_)
+                | Referred::ReferenceElement(_)
+        ),
+        AasSubmodelElements::AnnotatedRelationshipElement => {
+            matches!(element, Referred::AnnotatedRelationshipElement(_))
+        }
+        AasSubmodelElements::BasicEventElement => matches!(element, Referred::BasicEventElement(_)),
+        AasSubmodelElements::Blob => matches!(element, Referred::Blob(_)),
+        AasSubmodelElements::Capability => matches!(element, Referred::Capability(_)),
+        AasSubmodelElements::Entity => matches!(element, Referred::Entity(_)),
+        AasSubmodelElements::File => matches!(element, Referred::File(_)),
+        AasSubmodelElements::MultiLanguageProperty => {
+            matches!(element, Referred::MultiLanguageProperty(_))
+        }
+        AasSubmodelElements::Operation => matches!(element, Referred::Operation(_)),
+        AasSubmodelElements::Property => matches!(element, Referred::Property(_)),
+        AasSubmodelElements::Range => matches!(element, Referred::Range(_)),
+        AasSubmodelElements::ReferenceElement => matches!(element, Referred::ReferenceElement(_)),
+        AasSubmodelElements::RelationshipElement => {
+            matches!(element, Referred::RelationshipElement(_))
+        }
+        AasSubmodelElements::SubmodelElementCollection => {
+            matches!(element, Referred::SubmodelElementCollection(_))
+        }
+        AasSubmodelElements::SubmodelElementList => {
+            matches!(element, Referred::SubmodelElementList(_))
+        }
+    }
+}
+
+fn list(validator: &mut Validator, list: &SubmodelElementList, path: &str) {
+    let value_path = join(path, "value");
+    let needs_value_type = matches!(
+        list.type_value_list_element,
+        AasSubmodelElements::Property | AasSubmodelElements::Range
+    );
+    let mut first_semantic_id = None;
+
+    if needs_value_type && list.value_type_list_element.is_none() {
+        validator.violation(
+            "AASd-109",
+            join(path, "valueTypeListElement"),
+            "a valueTypeListElement is required for lists of Property or Range",
+        );
+    }
+
+    for (i, child) in list.value.iter().flatten().enumerate() {
+        let path = join(&value_path, i);
+        child.validate_with(validator, &path);
+
+        if child.referable().id_short.is_some() {
+            validator.violation(
+                "AASd-120",
+                join(&path, "idShort"),
+                "elements of a SubmodelElementList must not have an idShort",
+            );
+        }
+
+        let referred = Referred::from(child);
+        if !is_of_type(referred, &list.type_value_list_element) {
+            validator.violation(
+                "AASd-108",
+                path.clone(),
+                format!(
+                    "expected a {} as given by typeValueListElement, found a {referred}",
+                    list.type_value_list_element
+                ),
+            );
+        }
+
+        let value_type = match referred {
+            Referred::Property(property) => Some(DataTypeXSDef::from(&property.value)),
+            Referred::Range(range) => Some(range.value_type()),
+            _ => None,
+        };
+        if needs_value_type
+            && let (Some(expected), Some(value_type)) = (&list.value_type_list_element, value_type)
+            && *expected != value_type
+        {
+            validator.violation(
+                "AASd-109",
+                join(&path, "valueType"),
+                format!("expected value type {expected}, found {value_type}"),
+            );
+        }
+
+        let semantic_id = child.semantics().semantic_id.as_ref();
+        if let (Some(expected), Some(semantic_id)) = (&list.semantic_id_list_element, semantic_id)
+            && expected != semantic_id
+        {
+            validator.violation(
+                "AASd-107",
+                join(&path, "semanticId"),
+                "the semanticId differs from the semanticIdListElement of the list",
+            );
+        }
+        match (first_semantic_id, semantic_id) {
+            (None, Some(semantic_id)) => first_semantic_id = Some(semantic_id),
+            (Some(first), Some(semantic_id)) if first != semantic_id => validator.violation(
+                "AASd-114",
+                join(&path, "semanticId"),
+                "all elements of a list have to share the same semanticId",
+            ),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::attributes::qualifiable::QualifierInner;
+    use crate::part1::v3_1::core::{AssetInformation, AssetInformationInner};
+    use crate::part1::v3_1::fixtures::{
+        SUBMODEL_ID, environment, identifiable, property, referable, submodel,
+    };
+    use crate::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use crate::part1::v3_1::reference::ReferenceInner;
+    use crate::part1::v3_1::submodel_elements::SubmodelElementCollection;
+
+    fn constraints(report: &ValidationReport) -> Vec<(&str, &str)> {
+        report
+            .violations
+            .iter()
+            .map(|v| (v.constraint, v.path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn valid_submodel() {
+        let submodel = submodel(vec![property(Some("Speed"), DataXsd::Int(Some(1))).into()]);
+        assert!(submodel.validate().is_valid());
+    }
+
+    #[test]
+    fn namespace_and_list_constraints() {
+        let list = SubmodelElement::SubmodelElementList(SubmodelElementList {
+            referable: referable("List"),
+            semantics: Default::default(),
+            qualifiable: Default::default(),
+            embedded_data_specifications: Default::default(),
+            is_order_relevant: true,
+            semantic_id_list_element: None,
+            value: Some(vec![
+                property(None, DataXsd::Int(Some(1))).into(),
+                property(Some("Named"), DataXsd::String(None)).into(),
+                SubmodelElement::SubmodelElementCollection(SubmodelElementCollection::default()),
+            ]),
+            type_value_list_element: AasSubmodelElements::Property,
+            value_type_list_element: Some(DataTypeXSDef::Int),
+        });
+        let collection = SubmodelElement::SubmodelElementCollection(SubmodelElementCollection {
+            referable: referable("Collection"),
+            value: Some(vec![
+                property(Some("Speed"), DataXsd::Int(Some(1))).into(),
+                property(Some("Speed"), DataXsd::Int(Some(2))).into(),
+                property(None, DataXsd::Int(Some(3))).into(),
+            ]),
+            ..Default::default()
+        });
+
+        let report = submodel(vec![list, collection]).validate();
+        assert_eq!(
+            constraints(&report),
+            vec![
+                ("AASd-120", "/submodelElements/0/value/1/idShort"),
+                ("AASd-109", "/submodelElements/0/value/1/valueType"),
+                ("AASd-108", "/submodelElements/0/value/2"),
+                ("AASd-022", "/submodelElements/1/value/1/idShort"),
+                ("AASd-117", "/submodelElements/1/value/2/idShort"),
+            ]
+        );
+        assert_eq!(
+            report.violations[3].to_string(),
+            "AASd-022 at /submodelElements/1/value/1/idShort: duplicate idShort 'Speed' within the same namespace"
+        );
+    }
+
+    #[test]
+    fn list_element_types() {
+        let property = SubmodelElement::from(property(None, DataXsd::Int(Some(1))));
+        let collection =
+            SubmodelElement::SubmodelElementCollection(SubmodelElementCollection::default());
+        let property = Referred::from(&property);
+        let collection = Referred::from(&collection);
+
+        assert!(is_of_type(property, &AasSubmodelElements::Property));
+        assert!(is_of_type(property, &AasSubmodelElements::DataElement));
+        assert!(!is_of_type(property, &AasSubmodelElements::Range));
+        assert!(!is_of_type(
+            property,
+            &AasSubmodelElements::SubmodelElementCollection
+        ));
+        assert!(is_of_type(
+            collection,
+            &AasSubmodelElements::SubmodelElementCollection
+        ));
+        assert!(!is_of_type(collection, &AasSubmodelElements::DataElement));
+        assert!(!is_of_type(
+            collection,
+            &AasSubmodelElements::SubmodelElementList
+        ));
+    }
+
+    #[test]
+    fn id_short_pattern() {
+        let report = submodel(vec![
+            property(Some("Speed_1-a"), DataXsd::Int(Some(1))).into(),
+            property(Some("Speed-"), DataXsd::Int(Some(2))).into(),
+            property(Some("1Speed"), DataXsd::Int(Some(3))).into(),
+        ])
+        .validate();
+
+        assert_eq!(
+            constraints(&report),
+            vec![
+                ("AASd-002", "/submodelElements/1/idShort"),
+                ("AASd-002", "/submodelElements/2/idShort"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reference_constraints() {
+        let reference = Reference::ModelReference(ReferenceInner::from_vec(vec![
+            Key::Submodel(SUBMODEL_ID.into()),
+            Key::SubmodelElementList("List".into()),
+            Key::Property("Speed".into()),
+            Key::FragmentReference("x".into()),
+            Key::GlobalReference("https://example.com".into()),
+        ]));
+        assert_eq!(
+            constraints(&reference.validate()),
+            vec![
+                ("AASd-128", "/keys/2"),
+                ("AASd-126", "/keys/3"),
+                ("AASd-127", "/keys/3"),
+                ("AASd-125", "/keys/4"),
+            ]
+        );
+
+        let reference =
+            Reference::ExternalReference(ReferenceInner::new(Key::Submodel(SUBMODEL_ID.into())));
+        assert_eq!(
+            constraints(&reference.validate()),
+            vec![("AASd-122", "/keys/0"), ("AASd-124", "/keys/0")]
+        );
+    }
+
+    #[test]
+    fn environment_constraints() {
+        let mut templated = property(Some("Speed"), DataXsd::Int(Some(1)));
+        templated.qualifiable.qualifiers =
+            Some(vec![Qualifier::TemplateQualifier(QualifierInner {
+                semantics: Default::default(),
+                ty: "Cardinality".into(),
+                value: DataXsd::String(Some("One".into())),
+                value_id: None,
+            })]);
+
+        let mut env = environment(vec![submodel(vec![templated.clone().into()])]);
+        env.asset_administration_shells = Some(vec![AssetAdministrationShell {
+            asset_information: AssetInformation::Instance(AssetInformationInner::default()),
+            identifiable: identifiable("https://example.com/aas/1"),
+            data_specification: Default::default(),
+            derived_from: None,
+            submodels: None,
+        }]);
+        assert_eq!(
+            constraints(&env.validate()),
+            vec![
+                ("AASd-131", "/assetAdministrationShells/0/assetInformation"),
+                ("AASd-129", "/submodels/0/submodelElements/0/qualifiers"),
+            ]
+        );
+
+        // without its submodel the kind is unknown, within it AASd-129 applies again
+        let mut validator = Validator::detached();
+        SubmodelElement::from(templated.clone()).validate_with(&mut validator, "");
+        assert!(validator.into_report().is_valid());
+        let mut validator = Validator::detached();
+        submodel(vec![templated.clone().into()]).validate_with(&mut validator, "");
+        assert_eq!(
+            constraints(&validator.into_report()),
+            vec![("AASd-129", "/submodelElements/0/qualifiers")]
+        );
+
+        let mut template = submodel(vec![templated.into()]);
+        template.kind = Some(ModellingKind::Template);
+        assert!(template.validate().is_valid());
+    }
+}
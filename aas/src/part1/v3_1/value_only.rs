@@ -0,0 +1,1050 @@
+//! ValueOnly serialization of submodels and submodel elements, see
+//! <https://industrialdigitaltwin.io/aas-specifications/IDTA-01001/v3.1.1/mappings/mappings.html#value-only-serialization-in-json>
+//!
+//! Serializing an element yields only its value, without the idShort;
+//! containers and submodels map their children by idShort.
+//! Capabilities and Operations have no value and are left out.
+//!
+//! The `patch_value_only` methods implement the `$value` PATCH operations of Part 2:
+//! they update the values in place and change nothing if any value does not fit.
+
+use crate::part1::v3_1::LangString;
+use crate::part1::v3_1::core::{SpecificAssetId, Submodel};
+use crate::part1::v3_1::id_short_path::{ElementMut, IdShortPath};
+use crate::part1::v3_1::key::Key;
+use crate::part1::v3_1::primitives::data_type_def_xs::{ConversionError, DataTypeXSDef, DataXsd};
+use crate::part1::v3_1::primitives::{Identifier, Label, Uri};
+use crate::part1::v3_1::reference::{Reference, ReferenceInner};
+use crate::part1::v3_1::resolver::Referred;
+use crate::part1::v3_1::submodel_elements::{
+    AnnotatedRelationshipElement, DataElement, Entity, MultiLanguageProperty, Range, RangeValue,
+    SubmodelElement, SubmodelElementList,
+};
+use crate::part1::{FromJsonValue, ToJsonValue};
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+use serde_with::base64::Base64;
+use serde_with::de::DeserializeAsWrap;
+use serde_with::ser::SerializeAsWrap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A value that does not fit the element used as schema
+#[derive(Clone, PartialEq, Debug)]
+pub struct Mismatch {
+    /// JSON pointer into the value-only document
+    pub path: String,
+
+    pub message: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ValueOnlyError {
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Conversion(#[from] ConversionError),
+
+    #[error("A {0} has no value-only representation")]
+    NoValue(String),
+
+    #[error("No element at idShortPath '{0}'")]
+    NotFound(IdShortPath),
+
+    #[error("The value does not match the schema: {}", .0.iter().map(Mismatch::to_string).collect::<Vec<_>>().join(", "))]
+    Mismatches(Vec<Mismatch>),
+}
+
+impl ToJsonValue for SubmodelElement {
+    type Error = ValueOnlyError;
+
+    fn to_json_value(&self) -> Result<String, Self::Error> {
+        let element = Referred::from(self);
+        Ok(element_value(element)?
+            .ok_or_else(|| ValueOnlyError::NoValue(element.to_string()))?
+            .to_string())
+    }
+}
+
+impl ToJsonValue for Submodel {
+    type Error = ValueOnlyError;
+
+    fn to_json_value(&self) -> Result<String, Self::Error> {
+        Ok(named(self.submodel_elements.iter().flatten().map(Referred::from))?.to_string())
+    }
+}
+
+impl FromJsonValue for SubmodelElement {
+    type Error = ValueOnlyError;
+
+    fn from_json_value(schema: &Self, value: &str) -> Result<Self, Self::Error> {
+        let value: Value = serde_json::from_str(value)?;
+        let mut element = schema.clone();
+        let mut reader = Reader::default();
+
+        reader.element(&mut element, &value, "");
+        reader.finish(element)
+    }
+}
+
+/// Values of elements missing in the document are taken over from the schema.
+impl FromJsonValue for Submodel {
+    type Error = ValueOnlyError;
+
+    fn from_json_value(schema: &Self, value: &str) -> Result<Self, Self::Error> {
+        let value: Value = serde_json::from_str(value)?;
+        let mut submodel = schema.clone();
+        let mut reader = Reader::default();
+
+        reader.named(&mut submodel.submodel_elements, &value, "");
+        reader.finish(submodel)
+    }
+}
+
+impl Submodel {
+    /// Updates the values of the elements in the document, see `PATCH /submodel/$value`.
+    /// Elements missing in the document keep their value.
+    pub fn patch_value_only(&mut self, value: &str) -> Result<(), ValueOnlyError> {
+        *self = Submodel::from_json_value(self, value)?;
+        Ok(())
+    }
+
+    /// Updates the value of the element at the path,
+    /// see `PATCH /submodel/submodel-elements/{idShortPath}/$value`.
+    pub fn patch_value_only_at(
+        &mut self,
+        path: &IdShortPath,
+        value: &str,
+    ) -> Result<(), ValueOnlyError> {
+        let value: Value = serde_json::from_str(value)?;
+        let mut reader = Reader::default();
+
+        match self.get_mut(path) {
+            Some(ElementMut::SubmodelElement(element)) => {
+                let mut patched = element.clone();
+                reader.element(&mut patched, &value, "");
+                *element = reader.finish(patched)?;
+            }
+            Some(ElementMut::Annotation(annotation)) => {
+                let mut patched = annotation.clone();
+                reader.data_element(&mut patched, &value, "");
+                *annotation = reader.finish(patched)?;
+            }
+            None => return Err(ValueOnlyError::NotFound(path.clone())),
+        }
+        Ok(())
+    }
+}
+
+impl SubmodelElement {
+    /// Updates the value of the element. It is left unchanged if the document does not fit.
+    pub fn patch_value_only(&mut self, value: &str) -> Result<(), ValueOnlyError> {
+        *self = SubmodelElement::from_json_value(self, value)?;
+        Ok(())
+    }
+}
+
+fn object<'a>(entries: impl IntoIterator<Item = (&'a str, Option<Value>)>) -> Value {
+    Value::Object(
+        entries
+            .into_iter()
+            .filter_map(|(key, value)| Some((key.to_string(), value?)))
+            .collect(),
+    )
+}
+
+pub(crate) fn named<'a>(
+    elements: impl Iterator<Item = Referred<'a>>,
+) -> Result<Value, ValueOnlyError> {
+    let mut object = Map::new();
+    for element in elements {
+        let Some(id_short) = element.referable().id_short.as_ref() else {
+            continue;
+        };
+        if let Some(value) = element_value(element)? {
+            object.insert(id_short.to_string(), value);
+        }
+    }
+    Ok(Value::Object(object))
+}
+
+/// Values outside the value space of their type are rejected instead of written.
+fn data_value(value: &DataXsd) -> Result<Value, ConversionError> {
+    let Some(lexical) = value.to_lexical()? else {
+        return Ok(Value::Null);
+    };
+
+    Ok(match value {
+        DataXsd::Boolean(Some(value)) => Value::Bool(*value),
+        _ if is_numeric(&DataTypeXSDef::from(value)) => Number::from_str(&lexical)
+            .map(Value::Number)
+            // e.g. INF and NaN of xs:double
+            .unwrap_or(Value::String(lexical)),
+        _ => Value::String(lexical),
+    })
+}
+
+fn is_numeric(data_type: &DataTypeXSDef) -> bool {
+    matches!(
+        data_type,
+        DataTypeXSDef::Int
+            | DataTypeXSDef::Long
+            | DataTypeXSDef::Integer
+            | DataTypeXSDef::NegativeInteger
+            | DataTypeXSDef::NonNegativeInteger
+            | DataTypeXSDef::NonPositiveInteger
+            | DataTypeXSDef::PositiveInteger
+            | DataTypeXSDef::Short
+            | DataTypeXSDef::Byte
+            | DataTypeXSDef::UnsignedByte
+            | DataTypeXSDef::UnsignedInt
+            | DataTypeXSDef::UnsignedLong
+            | DataTypeXSDef::UnsignedShort
+            | DataTypeXSDef::Decimal
+            | DataTypeXSDef::Float
+            | DataTypeXSDef::Double
+    )
+}
+
+fn reference_value(reference: &Reference) -> Value {
+    let ty = match reference {
+        Reference::ExternalReference(_) => "ExternalReference",
+        Reference::ModelReference(_) => "ModelReference",
+    };
+    let keys = reference
+        .keys
+        .iter()
+        .map(|key| {
+            object([
+                ("type", Some(key.to_string().into())),
+                ("value", Some(key.value().as_str().into())),
+            ])
+        })
+        .collect();
+
+    object([
+        ("type", Some(ty.into())),
+        (
+            "referredSemanticId",
+            reference
+                .referred_semantic_id
+                .as_deref()
+                .map(reference_value),
+        ),
+        ("keys", Some(Value::Array(keys))),
+    ])
+}
+
+fn specific_asset_id_value(id: &SpecificAssetId) -> Value {
+    object([
+        ("name", Some(id.name.as_ref().into())),
+        ("value", Some(id.value.as_ref().into())),
+        (
+            "externalSubjectId",
+            id.external_subject_id.as_ref().map(reference_value),
+        ),
+        (
+            "semanticId",
+            id.has_semantics.semantic_id.as_ref().map(reference_value),
+        ),
+    ])
+}
+
+fn blob_value(value: &[u8]) -> Result<Value, ValueOnlyError> {
+    Ok(serde_json::to_value(SerializeAsWrap::<_, Base64>::new(
+        &value,
+    ))?)
+}
+
+pub(crate) fn element_value(element: Referred) -> Result<Option<Value>, ValueOnlyError> {
+    Ok(Some(match element {
+        Referred::Property(property) => data_value(&property.value)?,
+        Referred::MultiLanguageProperty(property) => Value::Array(
+            property
+                .value
+                .iter()
+                .flatten()
+                .map(|s| object([(s.language.as_str(), Some(s.text.as_str().into()))]))
+                .collect(),
+        ),
+        Referred::Range(range) => {
+            let (min, max) = range.value.clone().into_bounds();
+            let (min, max) = (data_value(&min)?, data_value(&max)?);
+            let bound = |value: Value| Some(value).filter(|v| !v.is_null());
+            object([("min", bound(min)), ("max", bound(max))])
+        }
+        Referred::File(file) => object([
+            ("contentType", file.content_type.as_deref().map(Value::from)),
+            ("value", file.value.as_ref().map(|uri| uri.as_str().into())),
+        ]),
+        Referred::Blob(blob) => object([
+            ("contentType", Some(blob.content_type.as_str().into())),
+            ("value", blob.value.as_deref().map(blob_value).transpose()?),
+        ]),
+        Referred::ReferenceElement(element) => {
+            element.value.as_ref().map_or(Value::Null, reference_value)
+        }
+        Referred::RelationshipElement(element) => object([
+            ("first", element.first.as_ref().map(reference_value)),
+            ("second", element.second.as_ref().map(reference_value)),
+        ]),
+        Referred::AnnotatedRelationshipElement(element) => {
+            let annotations = element
+                .annotations
+                .iter()
+                .flatten()
+                .map(|annotation| named([Referred::from(annotation)].into_iter()))
+                .collect::<Result<_, _>>()?;
+
+            object([
+                ("first", element.first.as_ref().map(reference_value)),
+                ("second", element.second.as_ref().map(reference_value)),
+                ("annotations", Some(Value::Array(annotations))),
+            ])
+        }
+        Referred::BasicEventElement(element) => {
+            object([("observed", Some(reference_value(&element.observed)))])
+        }
+        Referred::Entity(entity) => {
+            let (Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner)) = entity;
+
+            object([
+                (
+                    "statements",
+                    Some(named(
+                        inner.statements.iter().flatten().map(Referred::from),
+                    )?),
+                ),
+                ("entityType", Some(entity.to_string().into())),
+                (
+                    "globalAssetId",
+                    inner.global_asset_id.as_deref().map(Value::from),
+                ),
+                (
+                    "specificAssetIds",
+                    inner
+                        .specific_asset_id
+                        .as_ref()
+                        .map(|ids| Value::Array(ids.iter().map(specific_asset_id_value).collect())),
+                ),
+            ])
+        }
+        Referred::SubmodelElementCollection(collection) => {
+            named(collection.value.iter().flatten().map(Referred::from))?
+        }
+        // elements without a value keep their position as null
+        Referred::SubmodelElementList(list) => Value::Array(
+            list.value
+                .iter()
+                .flatten()
+                .map(|element| Ok(element_value(Referred::from(element))?.unwrap_or(Value::Null)))
+                .collect::<Result<_, ValueOnlyError>>()?,
+        ),
+        Referred::Capability(_)
+        | Referred::Operation(_)
+        | Referred::AssetAdministrationShell(_)
+        | Referred::Submodel(_)
+        | Referred::ConceptDescription(_) => return Ok(None),
+    }))
+}
+
+fn join(path: &str, segment: impl Display) -> String {
+    format!("{path}/{segment}")
+}
+
+/// Writes the values of a document into a copy of the schema, collecting all mismatches.
+#[derive(Default)]
+struct Reader {
+    mismatches: Vec<Mismatch>,
+}
+
+impl Reader {
+    fn finish<T>(self, result: T) -> Result<T, ValueOnlyError> {
+        if self.mismatches.is_empty() {
+            Ok(result)
+        } else {
+            Err(ValueOnlyError::Mismatches(self.mismatches))
+        }
+    }
+
+    fn mismatch(&mut self, path: &str, message: impl Into<String>) {
+        self.mismatches.push(Mismatch {
+            path: path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn object<'v>(&mut self, value: &'v Value, path: &str) -> Option<&'v Map<String, Value>> {
+        let object = value.as_object();
+        if object.is_none() {
+            self.mismatch(path, "expected an object");
+        }
+        object
+    }
+
+    fn string<'v>(&mut self, value: &'v Value, path: &str) -> Option<&'v str> {
+        let string = value.as_str();
+        if string.is_none() {
+            self.mismatch(path, "expected a string");
+        }
+        string
+    }
+
+    fn element(&mut self, element: &mut SubmodelElement, value: &Value, path: &str) {
+        match element {
+            SubmodelElement::DataElement(element) => self.data_element(element, value, path),
+            SubmodelElement::Property(property) => self.data(&mut property.value, value, path),
+            SubmodelElement::MultiLanguageProperty(property) => {
+                self.multi_language_property(property, value, path)
+            }
+            SubmodelElement::Range(range) => self.range(range, value, path),
+            // content type and value are kept if the document leaves them out
+            SubmodelElement::File(file) => {
+                if let Some(object) = self.object(value, path) {
+                    if let Some(content_type) = self.content_type(object, path) {
+                        file.content_type = Some(content_type);
+                    }
+                    if let Some(value) = object.get("value") {
+                        file.value = self.uri(value, &join(path, "value"));
+                    }
+                }
+            }
+            SubmodelElement::Blob(blob) => {
+                if let Some(object) = self.object(value, path) {
+                    if let Some(content_type) = self.content_type(object, path) {
+                        blob.content_type = content_type;
+                    }
+                    if let Some(value) = object.get("value") {
+                        blob.value = self.bytes(value, &join(path, "value"));
+                    }
+                }
+            }
+            SubmodelElement::ReferenceElement(element) => {
+                element.value = self.optional_reference(Some(value), path)
+            }
+            SubmodelElement::RelationshipElement(element) => {
+                if let Some(object) = self.object(value, path) {
+                    element.first =
+                        self.optional_reference(object.get("first"), &join(path, "first"));
+                    element.second =
+                        self.optional_reference(object.get("second"), &join(path, "second"));
+                }
+            }
+            SubmodelElement::AnnotatedRelationshipElement(element) => {
+                self.annotated_relationship(element, value, path)
+            }
+            SubmodelElement::BasicEventElement(element) => {
+                let observed = self
+                    .object(value, path)
+                    .and_then(|object| object.get("observed"));
+                if let Some(observed) = self.optional_reference(observed, &join(path, "observed")) {
+                    element.observed = observed;
+                }
+            }
+            SubmodelElement::Entity(entity) => self.entity(entity, value, path),
+            SubmodelElement::SubmodelElementCollection(collection) => {
+                self.named(&mut collection.value, value, path)
+            }
+            SubmodelElement::SubmodelElementList(list) => self.list(list, value, path),
+            SubmodelElement::Capability(_) | SubmodelElement::Operation(_) => {
+                self.mismatch(path, format!("a {element} has no value"))
+            }
+        }
+    }
+
+    fn data_element(&mut self, element: &mut DataElement, value: &Value, path: &str) {
+        let mut submodel_element = SubmodelElement::from(element.clone());
+        self.element(&mut submodel_element, value, path);
+        if let Ok(data_element) = DataElement::try_from(submodel_element) {
+            *element = data_element;
+        }
+    }
+
+    fn data(&mut self, data: &mut DataXsd, value: &Value, path: &str) {
+        let data_type = DataTypeXSDef::from(&*data);
+        let lexical = match value {
+            Value::Null => {
+                *data = DataXsd::empty(data_type);
+                return;
+            }
+            Value::String(s) => s.clone(),
+            Value::Number(n) if is_numeric(&data_type) => n.to_string(),
+            Value::Bool(b) if data_type == DataTypeXSDef::Boolean => b.to_string(),
+            _ => return self.mismatch(path, format!("expected a value of type {data_type}")),
+        };
+
+        match DataXsd::try_from((data_type, Some(lexical))) {
+            Ok(parsed) => *data = parsed,
+            Err(e) => self.mismatch(path, e.to_string()),
+        }
+    }
+
+    fn multi_language_property(
+        &mut self,
+        property: &mut MultiLanguageProperty,
+        value: &Value,
+        path: &str,
+    ) {
+        let Some(array) = value.as_array() else {
+            return self.mismatch(path, "expected an array of language strings");
+        };
+
+        let mut strings = Vec::new();
+        for (i, entry) in array.iter().enumerate() {
+            let path = join(path, i);
+            let single = entry.as_object().filter(|o| o.len() == 1);
+            let Some((language, text)) = single.and_then(|o| o.iter().next()) else {
+                self.mismatch(&path, "expected an object with a single language");
+                continue;
+            };
+            let Some(text) = self.string(text, &join(&path, language)) else {
+                continue;
+            };
+
+            match LangString::try_new(language, text.to_string()) {
+                Ok(string)
+                    if strings
+                        .iter()
+                        .any(|s: &LangString| s.language == string.language) =>
+                {
+                    self.mismatch(&path, format!("language '{language}' is given twice"))
+                }
+                Ok(string) => strings.push(string),
+                Err(e) => self.mismatch(&path, e.to_string()),
+            }
+        }
+        property.value = Some(strings).filter(|strings| !strings.is_empty());
+    }
+
+    fn range(&mut self, range: &mut Range, value: &Value, path: &str) {
+        let Some(object) = self.object(value, path) else {
+            return;
+        };
+
+        let data_type = range.value_type();
+        let mut bound = |name: &str| {
+            let mut bound = DataXsd::empty(data_type.clone());
+            if let Some(value) = object.get(name) {
+                self.data(&mut bound, value, &join(path, name));
+            }
+            bound
+        };
+        let (min, max) = (bound("min"), bound("max"));
+
+        match RangeValue::from_bounds(min, max) {
+            Ok(value) => range.value = value,
+            Err(e) => self.mismatch(path, e.to_string()),
+        }
+    }
+
+    /// The content type is kept if the document has none.
+    fn content_type(&mut self, object: &Map<String, Value>, path: &str) -> Option<String> {
+        let path = join(path, "contentType");
+        object
+            .get("contentType")
+            .and_then(|value| self.string(value, &path))
+            .map(str::to_string)
+    }
+
+    fn uri(&mut self, value: &Value, path: &str) -> Option<Uri> {
+        if value.is_null() {
+            return None;
+        }
+        let value = self.string(value, path)?;
+        match Uri::new(value.as_bytes().to_vec()) {
+            Ok(uri) => Some(uri),
+            Err(_) => {
+                self.mismatch(path, format!("'{value}' is not a valid URI reference"));
+                None
+            }
+        }
+    }
+
+    fn bytes(&mut self, value: &Value, path: &str) -> Option<Vec<u8>> {
+        if value.is_null() {
+            return None;
+        }
+        match DeserializeAsWrap::<Vec<u8>, Base64>::deserialize(value) {
+            Ok(bytes) => Some(bytes.into_inner()),
+            Err(e) => {
+                self.mismatch(path, format!("invalid base64: {e}"));
+                None
+            }
+        }
+    }
+
+    fn optional_reference(&mut self, value: Option<&Value>, path: &str) -> Option<Reference> {
+        match value? {
+            Value::Null => None,
+            value => self.reference(value, path),
+        }
+    }
+
+    fn reference(&mut self, value: &Value, path: &str) -> Option<Reference> {
+        let object = self.object(value, path)?;
+
+        let mut keys = Vec::new();
+        let key_values = object.get("keys").and_then(Value::as_array);
+        for (i, key) in key_values.into_iter().flatten().enumerate() {
+            let path = join(&join(path, "keys"), i);
+            let ty = key.get("type").and_then(Value::as_str);
+            let value = key.get("value").and_then(Value::as_str);
+
+            match (ty.and_then(|ty| Key::from_str(ty).ok()), value) {
+                (Some(mut key), Some(value)) => {
+                    *key.value_mut() = value.to_string();
+                    keys.push(key);
+                }
+                _ => self.mismatch(&path, "expected a key with a valid type and a value"),
+            }
+        }
+
+        let mut inner = ReferenceInner::from_vec(keys);
+        if let Some(referred) = object.get("referredSemanticId") {
+            inner.referred_semantic_id = self
+                .reference(referred, &join(path, "referredSemanticId"))
+                .map(Box::new);
+        }
+
+        match object.get("type").and_then(Value::as_str) {
+            Some("ExternalReference") => Some(Reference::ExternalReference(inner)),
+            Some("ModelReference") => Some(Reference::ModelReference(inner)),
+            _ => {
+                self.mismatch(
+                    &join(path, "type"),
+                    "expected ExternalReference or ModelReference",
+                );
+                None
+            }
+        }
+    }
+
+    fn annotated_relationship(
+        &mut self,
+        element: &mut AnnotatedRelationshipElement,
+        value: &Value,
+        path: &str,
+    ) {
+        let Some(object) = self.object(value, path) else {
+            return;
+        };
+        element.first = self.optional_reference(object.get("first"), &join(path, "first"));
+        element.second = self.optional_reference(object.get("second"), &join(path, "second"));
+
+        let path = join(path, "annotations");
+        let Some(annotations) = object.get("annotations") else {
+            return;
+        };
+        let Some(annotations) = annotations.as_array() else {
+            return self.mismatch(&path, "expected an array");
+        };
+
+        for (i, annotation) in annotations.iter().enumerate() {
+            let path = join(&path, i);
+            let Some(annotation) = self.object(annotation, &path) else {
+                continue;
+            };
+
+            for (id_short, value) in annotation {
+                let target = element
+                    .annotations
+                    .iter_mut()
+                    .flatten()
+                    .find(|a| a.referable().id_short.as_deref() == Some(id_short.as_str()));
+                match target {
+                    Some(target) => self.data_element(target, value, &join(&path, id_short)),
+                    None => self.mismatch(
+                        &join(&path, id_short),
+                        format!("no annotation with idShort '{id_short}'"),
+                    ),
+                }
+            }
+        }
+    }
+
+    fn entity(&mut self, entity: &mut Entity, value: &Value, path: &str) {
+        let Some(object) = self.object(value, path) else {
+            return;
+        };
+
+        match object.get("entityType").and_then(Value::as_str) {
+            None => {}
+            Some(entity_type) => match Entity::from_str(entity_type) {
+                Ok(_) if entity_type == entity.to_string() => {}
+                Ok(Entity::CoManagedEntity(_)) | Ok(Entity::SelfManagedEntity(_)) => {
+                    let (Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner)) =
+                        entity;
+                    let inner = std::mem::take(inner);
+                    *entity = match entity_type {
+                        "CoManagedEntity" => Entity::CoManagedEntity(inner),
+                        _ => Entity::SelfManagedEntity(inner),
+                    };
+                }
+                Err(_) => self.mismatch(
+                    &join(path, "entityType"),
+                    "expected CoManagedEntity or SelfManagedEntity",
+                ),
+            },
+        }
+
+        let (Entity::CoManagedEntity(inner) | Entity::SelfManagedEntity(inner)) = entity;
+        if let Some(statements) = object.get("statements") {
+            self.named(&mut inner.statements, statements, &join(path, "statements"));
+        }
+
+        let global_asset_id_path = join(path, "globalAssetId");
+        inner.global_asset_id = object
+            .get("globalAssetId")
+            .and_then(|value| self.string(value, &global_asset_id_path))
+            .and_then(|id| match Identifier::try_from(id) {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    self.mismatch(&global_asset_id_path, e.to_string());
+                    None
+                }
+            });
+
+        if let Some(ids) = object.get("specificAssetIds") {
+            inner.specific_asset_id = self.specific_asset_ids(ids, &join(path, "specificAssetIds"));
+        }
+    }
+
+    fn specific_asset_ids(&mut self, value: &Value, path: &str) -> Option<Vec<SpecificAssetId>> {
+        let Some(array) = value.as_array() else {
+            self.mismatch(path, "expected an array");
+            return None;
+        };
+
+        let mut ids = Vec::new();
+        for (i, id) in array.iter().enumerate() {
+            let path = join(path, i);
+            let Some(object) = self.object(id, &path) else {
+                continue;
+            };
+
+            let name = object.get("name").and_then(Value::as_str);
+            let value = object.get("value").and_then(Value::as_str);
+            let (Some(Ok(name)), Some(Ok(value))) =
+                (name.map(Label::try_from), value.map(Identifier::try_from))
+            else {
+                self.mismatch(&path, "expected a valid name and value");
+                continue;
+            };
+
+            let mut id = SpecificAssetId {
+                has_semantics: Default::default(),
+                name,
+                value,
+                external_subject_id: self.optional_reference(
+                    object.get("externalSubjectId"),
+                    &join(&path, "externalSubjectId"),
+                ),
+            };
+            id.has_semantics.semantic_id =
+                self.optional_reference(object.get("semanticId"), &join(&path, "semanticId"));
+            ids.push(id);
+        }
+        Some(ids)
+    }
+
+    /// Children of a namespace, i.e. Submodel, collection or entity statements, by idShort.
+    fn named(&mut self, elements: &mut Option<Vec<SubmodelElement>>, value: &Value, path: &str) {
+        let Some(object) = self.object(value, path) else {
+            return;
+        };
+
+        for (id_short, value) in object {
+            let target = elements
+                .iter_mut()
+                .flatten()
+                .find(|e| e.referable().id_short.as_deref() == Some(id_short.as_str()));
+            match target {
+                Some(target) => self.element(target, value, &join(path, id_short)),
+                None => self.mismatch(
+                    &join(path, id_short),
+                    format!("no element with idShort '{id_short}'"),
+                ),
+            }
+        }
+    }
+
+    /// The document defines the length of the list. Additional entries use the last
+    /// existing element as schema.
+    fn list(&mut self, list: &mut SubmodelElementList, value: &Value, path: &str) {
+        let Some(array) = value.as_array() else {
+            return self.mismatch(path, "expected an array");
+        };
+        let schema = list.value.take().unwrap_or_default();
+
+        let mut elements = Vec::new();
+        for (i, value) in array.iter().enumerate() {
+            let path = join(path, i);
+            let Some(mut element) = schema.get(i).or(schema.last()).cloned() else {
+                self.mismatch(&path, "the list has no element to use as schema");
+                continue;
+            };
+            self.element(&mut element, value, &path);
+            elements.push(element);
+        }
+        list.value = Some(elements);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::fixtures::{self, property, referable};
+    use crate::part1::v3_1::id_short_path::ElementRef;
+    use crate::part1::v3_1::submodel_elements::{
+        AasSubmodelElements, Blob, EntityInner, File, RangeInner, ReferenceElement,
+        SubmodelElementCollection,
+    };
+    use serde_json::json;
+
+    fn submodel() -> Submodel {
+        let list = SubmodelElement::SubmodelElementList(SubmodelElementList {
+            referable: referable("Speeds"),
+            semantics: Default::default(),
+            qualifiable: Default::default(),
+            embedded_data_specifications: Default::default(),
+            is_order_relevant: true,
+            semantic_id_list_element: None,
+            value: Some(vec![property(None, DataXsd::Double(Some(1.5))).into()]),
+            type_value_list_element: AasSubmodelElements::Property,
+            value_type_list_element: Some(DataTypeXSDef::Double),
+        });
+        let mut range = Range::new(RangeValue::Int(RangeInner {
+            min: Some(1),
+            max: None,
+        }));
+        range.referable = referable("Limits");
+        let collection = SubmodelElement::SubmodelElementCollection(SubmodelElementCollection {
+            referable: referable("Motor"),
+            value: Some(vec![
+                property(Some("Active"), DataXsd::Boolean(Some(true))).into(),
+                property(Some("Name"), DataXsd::String(Some("M1".into()))).into(),
+                SubmodelElement::Range(range),
+                list,
+            ]),
+            ..Default::default()
+        });
+        let file = SubmodelElement::File(File {
+            referable: referable("Manual"),
+            value: Some(Uri::new(b"/aasx/manual.pdf".to_vec()).unwrap()),
+            content_type: Some("application/pdf".into()),
+            ..Default::default()
+        });
+        let mut blob = Blob::new(Some(b"aas".to_vec()), "text/plain".into());
+        blob.referable = referable("Data");
+        let mlp = SubmodelElement::MultiLanguageProperty(MultiLanguageProperty {
+            referable: referable("Title"),
+            value: Some(vec![LangString::try_new("en", "Motor".into()).unwrap()]),
+            ..Default::default()
+        });
+        let reference = SubmodelElement::ReferenceElement(ReferenceElement {
+            referable: referable("Ref"),
+            value: Some(Reference::ModelReference(ReferenceInner::new(
+                Key::Submodel("https://example.com/sm/2".into()),
+            ))),
+            ..Default::default()
+        });
+        let entity = SubmodelElement::Entity(Entity::SelfManagedEntity(EntityInner {
+            referable: referable("Part"),
+            statements: Some(vec![property(Some("Count"), DataXsd::Int(Some(2))).into()]),
+            global_asset_id: Some("https://example.com/asset/1".try_into().unwrap()),
+            ..Default::default()
+        }));
+
+        fixtures::submodel(vec![
+            collection,
+            file,
+            SubmodelElement::Blob(blob),
+            mlp,
+            reference,
+            entity,
+        ])
+    }
+
+    #[test]
+    fn serializes_submodel() {
+        let value: Value = serde_json::from_str(&submodel().to_json_value().unwrap()).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "Motor": {
+                    "Active": true,
+                    "Name": "M1",
+                    "Limits": { "min": 1 },
+                    "Speeds": [1.5],
+                },
+                "Manual": { "contentType": "application/pdf", "value": "/aasx/manual.pdf" },
+                "Data": { "contentType": "text/plain", "value": "YWFz" },
+                "Title": [{ "en": "Motor" }],
+                "Ref": {
+                    "type": "ModelReference",
+                    "keys": [{ "type": "Submodel", "value": "https://example.com/sm/2" }],
+                },
+                "Part": {
+                    "statements": { "Count": 2 },
+                    "entityType": "SelfManagedEntity",
+                    "globalAssetId": "https://example.com/asset/1",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn round_trip_with_schema() {
+        let schema = submodel();
+        let json = schema.to_json_value().unwrap();
+        assert_eq!(Submodel::from_json_value(&schema, &json).unwrap(), schema);
+
+        let updated = Submodel::from_json_value(
+            &schema,
+            r#"{"Motor": {"Name": "M2", "Speeds": [2, 3.5], "Limits": {"min": 0, "max": 10}}}"#,
+        )
+        .unwrap();
+        let value: Value = serde_json::from_str(&updated.to_json_value().unwrap()).unwrap();
+        assert_eq!(
+            value["Motor"],
+            json!({
+                "Active": true,
+                "Name": "M2",
+                "Limits": { "min": 0, "max": 10 },
+                "Speeds": [2, 3.5],
+            })
+        );
+    }
+
+    #[test]
+    fn reports_all_mismatches() {
+        let error = Submodel::from_json_value(
+            &submodel(),
+            r#"{"Motor": {"Active": "yes", "Limits": {"min": 1.5}}, "Title": ["en"], "Unknown": 1}"#,
+        )
+        .unwrap_err();
+
+        let ValueOnlyError::Mismatches(mismatches) = error else {
+            panic!("expected mismatches, got {error}");
+        };
+        let paths: Vec<_> = mismatches.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["/Motor/Active", "/Motor/Limits/min", "/Title/0", "/Unknown"]
+        );
+    }
+
+    #[test]
+    fn patch_is_atomic() {
+        let mut patched = submodel();
+        patched
+            .patch_value_only(r#"{"Motor": {"Active": false}, "Title": [{"de": "Antrieb"}]}"#)
+            .unwrap();
+        let value: Value = serde_json::from_str(&patched.to_json_value().unwrap()).unwrap();
+        assert_eq!(value["Motor"]["Active"], json!(false));
+        assert_eq!(value["Title"], json!([{ "de": "Antrieb" }]));
+        assert_eq!(value["Manual"]["contentType"], json!("application/pdf"));
+
+        let before = patched.clone();
+        let error = patched
+            .patch_value_only(
+                r#"{"Motor": {"Name": "M3", "Limits": {"max": "x"}}, "Title": [{"en": "a"}, {"en": "b"}]}"#,
+            )
+            .unwrap_err();
+        let ValueOnlyError::Mismatches(mismatches) = error else {
+            panic!("expected mismatches, got {error}");
+        };
+        let paths: Vec<_> = mismatches.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(paths, vec!["/Motor/Limits/max", "/Title/1"]);
+        assert_eq!(patched, before);
+    }
+
+    #[test]
+    fn patch_element_at_path() {
+        let mut patched = submodel();
+        let path = "Motor.Speeds[0]".parse::<IdShortPath>().unwrap();
+        patched.patch_value_only_at(&path, "2.5").unwrap();
+        assert_eq!(
+            patched.get(&path),
+            Some(ElementRef::SubmodelElement(
+                &property(None, DataXsd::Double(Some(2.5))).into()
+            ))
+        );
+
+        let before = patched.clone();
+        assert!(matches!(
+            patched.patch_value_only_at(&path, r#""fast""#),
+            Err(ValueOnlyError::Mismatches(_))
+        ));
+        let missing = "Motor.Torque".parse::<IdShortPath>().unwrap();
+        assert!(matches!(
+            patched.patch_value_only_at(&missing, "1"),
+            Err(ValueOnlyError::NotFound(_))
+        ));
+        assert_eq!(patched, before);
+    }
+
+    #[test]
+    fn patch_keeps_missing_values() {
+        let mut patched = submodel();
+        patched
+            .patch_value_only(r#"{"Manual": {"contentType": "text/plain"}, "Title": []}"#)
+            .unwrap();
+        let Some(ElementRef::SubmodelElement(SubmodelElement::File(file))) =
+            patched.get(&"Manual".parse().unwrap())
+        else {
+            panic!("expected the file");
+        };
+        assert_eq!(file.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(
+            file.value.as_ref().map(|uri| uri.as_str()),
+            Some("/aasx/manual.pdf")
+        );
+        let Some(ElementRef::SubmodelElement(SubmodelElement::MultiLanguageProperty(title))) =
+            patched.get(&"Title".parse().unwrap())
+        else {
+            panic!("expected the title");
+        };
+        assert_eq!(title.value, None);
+
+        patched
+            .patch_value_only(r#"{"Manual": {"value": null}}"#)
+            .unwrap();
+        let value: Value = serde_json::from_str(&patched.to_json_value().unwrap()).unwrap();
+        assert_eq!(value["Manual"], json!({ "contentType": "text/plain" }));
+    }
+
+    #[test]
+    fn values_outside_their_value_space() {
+        let invalid = property(Some("Offset"), DataXsd::NegativeInteger(Some(5.into())));
+        assert!(matches!(
+            SubmodelElement::from(invalid.clone()).to_json_value(),
+            Err(ValueOnlyError::Conversion(
+                ConversionError::OutOfRange { .. }
+            ))
+        ));
+        assert!(matches!(
+            fixtures::submodel(vec![invalid.into()]).to_json_value(),
+            Err(ValueOnlyError::Conversion(_))
+        ));
+    }
+
+    #[test]
+    fn elements_without_value() {
+        let capability = SubmodelElement::Capability(Default::default());
+        assert!(matches!(
+            capability.to_json_value(),
+            Err(ValueOnlyError::NoValue(_))
+        ));
+    }
+}
@@ -0,0 +1,666 @@
+//! Walks over an [Environment], a [Submodel] or a [SubmodelElement] and calls a [Visitor]
+//! (or [VisitorMut]) for every element on the way.
+//!
+//! All containers are descended into: collections, lists, entity statements,
+//! annotations of annotated relationship elements and operation variables.
+//! Elements are visited in pre-order; [Visitor::leave_element] is called after the children.
+
+use crate::part1::v3_1::attributes::referable::Referable;
+use crate::part1::v3_1::concept_description::ConceptDescription;
+use crate::part1::v3_1::core::{AssetAdministrationShell, Submodel};
+use crate::part1::v3_1::environment::Environment;
+use crate::part1::v3_1::id_short_path::{ElementMut, ElementRef, IdShortPath, PathSegment};
+use crate::part1::v3_1::primitives::Identifier;
+use crate::part1::v3_1::submodel_elements::{Entity, OperationVariable, SubmodelElement};
+
+/// Returned by the visitor to control the walk.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Flow {
+    #[default]
+    Continue,
+
+    /// Do not descend into the children of the current node
+    SkipChildren,
+
+    /// End the walk
+    Stop,
+}
+
+/// The container a visited element is part of.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Parent {
+    /// The element is where the walk started
+    Root,
+
+    Submodel,
+
+    SubmodelElementCollection,
+
+    SubmodelElementList,
+
+    /// The element is a statement of the entity
+    Entity,
+
+    /// The element is an annotation
+    AnnotatedRelationshipElement,
+
+    /// The element is the value of an input, output or inoutput variable
+    Operation,
+}
+
+/// Where a visited element is located.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Location<'p> {
+    /// Id of the submodel the element belongs to, `None` when the walk started at the element
+    pub submodel: Option<&'p Identifier>,
+
+    /// Path of the element relative to the submodel, or to the element the walk started at.
+    /// Operation variables are addressed by the idShort of their value.
+    pub path: &'p IdShortPath,
+
+    pub parent: Parent,
+}
+
+impl Location<'_> {
+    /// Number of containers above the element, 0 for top level elements
+    pub fn depth(&self) -> usize {
+        self.path.segments().len() - 1
+    }
+}
+
+/// Callbacks of a walk over borrowed elements. All methods default to [Flow::Continue],
+/// so a visitor only implements the ones it needs.
+///
+/// The lifetime allows the visitor to keep references to the visited elements.
+pub trait Visitor<'a> {
+    fn visit_shell(&mut self, _shell: &'a AssetAdministrationShell) -> Flow {
+        Flow::Continue
+    }
+
+    /// Called before the elements of the submodel are visited.
+    fn visit_submodel(&mut self, _submodel: &'a Submodel) -> Flow {
+        Flow::Continue
+    }
+
+    fn leave_submodel(&mut self, _submodel: &'a Submodel) {}
+
+    fn visit_element(&mut self, _element: ElementRef<'a>, _location: &Location) -> Flow {
+        Flow::Continue
+    }
+
+    /// Called after the children of the element have been visited, or skipped.
+    fn leave_element(&mut self, _element: ElementRef<'a>, _location: &Location) {}
+
+    fn visit_concept_description(&mut self, _concept_description: &'a ConceptDescription) -> Flow {
+        Flow::Continue
+    }
+}
+
+/// Mutable counterpart of [Visitor]. Children are visited after the changes to their parent,
+/// so e.g. an element replaced in [VisitorMut::visit_element] is descended into with its new content.
+pub trait VisitorMut {
+    fn visit_shell(&mut self, _shell: &mut AssetAdministrationShell) -> Flow {
+        Flow::Continue
+    }
+
+    fn visit_submodel(&mut self, _submodel: &mut Submodel) -> Flow {
+        Flow::Continue
+    }
+
+    fn leave_submodel(&mut self, _submodel: &mut Submodel) {}
+
+    fn visit_element(&mut self, _element: ElementMut<'_>, _location: &Location) -> Flow {
+        Flow::Continue
+    }
+
+    fn leave_element(&mut self, _element: ElementMut<'_>, _location: &Location) {}
+
+    fn visit_concept_description(&mut self, _concept_description: &mut ConceptDescription) -> Flow {
+        Flow::Continue
+    }
+}
+
+/// Path segment of an element addressed by idShort.
+/// Elements without idShort violate AASd-117, they get an empty segment so they are still visited.
+fn id_short_segment(referable: &Referable) -> PathSegment {
+    PathSegment::IdShort(
+        referable
+            .id_short
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+    )
+}
+
+fn variables(
+    variables: [&Option<Vec<OperationVariable>>; 3],
+) -> impl Iterator<Item = &OperationVariable> {
+    variables.into_iter().flatten().flatten()
+}
+
+// ---------------------------------------------------------------------------
+// immutable walk
+
+fn walk_element<'a>(
+    element: ElementRef<'a>,
+    location: &Location,
+    visitor: &mut impl Visitor<'a>,
+) -> Flow {
+    match visitor.visit_element(element, location) {
+        Flow::Stop => return Flow::Stop,
+        Flow::SkipChildren => {}
+        Flow::Continue => {
+            if let ElementRef::SubmodelElement(element) = element
+                && walk_children(element, location, visitor) == Flow::Stop
+            {
+                return Flow::Stop;
+            }
+        }
+    }
+
+    visitor.leave_element(element, location);
+    Flow::Continue
+}
+
+fn walk_children<'a>(
+    element: &'a SubmodelElement,
+    location: &Location,
+    visitor: &mut impl Visitor<'a>,
+) -> Flow {
+    let (parent, children): (_, Vec<_>) = match element {
+        SubmodelElement::SubmodelElementCollection(collection) => (
+            Parent::SubmodelElementCollection,
+            named(collection.value.iter().flatten()),
+        ),
+        SubmodelElement::SubmodelElementList(list) => (
+            Parent::SubmodelElementList,
+            list.value
+                .iter()
+                .flatten()
+                .enumerate()
+                .map(|(i, element)| (PathSegment::Index(i), ElementRef::SubmodelElement(element)))
+                .collect(),
+        ),
+        SubmodelElement::Entity(Entity::CoManagedEntity(entity))
+        | SubmodelElement::Entity(Entity::SelfManagedEntity(entity)) => {
+            (Parent::Entity, named(entity.statements.iter().flatten()))
+        }
+        SubmodelElement::AnnotatedRelationshipElement(element) => (
+            Parent::AnnotatedRelationshipElement,
+            element
+                .annotations
+                .iter()
+                .flatten()
+                .map(|annotation| {
+                    (
+                        id_short_segment(annotation.referable()),
+                        ElementRef::Annotation(annotation),
+                    )
+                })
+                .collect(),
+        ),
+        SubmodelElement::Operation(operation) => (
+            Parent::Operation,
+            named(
+                variables([
+                    &operation.input_variables,
+                    &operation.output_variables,
+                    &operation.inoutput_variables,
+                ])
+                .map(|variable| &variable.value),
+            ),
+        ),
+        _ => return Flow::Continue,
+    };
+
+    for (segment, child) in children {
+        let path = location.path.join(segment);
+        let location = Location {
+            submodel: location.submodel,
+            path: &path,
+            parent,
+        };
+        if walk_element(child, &location, visitor) == Flow::Stop {
+            return Flow::Stop;
+        }
+    }
+
+    Flow::Continue
+}
+
+fn named<'a>(
+    elements: impl Iterator<Item = &'a SubmodelElement>,
+) -> Vec<(PathSegment, ElementRef<'a>)> {
+    elements
+        .map(|element| {
+            (
+                id_short_segment(element.referable()),
+                ElementRef::SubmodelElement(element),
+            )
+        })
+        .collect()
+}
+
+impl Environment {
+    /// Walks over all shells, submodels with their elements and concept descriptions.
+    pub fn walk<'a>(&'a self, visitor: &mut impl Visitor<'a>) -> Flow {
+        for shell in self.asset_administration_shells.iter().flatten() {
+            if visitor.visit_shell(shell) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        for submodel in self.submodels.iter().flatten() {
+            if submodel.walk(visitor) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        for concept_description in self.concept_descriptions.iter().flatten() {
+            if visitor.visit_concept_description(concept_description) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        Flow::Continue
+    }
+
+    pub fn walk_mut(&mut self, visitor: &mut impl VisitorMut) -> Flow {
+        for shell in self.asset_administration_shells.iter_mut().flatten() {
+            if visitor.visit_shell(shell) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        for submodel in self.submodels.iter_mut().flatten() {
+            if submodel.walk_mut(visitor) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        for concept_description in self.concept_descriptions.iter_mut().flatten() {
+            if visitor.visit_concept_description(concept_description) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+
+        Flow::Continue
+    }
+}
+
+impl Submodel {
+    /// Walks over the submodel and all its elements.
+    pub fn walk<'a>(&'a self, visitor: &mut impl Visitor<'a>) -> Flow {
+        match visitor.visit_submodel(self) {
+            Flow::Stop => return Flow::Stop,
+            Flow::SkipChildren => {}
+            Flow::Continue => {
+                for (segment, element) in named(self.submodel_elements.iter().flatten()) {
+                    let path = IdShortPath::from(segment);
+                    let location = Location {
+                        submodel: Some(&self.identifiable.id),
+                        path: &path,
+                        parent: Parent::Submodel,
+                    };
+                    if walk_element(element, &location, visitor) == Flow::Stop {
+                        return Flow::Stop;
+                    }
+                }
+            }
+        }
+
+        visitor.leave_submodel(self);
+        Flow::Continue
+    }
+
+    pub fn walk_mut(&mut self, visitor: &mut impl VisitorMut) -> Flow {
+        match visitor.visit_submodel(self) {
+            Flow::Stop => return Flow::Stop,
+            Flow::SkipChildren => {}
+            Flow::Continue => {
+                let id = self.identifiable.id.clone();
+                for element in self.submodel_elements.iter_mut().flatten() {
+                    let path = IdShortPath::from(id_short_segment(element.referable()));
+                    let location = Location {
+                        submodel: Some(&id),
+                        path: &path,
+                        parent: Parent::Submodel,
+                    };
+                    if walk_element_mut(element, &location, visitor) == Flow::Stop {
+                        return Flow::Stop;
+                    }
+                }
+            }
+        }
+
+        visitor.leave_submodel(self);
+        Flow::Continue
+    }
+}
+
+impl SubmodelElement {
+    /// Walks over the element and everything below it.
+    /// Paths are relative to the element, which is addressed by its idShort.
+    pub fn walk<'a>(&'a self, visitor: &mut impl Visitor<'a>) -> Flow {
+        let path = IdShortPath::from(id_short_segment(self.referable()));
+        let location = Location {
+            submodel: None,
+            path: &path,
+            parent: Parent::Root,
+        };
+        walk_element(ElementRef::SubmodelElement(self), &location, visitor)
+    }
+
+    pub fn walk_mut(&mut self, visitor: &mut impl VisitorMut) -> Flow {
+        let path = IdShortPath::from(id_short_segment(self.referable()));
+        let location = Location {
+            submodel: None,
+            path: &path,
+            parent: Parent::Root,
+        };
+        walk_element_mut(self, &location, visitor)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// mutable walk
+
+fn walk_element_mut(
+    element: &mut SubmodelElement,
+    location: &Location,
+    visitor: &mut impl VisitorMut,
+) -> Flow {
+    match visitor.visit_element(ElementMut::SubmodelElement(element), location) {
+        Flow::Stop => return Flow::Stop,
+        Flow::SkipChildren => {}
+        Flow::Continue => {
+            if walk_children_mut(element, location, visitor) == Flow::Stop {
+                return Flow::Stop;
+            }
+        }
+    }
+
+    visitor.leave_element(ElementMut::SubmodelElement(element), location);
+    Flow::Continue
+}
+
+fn walk_children_mut(
+    element: &mut SubmodelElement,
+    location: &Location,
+    visitor: &mut impl VisitorMut,
+) -> Flow {
+    let flow = match element {
+        SubmodelElement::SubmodelElementCollection(collection) => {
+            collection.value.iter_mut().flatten().try_for_each(|child| {
+                let segment = id_short_segment(child.referable());
+                walk_child_mut(
+                    segment,
+                    Parent::SubmodelElementCollection,
+                    child,
+                    location,
+                    visitor,
+                )
+            })
+        }
+        SubmodelElement::SubmodelElementList(list) => list
+            .value
+            .iter_mut()
+            .flatten()
+            .enumerate()
+            .try_for_each(|(i, child)| {
+                walk_child_mut(
+                    PathSegment::Index(i),
+                    Parent::SubmodelElementList,
+                    child,
+                    location,
+                    visitor,
+                )
+            }),
+        SubmodelElement::Entity(Entity::CoManagedEntity(entity))
+        | SubmodelElement::Entity(Entity::SelfManagedEntity(entity)) => entity
+            .statements
+            .iter_mut()
+            .flatten()
+            .try_for_each(|child| {
+                let segment = id_short_segment(child.referable());
+                walk_child_mut(segment, Parent::Entity, child, location, visitor)
+            }),
+        SubmodelElement::Operation(operation) => [
+            &mut operation.input_variables,
+            &mut operation.output_variables,
+            &mut operation.inoutput_variables,
+        ]
+        .into_iter()
+        .flatten()
+        .flatten()
+        .try_for_each(|variable| {
+            let segment = id_short_segment(variable.value.referable());
+            walk_child_mut(
+                segment,
+                Parent::Operation,
+                &mut variable.value,
+                location,
+                visitor,
+            )
+        }),
+        SubmodelElement::AnnotatedRelationshipElement(element) => element
+            .annotations
+            .iter_mut()
+            .flatten()
+            .try_for_each(|annotation| {
+                let path = location.path.join(id_short_segment(annotation.referable()));
+                let location = Location {
+                    submodel: location.submodel,
+                    path: &path,
+                    parent: Parent::AnnotatedRelationshipElement,
+                };
+                let flow = visitor.visit_element(ElementMut::Annotation(annotation), &location);
+                if flow != Flow::Stop {
+                    visitor.leave_element(ElementMut::Annotation(annotation), &location);
+                }
+                stop(flow)
+            }),
+        _ => Ok(()),
+    };
+
+    match flow {
+        Ok(()) => Flow::Continue,
+        Err(()) => Flow::Stop,
+    }
+}
+
+fn walk_child_mut(
+    segment: PathSegment,
+    parent: Parent,
+    child: &mut SubmodelElement,
+    location: &Location,
+    visitor: &mut impl VisitorMut,
+) -> Result<(), ()> {
+    let path = location.path.join(segment);
+    let location = Location {
+        submodel: location.submodel,
+        path: &path,
+        parent,
+    };
+    stop(walk_element_mut(child, &location, visitor))
+}
+
+/// Maps [Flow::Stop] to an error, to end `try_for_each` loops early.
+fn stop(flow: Flow) -> Result<(), ()> {
+    match flow {
+        Flow::Stop => Err(()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1::v3_1::fixtures::{self, int_property, referable};
+    use crate::part1::v3_1::primitives::data_type_def_xs::DataXsd;
+    use crate::part1::v3_1::submodel_elements::{
+        AnnotatedRelationshipElement, DataElement, EntityInner, Operation,
+        SubmodelElementCollection,
+    };
+
+    fn submodel() -> Submodel {
+        let operation = SubmodelElement::Operation(Operation {
+            referable: referable("Start"),
+            input_variables: Some(vec![OperationVariable {
+                value: SubmodelElement::Property(int_property(Some("Speed"), 1)),
+            }]),
+            ..Default::default()
+        });
+        let relationship =
+            SubmodelElement::AnnotatedRelationshipElement(AnnotatedRelationshipElement {
+                referable: referable("Link"),
+                semantics: Default::default(),
+                qualifiable: Default::default(),
+                embedded_data_specifications: Default::default(),
+                first: None,
+                second: None,
+                annotations: Some(vec![DataElement::Property(int_property(Some("Note"), 2))]),
+            });
+        let entity = SubmodelElement::Entity(Entity::SelfManagedEntity(EntityInner {
+            referable: referable("Part"),
+            statements: Some(vec![
+                SubmodelElement::Property(int_property(Some("Count"), 3)),
+                relationship,
+            ]),
+            ..Default::default()
+        }));
+        let collection = SubmodelElement::SubmodelElementCollection(SubmodelElementCollection {
+            referable: referable("Motor"),
+            value: Some(vec![operation, entity]),
+            ..Default::default()
+        });
+
+        fixtures::submodel(vec![collection])
+    }
+
+    /// Records the visited paths and stops or skips at the given idShorts.
+    #[derive(Default)]
+    struct Recorder {
+        paths: Vec<String>,
+        skip: Option<&'static str>,
+        stop: Option<&'static str>,
+    }
+
+    impl Recorder {
+        fn visit(&mut self, id_short: Option<&str>, location: &Location) -> Flow {
+            self.paths
+                .push(format!("{}:{:?}", location.path, location.parent));
+            match id_short {
+                Some(id_short) if Some(id_short) == self.stop => Flow::Stop,
+                Some(id_short) if Some(id_short) == self.skip => Flow::SkipChildren,
+                _ => Flow::Continue,
+            }
+        }
+    }
+
+    impl<'a> Visitor<'a> for Recorder {
+        fn visit_element(&mut self, element: ElementRef<'a>, location: &Location) -> Flow {
+            self.visit(element.referable().id_short.as_deref(), location)
+        }
+    }
+
+    impl VisitorMut for Recorder {
+        fn visit_element(&mut self, element: ElementMut<'_>, location: &Location) -> Flow {
+            let id_short = match &element {
+                ElementMut::SubmodelElement(element) => element.referable().id_short.clone(),
+                ElementMut::Annotation(annotation) => annotation.referable().id_short.clone(),
+            };
+            self.visit(id_short.as_deref(), location)
+        }
+    }
+
+    #[test]
+    fn visits_every_container() {
+        let mut recorder = Recorder::default();
+        assert_eq!(submodel().walk(&mut recorder), Flow::Continue);
+
+        assert_eq!(
+            recorder.paths,
+            [
+                "Motor:Submodel",
+                "Motor.Start:SubmodelElementCollection",
+                "Motor.Start.Speed:Operation",
+                "Motor.Part:SubmodelElementCollection",
+                "Motor.Part.Count:Entity",
+                "Motor.Part.Link:Entity",
+                "Motor.Part.Link.Note:AnnotatedRelationshipElement",
+            ]
+        );
+
+        let mut recorder_mut = Recorder::default();
+        submodel().walk_mut(&mut recorder_mut);
+        assert_eq!(recorder_mut.paths, recorder.paths);
+    }
+
+    #[test]
+    fn skip_and_stop() {
+        let mut recorder = Recorder {
+            skip: Some("Start"),
+            stop: Some("Count"),
+            ..Default::default()
+        };
+        assert_eq!(submodel().walk_mut(&mut recorder), Flow::Stop);
+
+        assert_eq!(
+            recorder.paths,
+            [
+                "Motor:Submodel",
+                "Motor.Start:SubmodelElementCollection",
+                "Motor.Part:SubmodelElementCollection",
+                "Motor.Part.Count:Entity",
+            ]
+        );
+    }
+
+    #[test]
+    fn mutate_elements() {
+        struct Increment;
+
+        impl VisitorMut for Increment {
+            fn visit_element(&mut self, element: ElementMut<'_>, _: &Location) -> Flow {
+                match element {
+                    ElementMut::SubmodelElement(SubmodelElement::Property(property))
+                    | ElementMut::Annotation(DataElement::Property(property)) => {
+                        if let DataXsd::Int(Some(value)) = &mut property.value {
+                            *value += 10;
+                        }
+                    }
+                    _ => {}
+                }
+                Flow::Continue
+            }
+        }
+
+        let mut submodel = submodel();
+        submodel.walk_mut(&mut Increment);
+
+        let mut values = Vec::new();
+        struct Collect<'v>(&'v mut Vec<String>);
+        impl<'a> Visitor<'a> for Collect<'_> {
+            fn visit_element(&mut self, element: ElementRef<'a>, location: &Location) -> Flow {
+                if let ElementRef::SubmodelElement(SubmodelElement::Property(property))
+                | ElementRef::Annotation(DataElement::Property(property)) = element
+                {
+                    self.0
+                        .push(format!("{}={:?}", location.path, property.value));
+                }
+                Flow::Continue
+            }
+        }
+        submodel.walk(&mut Collect(&mut values));
+
+        assert_eq!(
+            values,
+            [
+                "Motor.Start.Speed=Int(Some(11))",
+                "Motor.Part.Count=Int(Some(13))",
+                "Motor.Part.Link.Note=Int(Some(12))",
+            ]
+        );
+    }
+}